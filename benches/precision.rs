@@ -0,0 +1,65 @@
+//! Compare the `Precision` policies used to normalize vectors.
+//!
+//! Run with `cargo bench --features fast-rsqrt --bench precision`.
+
+use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion};
+use math_vector::protocol::inv_sqrt;
+use math_vector::{Precision, Vector};
+
+const POLICIES: [(&str, Precision); 3] = [
+    ("exact", Precision::Exact),
+    ("fast", Precision::Fast),
+    ("hardware", Precision::Hardware),
+];
+
+fn vectors<F: numeric::Float>(n: usize) -> Vec<Vector<F>> {
+    (0..n)
+        .map(|i| {
+            let t = F::from(i as f64 * 0.37).unwrap();
+            Vector::new(t.cos(), t.sin(), t)
+        })
+        .collect()
+}
+
+fn scalar(c: &mut Criterion) {
+    let mut group = c.benchmark_group("inv_sqrt");
+    for (name, precision) in POLICIES {
+        group.bench_function(BenchmarkId::new("f32", name), |b| {
+            b.iter(|| inv_sqrt(black_box(1.2345f32), precision))
+        });
+        group.bench_function(BenchmarkId::new("f64", name), |b| {
+            b.iter(|| inv_sqrt(black_box(1.2345f64), precision))
+        });
+    }
+    group.finish();
+}
+
+fn normalize(c: &mut Criterion) {
+    let mut group = c.benchmark_group("normalize");
+    let v32 = vectors::<f32>(10_000);
+    let v64 = vectors::<f64>(10_000);
+    for (name, precision) in POLICIES {
+        group.bench_function(BenchmarkId::new("f32", name), |b| {
+            b.iter(|| {
+                let mut v = v32.clone();
+                for v in v.iter_mut() {
+                    v.normalize_with(precision);
+                }
+                black_box(v)
+            })
+        });
+        group.bench_function(BenchmarkId::new("f64", name), |b| {
+            b.iter(|| {
+                let mut v = v64.clone();
+                for v in v.iter_mut() {
+                    v.normalize_with(precision);
+                }
+                black_box(v)
+            })
+        });
+    }
+    group.finish();
+}
+
+criterion_group!(benches, scalar, normalize);
+criterion_main!(benches);
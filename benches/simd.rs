@@ -0,0 +1,100 @@
+//! Compare `Vector<f32>` with `SimdVector` on the operations particle
+//! simulations spend their time in.
+//!
+//! Run with `cargo bench --features simd` to use the SSE2/NEON backend, and
+//! without the feature to measure the scalar fallback.
+
+use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion};
+use math_vector::simd::{add_scaled_slice, dot_slice, normalize_slice};
+use math_vector::{SimdVector, Vector};
+
+const SIZES: [usize; 2] = [1_000, 100_000];
+
+fn vectors(n: usize) -> Vec<Vector<f32>> {
+    (0..n)
+        .map(|i| {
+            let t = i as f32 * 0.37;
+            Vector::new(t.cos() * 3.0, t.sin() * 2.0, t * 0.01 + 1.0)
+        })
+        .collect()
+}
+
+fn dot(c: &mut Criterion) {
+    let mut group = c.benchmark_group("dot");
+    for n in SIZES {
+        let a = vectors(n);
+        let b: Vec<_> = a.iter().rev().copied().collect();
+        let mut out = vec![0.0f32; n];
+        group.bench_with_input(BenchmarkId::new("Vector", n), &n, |bench, _| {
+            bench.iter(|| {
+                for ((a, b), out) in a.iter().zip(&b).zip(out.iter_mut()) {
+                    *out = a.dot(*b);
+                }
+                black_box(&mut out);
+            })
+        });
+
+        let sa: Vec<SimdVector> = a.iter().map(|&v| v.into()).collect();
+        let sb: Vec<SimdVector> = b.iter().map(|&v| v.into()).collect();
+        group.bench_with_input(BenchmarkId::new("SimdVector", n), &n, |bench, _| {
+            bench.iter(|| {
+                dot_slice(&sa, &sb, &mut out);
+                black_box(&mut out);
+            })
+        });
+    }
+    group.finish();
+}
+
+fn normalize(c: &mut Criterion) {
+    let mut group = c.benchmark_group("normalize");
+    for n in SIZES {
+        let mut a = vectors(n);
+        group.bench_with_input(BenchmarkId::new("Vector", n), &n, |bench, _| {
+            bench.iter(|| {
+                for v in a.iter_mut() {
+                    v.normalize();
+                }
+                black_box(&mut a);
+            })
+        });
+
+        let mut sa: Vec<SimdVector> = vectors(n).into_iter().map(|v| v.into()).collect();
+        group.bench_with_input(BenchmarkId::new("SimdVector", n), &n, |bench, _| {
+            bench.iter(|| {
+                normalize_slice(&mut sa);
+                black_box(&mut sa);
+            })
+        });
+    }
+    group.finish();
+}
+
+fn euler_step(c: &mut Criterion) {
+    let mut group = c.benchmark_group("euler_step");
+    for n in SIZES {
+        let mut positions = vectors(n);
+        let velocities: Vec<_> = positions.iter().map(|v| v.cross(*v + 1.0)).collect();
+        group.bench_with_input(BenchmarkId::new("Vector", n), &n, |bench, _| {
+            bench.iter(|| {
+                for (p, v) in positions.iter_mut().zip(&velocities) {
+                    *p += *v * black_box(0.016);
+                }
+                black_box(&mut positions);
+            })
+        });
+
+        let mut sp: Vec<SimdVector> = vectors(n).into_iter().map(|v| v.into()).collect();
+        let sv: Vec<SimdVector> = velocities.iter().map(|&v| v.into()).collect();
+        group.bench_with_input(BenchmarkId::new("SimdVector", n), &n, |bench, _| {
+            bench.iter(|| {
+                add_scaled_slice(&mut sp, &sv, black_box(0.016));
+                black_box(&mut sp);
+            })
+        });
+    }
+    group.finish();
+}
+
+criterion_group!(benches, dot, normalize, euler_step);
+criterion_main!(benches);
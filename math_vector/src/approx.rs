@@ -0,0 +1,36 @@
+//! Approximate equality of the floating point types, component by component.
+
+use crate::matrix::{Matrix3, Matrix4};
+use crate::quaternion::Quaternion;
+use crate::transform::Transform;
+use crate::vector::Vector;
+use crate::vector2::Vector2;
+use crate::vector4::Vector4;
+use numeric::approx::{ApproxEq, Tolerance};
+use numeric::Float;
+
+macro_rules! approx_eq_impl {
+    ($($name:ident { $($field:ident),+ })*) => {$(
+        impl<F: Float + ApproxEq> ApproxEq for $name<F> {
+            type Epsilon = F::Epsilon;
+
+            fn default_tolerance() -> Tolerance<F::Epsilon> {
+                F::default_tolerance()
+            }
+
+            fn approx_eq(&self, other: &Self, tolerance: Tolerance<F::Epsilon>) -> bool {
+                $(self.$field.approx_eq(&other.$field, tolerance))&&+
+            }
+        }
+    )*};
+}
+
+approx_eq_impl! {
+    Vector2 { x, y }
+    Vector { x, y, z }
+    Vector4 { x, y, z, w }
+    Quaternion { x, y, z, w }
+    Matrix3 { cols }
+    Matrix4 { cols }
+    Transform { position, rotation, scale }
+}
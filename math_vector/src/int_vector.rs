@@ -0,0 +1,435 @@
+use crate::vector::Vector;
+use crate::vector2::Vector2;
+use core::fmt::{Debug, Display, Result};
+use numeric::{
+    CheckedAdd, CheckedDiv, CheckedMul, CheckedSub, Float, PrimInt, SaturatingAdd, SaturatingMul,
+    SaturatingSub, WrappingAdd, WrappingMul, WrappingSub,
+};
+
+/// A 3D vector of integers, containing an `x`, `y` and a `z` value.
+///
+/// Use it for voxel positions or chunk indices, where `Vector` would accumulate
+/// rounding errors. The plain operators behave like the underlying integer
+/// type, the `Checked*`, `Wrapping*` and `Saturating*` traits from `numeric`
+/// are implemented component-wise to control overflows.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub struct IntVector<I: PrimInt> {
+    pub x: I,
+    pub y: I,
+    pub z: I,
+}
+
+/// A 2D vector of integers, containing an `x` and a `y` value.
+///
+/// Use it for pixel coordinates or tile indices, see `IntVector`.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub struct IntVector2<I: PrimInt> {
+    pub x: I,
+    pub y: I,
+}
+
+/// An alias of `IntVector`, for symmetry with `IntVector2`.
+pub type IntVector3<I> = IntVector<I>;
+
+/// How floating point components are turned into integers.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub enum Rounding {
+    /// Round towards negative infinity, which is what you want to find the
+    /// cell containing a point.
+    Floor,
+    /// Round towards positive infinity.
+    Ceil,
+    /// Round to the nearest integer, half-way cases away from zero.
+    Round,
+    /// Round towards zero, like an `as` cast.
+    Truncate,
+}
+
+impl Rounding {
+    fn apply<F: Float>(self, value: F) -> F {
+        match self {
+            Rounding::Floor => value.floor(),
+            Rounding::Ceil => value.ceil(),
+            Rounding::Round => value.round(),
+            Rounding::Truncate => value.trunc(),
+        }
+    }
+}
+
+/// Return `|a - b|`, or `None` if it does not fit in `I`.
+fn abs_diff<I: PrimInt>(a: I, b: I) -> Option<I> {
+    if a > b {
+        a.checked_sub(&b)
+    } else {
+        b.checked_sub(&a)
+    }
+}
+
+impl<I: PrimInt + Debug> Display for IntVector<I> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> Result {
+        write!(f, "IntVector({:?}, {:?}, {:?})", self.x, self.y, self.z)
+    }
+}
+
+impl<I: PrimInt + Debug> Display for IntVector2<I> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> Result {
+        write!(f, "IntVector2({:?}, {:?})", self.x, self.y)
+    }
+}
+
+impl<I: PrimInt> IntVector<I> {
+    /// Create a new vector.
+    pub fn new(x: I, y: I, z: I) -> Self {
+        Self { x, y, z }
+    }
+
+    /// Drop the `z` component of the vector.
+    pub fn truncate(self) -> IntVector2<I> {
+        IntVector2::new(self.x, self.y)
+    }
+
+    /// Convert a floating point vector, rounding each component with the given policy.
+    /// Returns `None` if a component is not finite or does not fit in `I`.
+    ///
+    /// # Example
+    /// ```
+    /// use math_vector::{IntVector, Rounding, Vector};
+    /// let v = Vector::new(1.5, -1.5, 2.0);
+    /// assert_eq!(IntVector::from_vector(v, Rounding::Floor), Some(IntVector::new(1, -2, 2)));
+    /// assert_eq!(IntVector::from_vector(v, Rounding::Truncate), Some(IntVector::new(1, -1, 2)));
+    /// assert_eq!(IntVector::<u8>::from_vector(v, Rounding::Round), None);
+    /// ```
+    pub fn from_vector<F: Float>(v: Vector<F>, rounding: Rounding) -> Option<Self> {
+        Some(Self {
+            x: I::from(rounding.apply(v.x))?,
+            y: I::from(rounding.apply(v.y))?,
+            z: I::from(rounding.apply(v.z))?,
+        })
+    }
+
+    /// Convert to a floating point vector.
+    pub fn to_vector<F: Float>(self) -> Vector<F> {
+        Vector::new(
+            F::from(self.x).unwrap(),
+            F::from(self.y).unwrap(),
+            F::from(self.z).unwrap(),
+        )
+    }
+
+    /// Convert to another integer type.
+    /// Returns `None` if a component does not fit in `J`.
+    pub fn cast<J: PrimInt>(self) -> Option<IntVector<J>> {
+        Some(IntVector {
+            x: J::from(self.x)?,
+            y: J::from(self.y)?,
+            z: J::from(self.z)?,
+        })
+    }
+
+    /// Return the dot product of two vectors.
+    pub fn dot(self, other: Self) -> I {
+        self.x * other.x + self.y * other.y + self.z * other.z
+    }
+
+    /// Return the squared length of the vector.
+    pub fn length_squared(self) -> I {
+        self.dot(self)
+    }
+
+    /// Return the distance between two vectors when moving along the axes only,
+    /// also known as the taxicab distance.
+    ///
+    /// # Panics
+    /// Panics if the distance does not fit in `I`, see `checked_manhattan`.
+    pub fn manhattan(self, other: Self) -> I {
+        self.checked_manhattan(other)
+            .expect("manhattan distance overflow")
+    }
+
+    /// Return the taxicab distance between two vectors, or `None` if it does
+    /// not fit in `I`.
+    pub fn checked_manhattan(self, other: Self) -> Option<I> {
+        abs_diff(self.x, other.x)?
+            .checked_add(&abs_diff(self.y, other.y)?)?
+            .checked_add(&abs_diff(self.z, other.z)?)
+    }
+
+    /// Return the distance between two vectors when diagonal moves are allowed,
+    /// that is the largest difference between components.
+    ///
+    /// # Panics
+    /// Panics if the distance does not fit in `I`, see `checked_chebyshev`.
+    pub fn chebyshev(self, other: Self) -> I {
+        self.checked_chebyshev(other)
+            .expect("chebyshev distance overflow")
+    }
+
+    /// Return the largest difference between components of two vectors, or
+    /// `None` if it does not fit in `I`.
+    pub fn checked_chebyshev(self, other: Self) -> Option<I> {
+        Some(
+            abs_diff(self.x, other.x)?
+                .max(abs_diff(self.y, other.y)?)
+                .max(abs_diff(self.z, other.z)?),
+        )
+    }
+
+    /// Return the component-wise minimum of two vectors.
+    pub fn min(self, other: Self) -> Self {
+        Self::new(
+            self.x.min(other.x),
+            self.y.min(other.y),
+            self.z.min(other.z),
+        )
+    }
+
+    /// Return the component-wise maximum of two vectors.
+    pub fn max(self, other: Self) -> Self {
+        Self::new(
+            self.x.max(other.x),
+            self.y.max(other.y),
+            self.z.max(other.z),
+        )
+    }
+
+    /// Construct a vector with all components set to 0.
+    pub fn zero() -> Self {
+        Self::new(I::zero(), I::zero(), I::zero())
+    }
+
+    /// Construct a vector with all components set to 1.
+    pub fn one() -> Self {
+        Self::new(I::one(), I::one(), I::one())
+    }
+}
+
+impl<I: PrimInt> IntVector2<I> {
+    /// Create a new vector.
+    pub fn new(x: I, y: I) -> Self {
+        Self { x, y }
+    }
+
+    /// Extend the vector to 3D with the given `z` component.
+    pub fn extend(self, z: I) -> IntVector<I> {
+        IntVector::new(self.x, self.y, z)
+    }
+
+    /// Convert a floating point vector, rounding each component with the given policy.
+    /// Returns `None` if a component is not finite or does not fit in `I`.
+    pub fn from_vector<F: Float>(v: Vector2<F>, rounding: Rounding) -> Option<Self> {
+        Some(Self {
+            x: I::from(rounding.apply(v.x))?,
+            y: I::from(rounding.apply(v.y))?,
+        })
+    }
+
+    /// Convert to a floating point vector.
+    pub fn to_vector<F: Float>(self) -> Vector2<F> {
+        Vector2::new(F::from(self.x).unwrap(), F::from(self.y).unwrap())
+    }
+
+    /// Convert to another integer type.
+    /// Returns `None` if a component does not fit in `J`.
+    pub fn cast<J: PrimInt>(self) -> Option<IntVector2<J>> {
+        Some(IntVector2 {
+            x: J::from(self.x)?,
+            y: J::from(self.y)?,
+        })
+    }
+
+    /// Return the dot product of two vectors.
+    pub fn dot(self, other: Self) -> I {
+        self.x * other.x + self.y * other.y
+    }
+
+    /// Return the squared length of the vector.
+    pub fn length_squared(self) -> I {
+        self.dot(self)
+    }
+
+    /// Return the distance between two vectors when moving along the axes only,
+    /// also known as the taxicab distance.
+    ///
+    /// # Example
+    /// ```
+    /// use math_vector::IntVector2;
+    /// let a = IntVector2::new(1u32, 5);
+    /// let b = IntVector2::new(4u32, 1);
+    /// assert_eq!(a.manhattan(b), 7);
+    /// assert_eq!(a.chebyshev(b), 4);
+    /// ```
+    ///
+    /// # Panics
+    /// Panics if the distance does not fit in `I`, see `checked_manhattan`.
+    pub fn manhattan(self, other: Self) -> I {
+        self.checked_manhattan(other)
+            .expect("manhattan distance overflow")
+    }
+
+    /// Return the taxicab distance between two vectors, or `None` if it does
+    /// not fit in `I`.
+    pub fn checked_manhattan(self, other: Self) -> Option<I> {
+        abs_diff(self.x, other.x)?.checked_add(&abs_diff(self.y, other.y)?)
+    }
+
+    /// Return the distance between two vectors when diagonal moves are allowed,
+    /// that is the largest difference between components.
+    ///
+    /// # Panics
+    /// Panics if the distance does not fit in `I`, see `checked_chebyshev`.
+    pub fn chebyshev(self, other: Self) -> I {
+        self.checked_chebyshev(other)
+            .expect("chebyshev distance overflow")
+    }
+
+    /// Return the largest difference between components of two vectors, or
+    /// `None` if it does not fit in `I`.
+    pub fn checked_chebyshev(self, other: Self) -> Option<I> {
+        Some(abs_diff(self.x, other.x)?.max(abs_diff(self.y, other.y)?))
+    }
+
+    /// Return the component-wise minimum of two vectors.
+    pub fn min(self, other: Self) -> Self {
+        Self::new(self.x.min(other.x), self.y.min(other.y))
+    }
+
+    /// Return the component-wise maximum of two vectors.
+    pub fn max(self, other: Self) -> Self {
+        Self::new(self.x.max(other.x), self.y.max(other.y))
+    }
+
+    /// Construct a vector with all components set to 0.
+    pub fn zero() -> Self {
+        Self::new(I::zero(), I::zero())
+    }
+
+    /// Construct a vector with all components set to 1.
+    pub fn one() -> Self {
+        Self::new(I::one(), I::one())
+    }
+}
+
+impl<I: PrimInt> From<IntVector<I>> for (I, I, I) {
+    /// Performs the conversion.
+    fn from(v: IntVector<I>) -> Self {
+        (v.x, v.y, v.z)
+    }
+}
+
+impl<I: PrimInt> From<(I, I, I)> for IntVector<I> {
+    /// Performs the conversion.
+    fn from(src: (I, I, I)) -> Self {
+        Self::new(src.0, src.1, src.2)
+    }
+}
+
+impl<I: PrimInt> From<IntVector<I>> for [I; 3] {
+    /// Performs the conversion.
+    fn from(v: IntVector<I>) -> Self {
+        [v.x, v.y, v.z]
+    }
+}
+
+impl<I: PrimInt> From<[I; 3]> for IntVector<I> {
+    /// Performs the conversion.
+    fn from(src: [I; 3]) -> Self {
+        Self::new(src[0], src[1], src[2])
+    }
+}
+
+impl<I: PrimInt> From<IntVector2<I>> for (I, I) {
+    /// Performs the conversion.
+    fn from(v: IntVector2<I>) -> Self {
+        (v.x, v.y)
+    }
+}
+
+impl<I: PrimInt> From<(I, I)> for IntVector2<I> {
+    /// Performs the conversion.
+    fn from(src: (I, I)) -> Self {
+        Self::new(src.0, src.1)
+    }
+}
+
+impl<I: PrimInt> From<IntVector2<I>> for [I; 2] {
+    /// Performs the conversion.
+    fn from(v: IntVector2<I>) -> Self {
+        [v.x, v.y]
+    }
+}
+
+impl<I: PrimInt> From<[I; 2]> for IntVector2<I> {
+    /// Performs the conversion.
+    fn from(src: [I; 2]) -> Self {
+        Self::new(src[0], src[1])
+    }
+}
+
+// --- Checked / Wrapping / Saturating ---
+
+macro_rules! component_wise_impl {
+    ($V:ident { $($field:ident),+ }) => {
+        impl<I: PrimInt> CheckedAdd for $V<I> {
+            fn checked_add(&self, v: &Self) -> Option<Self> {
+                Some($V { $($field: self.$field.checked_add(&v.$field)?),+ })
+            }
+        }
+
+        impl<I: PrimInt> CheckedSub for $V<I> {
+            fn checked_sub(&self, v: &Self) -> Option<Self> {
+                Some($V { $($field: self.$field.checked_sub(&v.$field)?),+ })
+            }
+        }
+
+        impl<I: PrimInt> CheckedMul for $V<I> {
+            fn checked_mul(&self, v: &Self) -> Option<Self> {
+                Some($V { $($field: self.$field.checked_mul(&v.$field)?),+ })
+            }
+        }
+
+        impl<I: PrimInt> CheckedDiv for $V<I> {
+            fn checked_div(&self, v: &Self) -> Option<Self> {
+                Some($V { $($field: self.$field.checked_div(&v.$field)?),+ })
+            }
+        }
+
+        impl<I: PrimInt + WrappingAdd> WrappingAdd for $V<I> {
+            fn wrapping_add(&self, v: &Self) -> Self {
+                $V { $($field: self.$field.wrapping_add(&v.$field)),+ }
+            }
+        }
+
+        impl<I: PrimInt + WrappingSub> WrappingSub for $V<I> {
+            fn wrapping_sub(&self, v: &Self) -> Self {
+                $V { $($field: self.$field.wrapping_sub(&v.$field)),+ }
+            }
+        }
+
+        impl<I: PrimInt + WrappingMul> WrappingMul for $V<I> {
+            fn wrapping_mul(&self, v: &Self) -> Self {
+                $V { $($field: self.$field.wrapping_mul(&v.$field)),+ }
+            }
+        }
+
+        impl<I: PrimInt + SaturatingAdd> SaturatingAdd for $V<I> {
+            fn saturating_add(&self, v: &Self) -> Self {
+                $V { $($field: SaturatingAdd::saturating_add(&self.$field, &v.$field)),+ }
+            }
+        }
+
+        impl<I: PrimInt + SaturatingSub> SaturatingSub for $V<I> {
+            fn saturating_sub(&self, v: &Self) -> Self {
+                $V { $($field: SaturatingSub::saturating_sub(&self.$field, &v.$field)),+ }
+            }
+        }
+
+        impl<I: PrimInt + SaturatingMul> SaturatingMul for $V<I> {
+            fn saturating_mul(&self, v: &Self) -> Self {
+                $V { $($field: self.$field.saturating_mul(&v.$field)),+ }
+            }
+        }
+    };
+}
+
+component_wise_impl!(IntVector2 { x, y });
+component_wise_impl!(IntVector { x, y, z });
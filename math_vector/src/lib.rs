@@ -6,13 +6,49 @@
 //! into any excursions with the law using this library thanks to the awfully
 //! permissive Unlicense.
 //!
-//! The only type in this crate is `Vector`, which is highly generic;
+//! The main type in this crate is `Vector`, which is highly generic;
 //! shifting functionality depending upon the traits implemented by its internal
-//! components' types.
+//! components' types. `Vector2` and `Vector4` share its API for 2D and
+//! homogeneous coordinates, and `IntVector` and `IntVector2` are their integer
+//! counterparts for grids, pixels and voxels. `Matrix3` and `Matrix4` complement
+//! it to express linear and affine transformations, and `Quaternion` to compose
+//! and interpolate rotations. `Transform` combines a translation, a rotation
+//! and a scale for scene hierarchies.
+//!
+//! The floating point vectors also have shader-style swizzle accessors, such as
+//! `v.xy()`, `v.zyx()` or `v.xxzz()`, generated by `proc_vector`.
+//!
+//! The crate is `no_std`: the default `std` feature takes the floating point
+//! functions from the standard library, and without it the `libm` feature
+//! provides them for embedded targets.
+
+#![no_std]
+#[cfg(any(feature = "std", test))]
+extern crate std;
+
+#[cfg(not(any(feature = "std", feature = "libm")))]
+compile_error!("enable either the `std` or the `libm` feature");
 
 pub mod ops;
 
+mod approx;
+pub mod int_vector;
+pub mod matrix;
+pub mod projection;
 pub mod protocol;
+pub mod quaternion;
+pub mod simd;
+pub mod transform;
 pub mod vector;
+pub mod vector2;
+pub mod vector4;
 
-pub use vector::Vector;
+pub use int_vector::{IntVector, IntVector2, IntVector3, Rounding};
+pub use matrix::{Matrix3, Matrix4};
+pub use protocol::Precision;
+pub use quaternion::Quaternion;
+pub use simd::SimdVector;
+pub use transform::Transform;
+pub use vector::{Vector, Vector3};
+pub use vector2::Vector2;
+pub use vector4::Vector4;
@@ -0,0 +1,452 @@
+use crate::vector::Vector;
+use core::fmt::{Debug, Display, Result};
+use numeric::Float;
+
+/// A 3x3 matrix of floating point values.
+///
+/// The matrix is stored in column-major order, which is the layout expected
+/// by OpenGL: `cols[c][r]` is the element at column `c` and row `r`.
+#[repr(C)]
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub struct Matrix3<F: Float> {
+    pub cols: [[F; 3]; 3],
+}
+
+/// A 4x4 matrix of floating point values.
+///
+/// The matrix is stored in column-major order, which is the layout expected
+/// by OpenGL: `cols[c][r]` is the element at column `c` and row `r`.
+#[repr(C)]
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub struct Matrix4<F: Float> {
+    pub cols: [[F; 4]; 4],
+}
+
+impl<F: Float + Debug> Display for Matrix3<F> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> Result {
+        let c = &self.cols;
+        write!(
+            f,
+            "Matrix3([{:#?}, {:#?}, {:#?}], [{:#?}, {:#?}, {:#?}], [{:#?}, {:#?}, {:#?}])",
+            c[0][0], c[1][0], c[2][0], c[0][1], c[1][1], c[2][1], c[0][2], c[1][2], c[2][2]
+        )
+    }
+}
+
+impl<F: Float + Debug> Display for Matrix4<F> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> Result {
+        write!(f, "Matrix4(")?;
+        for r in 0..4 {
+            if r > 0 {
+                write!(f, ", ")?;
+            }
+            write!(
+                f,
+                "[{:#?}, {:#?}, {:#?}, {:#?}]",
+                self.cols[0][r], self.cols[1][r], self.cols[2][r], self.cols[3][r]
+            )?;
+        }
+        write!(f, ")")
+    }
+}
+
+// --- Matrix3 ---
+
+impl<F: Float> Matrix3<F> {
+    /// Create a new matrix from its components, given column by column.
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        c0r0: F,
+        c0r1: F,
+        c0r2: F,
+        c1r0: F,
+        c1r1: F,
+        c1r2: F,
+        c2r0: F,
+        c2r1: F,
+        c2r2: F,
+    ) -> Self {
+        Self {
+            cols: [[c0r0, c0r1, c0r2], [c1r0, c1r1, c1r2], [c2r0, c2r1, c2r2]],
+        }
+    }
+
+    /// Create a new matrix from its three columns.
+    pub fn from_cols(x: Vector<F>, y: Vector<F>, z: Vector<F>) -> Self {
+        Self {
+            cols: [x.into(), y.into(), z.into()],
+        }
+    }
+
+    /// Construct a matrix with all components set to 0.
+    pub fn zero() -> Self {
+        Self::from_diagonal(Vector::new(F::zero(), F::zero(), F::zero()))
+    }
+
+    /// Construct the identity matrix.
+    pub fn identity() -> Self {
+        Self::from_diagonal(Vector::new(F::one(), F::one(), F::one()))
+    }
+
+    /// Construct a diagonal matrix whose diagonal is the given vector.
+    pub fn from_diagonal(diagonal: Vector<F>) -> Self {
+        let o = F::zero();
+        Self::new(diagonal.x, o, o, o, diagonal.y, o, o, o, diagonal.z)
+    }
+
+    /// Construct a non-uniform scaling matrix.
+    pub fn from_scale(scale: Vector<F>) -> Self {
+        Self::from_diagonal(scale)
+    }
+
+    /// Construct a matrix rotating around some axis by a given angle in radians.
+    /// Please note that the axis is assumed to be normalized.
+    ///
+    /// Multiplying a vector by this matrix is equivalent to `Vector::rotated`.
+    pub fn from_rotation(angle: F, axis: Vector<F>) -> Self {
+        let (sin, cos) = angle.sin_cos();
+        let t = F::one() - cos;
+        let Vector { x, y, z } = axis;
+        Self::new(
+            t * x * x + cos,
+            t * x * y + sin * z,
+            t * x * z - sin * y,
+            t * x * y - sin * z,
+            t * y * y + cos,
+            t * y * z + sin * x,
+            t * x * z + sin * y,
+            t * y * z - sin * x,
+            t * z * z + cos,
+        )
+    }
+
+    /// Return the column of index `i` as a vector.
+    pub fn col(&self, i: usize) -> Vector<F> {
+        self.cols[i].into()
+    }
+
+    /// Return the row of index `i` as a vector.
+    pub fn row(&self, i: usize) -> Vector<F> {
+        Vector::new(self.cols[0][i], self.cols[1][i], self.cols[2][i])
+    }
+
+    /// Return the sum of the diagonal components.
+    pub fn trace(&self) -> F {
+        self.cols[0][0] + self.cols[1][1] + self.cols[2][2]
+    }
+
+    /// Return the determinant of the matrix.
+    pub fn determinant(&self) -> F {
+        self.col(0).dot(self.col(1).cross(self.col(2)))
+    }
+
+    /// Transpose the matrix in place.
+    pub fn transpose(&mut self) {
+        *self = self.transposed();
+    }
+
+    /// Return a transposed copy of the matrix.
+    pub fn transposed(self) -> Self {
+        Self::from_cols(self.row(0), self.row(1), self.row(2))
+    }
+
+    /// Return the inverse of the matrix,
+    /// or `None` if the matrix is singular.
+    ///
+    /// # Example
+    /// ```
+    /// use math_vector::{Matrix3, Vector};
+    /// let m = Matrix3::from_scale(Vector::new(2.0, 4.0, 8.0));
+    /// let inv = m.inverse().unwrap();
+    /// assert_eq!(inv, Matrix3::from_scale(Vector::new(0.5, 0.25, 0.125)));
+    /// assert_eq!(Matrix3::<f64>::zero().inverse(), None);
+    /// ```
+    pub fn inverse(&self) -> Option<Self> {
+        let (x, y, z) = (self.col(0), self.col(1), self.col(2));
+        let yz = y.cross(z);
+        let det = x.dot(yz);
+        if det == F::zero() {
+            return None;
+        }
+        let inv_det = det.recip();
+        Some(Self::from_cols(yz * inv_det, z.cross(x) * inv_det, x.cross(y) * inv_det).transposed())
+    }
+
+    /// Return the components of the matrix as a flat array in column-major order.
+    pub fn to_cols_array(&self) -> [F; 9] {
+        let c = &self.cols;
+        [
+            c[0][0], c[0][1], c[0][2], c[1][0], c[1][1], c[1][2], c[2][0], c[2][1], c[2][2],
+        ]
+    }
+}
+
+impl<F: Float> From<Matrix3<F>> for [[F; 3]; 3] {
+    /// Performs the conversion.
+    fn from(m: Matrix3<F>) -> Self {
+        m.cols
+    }
+}
+
+impl<F: Float> From<[[F; 3]; 3]> for Matrix3<F> {
+    /// Performs the conversion.
+    fn from(cols: [[F; 3]; 3]) -> Self {
+        Self { cols }
+    }
+}
+
+impl<F: Float> From<Matrix4<F>> for Matrix3<F> {
+    /// Keep the upper-left 3x3 part of the matrix.
+    fn from(src: Matrix4<F>) -> Self {
+        let c = &src.cols;
+        Self::new(
+            c[0][0], c[0][1], c[0][2], c[1][0], c[1][1], c[1][2], c[2][0], c[2][1], c[2][2],
+        )
+    }
+}
+
+// --- Matrix4 ---
+
+impl<F: Float> Matrix4<F> {
+    /// Create a new matrix from its components, given column by column.
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        c0r0: F,
+        c0r1: F,
+        c0r2: F,
+        c0r3: F,
+        c1r0: F,
+        c1r1: F,
+        c1r2: F,
+        c1r3: F,
+        c2r0: F,
+        c2r1: F,
+        c2r2: F,
+        c2r3: F,
+        c3r0: F,
+        c3r1: F,
+        c3r2: F,
+        c3r3: F,
+    ) -> Self {
+        Self {
+            cols: [
+                [c0r0, c0r1, c0r2, c0r3],
+                [c1r0, c1r1, c1r2, c1r3],
+                [c2r0, c2r1, c2r2, c2r3],
+                [c3r0, c3r1, c3r2, c3r3],
+            ],
+        }
+    }
+
+    /// Create a new matrix from its four columns.
+    pub fn from_cols(x: [F; 4], y: [F; 4], z: [F; 4], w: [F; 4]) -> Self {
+        Self { cols: [x, y, z, w] }
+    }
+
+    /// Construct a matrix with all components set to 0.
+    pub fn zero() -> Self {
+        Self {
+            cols: [[F::zero(); 4]; 4],
+        }
+    }
+
+    /// Construct the identity matrix.
+    pub fn identity() -> Self {
+        Self::from(Matrix3::identity())
+    }
+
+    /// Construct a translation matrix.
+    ///
+    /// # Example
+    /// ```
+    /// use math_vector::{Matrix4, Vector};
+    /// let m = Matrix4::from_translation(Vector::new(1.0, 2.0, 3.0));
+    /// assert_eq!(m * Vector::new(1.0, 1.0, 1.0), Vector::new(2.0, 3.0, 4.0));
+    /// assert_eq!(m.transform_vector(Vector::new(1.0, 1.0, 1.0)), Vector::new(1.0, 1.0, 1.0));
+    /// ```
+    pub fn from_translation(translation: Vector<F>) -> Self {
+        let mut m = Self::identity();
+        m.cols[3] = [translation.x, translation.y, translation.z, F::one()];
+        m
+    }
+
+    /// Construct a non-uniform scaling matrix.
+    pub fn from_scale(scale: Vector<F>) -> Self {
+        Self::from(Matrix3::from_scale(scale))
+    }
+
+    /// Construct a matrix rotating around some axis by a given angle in radians.
+    /// Please note that the axis is assumed to be normalized.
+    pub fn from_rotation(angle: F, axis: Vector<F>) -> Self {
+        Self::from(Matrix3::from_rotation(angle, axis))
+    }
+
+    /// Return the column of index `i`.
+    pub fn col(&self, i: usize) -> [F; 4] {
+        self.cols[i]
+    }
+
+    /// Return the row of index `i`.
+    pub fn row(&self, i: usize) -> [F; 4] {
+        [
+            self.cols[0][i],
+            self.cols[1][i],
+            self.cols[2][i],
+            self.cols[3][i],
+        ]
+    }
+
+    /// Return the sum of the diagonal components.
+    pub fn trace(&self) -> F {
+        self.cols[0][0] + self.cols[1][1] + self.cols[2][2] + self.cols[3][3]
+    }
+
+    /// Return the determinant of the matrix.
+    pub fn determinant(&self) -> F {
+        let (s, c) = self.minors();
+        s[0] * c[5] - s[1] * c[4] + s[2] * c[3] + s[3] * c[2] - s[4] * c[1] + s[5] * c[0]
+    }
+
+    /// Transpose the matrix in place.
+    pub fn transpose(&mut self) {
+        *self = self.transposed();
+    }
+
+    /// Return a transposed copy of the matrix.
+    pub fn transposed(self) -> Self {
+        Self::from_cols(self.row(0), self.row(1), self.row(2), self.row(3))
+    }
+
+    /// Return the inverse of the matrix,
+    /// or `None` if the matrix is singular.
+    ///
+    /// # Example
+    /// ```
+    /// use math_vector::{Matrix4, Vector};
+    /// let m = Matrix4::from_translation(Vector::new(1.0, 2.0, 3.0));
+    /// let inv = m.inverse().unwrap();
+    /// assert_eq!(inv, Matrix4::from_translation(Vector::new(-1.0, -2.0, -3.0)));
+    /// assert_eq!(Matrix4::<f64>::zero().inverse(), None);
+    /// ```
+    pub fn inverse(&self) -> Option<Self> {
+        let (s, c) = self.minors();
+        let det = s[0] * c[5] - s[1] * c[4] + s[2] * c[3] + s[3] * c[2] - s[4] * c[1] + s[5] * c[0];
+        if det == F::zero() {
+            return None;
+        }
+        let i = det.recip();
+        let a = &self.cols;
+
+        // The cofactors are computed on the transposed matrix, which yields the
+        // transposed inverse: this is exactly the column-major inverse we want.
+        Some(Self::new(
+            (a[1][1] * c[5] - a[1][2] * c[4] + a[1][3] * c[3]) * i,
+            (-a[0][1] * c[5] + a[0][2] * c[4] - a[0][3] * c[3]) * i,
+            (a[3][1] * s[5] - a[3][2] * s[4] + a[3][3] * s[3]) * i,
+            (-a[2][1] * s[5] + a[2][2] * s[4] - a[2][3] * s[3]) * i,
+            (-a[1][0] * c[5] + a[1][2] * c[2] - a[1][3] * c[1]) * i,
+            (a[0][0] * c[5] - a[0][2] * c[2] + a[0][3] * c[1]) * i,
+            (-a[3][0] * s[5] + a[3][2] * s[2] - a[3][3] * s[1]) * i,
+            (a[2][0] * s[5] - a[2][2] * s[2] + a[2][3] * s[1]) * i,
+            (a[1][0] * c[4] - a[1][1] * c[2] + a[1][3] * c[0]) * i,
+            (-a[0][0] * c[4] + a[0][1] * c[2] - a[0][3] * c[0]) * i,
+            (a[3][0] * s[4] - a[3][1] * s[2] + a[3][3] * s[0]) * i,
+            (-a[2][0] * s[4] + a[2][1] * s[2] - a[2][3] * s[0]) * i,
+            (-a[1][0] * c[3] + a[1][1] * c[1] - a[1][2] * c[0]) * i,
+            (a[0][0] * c[3] - a[0][1] * c[1] + a[0][2] * c[0]) * i,
+            (-a[3][0] * s[3] + a[3][1] * s[1] - a[3][2] * s[0]) * i,
+            (a[2][0] * s[3] - a[2][1] * s[1] + a[2][2] * s[0]) * i,
+        ))
+    }
+
+    /// Return the 2x2 minors of the two upper and the two lower lines of the
+    /// transposed matrix, as used by the Laplace expansion of the determinant.
+    fn minors(&self) -> ([F; 6], [F; 6]) {
+        let a = &self.cols;
+        let s = [
+            a[0][0] * a[1][1] - a[1][0] * a[0][1],
+            a[0][0] * a[1][2] - a[1][0] * a[0][2],
+            a[0][0] * a[1][3] - a[1][0] * a[0][3],
+            a[0][1] * a[1][2] - a[1][1] * a[0][2],
+            a[0][1] * a[1][3] - a[1][1] * a[0][3],
+            a[0][2] * a[1][3] - a[1][2] * a[0][3],
+        ];
+        let c = [
+            a[2][0] * a[3][1] - a[3][0] * a[2][1],
+            a[2][0] * a[3][2] - a[3][0] * a[2][2],
+            a[2][0] * a[3][3] - a[3][0] * a[2][3],
+            a[2][1] * a[3][2] - a[3][1] * a[2][2],
+            a[2][1] * a[3][3] - a[3][1] * a[2][3],
+            a[2][2] * a[3][3] - a[3][2] * a[2][3],
+        ];
+        (s, c)
+    }
+
+    /// Transform a point, that is a vector whose homogeneous coordinate `w` is 1.
+    /// The result is divided by its own `w` coordinate, so that this can be used
+    /// with projection matrices as well.
+    ///
+    /// This is what `Matrix4 * Vector` does.
+    pub fn transform_point(&self, point: Vector<F>) -> Vector<F> {
+        let [x, y, z, w] = self.mul_array([point.x, point.y, point.z, F::one()]);
+        if w == F::one() || w == F::zero() {
+            Vector::new(x, y, z)
+        } else {
+            let inv_w = w.recip();
+            Vector::new(x * inv_w, y * inv_w, z * inv_w)
+        }
+    }
+
+    /// Transform a direction, that is a vector whose homogeneous coordinate `w` is 0.
+    /// Directions are not affected by the translation part of the matrix.
+    pub fn transform_vector(&self, vector: Vector<F>) -> Vector<F> {
+        let [x, y, z, _] = self.mul_array([vector.x, vector.y, vector.z, F::zero()]);
+        Vector::new(x, y, z)
+    }
+
+    /// Multiply the matrix by a column of four homogeneous coordinates.
+    pub fn mul_array(&self, v: [F; 4]) -> [F; 4] {
+        let c = &self.cols;
+        let mut out = [F::zero(); 4];
+        for (r, o) in out.iter_mut().enumerate() {
+            *o = c[0][r] * v[0] + c[1][r] * v[1] + c[2][r] * v[2] + c[3][r] * v[3];
+        }
+        out
+    }
+
+    /// Return the components of the matrix as a flat array in column-major order,
+    /// ready to be uploaded with `glUniformMatrix4fv`.
+    pub fn to_cols_array(&self) -> [F; 16] {
+        let mut out = [F::zero(); 16];
+        for (c, col) in self.cols.iter().enumerate() {
+            out[c * 4..c * 4 + 4].copy_from_slice(col);
+        }
+        out
+    }
+}
+
+impl<F: Float> From<Matrix4<F>> for [[F; 4]; 4] {
+    /// Performs the conversion.
+    fn from(m: Matrix4<F>) -> Self {
+        m.cols
+    }
+}
+
+impl<F: Float> From<[[F; 4]; 4]> for Matrix4<F> {
+    /// Performs the conversion.
+    fn from(cols: [[F; 4]; 4]) -> Self {
+        Self { cols }
+    }
+}
+
+impl<F: Float> From<Matrix3<F>> for Matrix4<F> {
+    /// Embed the matrix in the upper-left part of an identity matrix.
+    fn from(src: Matrix3<F>) -> Self {
+        let (o, i) = (F::zero(), F::one());
+        let c = &src.cols;
+        Self::new(
+            c[0][0], c[0][1], c[0][2], o, c[1][0], c[1][1], c[1][2], o, c[2][0], c[2][1], c[2][2],
+            o, o, o, o, i,
+        )
+    }
+}
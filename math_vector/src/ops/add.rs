@@ -1,6 +1,12 @@
+use crate::int_vector::{IntVector, IntVector2};
+use crate::matrix::{Matrix3, Matrix4};
+use crate::quaternion::Quaternion;
+use crate::simd::SimdVector;
 use crate::vector::Vector;
-use numeric::Float;
-use std::ops::{Add, AddAssign};
+use crate::vector2::Vector2;
+use crate::vector4::Vector4;
+use core::ops::{Add, AddAssign};
+use numeric::{Float, PrimInt};
 
 // --- Add ---
 
@@ -151,3 +157,292 @@ where
         self.z += rhs.z;
     }
 }
+
+// --- Matrix ---
+
+// matrix
+
+impl<F: Float> Add<Matrix3<F>> for Matrix3<F> {
+    type Output = Matrix3<F>;
+
+    fn add(self, rhs: Matrix3<F>) -> Self::Output {
+        &self + &rhs
+    }
+}
+
+impl<F: Float> Add<Matrix4<F>> for Matrix4<F> {
+    type Output = Matrix4<F>;
+
+    fn add(self, rhs: Matrix4<F>) -> Self::Output {
+        &self + &rhs
+    }
+}
+
+// &matrix
+
+impl<F: Float> Add<&Matrix3<F>> for &Matrix3<F> {
+    type Output = Matrix3<F>;
+
+    fn add(self, rhs: &Matrix3<F>) -> Self::Output {
+        let mut m = *self;
+        m += rhs;
+        m
+    }
+}
+
+impl<F: Float> Add<&Matrix4<F>> for &Matrix4<F> {
+    type Output = Matrix4<F>;
+
+    fn add(self, rhs: &Matrix4<F>) -> Self::Output {
+        let mut m = *self;
+        m += rhs;
+        m
+    }
+}
+
+// --- Matrix AddAssign ---
+
+// matrix
+
+impl<F: Float> AddAssign<Matrix3<F>> for Matrix3<F> {
+    fn add_assign(&mut self, rhs: Matrix3<F>) {
+        *self += &rhs;
+    }
+}
+
+impl<F: Float> AddAssign<Matrix4<F>> for Matrix4<F> {
+    fn add_assign(&mut self, rhs: Matrix4<F>) {
+        *self += &rhs;
+    }
+}
+
+// &matrix
+
+impl<F: Float> AddAssign<&Matrix3<F>> for Matrix3<F> {
+    fn add_assign(&mut self, rhs: &Matrix3<F>) {
+        for (col, rhs_col) in self.cols.iter_mut().zip(rhs.cols.iter()) {
+            for (a, b) in col.iter_mut().zip(rhs_col.iter()) {
+                *a = *a + *b;
+            }
+        }
+    }
+}
+
+impl<F: Float> AddAssign<&Matrix4<F>> for Matrix4<F> {
+    fn add_assign(&mut self, rhs: &Matrix4<F>) {
+        for (col, rhs_col) in self.cols.iter_mut().zip(rhs.cols.iter()) {
+            for (a, b) in col.iter_mut().zip(rhs_col.iter()) {
+                *a = *a + *b;
+            }
+        }
+    }
+}
+
+// --- Quaternion ---
+
+impl<F: Float> Add<Quaternion<F>> for Quaternion<F> {
+    type Output = Quaternion<F>;
+
+    fn add(self, rhs: Quaternion<F>) -> Self::Output {
+        Quaternion {
+            x: self.x + rhs.x,
+            y: self.y + rhs.y,
+            z: self.z + rhs.z,
+            w: self.w + rhs.w,
+        }
+    }
+}
+
+impl<F: Float> Add<&Quaternion<F>> for &Quaternion<F> {
+    type Output = Quaternion<F>;
+
+    fn add(self, rhs: &Quaternion<F>) -> Self::Output {
+        *self + *rhs
+    }
+}
+
+impl<F: Float> AddAssign<Quaternion<F>> for Quaternion<F> {
+    fn add_assign(&mut self, rhs: Quaternion<F>) {
+        *self = *self + rhs;
+    }
+}
+
+impl<F: Float> AddAssign<&Quaternion<F>> for Quaternion<F> {
+    fn add_assign(&mut self, rhs: &Quaternion<F>) {
+        *self = *self + *rhs;
+    }
+}
+
+// --- Vector2 / Vector4 / IntVector ---
+
+macro_rules! add_impl {
+    ($V:ident<$bound:ident> { $($field:ident),+ }) => {
+        // vector
+
+        impl<T: $bound> Add<$V<T>> for $V<T> {
+            type Output = $V<T>;
+
+            fn add(self, rhs: $V<T>) -> Self::Output {
+                $V {
+                    $($field: self.$field + rhs.$field),+
+                }
+            }
+        }
+
+        impl<T: $bound> Add<T> for $V<T> {
+            type Output = $V<T>;
+
+            fn add(self, rhs: T) -> Self::Output {
+                $V {
+                    $($field: self.$field + rhs),+
+                }
+            }
+        }
+
+        // &vector
+
+        impl<T: $bound> Add<&$V<T>> for &$V<T> {
+            type Output = $V<T>;
+
+            fn add(self, rhs: &$V<T>) -> Self::Output {
+                $V {
+                    $($field: self.$field + rhs.$field),+
+                }
+            }
+        }
+
+        impl<T: $bound> Add<T> for &$V<T> {
+            type Output = $V<T>;
+
+            fn add(self, rhs: T) -> Self::Output {
+                $V {
+                    $($field: self.$field + rhs),+
+                }
+            }
+        }
+
+        // &mut vector
+
+        impl<T: $bound> Add<&mut $V<T>> for &mut $V<T> {
+            type Output = $V<T>;
+
+            fn add(self, rhs: &mut $V<T>) -> Self::Output {
+                $V {
+                    $($field: self.$field + rhs.$field),+
+                }
+            }
+        }
+
+        impl<T: $bound> Add<T> for &mut $V<T> {
+            type Output = $V<T>;
+
+            fn add(self, rhs: T) -> Self::Output {
+                $V {
+                    $($field: self.$field + rhs),+
+                }
+            }
+        }
+
+        // assign
+
+        impl<T: $bound> AddAssign<$V<T>> for $V<T>
+        where
+            T: AddAssign<T>,
+        {
+            fn add_assign(&mut self, rhs: $V<T>) {
+                $(self.$field += rhs.$field;)+
+            }
+        }
+
+        impl<T: $bound> AddAssign<T> for $V<T>
+        where
+            T: AddAssign<T>,
+        {
+            fn add_assign(&mut self, rhs: T) {
+                $(self.$field += rhs;)+
+            }
+        }
+
+        impl<T: $bound> AddAssign<&$V<T>> for $V<T>
+        where
+            T: AddAssign<T>,
+        {
+            fn add_assign(&mut self, rhs: &$V<T>) {
+                $(self.$field += rhs.$field;)+
+            }
+        }
+
+        impl<T: $bound> AddAssign<&mut $V<T>> for $V<T>
+        where
+            T: AddAssign<T>,
+        {
+            fn add_assign(&mut self, rhs: &mut $V<T>) {
+                $(self.$field += rhs.$field;)+
+            }
+        }
+    };
+}
+
+add_impl!(Vector2<Float> { x, y });
+add_impl!(Vector4<Float> { x, y, z, w });
+add_impl!(IntVector2<PrimInt> { x, y });
+add_impl!(IntVector<PrimInt> { x, y, z });
+
+// --- SimdVector ---
+
+impl Add<SimdVector> for SimdVector {
+    type Output = SimdVector;
+
+    #[inline]
+    fn add(self, rhs: SimdVector) -> Self::Output {
+        self.add_lanes(rhs)
+    }
+}
+
+impl Add<f32> for SimdVector {
+    type Output = SimdVector;
+
+    #[inline]
+    fn add(self, rhs: f32) -> Self::Output {
+        self.add_lanes(SimdVector::splat(rhs))
+    }
+}
+
+impl Add<&SimdVector> for &SimdVector {
+    type Output = SimdVector;
+
+    #[inline]
+    fn add(self, rhs: &SimdVector) -> Self::Output {
+        self.add_lanes(*rhs)
+    }
+}
+
+impl Add<f32> for &SimdVector {
+    type Output = SimdVector;
+
+    #[inline]
+    fn add(self, rhs: f32) -> Self::Output {
+        self.add_lanes(SimdVector::splat(rhs))
+    }
+}
+
+impl AddAssign<SimdVector> for SimdVector {
+    #[inline]
+    fn add_assign(&mut self, rhs: SimdVector) {
+        *self = self.add_lanes(rhs);
+    }
+}
+
+impl AddAssign<f32> for SimdVector {
+    #[inline]
+    fn add_assign(&mut self, rhs: f32) {
+        *self = self.add_lanes(SimdVector::splat(rhs));
+    }
+}
+
+impl AddAssign<&SimdVector> for SimdVector {
+    #[inline]
+    fn add_assign(&mut self, rhs: &SimdVector) {
+        *self = self.add_lanes(*rhs);
+    }
+}
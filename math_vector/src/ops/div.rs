@@ -1,6 +1,10 @@
+use crate::int_vector::{IntVector, IntVector2};
+use crate::simd::SimdVector;
 use crate::vector::Vector;
-use numeric::Float;
-use std::ops::{Div, DivAssign};
+use crate::vector2::Vector2;
+use crate::vector4::Vector4;
+use core::ops::{Div, DivAssign};
+use numeric::{Float, PrimInt};
 
 // --- Div ---
 
@@ -151,3 +155,177 @@ where
         self.z /= rhs.z;
     }
 }
+
+// --- Vector2 / Vector4 / IntVector ---
+
+macro_rules! div_impl {
+    ($V:ident<$bound:ident> { $($field:ident),+ }) => {
+        // vector
+
+        impl<T: $bound> Div<$V<T>> for $V<T> {
+            type Output = $V<T>;
+
+            fn div(self, rhs: $V<T>) -> Self::Output {
+                $V {
+                    $($field: self.$field / rhs.$field),+
+                }
+            }
+        }
+
+        impl<T: $bound> Div<T> for $V<T> {
+            type Output = $V<T>;
+
+            fn div(self, rhs: T) -> Self::Output {
+                $V {
+                    $($field: self.$field / rhs),+
+                }
+            }
+        }
+
+        // &vector
+
+        impl<T: $bound> Div<&$V<T>> for &$V<T> {
+            type Output = $V<T>;
+
+            fn div(self, rhs: &$V<T>) -> Self::Output {
+                $V {
+                    $($field: self.$field / rhs.$field),+
+                }
+            }
+        }
+
+        impl<T: $bound> Div<T> for &$V<T> {
+            type Output = $V<T>;
+
+            fn div(self, rhs: T) -> Self::Output {
+                $V {
+                    $($field: self.$field / rhs),+
+                }
+            }
+        }
+
+        // &mut vector
+
+        impl<T: $bound> Div<&mut $V<T>> for &mut $V<T> {
+            type Output = $V<T>;
+
+            fn div(self, rhs: &mut $V<T>) -> Self::Output {
+                $V {
+                    $($field: self.$field / rhs.$field),+
+                }
+            }
+        }
+
+        impl<T: $bound> Div<T> for &mut $V<T> {
+            type Output = $V<T>;
+
+            fn div(self, rhs: T) -> Self::Output {
+                $V {
+                    $($field: self.$field / rhs),+
+                }
+            }
+        }
+
+        // assign
+
+        impl<T: $bound> DivAssign<$V<T>> for $V<T>
+        where
+            T: DivAssign<T>,
+        {
+            fn div_assign(&mut self, rhs: $V<T>) {
+                $(self.$field /= rhs.$field;)+
+            }
+        }
+
+        impl<T: $bound> DivAssign<T> for $V<T>
+        where
+            T: DivAssign<T>,
+        {
+            fn div_assign(&mut self, rhs: T) {
+                $(self.$field /= rhs;)+
+            }
+        }
+
+        impl<T: $bound> DivAssign<&$V<T>> for $V<T>
+        where
+            T: DivAssign<T>,
+        {
+            fn div_assign(&mut self, rhs: &$V<T>) {
+                $(self.$field /= rhs.$field;)+
+            }
+        }
+
+        impl<T: $bound> DivAssign<&mut $V<T>> for $V<T>
+        where
+            T: DivAssign<T>,
+        {
+            fn div_assign(&mut self, rhs: &mut $V<T>) {
+                $(self.$field /= rhs.$field;)+
+            }
+        }
+    };
+}
+
+div_impl!(Vector2<Float> { x, y });
+div_impl!(Vector4<Float> { x, y, z, w });
+div_impl!(IntVector2<PrimInt> { x, y });
+div_impl!(IntVector<PrimInt> { x, y, z });
+
+// --- SimdVector ---
+
+impl Div<SimdVector> for SimdVector {
+    type Output = SimdVector;
+
+    #[inline]
+    fn div(self, rhs: SimdVector) -> Self::Output {
+        self.div_lanes(rhs)
+    }
+}
+
+impl Div<f32> for SimdVector {
+    type Output = SimdVector;
+
+    #[inline]
+    fn div(self, rhs: f32) -> Self::Output {
+        self.div_lanes(SimdVector::splat(rhs))
+    }
+}
+
+impl Div<&SimdVector> for &SimdVector {
+    type Output = SimdVector;
+
+    #[inline]
+    fn div(self, rhs: &SimdVector) -> Self::Output {
+        self.div_lanes(*rhs)
+    }
+}
+
+impl Div<f32> for &SimdVector {
+    type Output = SimdVector;
+
+    #[inline]
+    fn div(self, rhs: f32) -> Self::Output {
+        self.div_lanes(SimdVector::splat(rhs))
+    }
+}
+
+impl DivAssign<SimdVector> for SimdVector {
+    #[inline]
+    fn div_assign(&mut self, rhs: SimdVector) {
+        *self = self.div_lanes(rhs);
+    }
+}
+
+impl DivAssign<f32> for SimdVector {
+    #[inline]
+    fn div_assign(&mut self, rhs: f32) {
+        *self = self.div_lanes(SimdVector::splat(rhs));
+    }
+}
+
+impl DivAssign<&SimdVector> for SimdVector {
+    #[inline]
+    fn div_assign(&mut self, rhs: &SimdVector) {
+        *self = self.div_lanes(*rhs);
+    }
+}
@@ -1,6 +1,13 @@
+use crate::int_vector::{IntVector, IntVector2};
+use crate::matrix::{Matrix3, Matrix4};
+use crate::quaternion::Quaternion;
+use crate::simd::SimdVector;
+use crate::transform::Transform;
 use crate::vector::Vector;
-use numeric::Float;
-use std::ops::{Mul, MulAssign};
+use crate::vector2::Vector2;
+use crate::vector4::Vector4;
+use core::ops::{Mul, MulAssign};
+use numeric::{Float, PrimInt};
 
 // --- Mul ---
 
@@ -151,3 +158,478 @@ where
         self.z *= rhs.z;
     }
 }
+
+// --- Matrix ---
+
+// matrix
+
+impl<F: Float> Mul<Matrix3<F>> for Matrix3<F> {
+    type Output = Matrix3<F>;
+
+    fn mul(self, rhs: Matrix3<F>) -> Self::Output {
+        &self * &rhs
+    }
+}
+
+impl<F: Float> Mul<Vector<F>> for Matrix3<F> {
+    type Output = Vector<F>;
+
+    fn mul(self, rhs: Vector<F>) -> Self::Output {
+        &self * &rhs
+    }
+}
+
+impl<F: Float> Mul<F> for Matrix3<F> {
+    type Output = Matrix3<F>;
+
+    fn mul(mut self, rhs: F) -> Self::Output {
+        self *= rhs;
+        self
+    }
+}
+
+impl<F: Float> Mul<Matrix4<F>> for Matrix4<F> {
+    type Output = Matrix4<F>;
+
+    fn mul(self, rhs: Matrix4<F>) -> Self::Output {
+        &self * &rhs
+    }
+}
+
+impl<F: Float> Mul<Vector<F>> for Matrix4<F> {
+    type Output = Vector<F>;
+
+    /// Transform the vector as a point, see `Matrix4::transform_point`.
+    fn mul(self, rhs: Vector<F>) -> Self::Output {
+        self.transform_point(rhs)
+    }
+}
+
+impl<F: Float> Mul<Vector4<F>> for Matrix4<F> {
+    type Output = Vector4<F>;
+
+    fn mul(self, rhs: Vector4<F>) -> Self::Output {
+        &self * &rhs
+    }
+}
+
+impl<F: Float> Mul<F> for Matrix4<F> {
+    type Output = Matrix4<F>;
+
+    fn mul(mut self, rhs: F) -> Self::Output {
+        self *= rhs;
+        self
+    }
+}
+
+// &matrix
+
+impl<F: Float> Mul<&Matrix3<F>> for &Matrix3<F> {
+    type Output = Matrix3<F>;
+
+    fn mul(self, rhs: &Matrix3<F>) -> Self::Output {
+        Matrix3::from_cols(self * &rhs.col(0), self * &rhs.col(1), self * &rhs.col(2))
+    }
+}
+
+impl<F: Float> Mul<&Vector<F>> for &Matrix3<F> {
+    type Output = Vector<F>;
+
+    fn mul(self, rhs: &Vector<F>) -> Self::Output {
+        self.col(0) * rhs.x + self.col(1) * rhs.y + self.col(2) * rhs.z
+    }
+}
+
+impl<F: Float> Mul<F> for &Matrix3<F> {
+    type Output = Matrix3<F>;
+
+    fn mul(self, rhs: F) -> Self::Output {
+        let mut m = *self;
+        m *= rhs;
+        m
+    }
+}
+
+impl<F: Float> Mul<&Matrix4<F>> for &Matrix4<F> {
+    type Output = Matrix4<F>;
+
+    fn mul(self, rhs: &Matrix4<F>) -> Self::Output {
+        Matrix4::from_cols(
+            self.mul_array(rhs.cols[0]),
+            self.mul_array(rhs.cols[1]),
+            self.mul_array(rhs.cols[2]),
+            self.mul_array(rhs.cols[3]),
+        )
+    }
+}
+
+impl<F: Float> Mul<&Vector<F>> for &Matrix4<F> {
+    type Output = Vector<F>;
+
+    /// Transform the vector as a point, see `Matrix4::transform_point`.
+    fn mul(self, rhs: &Vector<F>) -> Self::Output {
+        self.transform_point(*rhs)
+    }
+}
+
+impl<F: Float> Mul<&Vector4<F>> for &Matrix4<F> {
+    type Output = Vector4<F>;
+
+    fn mul(self, rhs: &Vector4<F>) -> Self::Output {
+        self.mul_array((*rhs).into()).into()
+    }
+}
+
+impl<F: Float> Mul<F> for &Matrix4<F> {
+    type Output = Matrix4<F>;
+
+    fn mul(self, rhs: F) -> Self::Output {
+        let mut m = *self;
+        m *= rhs;
+        m
+    }
+}
+
+// --- Matrix MulAssign ---
+
+// matrix
+
+impl<F: Float> MulAssign<Matrix3<F>> for Matrix3<F> {
+    fn mul_assign(&mut self, rhs: Matrix3<F>) {
+        *self = *self * rhs;
+    }
+}
+
+impl<F: Float> MulAssign<F> for Matrix3<F> {
+    fn mul_assign(&mut self, rhs: F) {
+        self.cols.iter_mut().flatten().for_each(|a| *a = *a * rhs);
+    }
+}
+
+impl<F: Float> MulAssign<Matrix4<F>> for Matrix4<F> {
+    fn mul_assign(&mut self, rhs: Matrix4<F>) {
+        *self = *self * rhs;
+    }
+}
+
+impl<F: Float> MulAssign<F> for Matrix4<F> {
+    fn mul_assign(&mut self, rhs: F) {
+        self.cols.iter_mut().flatten().for_each(|a| *a = *a * rhs);
+    }
+}
+
+// &matrix
+
+impl<F: Float> MulAssign<&Matrix3<F>> for Matrix3<F> {
+    fn mul_assign(&mut self, rhs: &Matrix3<F>) {
+        *self = &*self * rhs;
+    }
+}
+
+impl<F: Float> MulAssign<&Matrix4<F>> for Matrix4<F> {
+    fn mul_assign(&mut self, rhs: &Matrix4<F>) {
+        *self = &*self * rhs;
+    }
+}
+
+// --- Quaternion ---
+
+impl<F: Float> Mul<Quaternion<F>> for Quaternion<F> {
+    type Output = Quaternion<F>;
+
+    /// Compose two rotations, the right-hand side being applied first.
+    fn mul(self, rhs: Quaternion<F>) -> Self::Output {
+        Quaternion {
+            x: self.w * rhs.x + self.x * rhs.w + self.y * rhs.z - self.z * rhs.y,
+            y: self.w * rhs.y - self.x * rhs.z + self.y * rhs.w + self.z * rhs.x,
+            z: self.w * rhs.z + self.x * rhs.y - self.y * rhs.x + self.z * rhs.w,
+            w: self.w * rhs.w - self.x * rhs.x - self.y * rhs.y - self.z * rhs.z,
+        }
+    }
+}
+
+impl<F: Float> Mul<Vector<F>> for Quaternion<F> {
+    type Output = Vector<F>;
+
+    /// Rotate the vector, see `Quaternion::rotate_vector`.
+    fn mul(self, rhs: Vector<F>) -> Self::Output {
+        self.rotate_vector(rhs)
+    }
+}
+
+impl<F: Float> Mul<F> for Quaternion<F> {
+    type Output = Quaternion<F>;
+
+    fn mul(self, rhs: F) -> Self::Output {
+        Quaternion {
+            x: self.x * rhs,
+            y: self.y * rhs,
+            z: self.z * rhs,
+            w: self.w * rhs,
+        }
+    }
+}
+
+impl<F: Float> Mul<&Quaternion<F>> for &Quaternion<F> {
+    type Output = Quaternion<F>;
+
+    fn mul(self, rhs: &Quaternion<F>) -> Self::Output {
+        *self * *rhs
+    }
+}
+
+impl<F: Float> Mul<&Vector<F>> for &Quaternion<F> {
+    type Output = Vector<F>;
+
+    fn mul(self, rhs: &Vector<F>) -> Self::Output {
+        self.rotate_vector(*rhs)
+    }
+}
+
+impl<F: Float> Mul<F> for &Quaternion<F> {
+    type Output = Quaternion<F>;
+
+    fn mul(self, rhs: F) -> Self::Output {
+        *self * rhs
+    }
+}
+
+impl<F: Float> MulAssign<Quaternion<F>> for Quaternion<F> {
+    fn mul_assign(&mut self, rhs: Quaternion<F>) {
+        *self = *self * rhs;
+    }
+}
+
+impl<F: Float> MulAssign<&Quaternion<F>> for Quaternion<F> {
+    fn mul_assign(&mut self, rhs: &Quaternion<F>) {
+        *self = *self * *rhs;
+    }
+}
+
+impl<F: Float> MulAssign<F> for Quaternion<F> {
+    fn mul_assign(&mut self, rhs: F) {
+        *self = *self * rhs;
+    }
+}
+
+// --- Transform ---
+
+impl<F: Float> Mul<Transform<F>> for Transform<F> {
+    type Output = Transform<F>;
+
+    /// Compose two transforms, see `Transform::compose`.
+    fn mul(self, rhs: Transform<F>) -> Self::Output {
+        self.compose(rhs)
+    }
+}
+
+impl<F: Float> Mul<Vector<F>> for Transform<F> {
+    type Output = Vector<F>;
+
+    /// Transform the point, see `Transform::transform_point`.
+    fn mul(self, rhs: Vector<F>) -> Self::Output {
+        self.transform_point(rhs)
+    }
+}
+
+impl<F: Float> Mul<&Transform<F>> for &Transform<F> {
+    type Output = Transform<F>;
+
+    fn mul(self, rhs: &Transform<F>) -> Self::Output {
+        self.compose(*rhs)
+    }
+}
+
+impl<F: Float> Mul<&Vector<F>> for &Transform<F> {
+    type Output = Vector<F>;
+
+    fn mul(self, rhs: &Vector<F>) -> Self::Output {
+        self.transform_point(*rhs)
+    }
+}
+
+impl<F: Float> MulAssign<Transform<F>> for Transform<F> {
+    fn mul_assign(&mut self, rhs: Transform<F>) {
+        *self = self.compose(rhs);
+    }
+}
+
+impl<F: Float> MulAssign<&Transform<F>> for Transform<F> {
+    fn mul_assign(&mut self, rhs: &Transform<F>) {
+        *self = self.compose(*rhs);
+    }
+}
+
+// --- Vector2 / Vector4 / IntVector ---
+
+macro_rules! mul_impl {
+    ($V:ident<$bound:ident> { $($field:ident),+ }) => {
+        // vector
+
+        impl<T: $bound> Mul<$V<T>> for $V<T> {
+            type Output = $V<T>;
+
+            fn mul(self, rhs: $V<T>) -> Self::Output {
+                $V {
+                    $($field: self.$field * rhs.$field),+
+                }
+            }
+        }
+
+        impl<T: $bound> Mul<T> for $V<T> {
+            type Output = $V<T>;
+
+            fn mul(self, rhs: T) -> Self::Output {
+                $V {
+                    $($field: self.$field * rhs),+
+                }
+            }
+        }
+
+        // &vector
+
+        impl<T: $bound> Mul<&$V<T>> for &$V<T> {
+            type Output = $V<T>;
+
+            fn mul(self, rhs: &$V<T>) -> Self::Output {
+                $V {
+                    $($field: self.$field * rhs.$field),+
+                }
+            }
+        }
+
+        impl<T: $bound> Mul<T> for &$V<T> {
+            type Output = $V<T>;
+
+            fn mul(self, rhs: T) -> Self::Output {
+                $V {
+                    $($field: self.$field * rhs),+
+                }
+            }
+        }
+
+        // &mut vector
+
+        impl<T: $bound> Mul<&mut $V<T>> for &mut $V<T> {
+            type Output = $V<T>;
+
+            fn mul(self, rhs: &mut $V<T>) -> Self::Output {
+                $V {
+                    $($field: self.$field * rhs.$field),+
+                }
+            }
+        }
+
+        impl<T: $bound> Mul<T> for &mut $V<T> {
+            type Output = $V<T>;
+
+            fn mul(self, rhs: T) -> Self::Output {
+                $V {
+                    $($field: self.$field * rhs),+
+                }
+            }
+        }
+
+        // assign
+
+        impl<T: $bound> MulAssign<$V<T>> for $V<T>
+        where
+            T: MulAssign<T>,
+        {
+            fn mul_assign(&mut self, rhs: $V<T>) {
+                $(self.$field *= rhs.$field;)+
+            }
+        }
+
+        impl<T: $bound> MulAssign<T> for $V<T>
+        where
+            T: MulAssign<T>,
+        {
+            fn mul_assign(&mut self, rhs: T) {
+                $(self.$field *= rhs;)+
+            }
+        }
+
+        impl<T: $bound> MulAssign<&$V<T>> for $V<T>
+        where
+            T: MulAssign<T>,
+        {
+            fn mul_assign(&mut self, rhs: &$V<T>) {
+                $(self.$field *= rhs.$field;)+
+            }
+        }
+
+        impl<T: $bound> MulAssign<&mut $V<T>> for $V<T>
+        where
+            T: MulAssign<T>,
+        {
+            fn mul_assign(&mut self, rhs: &mut $V<T>) {
+                $(self.$field *= rhs.$field;)+
+            }
+        }
+    };
+}
+
+mul_impl!(Vector2<Float> { x, y });
+mul_impl!(Vector4<Float> { x, y, z, w });
+mul_impl!(IntVector2<PrimInt> { x, y });
+mul_impl!(IntVector<PrimInt> { x, y, z });
+
+// --- SimdVector ---
+
+impl Mul<SimdVector> for SimdVector {
+    type Output = SimdVector;
+
+    #[inline]
+    fn mul(self, rhs: SimdVector) -> Self::Output {
+        self.mul_lanes(rhs)
+    }
+}
+
+impl Mul<f32> for SimdVector {
+    type Output = SimdVector;
+
+    #[inline]
+    fn mul(self, rhs: f32) -> Self::Output {
+        self.mul_lanes(SimdVector::splat(rhs))
+    }
+}
+
+impl Mul<&SimdVector> for &SimdVector {
+    type Output = SimdVector;
+
+    #[inline]
+    fn mul(self, rhs: &SimdVector) -> Self::Output {
+        self.mul_lanes(*rhs)
+    }
+}
+
+impl Mul<f32> for &SimdVector {
+    type Output = SimdVector;
+
+    #[inline]
+    fn mul(self, rhs: f32) -> Self::Output {
+        self.mul_lanes(SimdVector::splat(rhs))
+    }
+}
+
+impl MulAssign<SimdVector> for SimdVector {
+    #[inline]
+    fn mul_assign(&mut self, rhs: SimdVector) {
+        *self = self.mul_lanes(rhs);
+    }
+}
+
+impl MulAssign<f32> for SimdVector {
+    #[inline]
+    fn mul_assign(&mut self, rhs: f32) {
+        *self = self.mul_lanes(SimdVector::splat(rhs));
+    }
+}
+
+impl MulAssign<&SimdVector> for SimdVector {
+    #[inline]
+    fn mul_assign(&mut self, rhs: &SimdVector) {
+        *self = self.mul_lanes(*rhs);
+    }
+}
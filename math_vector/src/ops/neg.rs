@@ -1,6 +1,12 @@
+use crate::int_vector::{IntVector, IntVector2};
+use crate::matrix::{Matrix3, Matrix4};
+use crate::quaternion::Quaternion;
+use crate::simd::SimdVector;
 use crate::vector::Vector;
-use numeric::Float;
-use std::ops::Neg;
+use crate::vector2::Vector2;
+use crate::vector4::Vector4;
+use core::ops::Neg;
+use numeric::{Float, PrimInt};
 
 impl<F: Float> Neg for Vector<F>
 where
@@ -46,3 +52,136 @@ where
         }
     }
 }
+
+// matrix
+
+impl<F: Float> Neg for Matrix3<F> {
+    type Output = Matrix3<F>;
+
+    fn neg(self) -> Self::Output {
+        -&self
+    }
+}
+
+impl<F: Float> Neg for Matrix4<F> {
+    type Output = Matrix4<F>;
+
+    fn neg(self) -> Self::Output {
+        -&self
+    }
+}
+
+// &matrix
+
+impl<F: Float> Neg for &Matrix3<F> {
+    type Output = Matrix3<F>;
+
+    fn neg(self) -> Self::Output {
+        let mut m = *self;
+        m.cols.iter_mut().flatten().for_each(|a| *a = -*a);
+        m
+    }
+}
+
+impl<F: Float> Neg for &Matrix4<F> {
+    type Output = Matrix4<F>;
+
+    fn neg(self) -> Self::Output {
+        let mut m = *self;
+        m.cols.iter_mut().flatten().for_each(|a| *a = -*a);
+        m
+    }
+}
+
+// quaternion
+
+impl<F: Float> Neg for Quaternion<F> {
+    type Output = Quaternion<F>;
+
+    fn neg(self) -> Self::Output {
+        Quaternion {
+            x: -self.x,
+            y: -self.y,
+            z: -self.z,
+            w: -self.w,
+        }
+    }
+}
+
+impl<F: Float> Neg for &Quaternion<F> {
+    type Output = Quaternion<F>;
+
+    fn neg(self) -> Self::Output {
+        -*self
+    }
+}
+
+// --- Vector2 / Vector4 / IntVector ---
+
+macro_rules! neg_impl {
+    ($V:ident<$bound:ident> { $($field:ident),+ }) => {
+        impl<T: $bound> Neg for $V<T>
+        where
+            T: Neg<Output = T>,
+        {
+            type Output = $V<T>;
+
+            fn neg(self) -> Self::Output {
+                $V {
+                    $($field: -self.$field),+
+                }
+            }
+        }
+
+        impl<T: $bound> Neg for &$V<T>
+        where
+            T: Neg<Output = T>,
+        {
+            type Output = $V<T>;
+
+            fn neg(self) -> Self::Output {
+                $V {
+                    $($field: -self.$field),+
+                }
+            }
+        }
+
+        impl<T: $bound> Neg for &mut $V<T>
+        where
+            T: Neg<Output = T>,
+        {
+            type Output = $V<T>;
+
+            fn neg(self) -> Self::Output {
+                $V {
+                    $($field: -self.$field),+
+                }
+            }
+        }
+    };
+}
+
+neg_impl!(Vector2<Float> { x, y });
+neg_impl!(Vector4<Float> { x, y, z, w });
+neg_impl!(IntVector2<PrimInt> { x, y });
+neg_impl!(IntVector<PrimInt> { x, y, z });
+
+// --- SimdVector ---
+
+impl Neg for SimdVector {
+    type Output = SimdVector;
+
+    #[inline]
+    fn neg(self) -> Self::Output {
+        self.mul_lanes(SimdVector::splat(-1.0))
+    }
+}
+
+impl Neg for &SimdVector {
+    type Output = SimdVector;
+
+    #[inline]
+    fn neg(self) -> Self::Output {
+        self.mul_lanes(SimdVector::splat(-1.0))
+    }
+}
@@ -1,6 +1,12 @@
+use crate::int_vector::{IntVector, IntVector2};
+use crate::matrix::{Matrix3, Matrix4};
+use crate::quaternion::Quaternion;
+use crate::simd::SimdVector;
 use crate::vector::Vector;
-use numeric::Float;
-use std::ops::{Sub, SubAssign};
+use crate::vector2::Vector2;
+use crate::vector4::Vector4;
+use core::ops::{Sub, SubAssign};
+use numeric::{Float, PrimInt};
 
 // --- Sub ---
 
@@ -151,3 +157,292 @@ where
         self.z -= rhs.z;
     }
 }
+
+// --- Matrix ---
+
+// matrix
+
+impl<F: Float> Sub<Matrix3<F>> for Matrix3<F> {
+    type Output = Matrix3<F>;
+
+    fn sub(self, rhs: Matrix3<F>) -> Self::Output {
+        &self - &rhs
+    }
+}
+
+impl<F: Float> Sub<Matrix4<F>> for Matrix4<F> {
+    type Output = Matrix4<F>;
+
+    fn sub(self, rhs: Matrix4<F>) -> Self::Output {
+        &self - &rhs
+    }
+}
+
+// &matrix
+
+impl<F: Float> Sub<&Matrix3<F>> for &Matrix3<F> {
+    type Output = Matrix3<F>;
+
+    fn sub(self, rhs: &Matrix3<F>) -> Self::Output {
+        let mut m = *self;
+        m -= rhs;
+        m
+    }
+}
+
+impl<F: Float> Sub<&Matrix4<F>> for &Matrix4<F> {
+    type Output = Matrix4<F>;
+
+    fn sub(self, rhs: &Matrix4<F>) -> Self::Output {
+        let mut m = *self;
+        m -= rhs;
+        m
+    }
+}
+
+// --- Matrix SubAssign ---
+
+// matrix
+
+impl<F: Float> SubAssign<Matrix3<F>> for Matrix3<F> {
+    fn sub_assign(&mut self, rhs: Matrix3<F>) {
+        *self -= &rhs;
+    }
+}
+
+impl<F: Float> SubAssign<Matrix4<F>> for Matrix4<F> {
+    fn sub_assign(&mut self, rhs: Matrix4<F>) {
+        *self -= &rhs;
+    }
+}
+
+// &matrix
+
+impl<F: Float> SubAssign<&Matrix3<F>> for Matrix3<F> {
+    fn sub_assign(&mut self, rhs: &Matrix3<F>) {
+        for (col, rhs_col) in self.cols.iter_mut().zip(rhs.cols.iter()) {
+            for (a, b) in col.iter_mut().zip(rhs_col.iter()) {
+                *a = *a - *b;
+            }
+        }
+    }
+}
+
+impl<F: Float> SubAssign<&Matrix4<F>> for Matrix4<F> {
+    fn sub_assign(&mut self, rhs: &Matrix4<F>) {
+        for (col, rhs_col) in self.cols.iter_mut().zip(rhs.cols.iter()) {
+            for (a, b) in col.iter_mut().zip(rhs_col.iter()) {
+                *a = *a - *b;
+            }
+        }
+    }
+}
+
+// --- Quaternion ---
+
+impl<F: Float> Sub<Quaternion<F>> for Quaternion<F> {
+    type Output = Quaternion<F>;
+
+    fn sub(self, rhs: Quaternion<F>) -> Self::Output {
+        Quaternion {
+            x: self.x - rhs.x,
+            y: self.y - rhs.y,
+            z: self.z - rhs.z,
+            w: self.w - rhs.w,
+        }
+    }
+}
+
+impl<F: Float> Sub<&Quaternion<F>> for &Quaternion<F> {
+    type Output = Quaternion<F>;
+
+    fn sub(self, rhs: &Quaternion<F>) -> Self::Output {
+        *self - *rhs
+    }
+}
+
+impl<F: Float> SubAssign<Quaternion<F>> for Quaternion<F> {
+    fn sub_assign(&mut self, rhs: Quaternion<F>) {
+        *self = *self - rhs;
+    }
+}
+
+impl<F: Float> SubAssign<&Quaternion<F>> for Quaternion<F> {
+    fn sub_assign(&mut self, rhs: &Quaternion<F>) {
+        *self = *self - *rhs;
+    }
+}
+
+// --- Vector2 / Vector4 / IntVector ---
+
+macro_rules! sub_impl {
+    ($V:ident<$bound:ident> { $($field:ident),+ }) => {
+        // vector
+
+        impl<T: $bound> Sub<$V<T>> for $V<T> {
+            type Output = $V<T>;
+
+            fn sub(self, rhs: $V<T>) -> Self::Output {
+                $V {
+                    $($field: self.$field - rhs.$field),+
+                }
+            }
+        }
+
+        impl<T: $bound> Sub<T> for $V<T> {
+            type Output = $V<T>;
+
+            fn sub(self, rhs: T) -> Self::Output {
+                $V {
+                    $($field: self.$field - rhs),+
+                }
+            }
+        }
+
+        // &vector
+
+        impl<T: $bound> Sub<&$V<T>> for &$V<T> {
+            type Output = $V<T>;
+
+            fn sub(self, rhs: &$V<T>) -> Self::Output {
+                $V {
+                    $($field: self.$field - rhs.$field),+
+                }
+            }
+        }
+
+        impl<T: $bound> Sub<T> for &$V<T> {
+            type Output = $V<T>;
+
+            fn sub(self, rhs: T) -> Self::Output {
+                $V {
+                    $($field: self.$field - rhs),+
+                }
+            }
+        }
+
+        // &mut vector
+
+        impl<T: $bound> Sub<&mut $V<T>> for &mut $V<T> {
+            type Output = $V<T>;
+
+            fn sub(self, rhs: &mut $V<T>) -> Self::Output {
+                $V {
+                    $($field: self.$field - rhs.$field),+
+                }
+            }
+        }
+
+        impl<T: $bound> Sub<T> for &mut $V<T> {
+            type Output = $V<T>;
+
+            fn sub(self, rhs: T) -> Self::Output {
+                $V {
+                    $($field: self.$field - rhs),+
+                }
+            }
+        }
+
+        // assign
+
+        impl<T: $bound> SubAssign<$V<T>> for $V<T>
+        where
+            T: SubAssign<T>,
+        {
+            fn sub_assign(&mut self, rhs: $V<T>) {
+                $(self.$field -= rhs.$field;)+
+            }
+        }
+
+        impl<T: $bound> SubAssign<T> for $V<T>
+        where
+            T: SubAssign<T>,
+        {
+            fn sub_assign(&mut self, rhs: T) {
+                $(self.$field -= rhs;)+
+            }
+        }
+
+        impl<T: $bound> SubAssign<&$V<T>> for $V<T>
+        where
+            T: SubAssign<T>,
+        {
+            fn sub_assign(&mut self, rhs: &$V<T>) {
+                $(self.$field -= rhs.$field;)+
+            }
+        }
+
+        impl<T: $bound> SubAssign<&mut $V<T>> for $V<T>
+        where
+            T: SubAssign<T>,
+        {
+            fn sub_assign(&mut self, rhs: &mut $V<T>) {
+                $(self.$field -= rhs.$field;)+
+            }
+        }
+    };
+}
+
+sub_impl!(Vector2<Float> { x, y });
+sub_impl!(Vector4<Float> { x, y, z, w });
+sub_impl!(IntVector2<PrimInt> { x, y });
+sub_impl!(IntVector<PrimInt> { x, y, z });
+
+// --- SimdVector ---
+
+impl Sub<SimdVector> for SimdVector {
+    type Output = SimdVector;
+
+    #[inline]
+    fn sub(self, rhs: SimdVector) -> Self::Output {
+        self.sub_lanes(rhs)
+    }
+}
+
+impl Sub<f32> for SimdVector {
+    type Output = SimdVector;
+
+    #[inline]
+    fn sub(self, rhs: f32) -> Self::Output {
+        self.sub_lanes(SimdVector::splat(rhs))
+    }
+}
+
+impl Sub<&SimdVector> for &SimdVector {
+    type Output = SimdVector;
+
+    #[inline]
+    fn sub(self, rhs: &SimdVector) -> Self::Output {
+        self.sub_lanes(*rhs)
+    }
+}
+
+impl Sub<f32> for &SimdVector {
+    type Output = SimdVector;
+
+    #[inline]
+    fn sub(self, rhs: f32) -> Self::Output {
+        self.sub_lanes(SimdVector::splat(rhs))
+    }
+}
+
+impl SubAssign<SimdVector> for SimdVector {
+    #[inline]
+    fn sub_assign(&mut self, rhs: SimdVector) {
+        *self = self.sub_lanes(rhs);
+    }
+}
+
+impl SubAssign<f32> for SimdVector {
+    #[inline]
+    fn sub_assign(&mut self, rhs: f32) {
+        *self = self.sub_lanes(SimdVector::splat(rhs));
+    }
+}
+
+impl SubAssign<&SimdVector> for SimdVector {
+    #[inline]
+    fn sub_assign(&mut self, rhs: &SimdVector) {
+        *self = self.sub_lanes(*rhs);
+    }
+}
@@ -0,0 +1,147 @@
+//! Camera matrices following the OpenGL conventions: right-handed eye space
+//! looking down the negative `z` axis, and clip space where the visible depth
+//! range is mapped to `[-1, 1]` (or `[1, 0]` for the reversed-Z variants).
+
+use crate::matrix::Matrix4;
+use crate::vector::Vector;
+use core::ops::MulAssign;
+use numeric::Float;
+
+impl<F: Float> Matrix4<F> {
+    /// Construct a perspective projection matrix, like `gluPerspective`.
+    /// The vertical field of view `fovy` is given in radians.
+    ///
+    /// # Example
+    /// ```
+    /// use math_vector::{Matrix4, Vector};
+    /// let m = Matrix4::perspective(std::f64::consts::PI / 2.0, 1.0, 1.0, 3.0);
+    /// assert_eq!(m * Vector::new(0.0, 0.0, -1.0), Vector::new(0.0, 0.0, -1.0));
+    /// assert_eq!(m * Vector::new(0.0, 0.0, -3.0), Vector::new(0.0, 0.0, 1.0));
+    /// ```
+    pub fn perspective(fovy: F, aspect: F, near: F, far: F) -> Self {
+        let (o, two) = (F::zero(), F::from(2.0).unwrap());
+        let f = (fovy / two).tan().recip();
+        let depth = (near - far).recip();
+        Self::new(
+            f / aspect,
+            o,
+            o,
+            o,
+            o,
+            f,
+            o,
+            o,
+            o,
+            o,
+            (far + near) * depth,
+            -F::one(),
+            o,
+            o,
+            two * far * near * depth,
+            o,
+        )
+    }
+
+    /// Construct a perspective projection matrix whose far plane is at infinity.
+    /// This is the limit of `perspective` when `far` grows.
+    pub fn perspective_infinite(fovy: F, aspect: F, near: F) -> Self {
+        let mut m = Self::perspective(fovy, aspect, near, F::one());
+        m.cols[2][2] = -F::one();
+        m.cols[3][2] = -F::from(2.0).unwrap() * near;
+        m
+    }
+
+    /// Construct a perspective projection matrix with a reversed depth range:
+    /// the near plane is mapped to 1 and the far plane to 0.
+    ///
+    /// Reversed-Z spreads the floating point precision of the depth buffer much
+    /// more evenly, and is meant to be used with a `[0, 1]` clip depth range
+    /// (`glClipControl(GL_LOWER_LEFT, GL_ZERO_TO_ONE)`) and `GL_GREATER` depth tests.
+    pub fn perspective_reversed_z(fovy: F, aspect: F, near: F, far: F) -> Self {
+        let mut m = Self::perspective(fovy, aspect, near, far);
+        let depth = (far - near).recip();
+        m.cols[2][2] = near * depth;
+        m.cols[3][2] = near * far * depth;
+        m
+    }
+
+    /// Construct a reversed-Z perspective projection matrix whose far plane is
+    /// at infinity, see `perspective_reversed_z`.
+    pub fn perspective_infinite_reversed_z(fovy: F, aspect: F, near: F) -> Self {
+        let mut m = Self::perspective(fovy, aspect, near, F::one());
+        m.cols[2][2] = F::zero();
+        m.cols[3][2] = near;
+        m
+    }
+
+    /// Construct an orthographic projection matrix, like `glOrtho`.
+    ///
+    /// # Example
+    /// ```
+    /// use math_vector::{Matrix4, Vector};
+    /// let m = Matrix4::orthographic(0.0, 800.0, 0.0, 600.0, -1.0, 1.0);
+    /// assert_eq!(m * Vector::new(800.0, 600.0, 0.0), Vector::new(1.0, 1.0, 0.0));
+    /// ```
+    pub fn orthographic(left: F, right: F, bottom: F, top: F, near: F, far: F) -> Self {
+        let (o, i, two) = (F::zero(), F::one(), F::from(2.0).unwrap());
+        let w = (right - left).recip();
+        let h = (top - bottom).recip();
+        let d = (far - near).recip();
+        Self::new(
+            two * w,
+            o,
+            o,
+            o,
+            o,
+            two * h,
+            o,
+            o,
+            o,
+            o,
+            -two * d,
+            o,
+            -(right + left) * w,
+            -(top + bottom) * h,
+            -(far + near) * d,
+            i,
+        )
+    }
+}
+
+impl<F: Float + MulAssign> Matrix4<F> {
+    /// Construct a view matrix, like `gluLookAt`, placing the camera at `eye`
+    /// and looking at `target`. The `up` vector does not need to be normalized
+    /// but must not be parallel to the viewing direction.
+    ///
+    /// # Example
+    /// ```
+    /// use math_vector::{Matrix4, Vector};
+    /// let eye = Vector::new(0.0, 0.0, 5.0);
+    /// let m = Matrix4::look_at(eye, Vector::zero(), Vector::new(0.0, 1.0, 0.0));
+    /// assert_eq!(m * Vector::zero(), Vector::new(0.0, 0.0, -5.0));
+    /// ```
+    pub fn look_at(eye: Vector<F>, target: Vector<F>, up: Vector<F>) -> Self {
+        let f = (target - eye).normalized();
+        let s = f.cross(up).normalized();
+        let u = s.cross(f);
+        let (o, i) = (F::zero(), F::one());
+        Self::new(
+            s.x,
+            u.x,
+            -f.x,
+            o,
+            s.y,
+            u.y,
+            -f.y,
+            o,
+            s.z,
+            u.z,
+            -f.z,
+            o,
+            -s.dot(eye),
+            -u.dot(eye),
+            f.dot(eye),
+            i,
+        )
+    }
+}
@@ -4,7 +4,161 @@
 #![cfg_attr(all(feature = "nightly", test), feature(asm, test, core_intrinsics))]
 #![allow(dead_code)]
 
-use std::mem;
+use numeric::Float;
+
+/// How the reciprocal square roots used to rescale vectors are computed,
+/// see `Vector::set_length_with` and `Vector::normalize_with`.
+///
+/// The approximate policies only differ from `Exact` for `f32` and `f64`, and
+/// only with the `fast-rsqrt` feature. Recent CPUs compute square roots fast
+/// enough that `Exact` is often as fast as them once vectorized, so measure
+/// with the `precision` benchmark first.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash, Default)]
+pub enum Precision {
+    /// Compute `sqrt().recip()`, which is as accurate as the floating point type allows.
+    #[default]
+    Exact,
+    /// Use the "fast inverse square root" of `InvSqrt32` and `InvSqrt64`.
+    /// The relative error is below `5e-6`, for both `f32` and `f64`.
+    Fast,
+    /// Use the reciprocal square root estimate of the CPU (`rsqrtss` on x86,
+    /// `frsqrte` on aarch64) refined with Newton iterations. The relative error
+    /// is below `2e-6` for `f32` and `1e-9` for `f64`. Other targets fall back
+    /// to `Fast`.
+    Hardware,
+}
+
+/// Return `1 / sqrt(x)` computed with the given precision policy.
+/// The approximate policies are only meant for positive and finite values.
+///
+/// # Example
+/// ```
+/// use math_vector::protocol::{inv_sqrt, Precision};
+/// assert_eq!(inv_sqrt(4.0f32, Precision::Exact), 0.5);
+/// ```
+#[inline]
+pub fn inv_sqrt<F: Float>(x: F, precision: Precision) -> F {
+    #[cfg(feature = "fast-rsqrt")]
+    if precision != Precision::Exact {
+        if let Some(y) = approx::inv_sqrt(x, precision) {
+            return y;
+        }
+    }
+    #[cfg(not(feature = "fast-rsqrt"))]
+    let _ = precision;
+    x.sqrt().recip()
+}
+
+#[cfg(feature = "fast-rsqrt")]
+mod approx {
+    use super::{InvSqrt32, InvSqrt64, Precision};
+    use core::mem;
+    use numeric::Float;
+
+    /// Return the approximation if `F` is `f32` or `f64`, which are told apart
+    /// from other floats by their size and limits since `F` is not `'static`.
+    #[inline]
+    pub fn inv_sqrt<F: Float>(x: F, precision: Precision) -> Option<F> {
+        if mem::size_of::<F>() == 4
+            && F::epsilon().to_f32() == Some(f32::EPSILON)
+            && F::max_value().to_f32() == Some(f32::MAX)
+        {
+            let x = x.to_f32()?;
+            F::from(match precision {
+                Precision::Hardware => hardware32(x),
+                _ => x.inv_sqrt32(),
+            })
+        } else if mem::size_of::<F>() == 8
+            && F::epsilon().to_f64() == Some(f64::EPSILON)
+            && F::max_value().to_f64() == Some(f64::MAX)
+        {
+            let x = x.to_f64()?;
+            F::from(match precision {
+                Precision::Hardware => hardware64(x),
+                _ => x.inv_sqrt64(),
+            })
+        } else {
+            None
+        }
+    }
+
+    /// Each Newton iteration doubles the number of correct bits.
+    #[inline]
+    fn hardware32(x: f32) -> f32 {
+        let mut y = arch::estimate(x);
+        for _ in 0..arch::STEPS_32 {
+            y *= 1.5 - 0.5 * x * y * y;
+        }
+        y
+    }
+
+    /// The estimate is computed in single precision, so values outside of the
+    /// normal range of `f32` are computed exactly instead.
+    #[inline]
+    fn hardware64(x: f64) -> f64 {
+        let single = x as f32;
+        if !single.is_normal() {
+            return x.sqrt().recip();
+        }
+        let mut y = arch::estimate(single) as f64;
+        for _ in 0..arch::STEPS_64 {
+            y *= 1.5 - 0.5 * x * y * y;
+        }
+        y
+    }
+
+    #[cfg(any(
+        target_arch = "x86_64",
+        all(target_arch = "x86", target_feature = "sse")
+    ))]
+    mod arch {
+        #[cfg(target_arch = "x86")]
+        use core::arch::x86::*;
+        #[cfg(target_arch = "x86_64")]
+        use core::arch::x86_64::*;
+
+        /// About 12 bits of precision.
+        #[inline]
+        pub fn estimate(x: f32) -> f32 {
+            unsafe { _mm_cvtss_f32(_mm_rsqrt_ss(_mm_set_ss(x))) }
+        }
+
+        pub const STEPS_32: usize = 1;
+        pub const STEPS_64: usize = 2;
+    }
+
+    #[cfg(target_arch = "aarch64")]
+    mod arch {
+        use core::arch::aarch64::*;
+
+        /// About 8 bits of precision.
+        #[inline]
+        pub fn estimate(x: f32) -> f32 {
+            unsafe { vrsqrtes_f32(x) }
+        }
+
+        pub const STEPS_32: usize = 2;
+        pub const STEPS_64: usize = 3;
+    }
+
+    #[cfg(not(any(
+        target_arch = "x86_64",
+        all(target_arch = "x86", target_feature = "sse"),
+        target_arch = "aarch64"
+    )))]
+    mod arch {
+        use super::InvSqrt32;
+
+        /// Fall back on the software approximation.
+        #[inline]
+        pub fn estimate(x: f32) -> f32 {
+            x.inv_sqrt32()
+        }
+
+        pub const STEPS_32: usize = 0;
+        pub const STEPS_64: usize = 2;
+    }
+}
 
 /// A trait that allows calculation of inverse square root into a Float(32).
 pub trait InvSqrt32 {
@@ -40,13 +194,11 @@ impl InvSqrt32 for f32 {
         // const MAGIC_32: u32 = 0x5f3759df;
         const threehalfs: f32 = 1.5f32;
         let x2: f32 = self * 0.5f32;
-        let mut i: u32 = unsafe { mem::transmute(self) }; // evil floating point bit level hacking
+        let mut i: u32 = self.to_bits(); // evil floating point bit level hacking
         i = 0x5f375a86 - (i >> 1); // what the fuck?
-        let y: f32 = unsafe { mem::transmute(i) };
+        let y: f32 = f32::from_bits(i);
         let y = y * (threehalfs - (x2 * y * y)); // 1st iteration
-        let y = y * (threehalfs - (x2 * y * y)); // 2nd iteration, this can be removed
-
-        return y;
+        y * (threehalfs - (x2 * y * y)) // 2nd iteration, this can be removed
     }
 }
 
@@ -68,11 +220,10 @@ impl InvSqrt64 for f64 {
         const MAGIC_U64: u64 = 0x5fe6ec85e7de30da;
         const THREEHALFS: f64 = 1.5;
         let x2 = self * 0.5;
-        let i = MAGIC_U64 - (unsafe { mem::transmute::<_, u64>(self) } >> 1);
-        let y: f64 = unsafe { mem::transmute(i) };
-        let y = y * (THREEHALFS - (x2 * y * y));
+        let i = MAGIC_U64 - (self.to_bits() >> 1);
+        let y = f64::from_bits(i);
         let y = y * (THREEHALFS - (x2 * y * y));
-        return y;
+        y * (THREEHALFS - (x2 * y * y))
     }
 }
 
@@ -325,24 +476,30 @@ mod test32 {
     }
 
     fn relative_difference(lhs: f32, rhs: f32) -> f32 {
-        return 2.0 * (rhs - lhs).abs() / (rhs.abs() + lhs.abs());
+        2.0 * (rhs - lhs).abs() / (rhs.abs() + lhs.abs())
     }
 
-    // #[test]
-    // fn test_f32() {
-    // 	let value: f32 = 11.1111;
-    // 	assert_le!((0.3 - value.inv_sqrt32()).abs(), 0.0005);
-
-    // 	let mut value: f32 = 0.00000001f32;
-    // 	while value < 100.4f32 {
-    // 		let result = value.inv_sqrt32();
-    // 		let ref_result = ref_inv_sqrt32(value);
-    // 		let relative_diff = relative_difference(result, ref_result);
-    // 		//println!("inv_sqrt32() -> {}: {} / {} [{}]", value, result, ref_result, relative_diff);
-    // 		assert_le!(relative_diff, 0.002);
-    // 		value += 0.01f32;
-    // 	}
-    // }
+    #[test]
+    fn test_f32() {
+        let value: f32 = 11.1111;
+        assert!((0.3 - value.inv_sqrt32()).abs() <= 0.0005);
+
+        let mut value: f32 = 0.00000001f32;
+        while value < 100.4f32 {
+            let result = value.inv_sqrt32();
+            let ref_result = ref_inv_sqrt32(value);
+            let relative_diff = relative_difference(result, ref_result);
+            assert!(
+                relative_diff <= 5e-6,
+                "inv_sqrt32() -> {}: {} / {} [{}]",
+                value,
+                result,
+                ref_result,
+                relative_diff
+            );
+            value += 0.01f32;
+        }
+    }
 
     #[test]
     fn test_zero() {
@@ -362,11 +519,11 @@ mod test32 {
         assert!(negative_zero.inv_sqrt32().is_nan());
     }
 
-    // #[test]
-    // fn test_i8() {
-    // 	let value: i8 = 55;
-    // 	assert_le!((0.1348399725 - value.inv_sqrt32()).abs(), 0.00001);
-    // }
+    #[test]
+    fn test_i8() {
+        let value: i8 = 55;
+        assert!((0.13484 - value.inv_sqrt32()).abs() <= 0.00001);
+    }
 
     #[cfg(feature = "nightly")]
     #[bench]
@@ -439,24 +596,30 @@ mod test64 {
     }
 
     fn relative_difference(lhs: f64, rhs: f64) -> f64 {
-        return 2.0 * (rhs - lhs).abs() / (rhs.abs() + lhs.abs());
+        2.0 * (rhs - lhs).abs() / (rhs.abs() + lhs.abs())
     }
 
-    // #[test]
-    // fn test_f64() {
-    // 	let value: f64 = 11.1111;
-    // 	assert_le!((0.3 - value.inv_sqrt64()).abs(), 0.0005);
-
-    // 	let mut value: f64 = 0.00000001f64;
-    // 	while value < 100.4f64 {
-    // 		let result = value.inv_sqrt64();
-    // 		let ref_result = ref_inv_sqrt64(value);
-    // 		let relative_diff = relative_difference(result, ref_result);
-    // 		//println!("inv_sqrt64(): {} -> {} / {} [{}]", value, result, ref_result, relative_diff);
-    // 		assert_le!(relative_diff, 0.002);
-    // 		value += 0.01f64;
-    // 	}
-    // }
+    #[test]
+    fn test_f64() {
+        let value: f64 = 11.1111;
+        assert!((0.3 - value.inv_sqrt64()).abs() <= 0.0005);
+
+        let mut value: f64 = 0.00000001f64;
+        while value < 100.4f64 {
+            let result = value.inv_sqrt64();
+            let ref_result = ref_inv_sqrt64(value);
+            let relative_diff = relative_difference(result, ref_result);
+            assert!(
+                relative_diff <= 5e-6,
+                "inv_sqrt64(): {} -> {} / {} [{}]",
+                value,
+                result,
+                ref_result,
+                relative_diff
+            );
+            value += 0.01f64;
+        }
+    }
 
     #[test]
     fn test_zero() {
@@ -476,11 +639,11 @@ mod test64 {
         assert!(negative_zero.inv_sqrt64().is_nan());
     }
 
-    // #[test]
-    // fn test_i8() {
-    // 	let value: i8 = 55;
-    // 	assert_le!((0.1348399725 - value.inv_sqrt64()).abs(), 0.00001);
-    // }
+    #[test]
+    fn test_i8() {
+        let value: i8 = 55;
+        assert!((0.1348399725 - value.inv_sqrt64()).abs() <= 0.00001);
+    }
 
     #[cfg(feature = "nightly")]
     #[bench]
@@ -0,0 +1,316 @@
+use crate::matrix::{Matrix3, Matrix4};
+use crate::vector::Vector;
+use core::{
+    fmt::{Debug, Display, Result},
+    ops::MulAssign,
+};
+use numeric::Float;
+
+/// A quaternion, containing a vector part `x`, `y`, `z` and a scalar part `w`.
+///
+/// Unit quaternions represent rotations in the 3d space. Unlike `Vector::rotated`,
+/// which applies Rodrigues' formula on each call, quaternions can be composed
+/// (with `*`) and interpolated (with `slerp` or `nlerp`) cheaply.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub struct Quaternion<F: Float> {
+    pub x: F,
+    pub y: F,
+    pub z: F,
+    pub w: F,
+}
+
+impl<F: Float + Debug> Display for Quaternion<F> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> Result {
+        write!(
+            f,
+            "Quaternion({:#?}, {:#?}, {:#?}, {:#?})",
+            self.x, self.y, self.z, self.w
+        )
+    }
+}
+
+impl<F: Float> Quaternion<F> {
+    /// Create a new quaternion from its vector and scalar parts.
+    pub fn new(x: F, y: F, z: F, w: F) -> Self {
+        Self { x, y, z, w }
+    }
+
+    /// Create a new quaternion from a vector part and a scalar part.
+    pub fn from_parts(v: Vector<F>, w: F) -> Self {
+        Self::new(v.x, v.y, v.z, w)
+    }
+
+    /// Construct the identity quaternion, which represents no rotation.
+    pub fn identity() -> Self {
+        Self::new(F::zero(), F::zero(), F::zero(), F::one())
+    }
+
+    /// Return the vector part of the quaternion.
+    pub fn vector(self) -> Vector<F> {
+        Vector::new(self.x, self.y, self.z)
+    }
+
+    /// Create a quaternion rotating around some axis by a given angle in radians.
+    /// Please note that the axis is assumed to be normalized.
+    ///
+    /// # Example
+    /// ```
+    /// use math_vector::{Quaternion, Vector};
+    /// let axis = Vector::new(0.0, 0.0, 1.0);
+    /// let q = Quaternion::from_rotation(std::f64::consts::PI / 2.0, axis);
+    /// let v = q * Vector::new(1.0, 0.0, 0.0);
+    /// assert!(v.is_close(Vector::new(0.0, 1.0, 0.0)));
+    /// ```
+    pub fn from_rotation(angle: F, axis: Vector<F>) -> Self {
+        let (sin, cos) = (angle * F::from(0.5).unwrap()).sin_cos();
+        Self::from_parts(axis * sin, cos)
+    }
+
+    /// Create a quaternion from a pure rotation matrix.
+    pub fn from_matrix(m: Matrix3<F>) -> Self {
+        let c = &m.cols;
+        let (one, two, quarter) = (F::one(), F::from(2.0).unwrap(), F::from(0.25).unwrap());
+        let trace = m.trace();
+
+        if trace > F::zero() {
+            let s = (trace + one).sqrt() * two;
+            Self::new(
+                (c[1][2] - c[2][1]) / s,
+                (c[2][0] - c[0][2]) / s,
+                (c[0][1] - c[1][0]) / s,
+                quarter * s,
+            )
+        } else if c[0][0] > c[1][1] && c[0][0] > c[2][2] {
+            let s = (one + c[0][0] - c[1][1] - c[2][2]).sqrt() * two;
+            Self::new(
+                quarter * s,
+                (c[1][0] + c[0][1]) / s,
+                (c[2][0] + c[0][2]) / s,
+                (c[1][2] - c[2][1]) / s,
+            )
+        } else if c[1][1] > c[2][2] {
+            let s = (one + c[1][1] - c[0][0] - c[2][2]).sqrt() * two;
+            Self::new(
+                (c[1][0] + c[0][1]) / s,
+                quarter * s,
+                (c[2][1] + c[1][2]) / s,
+                (c[2][0] - c[0][2]) / s,
+            )
+        } else {
+            let s = (one + c[2][2] - c[0][0] - c[1][1]).sqrt() * two;
+            Self::new(
+                (c[2][0] + c[0][2]) / s,
+                (c[2][1] + c[1][2]) / s,
+                quarter * s,
+                (c[0][1] - c[1][0]) / s,
+            )
+        }
+    }
+
+    /// Return the angle in radians and the normalized axis of the rotation
+    /// represented by this unit quaternion.
+    /// The identity rotation is reported around the x axis.
+    pub fn to_rotation(self) -> (F, Vector<F>) {
+        let q = if self.w < F::zero() { -self } else { self };
+        let v = q.vector();
+        let sin = v.length();
+        if sin <= F::epsilon() {
+            return (F::zero(), Vector::new(F::one(), F::zero(), F::zero()));
+        }
+        (F::from(2.0).unwrap() * sin.atan2(q.w), v / sin)
+    }
+
+    /// Return the rotation matrix represented by this unit quaternion.
+    pub fn to_matrix(self) -> Matrix3<F> {
+        let Self { x, y, z, w } = self;
+        let (one, two) = (F::one(), F::from(2.0).unwrap());
+        Matrix3::new(
+            one - two * (y * y + z * z),
+            two * (x * y + w * z),
+            two * (x * z - w * y),
+            two * (x * y - w * z),
+            one - two * (x * x + z * z),
+            two * (y * z + w * x),
+            two * (x * z + w * y),
+            two * (y * z - w * x),
+            one - two * (x * x + y * y),
+        )
+    }
+}
+
+impl<F: Float + MulAssign> Quaternion<F> {
+    /// Create the shortest rotation that brings the direction of `from`
+    /// onto the direction of `to`. Neither vector needs to be normalized.
+    pub fn from_two_vectors(from: Vector<F>, to: Vector<F>) -> Self {
+        let from = from.normalized();
+        let to = to.normalized();
+        let d = from.dot(to);
+
+        if d <= -F::one() + F::epsilon() {
+            // opposite vectors: rotate by half a turn around any orthogonal axis
+            let mut axis = Vector::new(F::one(), F::zero(), F::zero()).cross(from);
+            if axis.length_squared() <= F::epsilon() {
+                axis = Vector::new(F::zero(), F::one(), F::zero()).cross(from);
+            }
+            return Self::from_parts(axis.normalized(), F::zero());
+        }
+
+        Self::from_parts(from.cross(to), F::one() + d).normalized()
+    }
+}
+
+impl<F: Float> Quaternion<F> {
+    /// Return the dot product of two quaternions.
+    pub fn dot(self, other: Self) -> F {
+        self.x * other.x + self.y * other.y + self.z * other.z + self.w * other.w
+    }
+
+    /// Return the squared length of the quaternion.
+    pub fn length_squared(self) -> F {
+        self.dot(self)
+    }
+
+    /// Return the length of the quaternion.
+    pub fn length(self) -> F {
+        self.length_squared().sqrt()
+    }
+
+    /// Normalize the quaternion.
+    /// Does nothing if the quaternion is of length zero.
+    pub fn normalize(&mut self) {
+        let len_sq = self.length_squared();
+        if len_sq != F::zero() {
+            *self *= len_sq.sqrt().recip();
+        }
+    }
+
+    /// Return a normalized copy of the quaternion.
+    /// Does nothing if the quaternion is of length zero.
+    pub fn normalized(self) -> Self {
+        let mut q = self;
+        q.normalize();
+        q
+    }
+
+    /// Conjugate the quaternion, that is negate its vector part.
+    pub fn conjugate(&mut self) {
+        *self = self.conjugated();
+    }
+
+    /// Return the conjugate of the quaternion.
+    /// For unit quaternions, this is also the inverse rotation.
+    pub fn conjugated(self) -> Self {
+        Self::new(-self.x, -self.y, -self.z, self.w)
+    }
+
+    /// Return the inverse of the quaternion,
+    /// or `None` if the quaternion is of length zero.
+    pub fn inverse(self) -> Option<Self> {
+        let len_sq = self.length_squared();
+        if len_sq == F::zero() {
+            return None;
+        }
+        Some(self.conjugated() * len_sq.recip())
+    }
+
+    /// Normalized linear interpolation between two quaternions.
+    /// This is cheaper than `slerp` but does not keep a constant angular velocity.
+    pub fn nlerp(self, other: Self, t: F) -> Self {
+        let other = if self.dot(other) < F::zero() {
+            -other
+        } else {
+            other
+        };
+        (self + (other - self) * t).normalized()
+    }
+
+    /// Spherical linear interpolation between two unit quaternions,
+    /// always following the shortest path.
+    ///
+    /// # Example
+    /// ```
+    /// use math_vector::{Quaternion, Vector};
+    /// let axis = Vector::new(0.0, 0.0, 1.0);
+    /// let a = Quaternion::identity();
+    /// let b = Quaternion::from_rotation(std::f64::consts::PI / 2.0, axis);
+    /// let half = a.slerp(b, 0.5);
+    /// let (angle, _) = half.to_rotation();
+    /// assert!((angle - std::f64::consts::PI / 4.0).abs() < 1e-12);
+    /// ```
+    pub fn slerp(self, other: Self, t: F) -> Self {
+        let mut cos = self.dot(other);
+        let mut other = other;
+        if cos < F::zero() {
+            cos = -cos;
+            other = -other;
+        }
+
+        // fall back to nlerp when the quaternions are too close for sin to be reliable
+        if cos > F::one() - F::from(1e-6).unwrap() {
+            return self.nlerp(other, t);
+        }
+
+        let theta = cos.acos();
+        let sin = theta.sin();
+        let a = ((F::one() - t) * theta).sin() / sin;
+        let b = (t * theta).sin() / sin;
+        self * a + other * b
+    }
+
+    /// Return a new vector which is the given vector rotated by this unit quaternion.
+    /// This is what `Quaternion * Vector` does.
+    pub fn rotate_vector(self, v: Vector<F>) -> Vector<F> {
+        let u = self.vector();
+        let t = u.cross(v) * F::from(2.0).unwrap();
+        v + t * self.w + u.cross(t)
+    }
+}
+
+impl<F: Float> From<Quaternion<F>> for (F, F, F, F) {
+    /// Performs the conversion.
+    fn from(q: Quaternion<F>) -> Self {
+        (q.x, q.y, q.z, q.w)
+    }
+}
+
+impl<F: Float> From<(F, F, F, F)> for Quaternion<F> {
+    /// Performs the conversion.
+    fn from(src: (F, F, F, F)) -> Self {
+        Self::new(src.0, src.1, src.2, src.3)
+    }
+}
+
+impl<F: Float> From<Quaternion<F>> for [F; 4] {
+    /// Performs the conversion.
+    fn from(q: Quaternion<F>) -> Self {
+        [q.x, q.y, q.z, q.w]
+    }
+}
+
+impl<F: Float> From<[F; 4]> for Quaternion<F> {
+    /// Performs the conversion.
+    fn from(src: [F; 4]) -> Self {
+        Self::new(src[0], src[1], src[2], src[3])
+    }
+}
+
+impl<F: Float> From<Quaternion<F>> for Matrix3<F> {
+    /// Performs the conversion, see `Quaternion::to_matrix`.
+    fn from(q: Quaternion<F>) -> Self {
+        q.to_matrix()
+    }
+}
+
+impl<F: Float> From<Quaternion<F>> for Matrix4<F> {
+    /// Performs the conversion, see `Quaternion::to_matrix`.
+    fn from(q: Quaternion<F>) -> Self {
+        Matrix4::from(q.to_matrix())
+    }
+}
+
+impl<F: Float> From<Matrix3<F>> for Quaternion<F> {
+    /// Performs the conversion, see `Quaternion::from_matrix`.
+    fn from(m: Matrix3<F>) -> Self {
+        Self::from_matrix(m)
+    }
+}
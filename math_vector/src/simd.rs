@@ -0,0 +1,731 @@
+//! A SIMD-friendly 3D vector of `f32`, padded to four lanes, and batched
+//! operations over slices of it.
+//!
+//! With the `simd` feature enabled, `SimdVector` is backed by SSE2 registers on
+//! `x86`/`x86_64` and by NEON registers on `aarch64`. On other targets, or
+//! without the feature, it falls back to a portable scalar implementation
+//! with the exact same API.
+
+use crate::vector::Vector;
+use core::fmt::{Display, Result};
+
+#[cfg(all(
+    feature = "simd",
+    any(
+        target_arch = "x86_64",
+        all(target_arch = "x86", target_feature = "sse2")
+    )
+))]
+mod imp {
+    #[cfg(target_arch = "x86")]
+    use core::arch::x86::*;
+    #[cfg(target_arch = "x86_64")]
+    use core::arch::x86_64::*;
+
+    pub type Lanes = __m128;
+
+    #[inline]
+    pub fn new(x: f32, y: f32, z: f32) -> Lanes {
+        unsafe { _mm_set_ps(0.0, z, y, x) }
+    }
+
+    #[inline]
+    pub fn splat(v: f32) -> Lanes {
+        unsafe { _mm_set1_ps(v) }
+    }
+
+    #[inline]
+    pub fn to_array(a: Lanes) -> [f32; 4] {
+        let mut out = [0.0; 4];
+        unsafe { _mm_storeu_ps(out.as_mut_ptr(), a) };
+        out
+    }
+
+    #[inline]
+    pub fn add(a: Lanes, b: Lanes) -> Lanes {
+        unsafe { _mm_add_ps(a, b) }
+    }
+
+    #[inline]
+    pub fn sub(a: Lanes, b: Lanes) -> Lanes {
+        unsafe { _mm_sub_ps(a, b) }
+    }
+
+    #[inline]
+    pub fn mul(a: Lanes, b: Lanes) -> Lanes {
+        unsafe { _mm_mul_ps(a, b) }
+    }
+
+    #[inline]
+    pub fn div(a: Lanes, b: Lanes) -> Lanes {
+        unsafe { _mm_div_ps(a, b) }
+    }
+
+    #[inline]
+    pub fn min(a: Lanes, b: Lanes) -> Lanes {
+        unsafe { _mm_min_ps(a, b) }
+    }
+
+    #[inline]
+    pub fn max(a: Lanes, b: Lanes) -> Lanes {
+        unsafe { _mm_max_ps(a, b) }
+    }
+
+    /// Sum of the products of the first three lanes, broadcast to all lanes.
+    #[inline]
+    pub fn dot(a: Lanes, b: Lanes) -> Lanes {
+        unsafe {
+            let m = _mm_mul_ps(a, b);
+            let y = _mm_shuffle_ps(m, m, 0b01_01_01_01);
+            let z = _mm_shuffle_ps(m, m, 0b10_10_10_10);
+            let s = _mm_add_ss(_mm_add_ss(m, y), z);
+            _mm_shuffle_ps(s, s, 0)
+        }
+    }
+
+    #[inline]
+    pub fn first(a: Lanes) -> f32 {
+        unsafe { _mm_cvtss_f32(a) }
+    }
+
+    #[inline]
+    pub fn sqrt(a: Lanes) -> Lanes {
+        unsafe { _mm_sqrt_ps(a) }
+    }
+
+    /// The four dot products of `a[i]` and `b[i]`, one per lane.
+    #[inline]
+    pub fn dot4(a: [Lanes; 4], b: [Lanes; 4]) -> Lanes {
+        unsafe {
+            let m = [
+                _mm_mul_ps(a[0], b[0]),
+                _mm_mul_ps(a[1], b[1]),
+                _mm_mul_ps(a[2], b[2]),
+                _mm_mul_ps(a[3], b[3]),
+            ];
+            // transpose the products so that each register holds one component
+            let t0 = _mm_unpacklo_ps(m[0], m[1]);
+            let t1 = _mm_unpacklo_ps(m[2], m[3]);
+            let t2 = _mm_unpackhi_ps(m[0], m[1]);
+            let t3 = _mm_unpackhi_ps(m[2], m[3]);
+            let xs = _mm_movelh_ps(t0, t1);
+            let ys = _mm_movehl_ps(t1, t0);
+            let zs = _mm_movelh_ps(t2, t3);
+            _mm_add_ps(_mm_add_ps(xs, ys), zs)
+        }
+    }
+
+    /// Broadcast each lane of `a` to a whole register.
+    #[inline]
+    pub fn broadcast4(a: Lanes) -> [Lanes; 4] {
+        unsafe {
+            [
+                _mm_shuffle_ps(a, a, 0b00_00_00_00),
+                _mm_shuffle_ps(a, a, 0b01_01_01_01),
+                _mm_shuffle_ps(a, a, 0b10_10_10_10),
+                _mm_shuffle_ps(a, a, 0b11_11_11_11),
+            ]
+        }
+    }
+
+    /// Pick the lanes of `then` where `a > b`, and those of `otherwise` elsewhere.
+    #[inline]
+    pub fn select_gt(a: Lanes, b: Lanes, then: Lanes, otherwise: Lanes) -> Lanes {
+        unsafe {
+            let mask = _mm_cmpgt_ps(a, b);
+            _mm_or_ps(_mm_and_ps(mask, then), _mm_andnot_ps(mask, otherwise))
+        }
+    }
+}
+
+#[cfg(all(feature = "simd", target_arch = "aarch64"))]
+mod imp {
+    use core::arch::aarch64::*;
+
+    pub type Lanes = float32x4_t;
+
+    #[inline]
+    pub fn new(x: f32, y: f32, z: f32) -> Lanes {
+        let lanes = [x, y, z, 0.0];
+        unsafe { vld1q_f32(lanes.as_ptr()) }
+    }
+
+    #[inline]
+    pub fn splat(v: f32) -> Lanes {
+        unsafe { vdupq_n_f32(v) }
+    }
+
+    #[inline]
+    pub fn to_array(a: Lanes) -> [f32; 4] {
+        let mut out = [0.0; 4];
+        unsafe { vst1q_f32(out.as_mut_ptr(), a) };
+        out
+    }
+
+    #[inline]
+    pub fn add(a: Lanes, b: Lanes) -> Lanes {
+        unsafe { vaddq_f32(a, b) }
+    }
+
+    #[inline]
+    pub fn sub(a: Lanes, b: Lanes) -> Lanes {
+        unsafe { vsubq_f32(a, b) }
+    }
+
+    #[inline]
+    pub fn mul(a: Lanes, b: Lanes) -> Lanes {
+        unsafe { vmulq_f32(a, b) }
+    }
+
+    #[inline]
+    pub fn div(a: Lanes, b: Lanes) -> Lanes {
+        unsafe { vdivq_f32(a, b) }
+    }
+
+    #[inline]
+    pub fn min(a: Lanes, b: Lanes) -> Lanes {
+        unsafe { vminq_f32(a, b) }
+    }
+
+    #[inline]
+    pub fn max(a: Lanes, b: Lanes) -> Lanes {
+        unsafe { vmaxq_f32(a, b) }
+    }
+
+    /// Sum of the products of the first three lanes, broadcast to all lanes.
+    #[inline]
+    pub fn dot(a: Lanes, b: Lanes) -> Lanes {
+        unsafe {
+            let m = vsetq_lane_f32(0.0, vmulq_f32(a, b), 3);
+            vdupq_n_f32(vaddvq_f32(m))
+        }
+    }
+
+    #[inline]
+    pub fn first(a: Lanes) -> f32 {
+        unsafe { vgetq_lane_f32(a, 0) }
+    }
+
+    #[inline]
+    pub fn sqrt(a: Lanes) -> Lanes {
+        unsafe { vsqrtq_f32(a) }
+    }
+
+    /// The four dot products of `a[i]` and `b[i]`, one per lane.
+    #[inline]
+    pub fn dot4(a: [Lanes; 4], b: [Lanes; 4]) -> Lanes {
+        unsafe {
+            let m = [
+                vsetq_lane_f32(0.0, vmulq_f32(a[0], b[0]), 3),
+                vsetq_lane_f32(0.0, vmulq_f32(a[1], b[1]), 3),
+                vsetq_lane_f32(0.0, vmulq_f32(a[2], b[2]), 3),
+                vsetq_lane_f32(0.0, vmulq_f32(a[3], b[3]), 3),
+            ];
+            vpaddq_f32(vpaddq_f32(m[0], m[1]), vpaddq_f32(m[2], m[3]))
+        }
+    }
+
+    /// Broadcast each lane of `a` to a whole register.
+    #[inline]
+    pub fn broadcast4(a: Lanes) -> [Lanes; 4] {
+        unsafe {
+            [
+                vdupq_laneq_f32::<0>(a),
+                vdupq_laneq_f32::<1>(a),
+                vdupq_laneq_f32::<2>(a),
+                vdupq_laneq_f32::<3>(a),
+            ]
+        }
+    }
+
+    /// Pick the lanes of `then` where `a > b`, and those of `otherwise` elsewhere.
+    #[inline]
+    pub fn select_gt(a: Lanes, b: Lanes, then: Lanes, otherwise: Lanes) -> Lanes {
+        unsafe { vbslq_f32(vcgtq_f32(a, b), then, otherwise) }
+    }
+}
+
+#[cfg(not(all(
+    feature = "simd",
+    any(
+        target_arch = "x86_64",
+        all(target_arch = "x86", target_feature = "sse2"),
+        target_arch = "aarch64"
+    )
+)))]
+mod imp {
+    use numeric::Float;
+
+    pub type Lanes = [f32; 4];
+
+    #[inline]
+    pub fn new(x: f32, y: f32, z: f32) -> Lanes {
+        [x, y, z, 0.0]
+    }
+
+    #[inline]
+    pub fn splat(v: f32) -> Lanes {
+        [v; 4]
+    }
+
+    #[inline]
+    pub fn to_array(a: Lanes) -> [f32; 4] {
+        a
+    }
+
+    #[inline]
+    fn map(a: Lanes, b: Lanes, f: impl Fn(f32, f32) -> f32) -> Lanes {
+        [f(a[0], b[0]), f(a[1], b[1]), f(a[2], b[2]), f(a[3], b[3])]
+    }
+
+    #[inline]
+    pub fn add(a: Lanes, b: Lanes) -> Lanes {
+        map(a, b, |a, b| a + b)
+    }
+
+    #[inline]
+    pub fn sub(a: Lanes, b: Lanes) -> Lanes {
+        map(a, b, |a, b| a - b)
+    }
+
+    #[inline]
+    pub fn mul(a: Lanes, b: Lanes) -> Lanes {
+        map(a, b, |a, b| a * b)
+    }
+
+    #[inline]
+    pub fn div(a: Lanes, b: Lanes) -> Lanes {
+        map(a, b, |a, b| a / b)
+    }
+
+    #[inline]
+    pub fn min(a: Lanes, b: Lanes) -> Lanes {
+        map(a, b, f32::min)
+    }
+
+    #[inline]
+    pub fn max(a: Lanes, b: Lanes) -> Lanes {
+        map(a, b, f32::max)
+    }
+
+    /// Sum of the products of the first three lanes, broadcast to all lanes.
+    #[inline]
+    pub fn dot(a: Lanes, b: Lanes) -> Lanes {
+        [a[0] * b[0] + a[1] * b[1] + a[2] * b[2]; 4]
+    }
+
+    #[inline]
+    pub fn first(a: Lanes) -> f32 {
+        a[0]
+    }
+
+    #[inline]
+    pub fn sqrt(a: Lanes) -> Lanes {
+        a.map(Float::sqrt)
+    }
+
+    /// The four dot products of `a[i]` and `b[i]`, one per lane.
+    #[inline]
+    pub fn dot4(a: [Lanes; 4], b: [Lanes; 4]) -> Lanes {
+        [
+            dot(a[0], b[0])[0],
+            dot(a[1], b[1])[0],
+            dot(a[2], b[2])[0],
+            dot(a[3], b[3])[0],
+        ]
+    }
+
+    /// Broadcast each lane of `a` to a whole register.
+    #[inline]
+    pub fn broadcast4(a: Lanes) -> [Lanes; 4] {
+        [[a[0]; 4], [a[1]; 4], [a[2]; 4], [a[3]; 4]]
+    }
+
+    /// Pick the lanes of `then` where `a > b`, and those of `otherwise` elsewhere.
+    #[inline]
+    pub fn select_gt(a: Lanes, b: Lanes, then: Lanes, otherwise: Lanes) -> Lanes {
+        let pick = |i: usize| if a[i] > b[i] { then[i] } else { otherwise[i] };
+        [pick(0), pick(1), pick(2), pick(3)]
+    }
+}
+
+/// A 3D vector of `f32` stored in four lanes, the last one being padding.
+///
+/// It mirrors the most used parts of the `Vector<f32>` API, and converts from
+/// and to it for free. The padding lane is not guaranteed to hold any
+/// particular value, it never leaks into the results.
+#[derive(Copy, Clone, Debug)]
+#[repr(transparent)]
+pub struct SimdVector(pub(crate) imp::Lanes);
+
+impl Display for SimdVector {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> Result {
+        let [x, y, z, _] = imp::to_array(self.0);
+        write!(f, "SimdVector({:#?}, {:#?}, {:#?})", x, y, z)
+    }
+}
+
+impl PartialEq for SimdVector {
+    fn eq(&self, other: &Self) -> bool {
+        let a = imp::to_array(self.0);
+        let b = imp::to_array(other.0);
+        a[..3] == b[..3]
+    }
+}
+
+impl SimdVector {
+    /// Create a new vector.
+    #[inline]
+    pub fn new(x: f32, y: f32, z: f32) -> Self {
+        Self(imp::new(x, y, z))
+    }
+
+    /// Construct a vector with all components set to `v`.
+    #[inline]
+    pub fn splat(v: f32) -> Self {
+        Self(imp::splat(v))
+    }
+
+    /// Construct a vector with all components set to 0.
+    #[inline]
+    pub fn zero() -> Self {
+        Self::splat(0.0)
+    }
+
+    /// Construct a vector with all components set to 1.
+    #[inline]
+    pub fn one() -> Self {
+        Self::splat(1.0)
+    }
+
+    /// Return the `x` component.
+    #[inline]
+    pub fn x(self) -> f32 {
+        imp::to_array(self.0)[0]
+    }
+
+    /// Return the `y` component.
+    #[inline]
+    pub fn y(self) -> f32 {
+        imp::to_array(self.0)[1]
+    }
+
+    /// Return the `z` component.
+    #[inline]
+    pub fn z(self) -> f32 {
+        imp::to_array(self.0)[2]
+    }
+
+    /// Return the component-wise minimum of two vectors.
+    #[inline]
+    pub fn min(self, other: Self) -> Self {
+        Self(imp::min(self.0, other.0))
+    }
+
+    /// Return the component-wise maximum of two vectors.
+    #[inline]
+    pub fn max(self, other: Self) -> Self {
+        Self(imp::max(self.0, other.0))
+    }
+
+    /// Return the dot product of two vectors.
+    #[inline]
+    pub fn dot(self, other: Self) -> f32 {
+        imp::first(imp::dot(self.0, other.0))
+    }
+
+    /// Return the cross product of two vectors.
+    #[inline]
+    pub fn cross(self, other: Self) -> Self {
+        let [ax, ay, az, _] = imp::to_array(self.0);
+        let [bx, by, bz, _] = imp::to_array(other.0);
+        Self::new(ay * bz - az * by, az * bx - ax * bz, ax * by - ay * bx)
+    }
+
+    /// Return the squared length of the vector.
+    /// This is faster than `length` because it does not need to compute the square root.
+    #[inline]
+    pub fn length_squared(self) -> f32 {
+        self.dot(self)
+    }
+
+    /// Return the length of the vector.
+    /// Prefer `length_squared` if you need to avoid computing the square root.
+    #[inline]
+    pub fn length(self) -> f32 {
+        imp::first(imp::sqrt(imp::dot(self.0, self.0)))
+    }
+
+    /// Return the squared euclidean distance between two vectors.
+    #[inline]
+    pub fn distance_squared(self, other: Self) -> f32 {
+        (self - other).length_squared()
+    }
+
+    /// Return the euclidean distance between two vectors.
+    #[inline]
+    pub fn distance(self, other: Self) -> f32 {
+        (self - other).length()
+    }
+
+    /// Linearly interpolate between two vectors.
+    #[inline]
+    pub fn lerp(self, other: Self, t: f32) -> Self {
+        self + (other - self) * t
+    }
+
+    /// Set the length of the vector.
+    #[inline]
+    pub fn set_length(&mut self, length: f32) {
+        let len = imp::sqrt(imp::dot(self.0, self.0));
+        if imp::first(len) != 0.0 {
+            self.0 = imp::mul(self.0, imp::div(imp::splat(length), len));
+        }
+    }
+
+    /// Return a new vector whose length is set to `length`.
+    #[inline]
+    pub fn with_length(self, length: f32) -> Self {
+        let mut v = self;
+        v.set_length(length);
+        v
+    }
+
+    /// Limit the length of the vector to a maximum length.
+    #[inline]
+    pub fn limit(&mut self, max_length: f32) {
+        let len_sq = imp::dot(self.0, self.0);
+        if imp::first(len_sq) > max_length * max_length {
+            self.0 = imp::mul(self.0, imp::div(imp::splat(max_length), imp::sqrt(len_sq)));
+        }
+    }
+
+    /// Return a limited version of the vector.
+    #[inline]
+    pub fn limited(self, max_length: f32) -> Self {
+        let mut v = self;
+        v.limit(max_length);
+        v
+    }
+
+    /// Normalize the vector.
+    /// Does nothing if the vector is of length zero.
+    #[inline]
+    pub fn normalize(&mut self) {
+        self.set_length(1.0);
+    }
+
+    /// Return a normalized copy of the vector.
+    /// Does nothing if the vector is of length zero.
+    #[inline]
+    pub fn normalized(self) -> Self {
+        let mut v = self;
+        v.normalize();
+        v
+    }
+
+    /// Compare two vectors for equality.
+    /// The comparison is done with the relative error `epsilon`
+    /// so that the error is relative to the magnitude of both vectors.
+    pub fn is_close(self, reference: SimdVector) -> bool {
+        let num = (self - reference).length_squared();
+        let den = reference.length_squared();
+
+        num <= den * f32::EPSILON
+    }
+
+    #[inline]
+    pub(crate) fn add_lanes(self, other: Self) -> Self {
+        Self(imp::add(self.0, other.0))
+    }
+
+    #[inline]
+    pub(crate) fn sub_lanes(self, other: Self) -> Self {
+        Self(imp::sub(self.0, other.0))
+    }
+
+    #[inline]
+    pub(crate) fn mul_lanes(self, other: Self) -> Self {
+        Self(imp::mul(self.0, other.0))
+    }
+
+    #[inline]
+    pub(crate) fn div_lanes(self, other: Self) -> Self {
+        Self(imp::div(self.0, other.0))
+    }
+}
+
+impl From<Vector<f32>> for SimdVector {
+    /// Performs the conversion.
+    #[inline]
+    fn from(src: Vector<f32>) -> Self {
+        Self::new(src.x, src.y, src.z)
+    }
+}
+
+impl From<SimdVector> for Vector<f32> {
+    /// Performs the conversion.
+    #[inline]
+    fn from(src: SimdVector) -> Self {
+        let [x, y, z, _] = imp::to_array(src.0);
+        Vector::new(x, y, z)
+    }
+}
+
+impl From<[f32; 3]> for SimdVector {
+    /// Performs the conversion.
+    #[inline]
+    fn from(src: [f32; 3]) -> Self {
+        Self::new(src[0], src[1], src[2])
+    }
+}
+
+impl From<SimdVector> for [f32; 3] {
+    /// Performs the conversion.
+    #[inline]
+    fn from(src: SimdVector) -> Self {
+        let [x, y, z, _] = imp::to_array(src.0);
+        [x, y, z]
+    }
+}
+
+// --- batched operations ---
+//
+// The slices are processed four vectors at a time, so that the horizontal
+// sums, square roots and divisions are shared between the four of them.
+
+/// Load four vectors, from a slice of at least four elements.
+#[inline]
+fn load4(a: &[SimdVector]) -> [imp::Lanes; 4] {
+    [a[0].0, a[1].0, a[2].0, a[3].0]
+}
+
+/// Multiply four vectors by the matching lane of `factors`.
+#[inline]
+fn scale4(a: &mut [SimdVector], factors: imp::Lanes) {
+    for (a, f) in a.iter_mut().zip(imp::broadcast4(factors)) {
+        a.0 = imp::mul(a.0, f);
+    }
+}
+
+/// Write the dot products of `a[i]` and `b[i]` into `out[i]`.
+///
+/// # Panics
+/// Panics if the slices do not have the same length.
+pub fn dot_slice(a: &[SimdVector], b: &[SimdVector], out: &mut [f32]) {
+    assert_eq!(a.len(), b.len(), "slices must have the same length");
+    assert_eq!(a.len(), out.len(), "slices must have the same length");
+    let mut chunks = a.chunks_exact(4).zip(b.chunks_exact(4));
+    let mut out_chunks = out.chunks_exact_mut(4);
+    for ((a, b), out) in chunks.by_ref().zip(out_chunks.by_ref()) {
+        out.copy_from_slice(&imp::to_array(imp::dot4(load4(a), load4(b))));
+    }
+    let (a, b) = (a.chunks_exact(4).remainder(), b.chunks_exact(4).remainder());
+    for ((a, b), out) in a.iter().zip(b).zip(out_chunks.into_remainder()) {
+        *out = a.dot(*b);
+    }
+}
+
+/// Write the lengths of `a[i]` into `out[i]`.
+///
+/// # Panics
+/// Panics if the slices do not have the same length.
+pub fn length_slice(a: &[SimdVector], out: &mut [f32]) {
+    assert_eq!(a.len(), out.len(), "slices must have the same length");
+    let mut chunks = a.chunks_exact(4);
+    let mut out_chunks = out.chunks_exact_mut(4);
+    for (a, out) in chunks.by_ref().zip(out_chunks.by_ref()) {
+        let a = load4(a);
+        out.copy_from_slice(&imp::to_array(imp::sqrt(imp::dot4(a, a))));
+    }
+    for (a, out) in chunks.remainder().iter().zip(out_chunks.into_remainder()) {
+        *out = a.length();
+    }
+}
+
+/// Add `b[i]` to `a[i]`.
+///
+/// # Panics
+/// Panics if the slices do not have the same length.
+pub fn add_slice(a: &mut [SimdVector], b: &[SimdVector]) {
+    assert_eq!(a.len(), b.len(), "slices must have the same length");
+    for (a, b) in a.iter_mut().zip(b) {
+        *a += *b;
+    }
+}
+
+/// Subtract `b[i]` from `a[i]`.
+///
+/// # Panics
+/// Panics if the slices do not have the same length.
+pub fn sub_slice(a: &mut [SimdVector], b: &[SimdVector]) {
+    assert_eq!(a.len(), b.len(), "slices must have the same length");
+    for (a, b) in a.iter_mut().zip(b) {
+        *a -= *b;
+    }
+}
+
+/// Multiply every vector of `a` by `s`.
+pub fn scale_slice(a: &mut [SimdVector], s: f32) {
+    let s = SimdVector::splat(s);
+    for a in a.iter_mut() {
+        *a *= s;
+    }
+}
+
+/// Add `b[i] * s` to `a[i]`, which is the usual explicit Euler step
+/// `position += velocity * dt`.
+///
+/// # Panics
+/// Panics if the slices do not have the same length.
+///
+/// # Example
+/// ```
+/// use math_vector::simd::{add_scaled_slice, SimdVector};
+/// let mut positions = vec![SimdVector::zero(); 3];
+/// let velocities = vec![SimdVector::new(1.0, 2.0, 3.0); 3];
+/// add_scaled_slice(&mut positions, &velocities, 0.5);
+/// assert_eq!(positions[2], SimdVector::new(0.5, 1.0, 1.5));
+/// ```
+pub fn add_scaled_slice(a: &mut [SimdVector], b: &[SimdVector], s: f32) {
+    assert_eq!(a.len(), b.len(), "slices must have the same length");
+    let s = SimdVector::splat(s);
+    for (a, b) in a.iter_mut().zip(b) {
+        *a += *b * s;
+    }
+}
+
+/// Normalize every vector of `a`.
+/// Vectors of length zero are left untouched.
+pub fn normalize_slice(a: &mut [SimdVector]) {
+    let (zero, one) = (imp::splat(0.0), imp::splat(1.0));
+    let mut chunks = a.chunks_exact_mut(4);
+    for a in chunks.by_ref() {
+        let v = load4(a);
+        let len_sq = imp::dot4(v, v);
+        let factors = imp::div(one, imp::sqrt(len_sq));
+        scale4(a, imp::select_gt(len_sq, zero, factors, one));
+    }
+    for a in chunks.into_remainder() {
+        a.normalize();
+    }
+}
+
+/// Limit the length of every vector of `a` to `max_length`.
+pub fn limit_slice(a: &mut [SimdVector], max_length: f32) {
+    let (max, max_sq, one) = (
+        imp::splat(max_length),
+        imp::splat(max_length * max_length),
+        imp::splat(1.0),
+    );
+    let mut chunks = a.chunks_exact_mut(4);
+    for a in chunks.by_ref() {
+        let v = load4(a);
+        let len_sq = imp::dot4(v, v);
+        let factors = imp::div(max, imp::sqrt(len_sq));
+        scale4(a, imp::select_gt(len_sq, max_sq, factors, one));
+    }
+    for a in chunks.into_remainder() {
+        a.limit(max_length);
+    }
+}
@@ -0,0 +1,234 @@
+use crate::matrix::{Matrix3, Matrix4};
+use crate::quaternion::Quaternion;
+use crate::vector::Vector;
+use core::fmt::{Debug, Display, Result};
+use numeric::Float;
+
+/// An affine transformation made of a scale, a rotation and a translation,
+/// applied in this order.
+///
+/// Transforms are composed like matrices: `parent * child` first applies
+/// `child`, then `parent`, so the world transform of a scene object is the
+/// product of the local transforms from the root down to the object.
+///
+/// A non-uniform scale followed by a rotation cannot always be expressed with
+/// a single scale and rotation. In that case `compose` and `inverse` return the
+/// closest transform, and `to_matrix` should be used to accumulate the exact
+/// result.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub struct Transform<F: Float> {
+    pub position: Vector<F>,
+    pub rotation: Quaternion<F>,
+    pub scale: Vector<F>,
+}
+
+impl<F: Float + Debug> Display for Transform<F> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> Result {
+        write!(
+            f,
+            "Transform({}, {}, {})",
+            self.position, self.rotation, self.scale
+        )
+    }
+}
+
+impl<F: Float> Transform<F> {
+    /// Create a new transform.
+    pub fn new(position: Vector<F>, rotation: Quaternion<F>, scale: Vector<F>) -> Self {
+        Self {
+            position,
+            rotation,
+            scale,
+        }
+    }
+
+    /// Construct the identity transform, which leaves everything in place.
+    pub fn identity() -> Self {
+        let one = F::one();
+        Self::new(
+            Vector::new(F::zero(), F::zero(), F::zero()),
+            Quaternion::identity(),
+            Vector::new(one, one, one),
+        )
+    }
+
+    /// Construct a transform which only translates.
+    pub fn from_translation(position: Vector<F>) -> Self {
+        Self {
+            position,
+            ..Self::identity()
+        }
+    }
+
+    /// Construct a transform which only rotates.
+    pub fn from_rotation(rotation: Quaternion<F>) -> Self {
+        Self {
+            rotation,
+            ..Self::identity()
+        }
+    }
+
+    /// Construct a transform which only scales.
+    pub fn from_scale(scale: Vector<F>) -> Self {
+        Self {
+            scale,
+            ..Self::identity()
+        }
+    }
+
+    /// Decompose an affine matrix into a transform, or return `None` if the
+    /// matrix is singular. A negative determinant is reflected on the `x` scale.
+    /// Shearing and projective parts are lost.
+    pub fn from_matrix(m: Matrix4<F>) -> Option<Self> {
+        let linear = Matrix3::from(m);
+        let det = linear.determinant();
+        if det == F::zero() {
+            return None;
+        }
+
+        let mut scale = Vector::new(
+            linear.col(0).length(),
+            linear.col(1).length(),
+            linear.col(2).length(),
+        );
+        if det < F::zero() {
+            scale.x = -scale.x;
+        }
+        let rotation = Matrix3::from_cols(
+            linear.col(0) / scale.x,
+            linear.col(1) / scale.y,
+            linear.col(2) / scale.z,
+        );
+        let [x, y, z, _] = m.col(3);
+        Some(Self::new(
+            Vector::new(x, y, z),
+            Quaternion::from_matrix(rotation),
+            scale,
+        ))
+    }
+
+    /// Return the matrix applying this transform, that is `T * R * S`.
+    ///
+    /// # Example
+    /// ```
+    /// use math_vector::{Quaternion, Transform, Vector};
+    /// let t = Transform::new(
+    ///     Vector::new(1.0, 2.0, 3.0),
+    ///     Quaternion::from_rotation(1.0, Vector::new(0.0, 1.0, 0.0)),
+    ///     Vector::new(2.0, 2.0, 2.0),
+    /// );
+    /// let p = Vector::new(4.0, 5.0, 6.0);
+    /// assert!((t.to_matrix() * p).is_close(t.transform_point(p)));
+    /// ```
+    pub fn to_matrix(self) -> Matrix4<F> {
+        let r = self.rotation.to_matrix();
+        let s = self.scale;
+        let t = self.position;
+        let o = F::zero();
+        Matrix4::from_cols(
+            [
+                r.cols[0][0] * s.x,
+                r.cols[0][1] * s.x,
+                r.cols[0][2] * s.x,
+                o,
+            ],
+            [
+                r.cols[1][0] * s.y,
+                r.cols[1][1] * s.y,
+                r.cols[1][2] * s.y,
+                o,
+            ],
+            [
+                r.cols[2][0] * s.z,
+                r.cols[2][1] * s.z,
+                r.cols[2][2] * s.z,
+                o,
+            ],
+            [t.x, t.y, t.z, F::one()],
+        )
+    }
+
+    /// Return the given point transformed: scaled, rotated, then translated.
+    pub fn transform_point(self, p: Vector<F>) -> Vector<F> {
+        self.rotation.rotate_vector(p * self.scale) + self.position
+    }
+
+    /// Return the given direction transformed: scaled then rotated.
+    /// Directions are not affected by the translation.
+    pub fn transform_vector(self, v: Vector<F>) -> Vector<F> {
+        self.rotation.rotate_vector(v * self.scale)
+    }
+
+    /// Return the given point transformed by the inverse of this transform.
+    /// Unlike `inverse().transform_point(p)`, this is exact even with a
+    /// non-uniform scale.
+    pub fn inverse_transform_point(self, p: Vector<F>) -> Vector<F> {
+        self.rotation.conjugated().rotate_vector(p - self.position) / self.scale
+    }
+
+    /// Return the given direction transformed by the inverse of this transform.
+    pub fn inverse_transform_vector(self, v: Vector<F>) -> Vector<F> {
+        self.rotation.conjugated().rotate_vector(v) / self.scale
+    }
+
+    /// Return the transform applying `other` first, then `self`.
+    /// This is what `self * other` does.
+    ///
+    /// # Example
+    /// ```
+    /// use math_vector::{Transform, Vector};
+    /// let parent = Transform::from_translation(Vector::new(10.0, 0.0, 0.0));
+    /// let child = Transform::from_scale(Vector::new(2.0, 2.0, 2.0));
+    /// let world = parent.compose(child);
+    /// assert_eq!(world.transform_point(Vector::new(1.0, 1.0, 1.0)), Vector::new(12.0, 2.0, 2.0));
+    /// ```
+    pub fn compose(self, other: Self) -> Self {
+        Self::new(
+            self.transform_point(other.position),
+            self.rotation * other.rotation,
+            self.scale * other.scale,
+        )
+    }
+
+    /// Return the inverse transform, or `None` if a scale component is zero.
+    /// The result is exact when the scale is uniform, see `Transform`.
+    pub fn inverse(self) -> Option<Self> {
+        let zero = F::zero();
+        if self.scale.x == zero || self.scale.y == zero || self.scale.z == zero {
+            return None;
+        }
+        let one = F::one();
+        let scale = Vector::new(one, one, one) / self.scale;
+        let rotation = self.rotation.conjugated();
+        Some(Self::new(
+            rotation.rotate_vector(-self.position) * scale,
+            rotation,
+            scale,
+        ))
+    }
+
+    /// Interpolate between two transforms: the position and the scale are
+    /// linearly interpolated, the rotation is spherically interpolated.
+    ///
+    /// # Example
+    /// ```
+    /// use math_vector::{Transform, Vector};
+    /// let a = Transform::from_translation(Vector::new(0.0, 0.0, 0.0));
+    /// let b = Transform::from_translation(Vector::new(2.0, 4.0, 0.0));
+    /// assert_eq!(a.lerp(b, 0.5).position, Vector::new(1.0, 2.0, 0.0));
+    /// ```
+    pub fn lerp(self, other: Self, t: F) -> Self {
+        Self::new(
+            self.position.lerp(other.position, t),
+            self.rotation.slerp(other.rotation, t),
+            self.scale.lerp(other.scale, t),
+        )
+    }
+}
+
+impl<F: Float> From<Transform<F>> for Matrix4<F> {
+    /// Performs the conversion, see `Transform::to_matrix`.
+    fn from(t: Transform<F>) -> Self {
+        t.to_matrix()
+    }
+}
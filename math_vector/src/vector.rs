@@ -1,8 +1,12 @@
-use numeric::{Float, One, Zero};
-use std::{
+use crate::protocol::{inv_sqrt, Precision};
+use crate::vector2::Vector2;
+use crate::vector4::Vector4;
+use core::{
     fmt::{Debug, Display, Result},
     ops::MulAssign,
 };
+use numeric::{Float, One, Zero};
+use proc_vector::swizzle;
 
 /// A 3D vector, containing an `x`, `y` and a `z` floating point value.
 ///
@@ -16,8 +20,11 @@ pub struct Vector<F: Float> {
     pub z: F,
 }
 
+/// An alias of `Vector`, for symmetry with `Vector2` and `Vector4`.
+pub type Vector3<F> = Vector<F>;
+
 impl<F: Float + Debug> Display for Vector<F> {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> Result {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> Result {
         write!(f, "Vector({:#?}, {:#?}, {:#?})", self.x, self.y, self.z)
     }
 }
@@ -43,6 +50,21 @@ impl<F: Copy + Clone + Float> Vector<F> {
             z: self.z.into(),
         }
     }
+
+    /// Drop the `z` component of the vector.
+    pub fn truncate(self) -> Vector2<F> {
+        Vector2::new(self.x, self.y)
+    }
+
+    /// Extend the vector to 4D with the given `w` component.
+    /// Use 1 for points and 0 for directions in homogeneous coordinates.
+    pub fn extend(self, w: F) -> Vector4<F> {
+        Vector4::new(self.x, self.y, self.z, w)
+    }
+}
+
+impl<F: Float> Vector<F> {
+    swizzle!(x, y, z);
 }
 
 impl<F: Float> Into<(F, F, F)> for Vector<F> {
@@ -86,9 +108,9 @@ impl<F: Default + Zero + One + Float> Vector<F> {
     ///
     /// # Example
     /// ```
-    /// use phoenyx::Vector;
-    /// let v = Vector::default();
-    /// assert_eq!(Vector::new(0, 0, 0), v);
+    /// use math_vector::Vector;
+    /// let v: Vector<f64> = Vector::default();
+    /// assert_eq!(Vector::new(0.0, 0.0, 0.0), v);
     /// ```
     pub fn default() -> Self {
         Self {
@@ -127,7 +149,7 @@ impl<F: Default + Zero + One + Float> Vector<F> {
     ///
     /// # Example
     /// ```
-    /// use phoenyx::Vector;
+    /// use math_vector::Vector;
     /// let v = Vector::new(10.0, 20.0, 30.0);
     /// assert_eq!(Vector::new(10.0, 0.0, 0.0), v.abscissa());
     /// ```
@@ -143,7 +165,7 @@ impl<F: Default + Zero + One + Float> Vector<F> {
     ///
     /// # Example
     /// ```
-    /// use phoenyx::Vector;
+    /// use math_vector::Vector;
     /// let v = Vector::new(10.0, 20.0, 30.0);
     /// assert_eq!(Vector::new(0.0, 20.0, 0.0), v.ordinate());
     /// ```
@@ -159,7 +181,7 @@ impl<F: Default + Zero + One + Float> Vector<F> {
     ///
     /// # Example
     /// ```
-    /// use phoenyx::Vector;
+    /// use math_vector::Vector;
     /// let v = Vector::new(10.0, 20.0, 30.0);
     /// assert_eq!(Vector::new(0.0, 0.0, 30.0), v.applicate());
     /// ```
@@ -206,7 +228,7 @@ impl<F: Copy + Clone + Float> Vector<F> {
     ///
     /// # Example
     /// ```
-    /// use phoenyx::Vector;
+    /// use math_vector::Vector;
     /// let v1 = Vector::new(1.0, 2.0, 3.0);
     /// let v2 = Vector::new(2.0, 3.0, 4.0);
     /// assert_eq!(Vector::new(1.5, 2.5, 3.5), v1.lerp(v2, 0.5));
@@ -221,10 +243,11 @@ impl<F: Float> Vector<F> {
     ///
     /// # Example
     /// ```
-    /// use phoenyx::Vector;
+    /// use math_vector::Vector;
     /// let x = Vector::new(1.0, 0.0, 0.0);
     /// let y = Vector::new(0.0, 1.0, 0.0);
-    /// assert_eq!(x.dot(y), 1.0);
+    /// assert_eq!(x.dot(y), 0.0);
+    /// assert_eq!(x.dot(x), 1.0);
     /// ```
     pub fn dot(self, other: Vector<F>) -> F {
         self.x * other.x + self.y * other.y + self.z * other.z
@@ -234,7 +257,7 @@ impl<F: Float> Vector<F> {
     ///
     /// # Example
     /// ```
-    /// use phoenyx::Vector;
+    /// use math_vector::Vector;
     /// let x = Vector::new(1.0, 0.0, 0.0);
     /// let y = Vector::new(0.0, 1.0, 0.0);
     /// let z = Vector::new(0.0, 0.0, 1.0);
@@ -309,21 +332,27 @@ impl<F: Float> Vector<F> {
     /// Compare two vectors for equality.
     /// The comparison is done with the relative error `epsilon`
     /// so that the error is relative to the magnitude of both vectors.
+    /// Near the origin, a relative tolerance alone rejects even tiny non-zero
+    /// differences, so compare small vectors with `ApproxEq::approx_eq` and a
+    /// `Tolerance::absolute` instead. Two zero vectors are still close.
     ///
     /// # Example
     /// ```
-    /// use phoenyx::Vector;
+    /// use math_vector::Vector;
     /// let x = Vector::new(1.0, 0.0, 0.0);
     /// let y = Vector::new(1.0000000001, 0.0, 0.0);
     /// assert!(y.is_close(x));
     ///
     /// let x = Vector::new(1000000.0, 0.0, 0.0);
-    /// let y = Vector::new(1000000000.1, 0.0, 0.0);
+    /// let y = Vector::new(1000000.0000001, 0.0, 0.0);
     /// assert!(y.is_close(x));
     ///
     /// let x = Vector::new(1.0, 0.0, 0.0);
     /// let y = Vector::new(1.0001, 0.0, 0.0);
     /// assert!(!y.is_close(x));
+    ///
+    /// assert!(Vector::<f64>::zero().is_close(Vector::zero()));
+    /// assert!(!Vector::new(1e-10, 0.0, 0.0).is_close(Vector::zero()));
     /// ```
     pub fn is_close(self, reference: Vector<F>) -> bool {
         let num = (self - reference).length_squared();
@@ -335,11 +364,17 @@ impl<F: Float> Vector<F> {
 
 impl<F: Float + MulAssign> Vector<F> {
     /// Set the length of the vector.
-    /// The scaling factor is computed with the help of the fast inverse square root.
+    /// See `set_length_with` to trade accuracy for speed.
     pub fn set_length(&mut self, length: F) {
+        self.set_length_with(length, Precision::Exact);
+    }
+
+    /// Set the length of the vector, computing the scaling factor with the
+    /// given precision policy.
+    pub fn set_length_with(&mut self, length: F, precision: Precision) {
         let len_sq = self.length_squared();
         if len_sq != F::zero() {
-            let factor = length * len_sq.sqrt().recip();
+            let factor = length * inv_sqrt(len_sq, precision);
             self.x *= factor;
             self.y *= factor;
             self.z *= factor;
@@ -358,7 +393,6 @@ impl<F: Float + MulAssign> Vector<F> {
     }
 
     /// Return a new vector whose length is set to `length`.
-    /// The scaling factor is computed with the help of the fast inverse square root.
     pub fn with_length(self, length: F) -> Self {
         let mut v = self;
         v.set_length(length);
@@ -374,9 +408,15 @@ impl<F: Float + MulAssign> Vector<F> {
 
     /// Limit the length of the vector to a maximum length.
     pub fn limit(&mut self, max_length: F) {
+        self.limit_with(max_length, Precision::Exact);
+    }
+
+    /// Limit the length of the vector to a maximum length, computing the
+    /// scaling factor with the given precision policy.
+    pub fn limit_with(&mut self, max_length: F, precision: Precision) {
         let len_sq = self.length_squared();
         if len_sq > max_length * max_length {
-            let factor = max_length * len_sq.sqrt().recip();
+            let factor = max_length * inv_sqrt(len_sq, precision);
             self.x *= factor;
             self.y *= factor;
             self.z *= factor;
@@ -390,6 +430,13 @@ impl<F: Float + MulAssign> Vector<F> {
         v
     }
 
+    /// Return a limited version of the vector, see `limit_with`.
+    pub fn limited_with(self, max_length: F, precision: Precision) -> Self {
+        let mut v = self;
+        v.limit_with(max_length, precision);
+        v
+    }
+
     /// Normalize the vector.
     /// Does nothing if the vector is of length zero.
     pub fn normalize(&mut self) {
@@ -403,6 +450,27 @@ impl<F: Float + MulAssign> Vector<F> {
         v.normalize();
         v
     }
+
+    /// Normalize the vector with the given precision policy.
+    /// Does nothing if the vector is of length zero.
+    ///
+    /// # Example
+    /// ```
+    /// use math_vector::{Precision, Vector};
+    /// let mut v = Vector::new(3.0, 0.0, 4.0);
+    /// v.normalize_with(Precision::Exact);
+    /// assert!(v.is_close(Vector::new(0.6, 0.0, 0.8)));
+    /// ```
+    pub fn normalize_with(&mut self, precision: Precision) {
+        self.set_length_with(F::one(), precision);
+    }
+
+    /// Return a normalized copy of the vector, see `normalize_with`.
+    pub fn normalized_with(self, precision: Precision) -> Self {
+        let mut v = self;
+        v.normalize_with(precision);
+        v
+    }
 }
 
 impl<F: Float> Vector<F> {
@@ -430,11 +498,11 @@ impl<F: Float> Vector<F> {
     ///
     /// # Example
     /// ```
-    /// use phoenyx::Vector;
+    /// use math_vector::Vector;
     /// let v = Vector::new(1.0, 0.0, 0.0);
     /// let axis = Vector::new(0.0, 0.0, 1.0);
     /// let angle = std::f64::consts::PI / 2.0;
-    /// let rotated = v.rotated(axis, angle);
+    /// let rotated = v.rotated(angle, axis);
     /// assert_eq!(rotated.is_close(Vector::new(0.0, 1.0, 0.0)), true);
     /// ```
     pub fn rotated(self, angle: F, axis: Vector<F>) -> Vector<F> {
@@ -447,11 +515,11 @@ impl<F: Float> Vector<F> {
     ///
     /// # Example
     /// ```
-    /// use phoenyx::Vector;
+    /// use math_vector::Vector;
     /// let mut v = Vector::new(1.0, 0.0, 0.0);
     /// let axis = Vector::new(0.0, 0.0, 1.0);
     /// let angle = std::f64::consts::PI / 2.0;
-    /// v.rotate(axis, angle);
+    /// v.rotate(angle, axis);
     /// assert_eq!(v.is_close(Vector::new(0.0, 1.0, 0.0)), true);
     /// ```
     pub fn rotate(&mut self, angle: F, axis: Vector<F>) {
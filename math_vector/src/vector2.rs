@@ -0,0 +1,346 @@
+use crate::protocol::{inv_sqrt, Precision};
+use crate::vector::Vector;
+use crate::vector4::Vector4;
+use core::{
+    fmt::{Debug, Display, Result},
+    ops::MulAssign,
+};
+use numeric::{Float, One, Zero};
+use proc_vector::swizzle;
+
+/// A 2D vector, containing an `x` and a `y` floating point value.
+///
+/// This is the planar counterpart of `Vector`, to be used for 2D sketches,
+/// texture coordinates or screen positions instead of a `Vector` with a zero `z`.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub struct Vector2<F: Float> {
+    pub x: F,
+    pub y: F,
+}
+
+impl<F: Float + Debug> Display for Vector2<F> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> Result {
+        write!(f, "Vector2({:#?}, {:#?})", self.x, self.y)
+    }
+}
+
+impl<F: Float> Vector2<F> {
+    /// Create a new vector.
+    pub fn new(x: F, y: F) -> Self {
+        Self { x, y }
+    }
+
+    /// Extend the vector to 3D with the given `z` component.
+    ///
+    /// # Example
+    /// ```
+    /// use math_vector::{Vector, Vector2};
+    /// let v = Vector2::new(1.0, 2.0);
+    /// assert_eq!(v.extend(3.0), Vector::new(1.0, 2.0, 3.0));
+    /// assert_eq!(v.extend(3.0).truncate(), v);
+    /// ```
+    pub fn extend(self, z: F) -> Vector<F> {
+        Vector::new(self.x, self.y, z)
+    }
+}
+
+impl<F: Float> Vector2<F> {
+    swizzle!(x, y);
+}
+
+impl<F: Float> From<Vector2<F>> for (F, F) {
+    /// Performs the conversion.
+    fn from(v: Vector2<F>) -> Self {
+        (v.x, v.y)
+    }
+}
+
+impl<F: Float> From<(F, F)> for Vector2<F> {
+    /// Performs the conversion.
+    fn from(src: (F, F)) -> Self {
+        Self { x: src.0, y: src.1 }
+    }
+}
+
+impl<F: Float> From<Vector2<F>> for [F; 2] {
+    /// Performs the conversion.
+    fn from(v: Vector2<F>) -> Self {
+        [v.x, v.y]
+    }
+}
+
+impl<F: Float> From<[F; 2]> for Vector2<F> {
+    /// Performs the conversion.
+    fn from(src: [F; 2]) -> Self {
+        Self {
+            x: src[0],
+            y: src[1],
+        }
+    }
+}
+
+impl<F: Float> From<Vector2<F>> for Vector<F> {
+    /// Extend the vector with a zero `z` component.
+    fn from(src: Vector2<F>) -> Self {
+        src.extend(F::zero())
+    }
+}
+
+impl<F: Default + Zero + One + Float> Vector2<F> {
+    /// Default construct a `Vector2` with all components set to 0.
+    pub fn default() -> Self {
+        Self {
+            x: F::default(),
+            y: F::default(),
+        }
+    }
+
+    /// Construct a `Vector2` with all components set to 1.
+    pub fn one() -> Self {
+        Self {
+            x: F::one(),
+            y: F::one(),
+        }
+    }
+
+    /// Construct a `Vector2` with all components set to 0.
+    pub fn zero() -> Self {
+        Self {
+            x: F::zero(),
+            y: F::zero(),
+        }
+    }
+
+    /// Reset all components to 0.
+    pub fn reset(&mut self) {
+        self.x = F::zero();
+        self.y = F::zero();
+    }
+
+    /// Create a new unit vector from its angle.
+    pub fn from_angle(theta: F) -> Self {
+        Self {
+            x: theta.cos(),
+            y: theta.sin(),
+        }
+    }
+}
+
+impl<F: Copy + Clone + Float> Vector2<F> {
+    /// Linearly interpolate between two vectors.
+    ///
+    /// # Example
+    /// ```
+    /// use math_vector::Vector2;
+    /// let v1 = Vector2::new(1.0, 2.0);
+    /// let v2 = Vector2::new(2.0, 3.0);
+    /// assert_eq!(Vector2::new(1.5, 2.5), v1.lerp(v2, 0.5));
+    /// ```
+    pub fn lerp(self, other: Self, t: F) -> Self {
+        self + (other - self) * t
+    }
+}
+
+impl<F: Float> Vector2<F> {
+    /// Return the dot product of two vectors.
+    pub fn dot(self, other: Vector2<F>) -> F {
+        self.x * other.x + self.y * other.y
+    }
+
+    /// Return the z component of the cross product of the two vectors
+    /// extended to 3D, also known as the perp dot product.
+    /// It is positive when `other` is counterclockwise from `self`.
+    pub fn cross(self, other: Vector2<F>) -> F {
+        self.x * other.y - self.y * other.x
+    }
+
+    /// Return the vector rotated counterclockwise by a quarter turn.
+    pub fn perp(self) -> Self {
+        Self {
+            x: -self.y,
+            y: self.x,
+        }
+    }
+
+    /// Return the squared length of the vector.
+    /// This is faster than `length` because it does not need to compute the square root.
+    pub fn length_squared(self) -> F {
+        self.dot(self)
+    }
+
+    /// Return the length of the vector.
+    /// Prefer `length_squared` if you need to avoid computing the square root.
+    pub fn length(self) -> F {
+        self.length_squared().sqrt()
+    }
+
+    /// Return the squared euclidean distance between two vectors.
+    pub fn distance_squared(self, other: Self) -> F {
+        (self - other).length_squared()
+    }
+
+    /// Return the euclidean distance between two vectors.
+    pub fn distance(self, other: Self) -> F {
+        (self - other).length()
+    }
+
+    /// Return a new vector which is the reflection of the current one
+    /// on a surface with the given local normal.
+    pub fn reflect(self, normal: Self) -> Self {
+        self - normal * self.dot(normal) * F::from(2.0).unwrap()
+    }
+
+    /// Return a new vector which is the projection of the current one
+    /// onto the given vector.
+    pub fn project(self, other: Self) -> Self {
+        other * (self.dot(other) / other.length_squared())
+    }
+
+    /// Return a new vector which is the rejection of the current one
+    /// onto the given vector.
+    pub fn reject(self, other: Self) -> Self {
+        self - self.project(other)
+    }
+
+    /// Compare two vectors for equality.
+    /// The comparison is done with the relative error `epsilon`
+    /// so that the error is relative to the magnitude of both vectors.
+    pub fn is_close(self, reference: Vector2<F>) -> bool {
+        let num = (self - reference).length_squared();
+        let den = reference.length_squared();
+
+        num <= den * Float::epsilon()
+    }
+
+    /// Return the angle between the positive x axis and the vector.
+    pub fn heading(self) -> F {
+        self.y.atan2(self.x)
+    }
+
+    /// Return the signed angle in radians from this vector to the other one.
+    pub fn angle_between(self, other: Vector2<F>) -> F {
+        F::atan2(self.cross(other), self.dot(other))
+    }
+
+    /// Return a new vector rotated counterclockwise by a given angle in radians.
+    ///
+    /// # Example
+    /// ```
+    /// use math_vector::Vector2;
+    /// let v = Vector2::new(1.0, 0.0);
+    /// let rotated = v.rotated(std::f64::consts::PI / 2.0);
+    /// assert!(rotated.is_close(Vector2::new(0.0, 1.0)));
+    /// ```
+    pub fn rotated(self, angle: F) -> Self {
+        let (sin, cos) = angle.sin_cos();
+        Self {
+            x: self.x * cos - self.y * sin,
+            y: self.x * sin + self.y * cos,
+        }
+    }
+
+    /// Rotate the vector counterclockwise by a given angle in radians.
+    pub fn rotate(&mut self, angle: F) {
+        *self = self.rotated(angle);
+    }
+}
+
+impl<F: Float + MulAssign> Vector2<F> {
+    /// Set the length of the vector.
+    /// See `set_length_with` to trade accuracy for speed.
+    pub fn set_length(&mut self, length: F) {
+        self.set_length_with(length, Precision::Exact);
+    }
+
+    /// Set the length of the vector, computing the scaling factor with the
+    /// given precision policy.
+    pub fn set_length_with(&mut self, length: F, precision: Precision) {
+        let len_sq = self.length_squared();
+        if len_sq != F::zero() {
+            let factor = length * inv_sqrt(len_sq, precision);
+            self.x *= factor;
+            self.y *= factor;
+        }
+    }
+
+    /// Set the squared length of the vector.
+    pub fn set_length_squared(&mut self, length_squared: F) {
+        let len_sq = self.length_squared();
+        if len_sq != F::zero() {
+            let factor = length_squared / len_sq;
+            self.x *= factor;
+            self.y *= factor;
+        }
+    }
+
+    /// Return a new vector whose length is set to `length`.
+    pub fn with_length(self, length: F) -> Self {
+        let mut v = self;
+        v.set_length(length);
+        v
+    }
+
+    /// Return a new vector whose squared length is set to `length_squared`.
+    pub fn with_length_squared(self, length_squared: F) -> Self {
+        let mut v = self;
+        v.set_length_squared(length_squared);
+        v
+    }
+
+    /// Limit the length of the vector to a maximum length.
+    pub fn limit(&mut self, max_length: F) {
+        self.limit_with(max_length, Precision::Exact);
+    }
+
+    /// Limit the length of the vector to a maximum length, computing the
+    /// scaling factor with the given precision policy.
+    pub fn limit_with(&mut self, max_length: F, precision: Precision) {
+        let len_sq = self.length_squared();
+        if len_sq > max_length * max_length {
+            let factor = max_length * inv_sqrt(len_sq, precision);
+            self.x *= factor;
+            self.y *= factor;
+        }
+    }
+
+    /// Return a limited version of the vector.
+    pub fn limited(self, max_length: F) -> Self {
+        let mut v = self;
+        v.limit(max_length);
+        v
+    }
+
+    /// Return a limited version of the vector, see `limit_with`.
+    pub fn limited_with(self, max_length: F, precision: Precision) -> Self {
+        let mut v = self;
+        v.limit_with(max_length, precision);
+        v
+    }
+
+    /// Normalize the vector.
+    /// Does nothing if the vector is of length zero.
+    pub fn normalize(&mut self) {
+        self.set_length(F::one());
+    }
+
+    /// Return a normalized copy of the vector.
+    /// Does nothing if the vector is of length zero.
+    pub fn normalized(self) -> Self {
+        let mut v = self;
+        v.normalize();
+        v
+    }
+
+    /// Normalize the vector with the given precision policy.
+    /// Does nothing if the vector is of length zero.
+    pub fn normalize_with(&mut self, precision: Precision) {
+        self.set_length_with(F::one(), precision);
+    }
+
+    /// Return a normalized copy of the vector, see `normalize_with`.
+    pub fn normalized_with(self, precision: Precision) -> Self {
+        let mut v = self;
+        v.normalize_with(precision);
+        v
+    }
+}
@@ -0,0 +1,305 @@
+use crate::protocol::{inv_sqrt, Precision};
+use crate::vector::Vector;
+use crate::vector2::Vector2;
+use core::{
+    fmt::{Debug, Display, Result},
+    ops::MulAssign,
+};
+use numeric::{Float, One, Zero};
+use proc_vector::swizzle;
+
+/// A 4D vector, containing an `x`, `y`, `z` and a `w` floating point value.
+///
+/// This is mostly used for homogeneous coordinates, where `w` is 1 for points
+/// and 0 for directions, and for RGBA colors.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub struct Vector4<F: Float> {
+    pub x: F,
+    pub y: F,
+    pub z: F,
+    pub w: F,
+}
+
+impl<F: Float + Debug> Display for Vector4<F> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> Result {
+        write!(
+            f,
+            "Vector4({:#?}, {:#?}, {:#?}, {:#?})",
+            self.x, self.y, self.z, self.w
+        )
+    }
+}
+
+impl<F: Float> Vector4<F> {
+    /// Create a new vector.
+    pub fn new(x: F, y: F, z: F, w: F) -> Self {
+        Self { x, y, z, w }
+    }
+
+    /// Drop the `w` component of the vector.
+    ///
+    /// # Example
+    /// ```
+    /// use math_vector::{Vector, Vector4};
+    /// let v = Vector4::new(1.0, 2.0, 3.0, 4.0);
+    /// assert_eq!(v.truncate(), Vector::new(1.0, 2.0, 3.0));
+    /// assert_eq!(v.truncate().extend(4.0), v);
+    /// ```
+    pub fn truncate(self) -> Vector<F> {
+        Vector::new(self.x, self.y, self.z)
+    }
+}
+
+impl<F: Float> Vector4<F> {
+    swizzle!(x, y, z, w);
+}
+
+impl<F: Float> From<Vector4<F>> for (F, F, F, F) {
+    /// Performs the conversion.
+    fn from(v: Vector4<F>) -> Self {
+        (v.x, v.y, v.z, v.w)
+    }
+}
+
+impl<F: Float> From<(F, F, F, F)> for Vector4<F> {
+    /// Performs the conversion.
+    fn from(src: (F, F, F, F)) -> Self {
+        Self {
+            x: src.0,
+            y: src.1,
+            z: src.2,
+            w: src.3,
+        }
+    }
+}
+
+impl<F: Float> From<Vector4<F>> for [F; 4] {
+    /// Performs the conversion.
+    fn from(v: Vector4<F>) -> Self {
+        [v.x, v.y, v.z, v.w]
+    }
+}
+
+impl<F: Float> From<[F; 4]> for Vector4<F> {
+    /// Performs the conversion.
+    fn from(src: [F; 4]) -> Self {
+        Self {
+            x: src[0],
+            y: src[1],
+            z: src[2],
+            w: src[3],
+        }
+    }
+}
+
+impl<F: Default + Zero + One + Float> Vector4<F> {
+    /// Default construct a `Vector4` with all components set to 0.
+    pub fn default() -> Self {
+        Self {
+            x: F::default(),
+            y: F::default(),
+            z: F::default(),
+            w: F::default(),
+        }
+    }
+
+    /// Construct a `Vector4` with all components set to 1.
+    pub fn one() -> Self {
+        Self {
+            x: F::one(),
+            y: F::one(),
+            z: F::one(),
+            w: F::one(),
+        }
+    }
+
+    /// Construct a `Vector4` with all components set to 0.
+    pub fn zero() -> Self {
+        Self {
+            x: F::zero(),
+            y: F::zero(),
+            z: F::zero(),
+            w: F::zero(),
+        }
+    }
+
+    /// Reset all components to 0.
+    pub fn reset(&mut self) {
+        self.x = F::zero();
+        self.y = F::zero();
+        self.z = F::zero();
+        self.w = F::zero();
+    }
+}
+
+impl<F: Copy + Clone + Float> Vector4<F> {
+    /// Linearly interpolate between two vectors.
+    ///
+    /// # Example
+    /// ```
+    /// use math_vector::Vector4;
+    /// let v1 = Vector4::new(1.0, 2.0, 3.0, 4.0);
+    /// let v2 = Vector4::new(2.0, 3.0, 4.0, 5.0);
+    /// assert_eq!(Vector4::new(1.5, 2.5, 3.5, 4.5), v1.lerp(v2, 0.5));
+    /// ```
+    pub fn lerp(self, other: Self, t: F) -> Self {
+        self + (other - self) * t
+    }
+}
+
+impl<F: Float> Vector4<F> {
+    /// Return the dot product of two vectors.
+    pub fn dot(self, other: Vector4<F>) -> F {
+        self.x * other.x + self.y * other.y + self.z * other.z + self.w * other.w
+    }
+
+    /// Return the squared length of the vector.
+    /// This is faster than `length` because it does not need to compute the square root.
+    pub fn length_squared(self) -> F {
+        self.dot(self)
+    }
+
+    /// Return the length of the vector.
+    /// Prefer `length_squared` if you need to avoid computing the square root.
+    pub fn length(self) -> F {
+        self.length_squared().sqrt()
+    }
+
+    /// Return the squared euclidean distance between two vectors.
+    pub fn distance_squared(self, other: Self) -> F {
+        (self - other).length_squared()
+    }
+
+    /// Return the euclidean distance between two vectors.
+    pub fn distance(self, other: Self) -> F {
+        (self - other).length()
+    }
+
+    /// Return a new vector which is the projection of the current one
+    /// onto the given vector.
+    pub fn project(self, other: Self) -> Self {
+        other * (self.dot(other) / other.length_squared())
+    }
+
+    /// Return a new vector which is the rejection of the current one
+    /// onto the given vector.
+    pub fn reject(self, other: Self) -> Self {
+        self - self.project(other)
+    }
+
+    /// Compare two vectors for equality.
+    /// The comparison is done with the relative error `epsilon`
+    /// so that the error is relative to the magnitude of both vectors.
+    pub fn is_close(self, reference: Vector4<F>) -> bool {
+        let num = (self - reference).length_squared();
+        let den = reference.length_squared();
+
+        num <= den * Float::epsilon()
+    }
+}
+
+impl<F: Float + MulAssign> Vector4<F> {
+    /// Set the length of the vector.
+    /// See `set_length_with` to trade accuracy for speed.
+    pub fn set_length(&mut self, length: F) {
+        self.set_length_with(length, Precision::Exact);
+    }
+
+    /// Set the length of the vector, computing the scaling factor with the
+    /// given precision policy.
+    pub fn set_length_with(&mut self, length: F, precision: Precision) {
+        let len_sq = self.length_squared();
+        if len_sq != F::zero() {
+            let factor = length * inv_sqrt(len_sq, precision);
+            self.x *= factor;
+            self.y *= factor;
+            self.z *= factor;
+            self.w *= factor;
+        }
+    }
+
+    /// Set the squared length of the vector.
+    pub fn set_length_squared(&mut self, length_squared: F) {
+        let len_sq = self.length_squared();
+        if len_sq != F::zero() {
+            let factor = length_squared / len_sq;
+            self.x *= factor;
+            self.y *= factor;
+            self.z *= factor;
+            self.w *= factor;
+        }
+    }
+
+    /// Return a new vector whose length is set to `length`.
+    pub fn with_length(self, length: F) -> Self {
+        let mut v = self;
+        v.set_length(length);
+        v
+    }
+
+    /// Return a new vector whose squared length is set to `length_squared`.
+    pub fn with_length_squared(self, length_squared: F) -> Self {
+        let mut v = self;
+        v.set_length_squared(length_squared);
+        v
+    }
+
+    /// Limit the length of the vector to a maximum length.
+    pub fn limit(&mut self, max_length: F) {
+        self.limit_with(max_length, Precision::Exact);
+    }
+
+    /// Limit the length of the vector to a maximum length, computing the
+    /// scaling factor with the given precision policy.
+    pub fn limit_with(&mut self, max_length: F, precision: Precision) {
+        let len_sq = self.length_squared();
+        if len_sq > max_length * max_length {
+            let factor = max_length * inv_sqrt(len_sq, precision);
+            self.x *= factor;
+            self.y *= factor;
+            self.z *= factor;
+            self.w *= factor;
+        }
+    }
+
+    /// Return a limited version of the vector.
+    pub fn limited(self, max_length: F) -> Self {
+        let mut v = self;
+        v.limit(max_length);
+        v
+    }
+
+    /// Return a limited version of the vector, see `limit_with`.
+    pub fn limited_with(self, max_length: F, precision: Precision) -> Self {
+        let mut v = self;
+        v.limit_with(max_length, precision);
+        v
+    }
+
+    /// Normalize the vector.
+    /// Does nothing if the vector is of length zero.
+    pub fn normalize(&mut self) {
+        self.set_length(F::one());
+    }
+
+    /// Return a normalized copy of the vector.
+    /// Does nothing if the vector is of length zero.
+    pub fn normalized(self) -> Self {
+        let mut v = self;
+        v.normalize();
+        v
+    }
+
+    /// Normalize the vector with the given precision policy.
+    /// Does nothing if the vector is of length zero.
+    pub fn normalize_with(&mut self, precision: Precision) {
+        self.set_length_with(F::one(), precision);
+    }
+
+    /// Return a normalized copy of the vector, see `normalize_with`.
+    pub fn normalized_with(self, precision: Precision) -> Self {
+        let mut v = self;
+        v.normalize_with(precision);
+        v
+    }
+}
@@ -0,0 +1,254 @@
+//! Approximate equality of floating point values.
+//!
+//! Two values are approximately equal when they are within an absolute
+//! tolerance, which suits values near zero, or within a relative tolerance
+//! of the larger magnitude, or a given number of representable floats apart.
+//!
+//! ```
+//! use numeric::approx::{ApproxEq, Tolerance};
+//! use numeric::assert_approx_eq;
+//!
+//! assert!(0.1f64.approx_eq(&(1.0 - 0.9), f64::default_tolerance()));
+//! assert!(1e-20f32.approx_eq(&0.0, Tolerance::absolute(1e-12)));
+//! assert!(!1e-20f32.approx_eq(&0.0, Tolerance::ulps(1000)));
+//!
+//! assert_approx_eq!(0.1 + 0.2, 0.3);
+//! assert_approx_eq!(100.0, 100.1, rel = 1e-3);
+//! assert_approx_eq!([1.0, 2.0], [1.0, 2.0 + 1e-15], ulps = 8);
+//! ```
+
+/// The tolerances of an approximate comparison, any of which is enough for
+/// the values to be considered equal.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Tolerance<E> {
+    /// Largest absolute difference.
+    pub abs: E,
+    /// Largest difference relative to the larger magnitude.
+    pub rel: E,
+    /// Largest number of representable values between the two.
+    pub ulps: u32,
+}
+
+impl<E: crate::Zero> Tolerance<E> {
+    /// Return a tolerance which only accepts equal values.
+    pub fn exact() -> Self {
+        Self {
+            abs: E::zero(),
+            rel: E::zero(),
+            ulps: 0,
+        }
+    }
+
+    /// Return an absolute tolerance.
+    pub fn absolute(abs: E) -> Self {
+        Self {
+            abs,
+            ..Self::exact()
+        }
+    }
+
+    /// Return a relative tolerance.
+    pub fn relative(rel: E) -> Self {
+        Self {
+            rel,
+            ..Self::exact()
+        }
+    }
+
+    /// Return a tolerance in units in the last place.
+    pub fn ulps(ulps: u32) -> Self {
+        Self {
+            ulps,
+            ..Self::exact()
+        }
+    }
+}
+
+/// Approximate equality.
+///
+/// Composite types compare their components pairwise with the tolerance of
+/// their component type.
+pub trait ApproxEq<Rhs: ?Sized = Self> {
+    /// The type of the absolute and relative tolerances.
+    type Epsilon: Copy;
+
+    /// Return a tolerance suitable for values computed with a few operations.
+    fn default_tolerance() -> Tolerance<Self::Epsilon>;
+
+    /// Return whether `self` and `other` are equal within `tolerance`.
+    fn approx_eq(&self, other: &Rhs, tolerance: Tolerance<Self::Epsilon>) -> bool;
+
+    /// Return whether `self` and `other` differ beyond `tolerance`.
+    fn approx_ne(&self, other: &Rhs, tolerance: Tolerance<Self::Epsilon>) -> bool {
+        !self.approx_eq(other, tolerance)
+    }
+}
+
+macro_rules! float_approx_eq_impl {
+    ($($t:ident $bits:ident)*) => {$(
+        impl ApproxEq for $t {
+            type Epsilon = $t;
+
+            /// Accept a difference of `EPSILON` in absolute or relative terms,
+            /// or of 4 ulps.
+            fn default_tolerance() -> Tolerance<$t> {
+                Tolerance {
+                    abs: $t::EPSILON,
+                    rel: $t::EPSILON,
+                    ulps: 4,
+                }
+            }
+
+            fn approx_eq(&self, other: &$t, tolerance: Tolerance<$t>) -> bool {
+                let (a, b) = (*self, *other);
+                if a == b {
+                    return true;
+                }
+                if !a.is_finite() || !b.is_finite() {
+                    return false;
+                }
+                let abs = |x: $t| if x < 0.0 { -x } else { x };
+                let diff = abs(a - b);
+                if diff <= tolerance.abs {
+                    return true;
+                }
+                let largest = if abs(a) > abs(b) { abs(a) } else { abs(b) };
+                if diff <= largest * tolerance.rel {
+                    return true;
+                }
+                // Map the floats to integers which have the same order and
+                // are consecutive for consecutive floats.
+                let ordered = |x: $t| {
+                    let i = x.to_bits() as $bits;
+                    if i < 0 {
+                        $bits::MIN.wrapping_sub(i)
+                    } else {
+                        i
+                    }
+                };
+                let ulps = (ordered(a) as i128 - ordered(b) as i128).unsigned_abs();
+                ulps <= tolerance.ulps as u128
+            }
+        }
+    )*};
+}
+
+float_approx_eq_impl!(f32 i32 f64 i64);
+
+impl<A: ApproxEq, const N: usize> ApproxEq for [A; N] {
+    type Epsilon = A::Epsilon;
+
+    fn default_tolerance() -> Tolerance<A::Epsilon> {
+        A::default_tolerance()
+    }
+
+    fn approx_eq(&self, other: &Self, tolerance: Tolerance<A::Epsilon>) -> bool {
+        self.iter()
+            .zip(other)
+            .all(|(a, b)| a.approx_eq(b, tolerance))
+    }
+}
+
+impl<A: ApproxEq> ApproxEq for [A] {
+    type Epsilon = A::Epsilon;
+
+    fn default_tolerance() -> Tolerance<A::Epsilon> {
+        A::default_tolerance()
+    }
+
+    /// Return whether the slices have the same length and approximately
+    /// equal elements.
+    fn approx_eq(&self, other: &Self, tolerance: Tolerance<A::Epsilon>) -> bool {
+        self.len() == other.len()
+            && self
+                .iter()
+                .zip(other)
+                .all(|(a, b)| a.approx_eq(b, tolerance))
+    }
+}
+
+impl<A: ApproxEq, const N: usize> ApproxEq<[A; N]> for [A] {
+    type Epsilon = A::Epsilon;
+
+    fn default_tolerance() -> Tolerance<A::Epsilon> {
+        A::default_tolerance()
+    }
+
+    fn approx_eq(&self, other: &[A; N], tolerance: Tolerance<A::Epsilon>) -> bool {
+        self.approx_eq(&other[..], tolerance)
+    }
+}
+
+/// Return the default tolerance to compare `a` with `b`.
+#[doc(hidden)]
+pub fn default_tolerance_of<A, B>(_: &A, _: &B) -> Tolerance<A::Epsilon>
+where
+    A: ApproxEq<B> + ?Sized,
+    B: ?Sized,
+{
+    A::default_tolerance()
+}
+
+/// Build the tolerance of an approximate assertion.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __approx_tolerance {
+    ($a:expr, $b:expr;) => {
+        $crate::approx::default_tolerance_of($a, $b)
+    };
+    ($a:expr, $b:expr; $($field:ident = $value:expr),+) => {
+        $crate::approx::Tolerance {
+            $($field: $value,)+
+            ..$crate::approx::Tolerance::exact()
+        }
+    };
+}
+
+/// Assert that two values are approximately equal.
+///
+/// The tolerance is the default one of the type, or built from the given
+/// `abs`, `rel` and `ulps` fields, the others being zero.
+///
+/// ```
+/// use numeric::assert_approx_eq;
+///
+/// assert_approx_eq!(1.0f32 / 3.0 * 3.0, 1.0);
+/// assert_approx_eq!(1e-9, 0.0, abs = 1e-6);
+/// assert_approx_eq!(1.0, 1.0 + 2.0 * f64::EPSILON, ulps = 2);
+/// ```
+#[macro_export]
+macro_rules! assert_approx_eq {
+    ($left:expr, $right:expr $(, $field:ident = $value:expr)* $(,)?) => {
+        match (&$left, &$right) {
+            (left, right) => {
+                let tolerance = $crate::__approx_tolerance!(left, right; $($field = $value),*);
+                if !$crate::approx::ApproxEq::approx_eq(left, right, tolerance) {
+                    panic!(
+                        "assertion `left ≈ right` failed\n  left: {:?}\n right: {:?}\n  with: {:?}",
+                        left, right, tolerance
+                    );
+                }
+            }
+        }
+    };
+}
+
+/// Assert that two values are not approximately equal.
+///
+/// The tolerance is specified like for [`assert_approx_eq!`].
+#[macro_export]
+macro_rules! assert_approx_ne {
+    ($left:expr, $right:expr $(, $field:ident = $value:expr)* $(,)?) => {
+        match (&$left, &$right) {
+            (left, right) => {
+                let tolerance = $crate::__approx_tolerance!(left, right; $($field = $value),*);
+                if $crate::approx::ApproxEq::approx_eq(left, right, tolerance) {
+                    panic!(
+                        "assertion `left !≈ right` failed\n  left: {:?}\n right: {:?}\n  with: {:?}",
+                        left, right, tolerance
+                    );
+                }
+            }
+        }
+    };
+}
@@ -0,0 +1,1418 @@
+//! Arbitrary precision integers.
+//!
+//! [`BigUint`] stores its magnitude as little-endian 32-bit digits without
+//! leading zeros, so equal values have equal representations and hashes.
+//! [`BigInt`] adds a sign to it, and its bit operations behave like those of
+//! an infinite two's complement representation, as for the primitive types.
+//!
+//! ```
+//! use numeric::bigint::{BigInt, BigUint};
+//! use numeric::{checked_pow, pow, Num};
+//!
+//! let big = pow(BigUint::from(3u32), 100);
+//! assert_eq!(big.to_string(), "515377520732011331036461129765621272702107522001");
+//! assert_eq!(checked_pow(BigInt::from(-2), 3), Some(BigInt::from(-8)));
+//! assert_eq!(BigInt::from_str_radix("-ff", 16).unwrap(), BigInt::from(-255));
+//! ```
+
+use alloc::string::String;
+use alloc::vec;
+use alloc::vec::Vec;
+use core::cmp::Ordering;
+use core::fmt;
+use core::iter::{Product, Sum};
+use core::ops::{Add, Div, Mul, Neg, Not, Rem, Shl, Shr, Sub};
+use core::ops::{AddAssign, DivAssign, MulAssign, RemAssign, ShlAssign, ShrAssign, SubAssign};
+use core::ops::{BitAnd, BitAndAssign, BitOr, BitOrAssign, BitXor, BitXorAssign};
+use core::str::FromStr;
+
+use crate::{CheckedAdd, CheckedDiv, CheckedMul, CheckedNeg, CheckedRem, CheckedSub};
+use crate::{FromPrimitive, Num, One, Pow, Signed, ToPrimitive, Unsigned, Zero};
+
+const BITS: usize = 32;
+
+// --- Digit slices ---
+
+/// Remove the leading zero digits.
+fn normalized(mut digits: Vec<u32>) -> Vec<u32> {
+    while digits.last() == Some(&0) {
+        digits.pop();
+    }
+    digits
+}
+
+fn cmp_digits(a: &[u32], b: &[u32]) -> Ordering {
+    a.len()
+        .cmp(&b.len())
+        .then_with(|| a.iter().rev().cmp(b.iter().rev()))
+}
+
+fn add_digits(a: &[u32], b: &[u32]) -> Vec<u32> {
+    let (a, b) = if a.len() >= b.len() { (a, b) } else { (b, a) };
+    let mut sum = Vec::with_capacity(a.len() + 1);
+    let mut carry = 0;
+    for (i, &x) in a.iter().enumerate() {
+        let t = x as u64 + *b.get(i).unwrap_or(&0) as u64 + carry;
+        sum.push(t as u32);
+        carry = t >> BITS;
+    }
+    sum.push(carry as u32);
+    normalized(sum)
+}
+
+/// Return `a - b`, for `a >= b`.
+fn sub_digits(a: &[u32], b: &[u32]) -> Vec<u32> {
+    let mut diff = Vec::with_capacity(a.len());
+    let mut borrow = 0;
+    for (i, &x) in a.iter().enumerate() {
+        let t = x as i64 - *b.get(i).unwrap_or(&0) as i64 - borrow;
+        diff.push(t as u32);
+        borrow = (t < 0) as i64;
+    }
+    debug_assert_eq!(borrow, 0);
+    normalized(diff)
+}
+
+fn mul_digits(a: &[u32], b: &[u32]) -> Vec<u32> {
+    if a.is_empty() || b.is_empty() {
+        return Vec::new();
+    }
+    let mut product = vec![0; a.len() + b.len()];
+    for (i, &x) in a.iter().enumerate() {
+        let mut carry = 0;
+        for (j, &y) in b.iter().enumerate() {
+            let t = x as u64 * y as u64 + product[i + j] as u64 + carry;
+            product[i + j] = t as u32;
+            carry = t >> BITS;
+        }
+        product[i + b.len()] = carry as u32;
+    }
+    normalized(product)
+}
+
+/// Multiply `digits` by `m` and add `a` in place.
+fn mul_add_digit(digits: &mut Vec<u32>, m: u32, a: u32) {
+    let mut carry = a as u64;
+    for d in digits.iter_mut() {
+        let t = *d as u64 * m as u64 + carry;
+        *d = t as u32;
+        carry = t >> BITS;
+    }
+    if carry != 0 {
+        digits.push(carry as u32);
+    }
+}
+
+/// Divide `digits` by `d` in place and return the remainder.
+fn div_rem_digit(digits: &mut Vec<u32>, d: u32) -> u32 {
+    let mut rem = 0u64;
+    for x in digits.iter_mut().rev() {
+        let t = rem << BITS | *x as u64;
+        *x = (t / d as u64) as u32;
+        rem = t % d as u64;
+    }
+    while digits.last() == Some(&0) {
+        digits.pop();
+    }
+    rem as u32
+}
+
+fn shl_digits(a: &[u32], n: usize) -> Vec<u32> {
+    if a.is_empty() {
+        return Vec::new();
+    }
+    let (words, bits) = (n / BITS, (n % BITS) as u32);
+    let mut shifted = vec![0; words];
+    shifted.reserve(a.len() + 1);
+    if bits == 0 {
+        shifted.extend_from_slice(a);
+    } else {
+        let mut carry = 0;
+        for &x in a {
+            shifted.push(x << bits | carry);
+            carry = x >> (BITS as u32 - bits);
+        }
+        shifted.push(carry);
+    }
+    normalized(shifted)
+}
+
+fn shr_digits(a: &[u32], n: usize) -> Vec<u32> {
+    let (words, bits) = (n / BITS, (n % BITS) as u32);
+    if words >= a.len() {
+        return Vec::new();
+    }
+    let a = &a[words..];
+    if bits == 0 {
+        return a.to_vec();
+    }
+    let mut shifted = Vec::with_capacity(a.len());
+    for (i, &x) in a.iter().enumerate() {
+        let high = a.get(i + 1).map_or(0, |&y| y << (BITS as u32 - bits));
+        shifted.push(x >> bits | high);
+    }
+    normalized(shifted)
+}
+
+/// Return the quotient and the remainder of `u / v`, for `v` non zero.
+fn div_rem_digits(u: &[u32], v: &[u32]) -> (Vec<u32>, Vec<u32>) {
+    if cmp_digits(u, v) == Ordering::Less {
+        return (Vec::new(), u.to_vec());
+    }
+    if v.len() == 1 {
+        let mut q = u.to_vec();
+        let r = div_rem_digit(&mut q, v[0]);
+        return (q, normalized(vec![r]));
+    }
+    // Knuth's algorithm D, with the divisor normalized so that its leading
+    // digit has its high bit set and the quotient digit estimates are off by
+    // at most two.
+    let shift = v[v.len() - 1].leading_zeros() as usize;
+    let v = shl_digits(v, shift);
+    let mut u = shl_digits(u, shift);
+    let n = v.len();
+    u.resize(u.len().max(n) + 1, 0);
+    let m = u.len() - n - 1;
+    let base = 1u64 << BITS;
+    let (v1, v2) = (v[n - 1] as u64, v[n - 2] as u64);
+    let mut q = vec![0; m + 1];
+    for j in (0..=m).rev() {
+        let num = (u[j + n] as u64) << BITS | u[j + n - 1] as u64;
+        let mut qhat = num / v1;
+        let mut rhat = num % v1;
+        while qhat >= base || qhat * v2 > (rhat << BITS | u[j + n - 2] as u64) {
+            qhat -= 1;
+            rhat += v1;
+            if rhat >= base {
+                break;
+            }
+        }
+        // u[j..=j + n] -= qhat * v
+        let mut borrow = 0i64;
+        let mut carry = 0u64;
+        for i in 0..n {
+            let p = qhat * v[i] as u64 + carry;
+            carry = p >> BITS;
+            let t = u[i + j] as i64 - borrow - (p as u32) as i64;
+            u[i + j] = t as u32;
+            borrow = (t < 0) as i64;
+        }
+        let t = u[j + n] as i64 - borrow - carry as i64;
+        u[j + n] = t as u32;
+        if t < 0 {
+            // The estimate was one too large: add v back.
+            qhat -= 1;
+            let mut carry = 0u64;
+            for i in 0..n {
+                let s = u[i + j] as u64 + v[i] as u64 + carry;
+                u[i + j] = s as u32;
+                carry = s >> BITS;
+            }
+            u[j + n] = u[j + n].wrapping_add(carry as u32);
+        }
+        q[j] = qhat as u32;
+    }
+    u.truncate(n);
+    (normalized(q), shr_digits(&normalized(u), shift))
+}
+
+/// Negate a fixed width two's complement number in place.
+fn negate_twos(digits: &mut [u32]) {
+    let mut carry = true;
+    for d in digits {
+        let (t, c) = (!*d).overflowing_add(carry as u32);
+        *d = t;
+        carry = c;
+    }
+}
+
+/// Return `2^e` as a float, or infinity beyond the largest exponent.
+macro_rules! exp2 {
+    ($f:ident, $bits:ident, $e:expr, $bias:expr, $mantissa:expr) => {
+        if $e > $bias {
+            $f::INFINITY
+        } else {
+            $f::from_bits((($e + $bias) as $bits) << $mantissa)
+        }
+    };
+}
+
+// --- BigUint ---
+
+/// An unsigned integer of arbitrary size.
+#[derive(Clone, Default, PartialEq, Eq, Hash)]
+pub struct BigUint {
+    digits: Vec<u32>,
+}
+
+impl BigUint {
+    /// Create an integer from its little-endian base `2^32` digits.
+    pub fn new(digits: Vec<u32>) -> Self {
+        Self {
+            digits: normalized(digits),
+        }
+    }
+
+    /// Return the little-endian base `2^32` digits, without leading zeros.
+    pub fn digits(&self) -> &[u32] {
+        &self.digits
+    }
+
+    /// Create an integer from its little-endian bytes.
+    pub fn from_bytes_le(bytes: &[u8]) -> Self {
+        let digits = bytes
+            .chunks(4)
+            .map(|c| c.iter().rev().fold(0, |d, &b| d << 8 | b as u32))
+            .collect();
+        Self::new(digits)
+    }
+
+    /// Create an integer from its big-endian bytes.
+    pub fn from_bytes_be(bytes: &[u8]) -> Self {
+        let mut le = bytes.to_vec();
+        le.reverse();
+        Self::from_bytes_le(&le)
+    }
+
+    /// Return the little-endian bytes, without leading zeros except for zero
+    /// itself which is `[0]`.
+    pub fn to_bytes_le(&self) -> Vec<u8> {
+        let mut bytes: Vec<u8> = self.digits.iter().flat_map(|d| d.to_le_bytes()).collect();
+        while bytes.len() > 1 && bytes.last() == Some(&0) {
+            bytes.pop();
+        }
+        if bytes.is_empty() {
+            bytes.push(0);
+        }
+        bytes
+    }
+
+    /// Return the big-endian bytes, like [`to_bytes_le`](Self::to_bytes_le).
+    pub fn to_bytes_be(&self) -> Vec<u8> {
+        let mut bytes = self.to_bytes_le();
+        bytes.reverse();
+        bytes
+    }
+
+    /// Return the number of bits needed to represent the integer, zero for
+    /// zero.
+    pub fn bits(&self) -> u64 {
+        match self.digits.last() {
+            Some(&top) => (self.digits.len() * BITS) as u64 - top.leading_zeros() as u64,
+            None => 0,
+        }
+    }
+
+    /// Return whether bit `n` is set.
+    pub fn bit(&self, n: u64) -> bool {
+        let word = (n / BITS as u64) as usize;
+        self.digits
+            .get(word)
+            .is_some_and(|d| d >> (n % BITS as u64) & 1 == 1)
+    }
+
+    /// Set bit `n` to `value`.
+    pub fn set_bit(&mut self, n: u64, value: bool) {
+        let word = (n / BITS as u64) as usize;
+        let mask = 1 << (n % BITS as u64);
+        if value {
+            if word >= self.digits.len() {
+                self.digits.resize(word + 1, 0);
+            }
+            self.digits[word] |= mask;
+        } else if word < self.digits.len() {
+            self.digits[word] &= !mask;
+            while self.digits.last() == Some(&0) {
+                self.digits.pop();
+            }
+        }
+    }
+
+    /// Return the number of trailing zero bits, or `None` for zero.
+    pub fn trailing_zeros(&self) -> Option<u64> {
+        let i = self.digits.iter().position(|&d| d != 0)?;
+        Some((i * BITS) as u64 + self.digits[i].trailing_zeros() as u64)
+    }
+
+    /// Return the number of set bits.
+    pub fn count_ones(&self) -> u64 {
+        self.digits.iter().map(|d| d.count_ones() as u64).sum()
+    }
+
+    /// Return `self` to the power `exp`.
+    pub fn pow(&self, exp: u32) -> Self {
+        crate::pow(self.clone(), exp as usize)
+    }
+
+    /// Return the representation of the integer in base `radix`, with
+    /// lowercase letters beyond 9.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `radix` is not in the range `2..=36`.
+    pub fn to_str_radix(&self, radix: u32) -> String {
+        assert!((2..=36).contains(&radix), "radix must be in 2..=36");
+        if self.digits.is_empty() {
+            return String::from("0");
+        }
+        // Divide by the largest power of the radix which fits in a digit, and
+        // write its remainders in reverse.
+        let (chunk, width) = radix_chunk(radix);
+        let mut rest = self.digits.clone();
+        let mut reversed = Vec::new();
+        while !rest.is_empty() {
+            let mut r = div_rem_digit(&mut rest, chunk);
+            for _ in 0..width {
+                reversed.push(char::from_digit(r % radix, radix).unwrap());
+                r /= radix;
+                if rest.is_empty() && r == 0 {
+                    break;
+                }
+            }
+        }
+        reversed.iter().rev().collect()
+    }
+
+    /// Return the quotient and the remainder of `self / other`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `other` is zero.
+    pub fn div_rem(&self, other: &Self) -> (Self, Self) {
+        assert!(!other.is_zero(), "attempt to divide by zero");
+        let (q, r) = div_rem_digits(&self.digits, &other.digits);
+        (Self { digits: q }, Self { digits: r })
+    }
+
+    /// Return the highest 64 bits, with the lowest one set if any of the
+    /// others is, and the number of bits below them.
+    fn top_bits(&self) -> (u64, u64) {
+        let bits = self.bits();
+        if bits <= 64 {
+            return (self.to_u64().unwrap(), 0);
+        }
+        let shift = bits - 64;
+        let top = (self >> shift as usize).to_u64().unwrap();
+        let sticky = self.trailing_zeros().unwrap() < shift;
+        (top | sticky as u64, shift)
+    }
+}
+
+/// Return the largest power of `radix` which fits in a digit, and its
+/// exponent.
+fn radix_chunk(radix: u32) -> (u32, usize) {
+    let mut chunk = radix;
+    let mut width = 1;
+    while let Some(next) = chunk.checked_mul(radix) {
+        chunk = next;
+        width += 1;
+    }
+    (chunk, width)
+}
+
+/// Parse the unsigned digits of `src` in base `radix`.
+fn parse_digits(src: &str, radix: u32) -> Result<BigUint, ParseBigIntError> {
+    assert!((2..=36).contains(&radix), "radix must be in 2..=36");
+    if src.is_empty() {
+        return Err(ParseBigIntError {
+            kind: BigIntErrorKind::Empty,
+        });
+    }
+    let (_, width) = radix_chunk(radix);
+    let mut digits = Vec::new();
+    for chunk in src.as_bytes().chunks(width) {
+        let mut value = 0;
+        let mut scale = 1;
+        for &c in chunk {
+            let d = (c as char).to_digit(radix).ok_or(ParseBigIntError {
+                kind: BigIntErrorKind::InvalidDigit,
+            })?;
+            value = value * radix + d;
+            scale *= radix;
+        }
+        mul_add_digit(&mut digits, scale, value);
+    }
+    Ok(BigUint::new(digits))
+}
+
+impl PartialOrd for BigUint {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for BigUint {
+    fn cmp(&self, other: &Self) -> Ordering {
+        cmp_digits(&self.digits, &other.digits)
+    }
+}
+
+impl fmt::Display for BigUint {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.pad_integral(true, "", &self.to_str_radix(10))
+    }
+}
+
+impl fmt::Debug for BigUint {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Display::fmt(self, f)
+    }
+}
+
+macro_rules! radix_fmt {
+    ($t:ident, $($imp:ident $radix:expr, $prefix:expr, $upper:expr;)*) => {$(
+        impl fmt::$imp for $t {
+            fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                let (nonneg, mag) = self.radix_parts();
+                let mut s = mag.to_str_radix($radix);
+                if $upper {
+                    s.make_ascii_uppercase();
+                }
+                f.pad_integral(nonneg, $prefix, &s)
+            }
+        }
+    )*};
+}
+
+macro_rules! radix_fmts {
+    ($($t:ident)*) => {$(
+        radix_fmt! {
+            $t,
+            Binary 2, "0b", false;
+            Octal 8, "0o", false;
+            LowerHex 16, "0x", false;
+            UpperHex 16, "0x", true;
+        }
+    )*};
+}
+
+impl BigUint {
+    fn radix_parts(&self) -> (bool, &BigUint) {
+        (true, self)
+    }
+}
+
+impl BigInt {
+    fn radix_parts(&self) -> (bool, &BigUint) {
+        (self.sign != Sign::Minus, &self.mag)
+    }
+}
+
+radix_fmts!(BigUint BigInt);
+
+/// Implement the operator for owned operands and the assignment operator
+/// from the one on references.
+macro_rules! forward_binop {
+    ($t:ident, $imp:ident, $method:ident, $assign:ident, $assign_method:ident) => {
+        impl $imp<$t> for $t {
+            type Output = $t;
+
+            fn $method(self, other: $t) -> $t {
+                (&self).$method(&other)
+            }
+        }
+
+        impl<'a> $imp<&'a $t> for $t {
+            type Output = $t;
+
+            fn $method(self, other: &$t) -> $t {
+                (&self).$method(other)
+            }
+        }
+
+        impl<'a> $imp<$t> for &'a $t {
+            type Output = $t;
+
+            fn $method(self, other: $t) -> $t {
+                self.$method(&other)
+            }
+        }
+
+        impl $assign<$t> for $t {
+            fn $assign_method(&mut self, other: $t) {
+                *self = (&*self).$method(&other);
+            }
+        }
+
+        impl<'a> $assign<&'a $t> for $t {
+            fn $assign_method(&mut self, other: &$t) {
+                *self = (&*self).$method(other);
+            }
+        }
+    };
+}
+
+macro_rules! forward_shift {
+    ($t:ident, $imp:ident, $method:ident, $assign:ident, $assign_method:ident) => {
+        impl $imp<usize> for $t {
+            type Output = $t;
+
+            fn $method(self, n: usize) -> $t {
+                (&self).$method(n)
+            }
+        }
+
+        impl $assign<usize> for $t {
+            fn $assign_method(&mut self, n: usize) {
+                *self = (&*self).$method(n);
+            }
+        }
+    };
+}
+
+impl Add<&BigUint> for &BigUint {
+    type Output = BigUint;
+
+    fn add(self, other: &BigUint) -> BigUint {
+        BigUint {
+            digits: add_digits(&self.digits, &other.digits),
+        }
+    }
+}
+
+impl Sub<&BigUint> for &BigUint {
+    type Output = BigUint;
+
+    fn sub(self, other: &BigUint) -> BigUint {
+        self.checked_sub(other)
+            .expect("attempt to subtract with overflow")
+    }
+}
+
+impl Mul<&BigUint> for &BigUint {
+    type Output = BigUint;
+
+    fn mul(self, other: &BigUint) -> BigUint {
+        BigUint {
+            digits: mul_digits(&self.digits, &other.digits),
+        }
+    }
+}
+
+impl Div<&BigUint> for &BigUint {
+    type Output = BigUint;
+
+    fn div(self, other: &BigUint) -> BigUint {
+        self.div_rem(other).0
+    }
+}
+
+impl Rem<&BigUint> for &BigUint {
+    type Output = BigUint;
+
+    fn rem(self, other: &BigUint) -> BigUint {
+        self.div_rem(other).1
+    }
+}
+
+impl BitAnd<&BigUint> for &BigUint {
+    type Output = BigUint;
+
+    fn bitand(self, other: &BigUint) -> BigUint {
+        let digits = self.digits.iter().zip(&other.digits);
+        BigUint::new(digits.map(|(a, b)| a & b).collect())
+    }
+}
+
+macro_rules! biguint_bitop {
+    ($imp:ident, $method:ident, $op:tt) => {
+        impl<'a, 'b> $imp<&'b BigUint> for &'a BigUint {
+            type Output = BigUint;
+
+            fn $method(self, other: &BigUint) -> BigUint {
+                let len = self.digits.len().max(other.digits.len());
+                let digit = |v: &BigUint, i| *v.digits.get(i).unwrap_or(&0);
+                BigUint::new((0..len).map(|i| digit(self, i) $op digit(other, i)).collect())
+            }
+        }
+    };
+}
+
+biguint_bitop!(BitOr, bitor, |);
+biguint_bitop!(BitXor, bitxor, ^);
+
+impl Shl<usize> for &BigUint {
+    type Output = BigUint;
+
+    fn shl(self, n: usize) -> BigUint {
+        BigUint {
+            digits: shl_digits(&self.digits, n),
+        }
+    }
+}
+
+impl Shr<usize> for &BigUint {
+    type Output = BigUint;
+
+    fn shr(self, n: usize) -> BigUint {
+        BigUint {
+            digits: shr_digits(&self.digits, n),
+        }
+    }
+}
+
+forward_binop!(BigUint, Add, add, AddAssign, add_assign);
+forward_binop!(BigUint, Sub, sub, SubAssign, sub_assign);
+forward_binop!(BigUint, Mul, mul, MulAssign, mul_assign);
+forward_binop!(BigUint, Div, div, DivAssign, div_assign);
+forward_binop!(BigUint, Rem, rem, RemAssign, rem_assign);
+forward_binop!(BigUint, BitAnd, bitand, BitAndAssign, bitand_assign);
+forward_binop!(BigUint, BitOr, bitor, BitOrAssign, bitor_assign);
+forward_binop!(BigUint, BitXor, bitxor, BitXorAssign, bitxor_assign);
+forward_shift!(BigUint, Shl, shl, ShlAssign, shl_assign);
+forward_shift!(BigUint, Shr, shr, ShrAssign, shr_assign);
+
+impl CheckedAdd for BigUint {
+    fn checked_add(&self, v: &Self) -> Option<Self> {
+        Some(self + v)
+    }
+}
+
+impl CheckedSub for BigUint {
+    fn checked_sub(&self, v: &Self) -> Option<Self> {
+        match self.cmp(v) {
+            Ordering::Less => None,
+            _ => Some(BigUint {
+                digits: sub_digits(&self.digits, &v.digits),
+            }),
+        }
+    }
+}
+
+impl CheckedMul for BigUint {
+    fn checked_mul(&self, v: &Self) -> Option<Self> {
+        Some(self * v)
+    }
+}
+
+impl CheckedDiv for BigUint {
+    fn checked_div(&self, v: &Self) -> Option<Self> {
+        (!v.is_zero()).then(|| self / v)
+    }
+}
+
+impl CheckedRem for BigUint {
+    fn checked_rem(&self, v: &Self) -> Option<Self> {
+        (!v.is_zero()).then(|| self % v)
+    }
+}
+
+impl CheckedNeg for BigUint {
+    /// Return zero for zero, and `None` otherwise.
+    fn checked_neg(&self) -> Option<Self> {
+        self.is_zero().then(Self::zero)
+    }
+}
+
+impl Zero for BigUint {
+    fn zero() -> Self {
+        Self { digits: Vec::new() }
+    }
+
+    fn is_zero(&self) -> bool {
+        self.digits.is_empty()
+    }
+}
+
+impl One for BigUint {
+    fn one() -> Self {
+        Self { digits: vec![1] }
+    }
+}
+
+impl Num for BigUint {
+    type FromStrRadixErr = ParseBigIntError;
+
+    /// Parse the digits in base `radix`, with an optional `+` sign.
+    fn from_str_radix(src: &str, radix: u32) -> Result<Self, ParseBigIntError> {
+        parse_digits(src.strip_prefix('+').unwrap_or(src), radix)
+    }
+}
+
+impl FromStr for BigUint {
+    type Err = ParseBigIntError;
+
+    fn from_str(src: &str) -> Result<Self, Self::Err> {
+        Self::from_str_radix(src, 10)
+    }
+}
+
+impl Unsigned for BigUint {}
+
+macro_rules! bigint_pow_impl {
+    ($t:ident, $($rhs:ty)*) => {$(
+        impl Pow<$rhs> for $t {
+            type Output = $t;
+
+            fn pow(self, rhs: $rhs) -> $t {
+                crate::pow(self, rhs as usize)
+            }
+        }
+
+        impl<'a> Pow<$rhs> for &'a $t {
+            type Output = $t;
+
+            fn pow(self, rhs: $rhs) -> $t {
+                crate::pow(self.clone(), rhs as usize)
+            }
+        }
+    )*};
+}
+
+bigint_pow_impl!(BigUint, u8 u16 u32 usize);
+bigint_pow_impl!(BigInt, u8 u16 u32 usize);
+
+impl ToPrimitive for BigUint {
+    fn to_i64(&self) -> Option<i64> {
+        self.to_u64().and_then(|n| n.to_i64())
+    }
+
+    fn to_u64(&self) -> Option<u64> {
+        match *self.digits {
+            [] => Some(0),
+            [a] => Some(a as u64),
+            [a, b] => Some((b as u64) << BITS | a as u64),
+            _ => None,
+        }
+    }
+
+    /// Round to the nearest float, or to infinity beyond the largest one.
+    fn to_f32(&self) -> Option<f32> {
+        let (top, shift) = self.top_bits();
+        Some(top as f32 * exp2!(f32, u32, shift, 127, 23))
+    }
+
+    /// Round to the nearest float, or to infinity beyond the largest one.
+    fn to_f64(&self) -> Option<f64> {
+        let (top, shift) = self.top_bits();
+        Some(top as f64 * exp2!(f64, u64, shift, 1023, 52))
+    }
+}
+
+impl FromPrimitive for BigUint {
+    fn from_i64(n: i64) -> Option<Self> {
+        n.to_u64().map(Self::from)
+    }
+
+    fn from_u64(n: u64) -> Option<Self> {
+        Some(Self::from(n))
+    }
+
+    /// Truncate toward zero.
+    fn from_f64(n: f64) -> Option<Self> {
+        match BigInt::from_f64(n)? {
+            BigInt {
+                sign: Sign::Minus, ..
+            } => None,
+            BigInt { mag, .. } => Some(mag),
+        }
+    }
+}
+
+impl crate::NumCast for BigUint {
+    fn from<T: ToPrimitive>(n: T) -> Option<Self> {
+        BigInt::cast_from(n).and_then(|n| n.to_biguint())
+    }
+}
+
+macro_rules! from_unsigned {
+    ($t:ident, $($u:ty)*) => {$(
+        impl From<$u> for $t {
+            fn from(n: $u) -> Self {
+                let mut n = n as u128;
+                let mut digits = Vec::new();
+                while n != 0 {
+                    digits.push(n as u32);
+                    n >>= BITS;
+                }
+                BigUint { digits }.into()
+            }
+        }
+    )*};
+}
+
+from_unsigned!(BigUint, u8 u16 u32 u64 u128 usize);
+
+impl<T> Sum<T> for BigUint
+where
+    BigUint: Add<T, Output = BigUint>,
+{
+    fn sum<I: Iterator<Item = T>>(iter: I) -> Self {
+        iter.fold(Self::zero(), |a, b| a + b)
+    }
+}
+
+impl<T> Product<T> for BigUint
+where
+    BigUint: Mul<T, Output = BigUint>,
+{
+    fn product<I: Iterator<Item = T>>(iter: I) -> Self {
+        iter.fold(Self::one(), |a, b| a * b)
+    }
+}
+
+// --- BigInt ---
+
+/// The sign of a [`BigInt`].
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Sign {
+    Minus,
+    #[default]
+    NoSign,
+    Plus,
+}
+
+impl Neg for Sign {
+    type Output = Sign;
+
+    fn neg(self) -> Sign {
+        match self {
+            Sign::Minus => Sign::Plus,
+            Sign::NoSign => Sign::NoSign,
+            Sign::Plus => Sign::Minus,
+        }
+    }
+}
+
+impl Mul for Sign {
+    type Output = Sign;
+
+    fn mul(self, other: Sign) -> Sign {
+        match (self, other) {
+            (Sign::NoSign, _) | (_, Sign::NoSign) => Sign::NoSign,
+            (a, b) if a == b => Sign::Plus,
+            _ => Sign::Minus,
+        }
+    }
+}
+
+/// A signed integer of arbitrary size.
+///
+/// Division truncates toward zero and the remainder has the sign of the
+/// dividend, like for the primitive types.
+#[derive(Clone, Default, PartialEq, Eq, Hash)]
+pub struct BigInt {
+    sign: Sign,
+    mag: BigUint,
+}
+
+impl BigInt {
+    /// Create an integer from its sign and magnitude.
+    ///
+    /// The sign of zero is `NoSign`, and a `NoSign` magnitude is zero.
+    pub fn from_biguint(sign: Sign, mag: BigUint) -> Self {
+        if sign == Sign::NoSign || mag.is_zero() {
+            Self::zero()
+        } else {
+            Self { sign, mag }
+        }
+    }
+
+    /// Create an integer from its sign and the little-endian bytes of its
+    /// magnitude.
+    pub fn from_bytes_le(sign: Sign, bytes: &[u8]) -> Self {
+        Self::from_biguint(sign, BigUint::from_bytes_le(bytes))
+    }
+
+    /// Return the sign and the little-endian bytes of the magnitude.
+    pub fn to_bytes_le(&self) -> (Sign, Vec<u8>) {
+        (self.sign, self.mag.to_bytes_le())
+    }
+
+    /// Return the sign.
+    pub fn sign(&self) -> Sign {
+        self.sign
+    }
+
+    /// Return the absolute value as an unsigned integer.
+    pub fn magnitude(&self) -> &BigUint {
+        &self.mag
+    }
+
+    /// Split the integer into its sign and magnitude.
+    pub fn into_parts(self) -> (Sign, BigUint) {
+        (self.sign, self.mag)
+    }
+
+    /// Return the integer as an unsigned one, or `None` if it is negative.
+    pub fn to_biguint(&self) -> Option<BigUint> {
+        match self.sign {
+            Sign::Minus => None,
+            _ => Some(self.mag.clone()),
+        }
+    }
+
+    /// Return the number of bits of the magnitude.
+    pub fn bits(&self) -> u64 {
+        self.mag.bits()
+    }
+
+    /// Return `self` to the power `exp`.
+    pub fn pow(&self, exp: u32) -> Self {
+        crate::pow(self.clone(), exp as usize)
+    }
+
+    /// Return the representation of the integer in base `radix`, like
+    /// [`BigUint::to_str_radix`] with a leading `-` if it is negative.
+    pub fn to_str_radix(&self, radix: u32) -> String {
+        let mut s = self.mag.to_str_radix(radix);
+        if self.sign == Sign::Minus {
+            s.insert(0, '-');
+        }
+        s
+    }
+
+    /// Return the quotient truncated toward zero, and the remainder with the
+    /// sign of `self`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `other` is zero.
+    pub fn div_rem(&self, other: &Self) -> (Self, Self) {
+        let (q, r) = self.mag.div_rem(&other.mag);
+        (
+            Self::from_biguint(self.sign * other.sign, q),
+            Self::from_biguint(self.sign, r),
+        )
+    }
+
+    /// Convert any primitive through the widest conversion which holds it.
+    fn cast_from<T: ToPrimitive>(n: T) -> Option<Self> {
+        if let Some(i) = n.to_i64() {
+            Some(Self::from(i))
+        } else if let Some(u) = n.to_u64() {
+            Some(Self::from(u))
+        } else {
+            n.to_f64().and_then(Self::from_f64)
+        }
+    }
+
+    /// Return `digits` long two's complement digits, which must be enough for
+    /// the magnitude and a sign bit.
+    fn to_twos(&self, digits: usize) -> Vec<u32> {
+        let mut twos = self.mag.digits.clone();
+        twos.resize(digits, 0);
+        if self.sign == Sign::Minus {
+            negate_twos(&mut twos);
+        }
+        twos
+    }
+
+    /// Apply `op` to the infinite two's complement representations.
+    fn bitop(&self, other: &Self, op: impl Fn(u32, u32) -> u32) -> Self {
+        let len = self.mag.digits.len().max(other.mag.digits.len()) + 1;
+        let (a, b) = (self.to_twos(len), other.to_twos(len));
+        let mut digits: Vec<u32> = a.iter().zip(&b).map(|(&x, &y)| op(x, y)).collect();
+        let extension = |x: &Self| if x.sign == Sign::Minus { !0 } else { 0 };
+        let negative = op(extension(self), extension(other)) != 0;
+        if negative {
+            negate_twos(&mut digits);
+        }
+        let sign = if negative { Sign::Minus } else { Sign::Plus };
+        Self::from_biguint(sign, BigUint::new(digits))
+    }
+}
+
+impl PartialOrd for BigInt {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for BigInt {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.sign.cmp(&other.sign).then_with(|| match self.sign {
+            Sign::Minus => other.mag.cmp(&self.mag),
+            _ => self.mag.cmp(&other.mag),
+        })
+    }
+}
+
+impl fmt::Display for BigInt {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.pad_integral(self.sign != Sign::Minus, "", &self.mag.to_str_radix(10))
+    }
+}
+
+impl fmt::Debug for BigInt {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Display::fmt(self, f)
+    }
+}
+
+impl From<BigUint> for BigInt {
+    fn from(mag: BigUint) -> Self {
+        Self::from_biguint(Sign::Plus, mag)
+    }
+}
+
+from_unsigned!(BigInt, u8 u16 u32 u64 u128 usize);
+
+macro_rules! from_signed {
+    ($($i:ty)*) => {$(
+        impl From<$i> for BigInt {
+            fn from(n: $i) -> Self {
+                let mag = BigUint::from(n.unsigned_abs());
+                if n < 0 {
+                    Self::from_biguint(Sign::Minus, mag)
+                } else {
+                    mag.into()
+                }
+            }
+        }
+    )*};
+}
+
+from_signed!(i8 i16 i32 i64 i128 isize);
+
+impl Neg for &BigInt {
+    type Output = BigInt;
+
+    fn neg(self) -> BigInt {
+        BigInt {
+            sign: -self.sign,
+            mag: self.mag.clone(),
+        }
+    }
+}
+
+impl Neg for BigInt {
+    type Output = BigInt;
+
+    fn neg(mut self) -> BigInt {
+        self.sign = -self.sign;
+        self
+    }
+}
+
+impl Not for &BigInt {
+    type Output = BigInt;
+
+    /// Return `-self - 1`, the complement of the two's complement
+    /// representation.
+    fn not(self) -> BigInt {
+        -self - BigInt::one()
+    }
+}
+
+impl Not for BigInt {
+    type Output = BigInt;
+
+    fn not(self) -> BigInt {
+        !&self
+    }
+}
+
+impl Add<&BigInt> for &BigInt {
+    type Output = BigInt;
+
+    fn add(self, other: &BigInt) -> BigInt {
+        match (self.sign, other.sign) {
+            (_, Sign::NoSign) => self.clone(),
+            (Sign::NoSign, _) => other.clone(),
+            (a, b) if a == b => BigInt::from_biguint(a, &self.mag + &other.mag),
+            (a, _) => match self.mag.cmp(&other.mag) {
+                Ordering::Less => BigInt::from_biguint(-a, &other.mag - &self.mag),
+                Ordering::Equal => BigInt::zero(),
+                Ordering::Greater => BigInt::from_biguint(a, &self.mag - &other.mag),
+            },
+        }
+    }
+}
+
+impl Sub<&BigInt> for &BigInt {
+    type Output = BigInt;
+
+    fn sub(self, other: &BigInt) -> BigInt {
+        self + &-other
+    }
+}
+
+impl Mul<&BigInt> for &BigInt {
+    type Output = BigInt;
+
+    fn mul(self, other: &BigInt) -> BigInt {
+        BigInt::from_biguint(self.sign * other.sign, &self.mag * &other.mag)
+    }
+}
+
+impl Div<&BigInt> for &BigInt {
+    type Output = BigInt;
+
+    fn div(self, other: &BigInt) -> BigInt {
+        self.div_rem(other).0
+    }
+}
+
+impl Rem<&BigInt> for &BigInt {
+    type Output = BigInt;
+
+    fn rem(self, other: &BigInt) -> BigInt {
+        self.div_rem(other).1
+    }
+}
+
+macro_rules! bigint_bitop {
+    ($imp:ident, $method:ident, $op:tt) => {
+        impl<'a, 'b> $imp<&'b BigInt> for &'a BigInt {
+            type Output = BigInt;
+
+            fn $method(self, other: &BigInt) -> BigInt {
+                self.bitop(other, |a, b| a $op b)
+            }
+        }
+    };
+}
+
+bigint_bitop!(BitAnd, bitand, &);
+bigint_bitop!(BitOr, bitor, |);
+bigint_bitop!(BitXor, bitxor, ^);
+
+impl Shl<usize> for &BigInt {
+    type Output = BigInt;
+
+    fn shl(self, n: usize) -> BigInt {
+        BigInt::from_biguint(self.sign, &self.mag << n)
+    }
+}
+
+impl Shr<usize> for &BigInt {
+    type Output = BigInt;
+
+    /// Shift arithmetically, rounding toward negative infinity.
+    fn shr(self, n: usize) -> BigInt {
+        match self.sign {
+            // -((|x| - 1) >> n) - 1
+            Sign::Minus => !BigInt::from(&(&self.mag - &BigUint::one()) >> n),
+            _ => BigInt::from(&self.mag >> n),
+        }
+    }
+}
+
+forward_binop!(BigInt, Add, add, AddAssign, add_assign);
+forward_binop!(BigInt, Sub, sub, SubAssign, sub_assign);
+forward_binop!(BigInt, Mul, mul, MulAssign, mul_assign);
+forward_binop!(BigInt, Div, div, DivAssign, div_assign);
+forward_binop!(BigInt, Rem, rem, RemAssign, rem_assign);
+forward_binop!(BigInt, BitAnd, bitand, BitAndAssign, bitand_assign);
+forward_binop!(BigInt, BitOr, bitor, BitOrAssign, bitor_assign);
+forward_binop!(BigInt, BitXor, bitxor, BitXorAssign, bitxor_assign);
+forward_shift!(BigInt, Shl, shl, ShlAssign, shl_assign);
+forward_shift!(BigInt, Shr, shr, ShrAssign, shr_assign);
+
+impl CheckedAdd for BigInt {
+    fn checked_add(&self, v: &Self) -> Option<Self> {
+        Some(self + v)
+    }
+}
+
+impl CheckedSub for BigInt {
+    fn checked_sub(&self, v: &Self) -> Option<Self> {
+        Some(self - v)
+    }
+}
+
+impl CheckedMul for BigInt {
+    fn checked_mul(&self, v: &Self) -> Option<Self> {
+        Some(self * v)
+    }
+}
+
+impl CheckedDiv for BigInt {
+    fn checked_div(&self, v: &Self) -> Option<Self> {
+        (!v.is_zero()).then(|| self / v)
+    }
+}
+
+impl CheckedRem for BigInt {
+    fn checked_rem(&self, v: &Self) -> Option<Self> {
+        (!v.is_zero()).then(|| self % v)
+    }
+}
+
+impl CheckedNeg for BigInt {
+    fn checked_neg(&self) -> Option<Self> {
+        Some(-self)
+    }
+}
+
+impl Zero for BigInt {
+    fn zero() -> Self {
+        Self {
+            sign: Sign::NoSign,
+            mag: BigUint::zero(),
+        }
+    }
+
+    fn is_zero(&self) -> bool {
+        self.sign == Sign::NoSign
+    }
+}
+
+impl One for BigInt {
+    fn one() -> Self {
+        Self {
+            sign: Sign::Plus,
+            mag: BigUint::one(),
+        }
+    }
+}
+
+impl Num for BigInt {
+    type FromStrRadixErr = ParseBigIntError;
+
+    /// Parse the digits in base `radix`, with an optional sign.
+    fn from_str_radix(src: &str, radix: u32) -> Result<Self, ParseBigIntError> {
+        let (sign, digits) = match src.strip_prefix('-') {
+            Some(digits) => (Sign::Minus, digits),
+            None => (Sign::Plus, src.strip_prefix('+').unwrap_or(src)),
+        };
+        Ok(Self::from_biguint(sign, parse_digits(digits, radix)?))
+    }
+}
+
+impl FromStr for BigInt {
+    type Err = ParseBigIntError;
+
+    fn from_str(src: &str) -> Result<Self, Self::Err> {
+        Self::from_str_radix(src, 10)
+    }
+}
+
+impl Signed for BigInt {
+    fn abs(&self) -> Self {
+        Self::from_biguint(Sign::Plus, self.mag.clone())
+    }
+
+    fn abs_sub(&self, other: &Self) -> Self {
+        if self <= other {
+            Self::zero()
+        } else {
+            self - other
+        }
+    }
+
+    fn signum(&self) -> Self {
+        match self.sign {
+            Sign::Minus => -Self::one(),
+            Sign::NoSign => Self::zero(),
+            Sign::Plus => Self::one(),
+        }
+    }
+
+    fn is_positive(&self) -> bool {
+        self.sign == Sign::Plus
+    }
+
+    fn is_negative(&self) -> bool {
+        self.sign == Sign::Minus
+    }
+}
+
+impl ToPrimitive for BigInt {
+    fn to_i64(&self) -> Option<i64> {
+        let mag = self.mag.to_u64()?;
+        match self.sign {
+            Sign::Minus if mag == i64::MIN.unsigned_abs() => Some(i64::MIN),
+            Sign::Minus => mag.to_i64().map(|n| -n),
+            _ => mag.to_i64(),
+        }
+    }
+
+    fn to_u64(&self) -> Option<u64> {
+        match self.sign {
+            Sign::Minus => None,
+            _ => self.mag.to_u64(),
+        }
+    }
+
+    fn to_f32(&self) -> Option<f32> {
+        let x = self.mag.to_f32()?;
+        Some(if self.sign == Sign::Minus { -x } else { x })
+    }
+
+    fn to_f64(&self) -> Option<f64> {
+        let x = self.mag.to_f64()?;
+        Some(if self.sign == Sign::Minus { -x } else { x })
+    }
+}
+
+impl FromPrimitive for BigInt {
+    fn from_i64(n: i64) -> Option<Self> {
+        Some(Self::from(n))
+    }
+
+    fn from_u64(n: u64) -> Option<Self> {
+        Some(Self::from(n))
+    }
+
+    /// Truncate toward zero, and return `None` for infinities and NaN.
+    fn from_f64(n: f64) -> Option<Self> {
+        if !n.is_finite() {
+            return None;
+        }
+        let bits = n.to_bits();
+        let exponent = (bits >> 52 & 0x7ff) as i64;
+        let mantissa = match exponent {
+            0 => (bits & ((1 << 52) - 1)) << 1,
+            _ => (bits & ((1 << 52) - 1)) | 1 << 52,
+        };
+        // n = mantissa * 2^(exponent - 1075)
+        let shift = exponent - 1075;
+        let mag = BigUint::from(mantissa);
+        let mag = if shift >= 0 {
+            mag << shift as usize
+        } else {
+            mag >> (-shift) as usize
+        };
+        let sign = if n < 0.0 { Sign::Minus } else { Sign::Plus };
+        Some(Self::from_biguint(sign, mag))
+    }
+}
+
+impl crate::NumCast for BigInt {
+    /// Convert through `i64`, `u64` or else `f64`, which truncates and may
+    /// round wider types.
+    fn from<T: ToPrimitive>(n: T) -> Option<Self> {
+        BigInt::cast_from(n)
+    }
+}
+
+impl<T> Sum<T> for BigInt
+where
+    BigInt: Add<T, Output = BigInt>,
+{
+    fn sum<I: Iterator<Item = T>>(iter: I) -> Self {
+        iter.fold(Self::zero(), |a, b| a + b)
+    }
+}
+
+impl<T> Product<T> for BigInt
+where
+    BigInt: Mul<T, Output = BigInt>,
+{
+    fn product<I: Iterator<Item = T>>(iter: I) -> Self {
+        iter.fold(Self::one(), |a, b| a * b)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum BigIntErrorKind {
+    Empty,
+    InvalidDigit,
+}
+
+/// An error which can be returned when parsing a big integer.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseBigIntError {
+    pub kind: BigIntErrorKind,
+}
+
+impl fmt::Display for ParseBigIntError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self.kind {
+            BigIntErrorKind::Empty => "cannot parse integer from empty string".fmt(f),
+            BigIntErrorKind::InvalidDigit => "invalid digit found in string".fmt(f),
+        }
+    }
+}
@@ -0,0 +1,716 @@
+//! Complex numbers.
+//!
+//! ```
+//! use numeric::complex::Complex;
+//!
+//! let z = Complex::new(3.0, 4.0);
+//! assert_eq!(z.norm(), 5.0);
+//! assert_eq!(z * z.conj(), Complex::new(25.0, 0.0));
+//! assert_eq!("3-4i".parse::<Complex<f64>>().unwrap(), z.conj());
+//! ```
+
+use core::fmt;
+use core::iter::{Product, Sum};
+use core::ops::{Add, Div, Mul, Neg, Rem, Sub};
+use core::ops::{AddAssign, DivAssign, MulAssign, RemAssign, SubAssign};
+use core::str::FromStr;
+
+use crate::{Float, Inv, Num, One, Pow, Zero};
+
+/// A complex number in Cartesian form, `re + im i`.
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq, Hash)]
+pub struct Complex<F: Float> {
+    /// Real part.
+    pub re: F,
+    /// Imaginary part.
+    pub im: F,
+}
+
+impl<F: Float> Complex<F> {
+    /// Create a new complex number.
+    pub fn new(re: F, im: F) -> Self {
+        Self { re, im }
+    }
+
+    /// Return the imaginary unit.
+    pub fn i() -> Self {
+        Self::new(F::zero(), F::one())
+    }
+
+    /// Create a complex number from its modulus and argument.
+    pub fn from_polar(r: F, theta: F) -> Self {
+        let (sin, cos) = theta.sin_cos();
+        Self::new(r * cos, r * sin)
+    }
+
+    /// Return the modulus and the argument, in `(-π, π]`.
+    pub fn to_polar(self) -> (F, F) {
+        (self.norm(), self.arg())
+    }
+
+    /// Return the modulus `|self|`, without undue overflow.
+    pub fn norm(self) -> F {
+        self.re.hypot(self.im)
+    }
+
+    /// Return the squared modulus, which is cheaper than `norm`.
+    pub fn norm_sqr(self) -> F {
+        self.re * self.re + self.im * self.im
+    }
+
+    /// Return `|re| + |im|`, the Manhattan length.
+    pub fn l1_norm(self) -> F {
+        self.re.abs() + self.im.abs()
+    }
+
+    /// Return the argument, the angle with the positive real axis in `(-π, π]`.
+    pub fn arg(self) -> F {
+        self.im.atan2(self.re)
+    }
+
+    /// Return the complex conjugate.
+    pub fn conj(self) -> Self {
+        Self::new(self.re, -self.im)
+    }
+
+    /// Return `1 / self`.
+    pub fn inv(self) -> Self {
+        Self::one() / self
+    }
+
+    /// Multiply both parts by a real number.
+    pub fn scale(self, t: F) -> Self {
+        Self::new(self.re * t, self.im * t)
+    }
+
+    /// Divide both parts by a real number.
+    pub fn unscale(self, t: F) -> Self {
+        Self::new(self.re / t, self.im / t)
+    }
+
+    /// Return `true` if either part is NaN.
+    pub fn is_nan(self) -> bool {
+        self.re.is_nan() || self.im.is_nan()
+    }
+
+    /// Return `true` if either part is infinite and neither is NaN.
+    pub fn is_infinite(self) -> bool {
+        !self.is_nan() && (self.re.is_infinite() || self.im.is_infinite())
+    }
+
+    /// Return `true` if both parts are finite.
+    pub fn is_finite(self) -> bool {
+        self.re.is_finite() && self.im.is_finite()
+    }
+
+    /// Return `e^self`.
+    pub fn exp(self) -> Self {
+        Self::from_polar(self.re.exp(), self.im)
+    }
+
+    /// Return the principal natural logarithm, whose imaginary part lies in
+    /// `(-π, π]`.
+    pub fn ln(self) -> Self {
+        let (r, theta) = self.to_polar();
+        Self::new(r.ln(), theta)
+    }
+
+    /// Return the principal square root, whose real part is non-negative.
+    ///
+    /// # Example
+    /// ```
+    /// use numeric::complex::Complex;
+    /// assert_eq!(Complex::new(-4.0, 0.0).sqrt(), Complex::new(0.0, 2.0));
+    /// assert_eq!(Complex::new(-4.0, -0.0).sqrt(), Complex::new(0.0, -2.0));
+    /// ```
+    pub fn sqrt(self) -> Self {
+        let zero = F::zero();
+        let two = F::one() + F::one();
+        if self.im == zero {
+            return if self.re >= zero {
+                Self::new(self.re.sqrt(), self.im)
+            } else if self.im.is_sign_negative() {
+                Self::new(zero, -(-self.re).sqrt())
+            } else {
+                Self::new(zero, (-self.re).sqrt())
+            };
+        }
+        let t = ((self.norm() + self.re.abs()) / two).sqrt();
+        if self.re >= zero {
+            Self::new(t, self.im / (two * t))
+        } else if self.im < zero {
+            Self::new(self.im.abs() / (two * t), -t)
+        } else {
+            Self::new(self.im.abs() / (two * t), t)
+        }
+    }
+
+    /// Raise `self` to an integer power by repeated squaring.
+    pub fn powi(self, n: i32) -> Self {
+        let mut base = self;
+        let mut acc = Self::one();
+        let mut e = n.unsigned_abs();
+        while e > 0 {
+            if e & 1 == 1 {
+                acc *= base;
+            }
+            base *= base;
+            e >>= 1;
+        }
+        if n < 0 {
+            acc.inv()
+        } else {
+            acc
+        }
+    }
+
+    /// Raise `self` to a real power, using the principal branch.
+    pub fn powf(self, exp: F) -> Self {
+        if self.is_zero() {
+            return if exp.is_zero() { Self::one() } else { self };
+        }
+        let (r, theta) = self.to_polar();
+        Self::from_polar(r.powf(exp), theta * exp)
+    }
+
+    /// Raise `self` to a complex power, using the principal branch.
+    pub fn powc(self, exp: Self) -> Self {
+        if self.is_zero() {
+            return if exp.is_zero() { Self::one() } else { self };
+        }
+        (exp * self.ln()).exp()
+    }
+
+    /// Return the sine.
+    pub fn sin(self) -> Self {
+        Self::new(
+            self.re.sin() * self.im.cosh(),
+            self.re.cos() * self.im.sinh(),
+        )
+    }
+
+    /// Return the cosine.
+    pub fn cos(self) -> Self {
+        Self::new(
+            self.re.cos() * self.im.cosh(),
+            -self.re.sin() * self.im.sinh(),
+        )
+    }
+
+    /// Return the tangent.
+    pub fn tan(self) -> Self {
+        self.sin() / self.cos()
+    }
+
+    /// Return the hyperbolic sine.
+    pub fn sinh(self) -> Self {
+        Self::new(
+            self.re.sinh() * self.im.cos(),
+            self.re.cosh() * self.im.sin(),
+        )
+    }
+
+    /// Return the hyperbolic cosine.
+    pub fn cosh(self) -> Self {
+        Self::new(
+            self.re.cosh() * self.im.cos(),
+            self.re.sinh() * self.im.sin(),
+        )
+    }
+
+    /// Return the hyperbolic tangent.
+    pub fn tanh(self) -> Self {
+        self.sinh() / self.cosh()
+    }
+}
+
+impl<F: Float> From<F> for Complex<F> {
+    fn from(re: F) -> Self {
+        Self::new(re, F::zero())
+    }
+}
+
+impl<F: Float> From<(F, F)> for Complex<F> {
+    fn from((re, im): (F, F)) -> Self {
+        Self::new(re, im)
+    }
+}
+
+impl<F: Float> From<[F; 2]> for Complex<F> {
+    fn from([re, im]: [F; 2]) -> Self {
+        Self::new(re, im)
+    }
+}
+
+impl<F: Float> From<Complex<F>> for (F, F) {
+    fn from(z: Complex<F>) -> Self {
+        (z.re, z.im)
+    }
+}
+
+impl<F: Float> From<Complex<F>> for [F; 2] {
+    fn from(z: Complex<F>) -> Self {
+        [z.re, z.im]
+    }
+}
+
+impl<F: Float + fmt::Display> fmt::Display for Complex<F> {
+    /// Format as `re+imi` or `re-imi`, which `FromStr` parses back. The
+    /// precision, if any, applies to both parts.
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let (sign, im) = if self.im.is_sign_negative() {
+            ('-', -self.im)
+        } else {
+            ('+', self.im)
+        };
+        match f.precision() {
+            Some(p) => write!(f, "{:.*}{}{:.*}i", p, self.re, sign, p, im),
+            None => write!(f, "{}{}{}i", self.re, sign, im),
+        }
+    }
+}
+
+// --- Operators ---
+
+/// Implement the reference variants of a binary operator from the owned one,
+/// both with a complex and with a real right-hand side.
+macro_rules! forward_ref_binop {
+    ($imp:ident, $method:ident) => {
+        impl<'a, F: Float> $imp<&'a Complex<F>> for Complex<F> {
+            type Output = Complex<F>;
+
+            fn $method(self, other: &Complex<F>) -> Complex<F> {
+                self.$method(*other)
+            }
+        }
+
+        impl<'a, F: Float> $imp<Complex<F>> for &'a Complex<F> {
+            type Output = Complex<F>;
+
+            fn $method(self, other: Complex<F>) -> Complex<F> {
+                (*self).$method(other)
+            }
+        }
+
+        impl<'a, 'b, F: Float> $imp<&'b Complex<F>> for &'a Complex<F> {
+            type Output = Complex<F>;
+
+            fn $method(self, other: &Complex<F>) -> Complex<F> {
+                (*self).$method(*other)
+            }
+        }
+
+        impl<'a, F: Float> $imp<&'a F> for Complex<F> {
+            type Output = Complex<F>;
+
+            fn $method(self, other: &F) -> Complex<F> {
+                self.$method(*other)
+            }
+        }
+
+        impl<'a, F: Float> $imp<F> for &'a Complex<F> {
+            type Output = Complex<F>;
+
+            fn $method(self, other: F) -> Complex<F> {
+                (*self).$method(other)
+            }
+        }
+
+        impl<'a, 'b, F: Float> $imp<&'b F> for &'a Complex<F> {
+            type Output = Complex<F>;
+
+            fn $method(self, other: &F) -> Complex<F> {
+                (*self).$method(*other)
+            }
+        }
+    };
+}
+
+/// Implement an assignment operator from the binary one.
+macro_rules! assign_op {
+    ($imp:ident, $method:ident, $op:ident) => {
+        impl<F: Float> $imp for Complex<F> {
+            fn $method(&mut self, other: Self) {
+                *self = (*self).$op(other);
+            }
+        }
+
+        impl<F: Float> $imp<F> for Complex<F> {
+            fn $method(&mut self, other: F) {
+                *self = (*self).$op(other);
+            }
+        }
+
+        impl<'a, F: Float> $imp<&'a Complex<F>> for Complex<F> {
+            fn $method(&mut self, other: &Self) {
+                *self = (*self).$op(*other);
+            }
+        }
+
+        impl<'a, F: Float> $imp<&'a F> for Complex<F> {
+            fn $method(&mut self, other: &F) {
+                *self = (*self).$op(*other);
+            }
+        }
+    };
+}
+
+impl<F: Float> Add for Complex<F> {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        Self::new(self.re + other.re, self.im + other.im)
+    }
+}
+
+impl<F: Float> Add<F> for Complex<F> {
+    type Output = Self;
+
+    fn add(self, other: F) -> Self {
+        Self::new(self.re + other, self.im)
+    }
+}
+
+impl<F: Float> Sub for Complex<F> {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self {
+        Self::new(self.re - other.re, self.im - other.im)
+    }
+}
+
+impl<F: Float> Sub<F> for Complex<F> {
+    type Output = Self;
+
+    fn sub(self, other: F) -> Self {
+        Self::new(self.re - other, self.im)
+    }
+}
+
+impl<F: Float> Mul for Complex<F> {
+    type Output = Self;
+
+    fn mul(self, other: Self) -> Self {
+        Self::new(
+            self.re * other.re - self.im * other.im,
+            self.re * other.im + self.im * other.re,
+        )
+    }
+}
+
+impl<F: Float> Mul<F> for Complex<F> {
+    type Output = Self;
+
+    fn mul(self, other: F) -> Self {
+        self.scale(other)
+    }
+}
+
+impl<F: Float> Div for Complex<F> {
+    type Output = Self;
+
+    /// Divide with Smith's algorithm, which avoids overflowing when squaring
+    /// the parts of `other`.
+    fn div(self, other: Self) -> Self {
+        if other.re.abs() >= other.im.abs() {
+            let r = other.im / other.re;
+            let d = other.re + other.im * r;
+            Self::new(
+                (self.re + self.im * r) / d,
+                (self.im - self.re * r) / d,
+            )
+        } else {
+            let r = other.re / other.im;
+            let d = other.re * r + other.im;
+            Self::new(
+                (self.re * r + self.im) / d,
+                (self.im * r - self.re) / d,
+            )
+        }
+    }
+}
+
+impl<F: Float> Div<F> for Complex<F> {
+    type Output = Self;
+
+    fn div(self, other: F) -> Self {
+        self.unscale(other)
+    }
+}
+
+impl<F: Float> Rem for Complex<F> {
+    type Output = Self;
+
+    /// Return the remainder of the division truncated towards zero, part by
+    /// part, like Gaussian integers.
+    fn rem(self, other: Self) -> Self {
+        let q = self / other;
+        self - other * Self::new(q.re.trunc(), q.im.trunc())
+    }
+}
+
+impl<F: Float> Rem<F> for Complex<F> {
+    type Output = Self;
+
+    fn rem(self, other: F) -> Self {
+        Self::new(self.re % other, self.im % other)
+    }
+}
+
+impl<F: Float> Neg for Complex<F> {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Self::new(-self.re, -self.im)
+    }
+}
+
+impl<F: Float> Neg for &Complex<F> {
+    type Output = Complex<F>;
+
+    fn neg(self) -> Complex<F> {
+        -*self
+    }
+}
+
+forward_ref_binop!(Add, add);
+forward_ref_binop!(Sub, sub);
+forward_ref_binop!(Mul, mul);
+forward_ref_binop!(Div, div);
+forward_ref_binop!(Rem, rem);
+
+assign_op!(AddAssign, add_assign, add);
+assign_op!(SubAssign, sub_assign, sub);
+assign_op!(MulAssign, mul_assign, mul);
+assign_op!(DivAssign, div_assign, div);
+assign_op!(RemAssign, rem_assign, rem);
+
+/// Implement the operators with a real left-hand side for a float type.
+macro_rules! real_lhs_ops {
+    ($($t:ty)*) => {$(
+        impl Add<Complex<$t>> for $t {
+            type Output = Complex<$t>;
+
+            fn add(self, other: Complex<$t>) -> Complex<$t> {
+                Complex::new(self + other.re, other.im)
+            }
+        }
+
+        impl Sub<Complex<$t>> for $t {
+            type Output = Complex<$t>;
+
+            fn sub(self, other: Complex<$t>) -> Complex<$t> {
+                Complex::new(self - other.re, -other.im)
+            }
+        }
+
+        impl Mul<Complex<$t>> for $t {
+            type Output = Complex<$t>;
+
+            fn mul(self, other: Complex<$t>) -> Complex<$t> {
+                other.scale(self)
+            }
+        }
+
+        impl Div<Complex<$t>> for $t {
+            type Output = Complex<$t>;
+
+            fn div(self, other: Complex<$t>) -> Complex<$t> {
+                Complex::from(self) / other
+            }
+        }
+    )*};
+}
+
+real_lhs_ops!(f32 f64);
+
+impl<F: Float> Sum for Complex<F> {
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Self::zero(), |acc, z| acc + z)
+    }
+}
+
+impl<'a, F: Float> Sum<&'a Complex<F>> for Complex<F> {
+    fn sum<I: Iterator<Item = &'a Self>>(iter: I) -> Self {
+        iter.fold(Self::zero(), |acc, z| acc + z)
+    }
+}
+
+impl<F: Float> Product for Complex<F> {
+    fn product<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Self::one(), |acc, z| acc * z)
+    }
+}
+
+impl<'a, F: Float> Product<&'a Complex<F>> for Complex<F> {
+    fn product<I: Iterator<Item = &'a Self>>(iter: I) -> Self {
+        iter.fold(Self::one(), |acc, z| acc * z)
+    }
+}
+
+// --- Numeric traits ---
+
+impl<F: Float> Zero for Complex<F> {
+    fn zero() -> Self {
+        Self::new(F::zero(), F::zero())
+    }
+
+    fn is_zero(&self) -> bool {
+        self.re.is_zero() && self.im.is_zero()
+    }
+}
+
+impl<F: Float> One for Complex<F> {
+    fn one() -> Self {
+        Self::new(F::one(), F::zero())
+    }
+}
+
+impl<F: Float> Inv for Complex<F> {
+    type Output = Self;
+
+    fn inv(self) -> Self {
+        Complex::inv(self)
+    }
+}
+
+impl<F: Float> Inv for &Complex<F> {
+    type Output = Complex<F>;
+
+    fn inv(self) -> Complex<F> {
+        Complex::inv(*self)
+    }
+}
+
+impl<F: Float> Pow<i32> for Complex<F> {
+    type Output = Self;
+
+    fn pow(self, exp: i32) -> Self {
+        self.powi(exp)
+    }
+}
+
+impl<F: Float> Pow<F> for Complex<F> {
+    type Output = Self;
+
+    fn pow(self, exp: F) -> Self {
+        self.powf(exp)
+    }
+}
+
+impl<F: Float> Pow<Complex<F>> for Complex<F> {
+    type Output = Self;
+
+    fn pow(self, exp: Self) -> Self {
+        self.powc(exp)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ComplexErrorKind<E> {
+    Empty,
+    Invalid,
+    /// One of the parts could not be parsed.
+    Part(E),
+}
+
+/// An error which can be returned when parsing a complex number.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseComplexError<E> {
+    pub kind: ComplexErrorKind<E>,
+}
+
+impl<E: fmt::Display> fmt::Display for ParseComplexError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self.kind {
+            ComplexErrorKind::Empty => "cannot parse complex number from empty string".fmt(f),
+            ComplexErrorKind::Invalid => "invalid complex number literal".fmt(f),
+            ComplexErrorKind::Part(ref e) => e.fmt(f),
+        }
+    }
+}
+
+impl<F: Float> Num for Complex<F> {
+    type FromStrRadixErr = ParseComplexError<F::FromStrRadixErr>;
+
+    /// Parse `a`, `bi`, `a+bi` or `a-bi`, where `a` and `b` are parsed with
+    /// `F::from_str_radix` and the coefficient of `i` may be omitted. Spaces
+    /// are allowed around the sign between the parts.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `radix` is greater than 18, since `i` would be a digit.
+    fn from_str_radix(src: &str, radix: u32) -> Result<Self, Self::FromStrRadixErr> {
+        use self::ComplexErrorKind::*;
+
+        assert!(
+            radix <= 18,
+            "from_str_radix: radix is too high for complex numbers (maximum 18)"
+        );
+        let src = src.trim();
+        if src.is_empty() {
+            return Err(ParseComplexError { kind: Empty });
+        }
+
+        // Split at the first sign which does not start the string or an
+        // exponent.
+        let bytes = src.as_bytes();
+        let split = (1..bytes.len()).find(|&i| {
+            let prev = bytes[i - 1];
+            (bytes[i] == b'+' || bytes[i] == b'-')
+                && !(radix <= 10 && (prev == b'e' || prev == b'E'))
+        });
+        let (first, second) = match split {
+            Some(i) => (src[..i].trim_end(), Some(src[i..].trim_start())),
+            None => (src, None),
+        };
+
+        let part = |s: &str| -> Result<(F, bool), Self::FromStrRadixErr> {
+            let (s, imaginary) = match s.strip_suffix('i') {
+                Some(s) => (s.trim_end(), true),
+                None => (s, false),
+            };
+            let value = match s {
+                "" | "+" if imaginary => F::one(),
+                "-" if imaginary => -F::one(),
+                _ => {
+                    // Allow a space after the sign of the second part.
+                    let (neg, digits) = match s.strip_prefix('-') {
+                        Some(rest) => (true, rest.trim_start()),
+                        None => (false, s.strip_prefix('+').map_or(s, str::trim_start)),
+                    };
+                    if digits.starts_with(['+', '-']) {
+                        return Err(ParseComplexError { kind: Invalid });
+                    }
+                    let v = F::from_str_radix(digits, radix)
+                        .map_err(|e| ParseComplexError { kind: Part(e) })?;
+                    if neg {
+                        -v
+                    } else {
+                        v
+                    }
+                }
+            };
+            Ok((value, imaginary))
+        };
+
+        let (a, a_imaginary) = part(first)?;
+        match second {
+            None if a_imaginary => Ok(Self::new(F::zero(), a)),
+            None => Ok(Self::new(a, F::zero())),
+            Some(second) => match part(second)? {
+                (b, true) if !a_imaginary => Ok(Self::new(a, b)),
+                (b, false) if a_imaginary => Ok(Self::new(b, a)),
+                _ => Err(ParseComplexError { kind: Invalid }),
+            },
+        }
+    }
+}
+
+impl<F: Float> FromStr for Complex<F> {
+    type Err = ParseComplexError<F::FromStrRadixErr>;
+
+    fn from_str(src: &str) -> Result<Self, Self::Err> {
+        Self::from_str_radix(src, 10)
+    }
+}
@@ -0,0 +1,632 @@
+//! Dual numbers for forward-mode automatic differentiation.
+//!
+//! A dual number `re + eps ε` with `ε² = 0` carries a value and its derivative
+//! with respect to some input. Evaluating a function with `Dual` arguments
+//! returns the exact derivative along with the value, so any code generic over
+//! `F: Float` can be differentiated without finite differences.
+//! [`DualN`] carries `N` partial derivatives at once, which gives whole
+//! gradients in a single evaluation.
+//!
+//! Comparisons only look at the value: derivatives follow the branch taken by
+//! the value, like the derivative of `max` or `abs` away from their kinks.
+//!
+//! ```
+//! use numeric::dual::{derivative, gradient, DualN};
+//! use numeric::Float;
+//!
+//! // d/dx x sin(x) = sin(x) + x cos(x)
+//! let (y, dy) = derivative(|x| x * x.sin(), 2.0);
+//! assert_eq!(y, 2.0 * 2f64.sin());
+//! assert_eq!(dy, 2f64.sin() + 2.0 * 2f64.cos());
+//!
+//! // The gradient of the distance to the origin is the unit direction.
+//! let (d, g) = gradient(|[x, y]: [DualN<f64, 2>; 2]| x.hypot(y), [3.0, 4.0]);
+//! assert_eq!(d, 5.0);
+//! assert_eq!(g, [0.6, 0.8]);
+//! ```
+
+use core::cmp::Ordering;
+use core::num::FpCategory;
+use core::ops::{Add, Div, Mul, Neg, Rem, Sub};
+use core::ops::{AddAssign, DivAssign, MulAssign, RemAssign, SubAssign};
+
+use crate::{Float, FloatConst, Num, NumCast, One, ToPrimitive, Zero};
+
+/// A dual number with a single derivative.
+#[derive(Copy, Clone, Debug, Default)]
+pub struct Dual<F: Float> {
+    /// The value.
+    pub re: F,
+    /// The derivative.
+    pub eps: F,
+}
+
+/// A dual number with `N` partial derivatives.
+#[derive(Copy, Clone, Debug)]
+pub struct DualN<F: Float, const N: usize> {
+    /// The value.
+    pub re: F,
+    /// The partial derivatives.
+    pub eps: [F; N],
+}
+
+impl<F: Float> Dual<F> {
+    /// Create a new dual number.
+    pub fn new(re: F, eps: F) -> Self {
+        Self { re, eps }
+    }
+
+    /// Create a constant, whose derivative is zero.
+    pub fn constant(re: F) -> Self {
+        Self::new(re, F::zero())
+    }
+
+    /// Create the variable to differentiate with respect to, whose derivative
+    /// is one.
+    pub fn variable(re: F) -> Self {
+        Self::new(re, F::one())
+    }
+
+    /// Return a number with the value `re` and the derivative scaled by `d`,
+    /// the derivative of the function applied to `self`.
+    ///
+    /// A zero derivative stays zero, even where `d` is infinite like for the
+    /// square root of zero.
+    fn chain(self, re: F, d: F) -> Self {
+        Self::new(re, scale(self.eps, d))
+    }
+
+    /// Return a number with the value `re` and the derivative of a function
+    /// of `self` and `other` with partial derivatives `da` and `db`.
+    fn combine(self, other: Self, re: F, da: F, db: F) -> Self {
+        Self::new(re, self.eps * da + other.eps * db)
+    }
+}
+
+impl<F: Float, const N: usize> DualN<F, N> {
+    /// Create a new dual number.
+    pub fn new(re: F, eps: [F; N]) -> Self {
+        Self { re, eps }
+    }
+
+    /// Create a constant, whose derivatives are zero.
+    pub fn constant(re: F) -> Self {
+        Self::new(re, [F::zero(); N])
+    }
+
+    /// Create the `i`-th variable to differentiate with respect to, whose
+    /// `i`-th partial derivative is one and the others zero.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `i >= N`.
+    pub fn variable(re: F, i: usize) -> Self {
+        let mut eps = [F::zero(); N];
+        eps[i] = F::one();
+        Self::new(re, eps)
+    }
+
+    fn chain(self, re: F, d: F) -> Self {
+        Self::new(re, self.eps.map(|e| scale(e, d)))
+    }
+
+    fn combine(self, other: Self, re: F, da: F, db: F) -> Self {
+        Self::new(
+            re,
+            core::array::from_fn(|i| self.eps[i] * da + other.eps[i] * db),
+        )
+    }
+}
+
+impl<F: Float, const N: usize> Default for DualN<F, N> {
+    fn default() -> Self {
+        Self::constant(F::zero())
+    }
+}
+
+/// Return the derivative `e` scaled by `d`, or zero if `e` is zero.
+fn scale<F: Float>(e: F, d: F) -> F {
+    if e.is_zero() {
+        F::zero()
+    } else {
+        e * d
+    }
+}
+
+/// Return the value and the derivative of `f` at `x`.
+pub fn derivative<F: Float>(f: impl FnOnce(Dual<F>) -> Dual<F>, x: F) -> (F, F) {
+    let y = f(Dual::variable(x));
+    (y.re, y.eps)
+}
+
+/// Return the value and the gradient of `f` at `x`.
+pub fn gradient<F: Float, const N: usize>(
+    f: impl FnOnce([DualN<F, N>; N]) -> DualN<F, N>,
+    x: [F; N],
+) -> (F, [F; N]) {
+    let y = f(core::array::from_fn(|i| DualN::variable(x[i], i)));
+    (y.re, y.eps)
+}
+
+macro_rules! dual_impl {
+    ([$($gen:tt)*] $t:ty) => {
+        impl<$($gen)*> From<F> for $t {
+            fn from(re: F) -> Self {
+                Self::constant(re)
+            }
+        }
+
+        impl<$($gen)*> PartialEq for $t {
+            /// Compare the values only.
+            fn eq(&self, other: &Self) -> bool {
+                self.re == other.re
+            }
+        }
+
+        impl<$($gen)*> PartialOrd for $t {
+            /// Compare the values only.
+            fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+                self.re.partial_cmp(&other.re)
+            }
+        }
+
+        // --- Operators ---
+
+        impl<$($gen)*> Add for $t {
+            type Output = Self;
+
+            fn add(self, other: Self) -> Self {
+                self.combine(other, self.re + other.re, F::one(), F::one())
+            }
+        }
+
+        impl<$($gen)*> Sub for $t {
+            type Output = Self;
+
+            fn sub(self, other: Self) -> Self {
+                self.combine(other, self.re - other.re, F::one(), -F::one())
+            }
+        }
+
+        impl<$($gen)*> Mul for $t {
+            type Output = Self;
+
+            fn mul(self, other: Self) -> Self {
+                self.combine(other, self.re * other.re, other.re, self.re)
+            }
+        }
+
+        impl<$($gen)*> Div for $t {
+            type Output = Self;
+
+            fn div(self, other: Self) -> Self {
+                let inv = other.re.recip();
+                let re = self.re * inv;
+                self.combine(other, re, inv, -re * inv)
+            }
+        }
+
+        impl<$($gen)*> Rem for $t {
+            type Output = Self;
+
+            fn rem(self, other: Self) -> Self {
+                let q = (self.re / other.re).trunc();
+                self.combine(other, self.re % other.re, F::one(), -q)
+            }
+        }
+
+        impl<$($gen)*> Neg for $t {
+            type Output = Self;
+
+            fn neg(self) -> Self {
+                self.chain(-self.re, -F::one())
+            }
+        }
+
+        impl<$($gen)*> Add<F> for $t {
+            type Output = Self;
+
+            fn add(self, other: F) -> Self {
+                self.chain(self.re + other, F::one())
+            }
+        }
+
+        impl<$($gen)*> Sub<F> for $t {
+            type Output = Self;
+
+            fn sub(self, other: F) -> Self {
+                self.chain(self.re - other, F::one())
+            }
+        }
+
+        impl<$($gen)*> Mul<F> for $t {
+            type Output = Self;
+
+            fn mul(self, other: F) -> Self {
+                self.chain(self.re * other, other)
+            }
+        }
+
+        impl<$($gen)*> Div<F> for $t {
+            type Output = Self;
+
+            fn div(self, other: F) -> Self {
+                self.chain(self.re / other, other.recip())
+            }
+        }
+
+        impl<$($gen)*> AddAssign for $t {
+            fn add_assign(&mut self, other: Self) {
+                *self = *self + other;
+            }
+        }
+
+        impl<$($gen)*> SubAssign for $t {
+            fn sub_assign(&mut self, other: Self) {
+                *self = *self - other;
+            }
+        }
+
+        impl<$($gen)*> MulAssign for $t {
+            fn mul_assign(&mut self, other: Self) {
+                *self = *self * other;
+            }
+        }
+
+        impl<$($gen)*> DivAssign for $t {
+            fn div_assign(&mut self, other: Self) {
+                *self = *self / other;
+            }
+        }
+
+        impl<$($gen)*> RemAssign for $t {
+            fn rem_assign(&mut self, other: Self) {
+                *self = *self % other;
+            }
+        }
+
+        // --- Numeric traits ---
+
+        impl<$($gen)*> Zero for $t {
+            fn zero() -> Self {
+                Self::constant(F::zero())
+            }
+
+            fn is_zero(&self) -> bool {
+                self.re.is_zero()
+            }
+        }
+
+        impl<$($gen)*> One for $t {
+            fn one() -> Self {
+                Self::constant(F::one())
+            }
+        }
+
+        impl<$($gen)*> Num for $t {
+            type FromStrRadixErr = F::FromStrRadixErr;
+
+            /// Parse a constant.
+            fn from_str_radix(src: &str, radix: u32) -> Result<Self, Self::FromStrRadixErr> {
+                F::from_str_radix(src, radix).map(Self::constant)
+            }
+        }
+
+        impl<$($gen)*> ToPrimitive for $t {
+            fn to_i64(&self) -> Option<i64> {
+                self.re.to_i64()
+            }
+
+            fn to_u64(&self) -> Option<u64> {
+                self.re.to_u64()
+            }
+
+            fn to_f64(&self) -> Option<f64> {
+                self.re.to_f64()
+            }
+        }
+
+        impl<$($gen)*> NumCast for $t {
+            fn from<T: ToPrimitive>(n: T) -> Option<Self> {
+                <F as NumCast>::from(n).map(Self::constant)
+            }
+        }
+
+        impl<$($gen)*> FloatConst for $t
+        where
+            F: FloatConst,
+        {
+            constant! {
+                E() -> Self::constant(F::E());
+                FRAC_1_PI() -> Self::constant(F::FRAC_1_PI());
+                FRAC_1_SQRT_2() -> Self::constant(F::FRAC_1_SQRT_2());
+                FRAC_2_PI() -> Self::constant(F::FRAC_2_PI());
+                FRAC_2_SQRT_PI() -> Self::constant(F::FRAC_2_SQRT_PI());
+                FRAC_PI_2() -> Self::constant(F::FRAC_PI_2());
+                FRAC_PI_3() -> Self::constant(F::FRAC_PI_3());
+                FRAC_PI_4() -> Self::constant(F::FRAC_PI_4());
+                FRAC_PI_6() -> Self::constant(F::FRAC_PI_6());
+                FRAC_PI_8() -> Self::constant(F::FRAC_PI_8());
+                LN_10() -> Self::constant(F::LN_10());
+                LN_2() -> Self::constant(F::LN_2());
+                LOG10_E() -> Self::constant(F::LOG10_E());
+                LOG2_E() -> Self::constant(F::LOG2_E());
+                PI() -> Self::constant(F::PI());
+                SQRT_2() -> Self::constant(F::SQRT_2());
+            }
+        }
+
+        impl<$($gen)*> Float for $t {
+            constant! {
+                nan() -> Self::constant(F::nan());
+                infinity() -> Self::constant(F::infinity());
+                neg_infinity() -> Self::constant(F::neg_infinity());
+                neg_zero() -> Self::constant(F::neg_zero());
+                min_value() -> Self::constant(F::min_value());
+                min_positive_value() -> Self::constant(F::min_positive_value());
+                epsilon() -> Self::constant(F::epsilon());
+                max_value() -> Self::constant(F::max_value());
+            }
+
+            fn is_nan(self) -> bool {
+                self.re.is_nan()
+            }
+
+            fn is_infinite(self) -> bool {
+                self.re.is_infinite()
+            }
+
+            fn is_finite(self) -> bool {
+                self.re.is_finite()
+            }
+
+            fn is_normal(self) -> bool {
+                self.re.is_normal()
+            }
+
+            fn classify(self) -> FpCategory {
+                self.re.classify()
+            }
+
+            fn is_sign_positive(self) -> bool {
+                self.re.is_sign_positive()
+            }
+
+            fn is_sign_negative(self) -> bool {
+                self.re.is_sign_negative()
+            }
+
+            fn integer_decode(self) -> (u64, i16, i8) {
+                self.re.integer_decode()
+            }
+
+            fn floor(self) -> Self {
+                self.chain(self.re.floor(), F::zero())
+            }
+
+            fn ceil(self) -> Self {
+                self.chain(self.re.ceil(), F::zero())
+            }
+
+            fn round(self) -> Self {
+                self.chain(self.re.round(), F::zero())
+            }
+
+            fn trunc(self) -> Self {
+                self.chain(self.re.trunc(), F::zero())
+            }
+
+            fn fract(self) -> Self {
+                self.chain(self.re.fract(), F::one())
+            }
+
+            fn abs(self) -> Self {
+                self.chain(self.re.abs(), self.re.signum())
+            }
+
+            fn signum(self) -> Self {
+                self.chain(self.re.signum(), F::zero())
+            }
+
+            fn mul_add(self, a: Self, b: Self) -> Self {
+                self * a + b
+            }
+
+            fn recip(self) -> Self {
+                let inv = self.re.recip();
+                self.chain(inv, -inv * inv)
+            }
+
+            fn powi(self, n: i32) -> Self {
+                if n == 0 {
+                    return Self::one();
+                }
+                let re = self.re.powi(n);
+                // `n - 1` overflows for `i32::MIN`.
+                let pow = match n.checked_sub(1) {
+                    Some(m) => self.re.powi(m),
+                    None => re / self.re,
+                };
+                self.chain(re, F::from(n).unwrap() * pow)
+            }
+
+            /// The derivative with respect to the exponent is only defined for
+            /// a positive base, it is taken as zero otherwise.
+            fn powf(self, n: Self) -> Self {
+                let re = self.re.powf(n.re);
+                let da = if n.re.is_zero() {
+                    F::zero()
+                } else {
+                    n.re * self.re.powf(n.re - F::one())
+                };
+                let db = if self.re > F::zero() {
+                    re * self.re.ln()
+                } else {
+                    F::zero()
+                };
+                self.combine(n, re, da, db)
+            }
+
+            fn sqrt(self) -> Self {
+                let re = self.re.sqrt();
+                self.chain(re, (re + re).recip())
+            }
+
+            fn exp(self) -> Self {
+                let re = self.re.exp();
+                self.chain(re, re)
+            }
+
+            fn exp2(self) -> Self {
+                let re = self.re.exp2();
+                self.chain(re, re * F::from(2.0).unwrap().ln())
+            }
+
+            fn ln(self) -> Self {
+                self.chain(self.re.ln(), self.re.recip())
+            }
+
+            fn log(self, base: Self) -> Self {
+                self.ln() / base.ln()
+            }
+
+            fn log2(self) -> Self {
+                let ln2 = F::from(2.0).unwrap().ln();
+                self.chain(self.re.log2(), (self.re * ln2).recip())
+            }
+
+            fn log10(self) -> Self {
+                let ln10 = F::from(10.0).unwrap().ln();
+                self.chain(self.re.log10(), (self.re * ln10).recip())
+            }
+
+            fn to_degrees(self) -> Self {
+                self.chain(self.re.to_degrees(), F::one().to_degrees())
+            }
+
+            fn to_radians(self) -> Self {
+                self.chain(self.re.to_radians(), F::one().to_radians())
+            }
+
+            fn max(self, other: Self) -> Self {
+                if self.re >= other.re || other.re.is_nan() {
+                    self
+                } else {
+                    other
+                }
+            }
+
+            fn min(self, other: Self) -> Self {
+                if self.re <= other.re || other.re.is_nan() {
+                    self
+                } else {
+                    other
+                }
+            }
+
+            fn abs_sub(self, other: Self) -> Self {
+                if self.re <= other.re {
+                    Self::zero()
+                } else {
+                    self - other
+                }
+            }
+
+            fn cbrt(self) -> Self {
+                let re = self.re.cbrt();
+                self.chain(re, (F::from(3.0).unwrap() * re * re).recip())
+            }
+
+            fn hypot(self, other: Self) -> Self {
+                let re = self.re.hypot(other.re);
+                if re.is_zero() {
+                    return self.combine(other, re, F::zero(), F::zero());
+                }
+                self.combine(other, re, self.re / re, other.re / re)
+            }
+
+            fn sin(self) -> Self {
+                let (sin, cos) = self.re.sin_cos();
+                self.chain(sin, cos)
+            }
+
+            fn cos(self) -> Self {
+                let (sin, cos) = self.re.sin_cos();
+                self.chain(cos, -sin)
+            }
+
+            fn tan(self) -> Self {
+                let tan = self.re.tan();
+                self.chain(tan, F::one() + tan * tan)
+            }
+
+            fn asin(self) -> Self {
+                let d = (F::one() - self.re * self.re).sqrt().recip();
+                self.chain(self.re.asin(), d)
+            }
+
+            fn acos(self) -> Self {
+                let d = (F::one() - self.re * self.re).sqrt().recip();
+                self.chain(self.re.acos(), -d)
+            }
+
+            fn atan(self) -> Self {
+                let d = (F::one() + self.re * self.re).recip();
+                self.chain(self.re.atan(), d)
+            }
+
+            fn atan2(self, other: Self) -> Self {
+                let (y, x) = (self.re, other.re);
+                let r2 = x * x + y * y;
+                if r2.is_zero() {
+                    return self.combine(other, y.atan2(x), F::zero(), F::zero());
+                }
+                self.combine(other, y.atan2(x), x / r2, -y / r2)
+            }
+
+            fn sin_cos(self) -> (Self, Self) {
+                let (sin, cos) = self.re.sin_cos();
+                (self.chain(sin, cos), self.chain(cos, -sin))
+            }
+
+            fn exp_m1(self) -> Self {
+                self.chain(self.re.exp_m1(), self.re.exp())
+            }
+
+            fn ln_1p(self) -> Self {
+                self.chain(self.re.ln_1p(), (F::one() + self.re).recip())
+            }
+
+            fn sinh(self) -> Self {
+                self.chain(self.re.sinh(), self.re.cosh())
+            }
+
+            fn cosh(self) -> Self {
+                self.chain(self.re.cosh(), self.re.sinh())
+            }
+
+            fn tanh(self) -> Self {
+                let tanh = self.re.tanh();
+                self.chain(tanh, F::one() - tanh * tanh)
+            }
+
+            fn asinh(self) -> Self {
+                let d = (self.re * self.re + F::one()).sqrt().recip();
+                self.chain(self.re.asinh(), d)
+            }
+
+            fn acosh(self) -> Self {
+                let d = (self.re * self.re - F::one()).sqrt().recip();
+                self.chain(self.re.acosh(), d)
+            }
+
+            fn atanh(self) -> Self {
+                let d = (F::one() - self.re * self.re).recip();
+                self.chain(self.re.atanh(), d)
+            }
+        }
+    };
+}
+
+dual_impl!([F: Float] Dual<F>);
+dual_impl!([F: Float, const N: usize] DualN<F, N>);
@@ -0,0 +1,1309 @@
+//! Signed binary fixed-point numbers.
+//!
+//! [`Q16_16`] stores a number in an `i32` with 16 fractional bits, [`Q32_32`]
+//! in an `i64` with 32 fractional bits. Arithmetic only uses integer
+//! operations, so results are bit-for-bit identical on every platform, which is
+//! what lockstep simulations and replays need.
+//!
+//! Both types implement [`Real`](crate::real::Real), so code written against
+//! `Real` runs unchanged on them. The transcendental functions are computed on
+//! integers as well: square roots with an integer square root, trigonometry
+//! with CORDIC, and `exp`/`ln` with range reduction and series, all carried out
+//! with 60 fractional bits before rounding to the result type.
+//!
+//! There is no infinity or NaN: `Real` functions saturate at `MIN` and `MAX`
+//! instead of overflowing, return zero for the square root of a negative
+//! number and `MIN` for the logarithm of a non-positive one. The arithmetic
+//! operators behave like the integer ones: they panic on overflow in debug
+//! builds and wrap in release builds, and division by zero panics. Products
+//! and quotients are rounded to the nearest representable value.
+//!
+//! ```
+//! use numeric::fixed::Q16_16;
+//! use numeric::real::Real;
+//!
+//! let x = Q16_16::from(3) / Q16_16::from(4);
+//! assert_eq!(x.to_string(), "0.75");
+//! assert_eq!((x * x).sqrt(), x);
+//! ```
+
+use crate::real::Real;
+use crate::{
+    Bounded, CheckedAdd, CheckedDiv, CheckedMul, CheckedNeg, CheckedRem, CheckedSub, FloatConst,
+    FromPrimitive, Num, NumCast, One, Saturating, SaturatingAdd, SaturatingMul, SaturatingSub,
+    Signed, ToPrimitive, WrappingAdd, WrappingMul, WrappingNeg, WrappingSub, Zero,
+};
+use core::fmt;
+use core::ops::{Add, Div, Mul, Neg, Rem, Sub};
+use core::ops::{AddAssign, DivAssign, MulAssign, RemAssign, SubAssign};
+use core::str::FromStr;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum FixedErrorKind {
+    Empty,
+    Invalid,
+    Overflow,
+}
+
+/// An error which can be returned when parsing a fixed-point number.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseFixedError {
+    pub kind: FixedErrorKind,
+}
+
+impl fmt::Display for ParseFixedError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let description = match self.kind {
+            FixedErrorKind::Empty => "cannot parse fixed-point number from empty string",
+            FixedErrorKind::Invalid => "invalid fixed-point literal",
+            FixedErrorKind::Overflow => "fixed-point literal out of range",
+        };
+
+        description.fmt(f)
+    }
+}
+
+/// Integer implementations of the `Real` functions.
+///
+/// Arguments and results are Q32.32 values stored in an `i64`; intermediate
+/// values are Q4.60 stored in an `i128`.
+mod kernel {
+    const ONE: i128 = 1 << 60;
+    const ONE32: i64 = 1 << 32;
+    const PI: i128 = 3622009729038561421;
+    const HALF_PI: i128 = 1811004864519280711;
+    const TWO_PI: i128 = 7244019458077122842;
+    const LN_2: i128 = 799144290325165979;
+    const LN_10: i128 = 2654699869899991814;
+    /// The CORDIC gain `∏ cos(atan(2^-i))`.
+    const GAIN: i128 = 700114967507363238;
+    /// `atan(2^-i)` for `i` in `0..=60`.
+    const ATAN: [i128; 61] = [
+        905502432259640355,
+        534549298976576474,
+        282441168888798124,
+        143371547418228444,
+        71963988336308046,
+        36017075762092179,
+        18012932708689205,
+        9007016009513623,
+        4503576721087964,
+        2251796950380271,
+        1125899548928887,
+        562949908682076,
+        281474971118251,
+        140737487656277,
+        70368744090283,
+        35184372077909,
+        17592186043051,
+        8796093022037,
+        4398046511083,
+        2199023255549,
+        1099511627776,
+        549755813888,
+        274877906944,
+        137438953472,
+        68719476736,
+        34359738368,
+        17179869184,
+        8589934592,
+        4294967296,
+        2147483648,
+        1073741824,
+        536870912,
+        268435456,
+        134217728,
+        67108864,
+        33554432,
+        16777216,
+        8388608,
+        4194304,
+        2097152,
+        1048576,
+        524288,
+        262144,
+        131072,
+        65536,
+        32768,
+        16384,
+        8192,
+        4096,
+        2048,
+        1024,
+        512,
+        256,
+        128,
+        64,
+        32,
+        16,
+        8,
+        4,
+        2,
+        1,
+    ];
+
+    fn widen(x: i64) -> i128 {
+        (x as i128) << 28
+    }
+
+    fn saturate(x: i128) -> i64 {
+        x.clamp(i64::MIN as i128, i64::MAX as i128) as i64
+    }
+
+    /// Round a Q4.60 value to Q32.32, saturating.
+    fn narrow(x: i128) -> i64 {
+        saturate((x + (1 << 27)) >> 28)
+    }
+
+    fn mul(a: i128, b: i128) -> i128 {
+        (a * b + (1 << 59)) >> 60
+    }
+
+    fn div(a: i128, b: i128) -> i128 {
+        (a << 60) / b
+    }
+
+    /// Integer square root, rounded to nearest.
+    fn isqrt(n: u128) -> u128 {
+        if n == 0 {
+            return 0;
+        }
+        let mut rest = n;
+        let mut root = 0u128;
+        let mut bit = 1u128 << ((127 - n.leading_zeros()) & !1);
+        while bit != 0 {
+            if rest >= root + bit {
+                rest -= root + bit;
+                root = (root >> 1) + bit;
+            } else {
+                root >>= 1;
+            }
+            bit >>= 2;
+        }
+        if rest > root {
+            root + 1
+        } else {
+            root
+        }
+    }
+
+    pub fn to_degrees(a: i64) -> i64 {
+        // 180 / π with 56 fractional bits
+        saturate((a as i128 * 4128596020240314225 + (1 << 55)) >> 56)
+    }
+
+    pub fn to_radians(a: i64) -> i64 {
+        // π / 180 with 64 fractional bits
+        saturate((a as i128 * 321956420358983237 + (1 << 63)) >> 64)
+    }
+
+    pub fn sqrt(a: i64) -> i64 {
+        if a <= 0 {
+            return 0;
+        }
+        isqrt((a as u128) << 32) as i64
+    }
+
+    pub fn hypot(a: i64, b: i64) -> i64 {
+        let (a, b) = (a.unsigned_abs() as u128, b.unsigned_abs() as u128);
+        saturate(isqrt(a * a + b * b) as i128)
+    }
+
+    /// Return the Q4.60 mantissa `m` and the exponent `k` such that
+    /// `exp(x) = m * 2^k`, for a Q4.60 argument.
+    fn exp_parts(x: i128) -> (i128, i128) {
+        let k = (x + LN_2 / 2).div_euclid(LN_2);
+        let r = x - k * LN_2;
+        let mut sum = ONE;
+        let mut term = ONE;
+        let mut n = 1;
+        while term != 0 {
+            term = mul(term, r) / n;
+            sum += term;
+            n += 1;
+        }
+        (sum, k)
+    }
+
+    /// Return `m * 2^k` as Q32.32 for a Q4.60 mantissa, saturating.
+    fn ldexp((m, k): (i128, i128)) -> i64 {
+        let shift = 28 - k;
+        if shift <= 0 {
+            if shift < -64 {
+                i64::MAX
+            } else {
+                saturate(m << -shift)
+            }
+        } else if shift >= 126 {
+            0
+        } else {
+            saturate((m + (1 << (shift - 1))) >> shift)
+        }
+    }
+
+    pub fn exp(a: i64) -> i64 {
+        ldexp(exp_parts(widen(a)))
+    }
+
+    pub fn exp2(a: i64) -> i64 {
+        let fract = ((a & (ONE32 - 1)) as i128) << 28;
+        let (m, k) = exp_parts(mul(fract, LN_2));
+        ldexp((m, k + (a >> 32) as i128))
+    }
+
+    pub fn exp_m1(a: i64) -> i64 {
+        if a.unsigned_abs() < (ONE32 as u64) / 4 {
+            narrow(exp_parts(widen(a)).0 - ONE)
+        } else {
+            exp(a).saturating_sub(ONE32)
+        }
+    }
+
+    /// Natural logarithm as Q4.60 of a positive Q32.32 value.
+    fn ln60(a: i128) -> i128 {
+        let top = 127 - a.leading_zeros() as i128;
+        let m = if top <= 60 {
+            a << (60 - top)
+        } else {
+            a >> (top - 60)
+        };
+        // ln(m) = 2 atanh((m - 1) / (m + 1)) with m in [1, 2)
+        let s = div(m - ONE, m + ONE);
+        let s2 = mul(s, s);
+        let mut sum = 0;
+        let mut term = s;
+        let mut d = 1;
+        while term != 0 {
+            sum += term / d;
+            term = mul(term, s2);
+            d += 2;
+        }
+        2 * sum + (top - 32) * LN_2
+    }
+
+    pub fn ln(a: i64) -> i64 {
+        if a <= 0 {
+            return i64::MIN;
+        }
+        narrow(ln60(a as i128))
+    }
+
+    pub fn log(a: i64, base: i64) -> i64 {
+        if a <= 0 {
+            return i64::MIN;
+        }
+        let l = ln60(a as i128);
+        let b = if base <= 0 { 0 } else { ln60(base as i128) };
+        if b == 0 {
+            return if l >= 0 { i64::MAX } else { i64::MIN };
+        }
+        narrow(div(l, b))
+    }
+
+    pub fn log2(a: i64) -> i64 {
+        if a <= 0 {
+            return i64::MIN;
+        }
+        narrow(div(ln60(a as i128), LN_2))
+    }
+
+    pub fn log10(a: i64) -> i64 {
+        if a <= 0 {
+            return i64::MIN;
+        }
+        narrow(div(ln60(a as i128), LN_10))
+    }
+
+    pub fn ln_1p(a: i64) -> i64 {
+        ln(a.saturating_add(ONE32))
+    }
+
+    pub fn powf(a: i64, n: i64) -> i64 {
+        if a <= 0 {
+            return 0;
+        }
+        let l = ln60(a as i128);
+        match (n as i128).checked_mul(l) {
+            Some(p) => ldexp(exp_parts(p >> 32)),
+            None if (n < 0) == (l < 0) => i64::MAX,
+            None => 0,
+        }
+    }
+
+    pub fn cbrt(a: i64) -> i64 {
+        if a == 0 {
+            return 0;
+        }
+        let r = ldexp(exp_parts(ln60(a.unsigned_abs() as i128) / 3));
+        if a < 0 {
+            -r
+        } else {
+            r
+        }
+    }
+
+    pub fn sin_cos(a: i64) -> (i64, i64) {
+        let x = widen(a);
+        let mut r = x - (x + PI).div_euclid(TWO_PI) * TWO_PI;
+        let mut sign = 1;
+        if r > HALF_PI {
+            r = PI - r;
+            sign = -1;
+        } else if r < -HALF_PI {
+            r = -PI - r;
+            sign = -1;
+        }
+        let (mut cx, mut cy, mut z) = (GAIN, 0, r);
+        for (i, t) in ATAN.iter().enumerate() {
+            let (dx, dy) = (cy >> i, cx >> i);
+            if z >= 0 {
+                cx -= dx;
+                cy += dy;
+                z -= t;
+            } else {
+                cx += dx;
+                cy -= dy;
+                z += t;
+            }
+        }
+        (narrow(cy), narrow(sign * cx))
+    }
+
+    pub fn tan(a: i64) -> i64 {
+        let (sin, cos) = sin_cos(a);
+        if cos == 0 {
+            return if sin >= 0 { i64::MAX } else { i64::MIN };
+        }
+        saturate(((sin as i128) << 32) / cos as i128)
+    }
+
+    pub fn atan2(y: i64, x: i64) -> i64 {
+        if x == 0 && y == 0 {
+            return 0;
+        }
+        let (mut x, mut y) = (x as i128, y as i128);
+        let mut z = 0;
+        if x < 0 {
+            z = if y >= 0 { PI } else { -PI };
+            x = -x;
+            y = -y;
+        }
+        let top = 128 - x.abs().max(y.abs()).leading_zeros() as i32;
+        let shift = 61 - top;
+        if shift >= 0 {
+            x <<= shift;
+            y <<= shift;
+        } else {
+            x >>= -shift;
+            y >>= -shift;
+        }
+        for (i, t) in ATAN.iter().enumerate() {
+            let (dx, dy) = (y >> i, x >> i);
+            if y > 0 {
+                x += dx;
+                y -= dy;
+                z += t;
+            } else {
+                x -= dx;
+                y += dy;
+                z -= t;
+            }
+        }
+        narrow(z)
+    }
+
+    /// `sqrt(1 - a²)` for `a` in `[-1, 1]`.
+    fn cathetus(a: i64) -> i64 {
+        let a = a.unsigned_abs() as u128;
+        isqrt((1u128 << 64) - a * a) as i64
+    }
+
+    pub fn asin(a: i64) -> i64 {
+        let a = a.clamp(-ONE32, ONE32);
+        atan2(a, cathetus(a))
+    }
+
+    pub fn acos(a: i64) -> i64 {
+        let a = a.clamp(-ONE32, ONE32);
+        atan2(cathetus(a), a)
+    }
+
+    pub fn sinh(a: i64) -> i64 {
+        let (m, k) = exp_parts(widen(a));
+        let (n, j) = exp_parts(-widen(a));
+        ldexp((m, k - 1)).saturating_sub(ldexp((n, j - 1)))
+    }
+
+    pub fn cosh(a: i64) -> i64 {
+        let (m, k) = exp_parts(widen(a));
+        let (n, j) = exp_parts(-widen(a));
+        ldexp((m, k - 1)).saturating_add(ldexp((n, j - 1)))
+    }
+
+    pub fn tanh(a: i64) -> i64 {
+        // tanh(|a|) = (1 - t) / (1 + t) with t = exp(-2|a|)
+        let (m, k) = exp_parts(-2 * widen(a).abs());
+        let t = if k <= -126 { 0 } else { m >> -k };
+        let r = narrow(div(ONE - t, ONE + t));
+        if a < 0 {
+            -r
+        } else {
+            r
+        }
+    }
+
+    pub fn asinh(a: i64) -> i64 {
+        let x = a.unsigned_abs() as i128 + hypot(a, ONE32) as i128;
+        let r = narrow(ln60(x));
+        if a < 0 {
+            -r
+        } else {
+            r
+        }
+    }
+
+    pub fn acosh(a: i64) -> i64 {
+        if a <= ONE32 {
+            return 0;
+        }
+        let root = isqrt(((a - ONE32) as u128) * ((a as u128) + ONE32 as u128));
+        narrow(ln60(a as i128 + root as i128))
+    }
+
+    pub fn atanh(a: i64) -> i64 {
+        if a >= ONE32 {
+            return i64::MAX;
+        } else if a <= -ONE32 {
+            return i64::MIN;
+        }
+        let ratio = (((ONE32 + a) as i128) << 32) / (ONE32 - a) as i128;
+        narrow(ln60(ratio) / 2)
+    }
+}
+
+/// Write the decimal expansion of `bits / 2^frac` with enough digits to
+/// parse back to the same value, or exactly `f.precision()` digits.
+fn fmt_fixed(f: &mut fmt::Formatter, negative: bool, bits: u128, frac: u32) -> fmt::Result {
+    let one = 1u128 << frac;
+    let mut int = bits >> frac;
+    let mut rest = bits & (one - 1);
+    let mut digits = [0u8; 40];
+    let mut len = 0;
+    let mut ulp = 1u128;
+    let mut round_up = false;
+    loop {
+        match f.precision() {
+            Some(p) if len == p.min(digits.len()) => {
+                round_up = 2 * rest >= one;
+                break;
+            }
+            None if 2 * rest < ulp => break,
+            None if 2 * (one - rest) < ulp => {
+                round_up = true;
+                break;
+            }
+            _ => {}
+        }
+        rest *= 10;
+        ulp *= 10;
+        digits[len] = (rest >> frac) as u8;
+        rest &= one - 1;
+        len += 1;
+    }
+    if round_up {
+        let mut i = len;
+        loop {
+            if i == 0 {
+                int += 1;
+                break;
+            }
+            i -= 1;
+            if digits[i] == 9 {
+                digits[i] = 0;
+            } else {
+                digits[i] += 1;
+                break;
+            }
+        }
+    }
+    // Without a precision, drop the zeros left behind by rounding up.
+    if f.precision().is_none() {
+        while len > 0 && digits[len - 1] == 0 {
+            len -= 1;
+        }
+    }
+
+    let mut buf = [0u8; 80];
+    let mut start = 40;
+    loop {
+        start -= 1;
+        buf[start] = b'0' + (int % 10) as u8;
+        int /= 10;
+        if int == 0 {
+            break;
+        }
+    }
+    let mut end = 40;
+    if len > 0 {
+        buf[end] = b'.';
+        end += 1;
+        for &d in &digits[..len] {
+            buf[end] = b'0' + d;
+            end += 1;
+        }
+    }
+    let s = core::str::from_utf8(&buf[start..end]).unwrap();
+    f.pad_integral(!negative, "", s)
+}
+
+/// Parse `src` as `[+-]digits[.digits]` in the given radix into a number with
+/// `frac` fractional bits, without range checks.
+fn parse_fixed(src: &str, radix: u32, frac: u32) -> Result<i128, ParseFixedError> {
+    use self::FixedErrorKind::*;
+
+    assert!(
+        (2..=36).contains(&radix),
+        "from_str_radix: radix must lie in the range `[2, 36]` - found {}",
+        radix
+    );
+    if src.is_empty() {
+        return Err(ParseFixedError { kind: Empty });
+    }
+    let (negative, body) = match src.as_bytes()[0] {
+        b'-' => (true, &src[1..]),
+        b'+' => (false, &src[1..]),
+        _ => (false, src),
+    };
+    let (int, fract) = match body.find('.') {
+        Some(i) => (&body[..i], &body[i + 1..]),
+        None => (body, ""),
+    };
+    if int.is_empty() && fract.is_empty() {
+        return Err(ParseFixedError { kind: Invalid });
+    }
+
+    let digit = |c: char| c.to_digit(radix).ok_or(ParseFixedError { kind: Invalid });
+    let mut value = 0i128;
+    for c in int.chars() {
+        value = value
+            .checked_mul(radix as i128)
+            .and_then(|v| v.checked_add(digit(c).ok()? as i128))
+            .ok_or(ParseFixedError {
+                kind: if c.is_digit(radix) { Overflow } else { Invalid },
+            })?;
+        if value >> (126 - frac) != 0 {
+            return Err(ParseFixedError { kind: Overflow });
+        }
+    }
+    // Accumulate the fraction from its last digit with 32 guard bits.
+    let mut fraction = 0u128;
+    for c in fract.chars().rev() {
+        fraction = (((digit(c)? as u128) << (frac + 32)) + fraction) / radix as u128;
+    }
+    let fraction = ((fraction + (1 << 31)) >> 32) as i128;
+
+    let value = (value << frac) + fraction;
+    Ok(if negative { -value } else { value })
+}
+
+impl Q16_16 {
+    fn to_q32(self) -> i64 {
+        (self.0 as i64) << 16
+    }
+
+    fn from_q32(v: i64) -> Self {
+        let v = ((v >> 15) + 1) >> 1;
+        Self(v.clamp(i32::MIN as i64, i32::MAX as i64) as i32)
+    }
+}
+
+impl Q32_32 {
+    fn to_q32(self) -> i64 {
+        self.0
+    }
+
+    fn from_q32(v: i64) -> Self {
+        Self(v)
+    }
+}
+
+macro_rules! fixed_impl {
+    ($name:ident, $bits:ty, $wide:ty, $int:ty, $frac:expr, $doc:expr) => {
+        #[doc = $doc]
+        #[derive(Copy, Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+        pub struct $name($bits);
+
+        impl $name {
+            /// The number of fractional bits.
+            pub const FRAC_BITS: u32 = $frac;
+            /// Zero.
+            pub const ZERO: Self = Self(0);
+            /// One.
+            pub const ONE: Self = Self(1 << $frac);
+            /// The smallest representable value.
+            pub const MIN: Self = Self(<$bits>::MIN);
+            /// The largest representable value.
+            pub const MAX: Self = Self(<$bits>::MAX);
+            /// The smallest positive value, which is also the step between
+            /// two consecutive values.
+            pub const DELTA: Self = Self(1);
+
+            /// Create a number from its underlying integer representation,
+            /// that is the value multiplied by `2^FRAC_BITS`.
+            pub const fn from_bits(bits: $bits) -> Self {
+                Self(bits)
+            }
+
+            /// Return the underlying integer representation.
+            pub const fn to_bits(self) -> $bits {
+                self.0
+            }
+
+            fn mul_wide(self, rhs: Self) -> $wide {
+                (self.0 as $wide * rhs.0 as $wide + (1 << ($frac - 1))) >> $frac
+            }
+
+            /// Quotient rounded half away from zero, `rhs` must not be zero.
+            fn div_wide(self, rhs: Self) -> $wide {
+                let q = ((self.0 as $wide) << ($frac + 1)) / rhs.0 as $wide;
+                (q + q.signum()) / 2
+            }
+
+            fn narrow(v: $wide) -> Option<Self> {
+                <$bits>::try_from(v).ok().map(Self)
+            }
+
+            fn saturate(v: $wide) -> Self {
+                Self(v.clamp(<$bits>::MIN as $wide, <$bits>::MAX as $wide) as $bits)
+            }
+
+            fn overflowing(v: $wide, op: &str) -> Self {
+                if cfg!(debug_assertions) && <$bits>::try_from(v).is_err() {
+                    panic!("attempt to {} with overflow", op);
+                }
+                Self(v as $bits)
+            }
+        }
+
+        impl From<$int> for $name {
+            fn from(n: $int) -> Self {
+                Self((n as $bits) << $frac)
+            }
+        }
+
+        impl fmt::Display for $name {
+            fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                fmt_fixed(f, self.0 < 0, self.0.unsigned_abs() as u128, $frac)
+            }
+        }
+
+        impl FromStr for $name {
+            type Err = ParseFixedError;
+
+            fn from_str(src: &str) -> Result<Self, ParseFixedError> {
+                Self::from_str_radix(src, 10)
+            }
+        }
+
+        // --- Operators ---
+
+        impl Add for $name {
+            type Output = Self;
+
+            fn add(self, rhs: Self) -> Self {
+                Self(self.0 + rhs.0)
+            }
+        }
+
+        impl Sub for $name {
+            type Output = Self;
+
+            fn sub(self, rhs: Self) -> Self {
+                Self(self.0 - rhs.0)
+            }
+        }
+
+        impl Mul for $name {
+            type Output = Self;
+
+            fn mul(self, rhs: Self) -> Self {
+                Self::overflowing(self.mul_wide(rhs), "multiply")
+            }
+        }
+
+        impl Div for $name {
+            type Output = Self;
+
+            fn div(self, rhs: Self) -> Self {
+                if rhs.0 == 0 {
+                    panic!("attempt to divide by zero");
+                }
+                Self::overflowing(self.div_wide(rhs), "divide")
+            }
+        }
+
+        impl Rem for $name {
+            type Output = Self;
+
+            fn rem(self, rhs: Self) -> Self {
+                Self(self.0 % rhs.0)
+            }
+        }
+
+        impl Neg for $name {
+            type Output = Self;
+
+            fn neg(self) -> Self {
+                Self(-self.0)
+            }
+        }
+
+        impl AddAssign for $name {
+            fn add_assign(&mut self, rhs: Self) {
+                *self = *self + rhs;
+            }
+        }
+
+        impl SubAssign for $name {
+            fn sub_assign(&mut self, rhs: Self) {
+                *self = *self - rhs;
+            }
+        }
+
+        impl MulAssign for $name {
+            fn mul_assign(&mut self, rhs: Self) {
+                *self = *self * rhs;
+            }
+        }
+
+        impl DivAssign for $name {
+            fn div_assign(&mut self, rhs: Self) {
+                *self = *self / rhs;
+            }
+        }
+
+        impl RemAssign for $name {
+            fn rem_assign(&mut self, rhs: Self) {
+                *self = *self % rhs;
+            }
+        }
+
+        // --- Checked, wrapping and saturating operations ---
+
+        impl CheckedAdd for $name {
+            fn checked_add(&self, v: &Self) -> Option<Self> {
+                self.0.checked_add(v.0).map(Self)
+            }
+        }
+
+        impl CheckedSub for $name {
+            fn checked_sub(&self, v: &Self) -> Option<Self> {
+                self.0.checked_sub(v.0).map(Self)
+            }
+        }
+
+        impl CheckedMul for $name {
+            fn checked_mul(&self, v: &Self) -> Option<Self> {
+                Self::narrow(self.mul_wide(*v))
+            }
+        }
+
+        impl CheckedDiv for $name {
+            fn checked_div(&self, v: &Self) -> Option<Self> {
+                if v.0 == 0 {
+                    return None;
+                }
+                Self::narrow(self.div_wide(*v))
+            }
+        }
+
+        impl CheckedRem for $name {
+            fn checked_rem(&self, v: &Self) -> Option<Self> {
+                self.0.checked_rem(v.0).map(Self)
+            }
+        }
+
+        impl CheckedNeg for $name {
+            fn checked_neg(&self) -> Option<Self> {
+                self.0.checked_neg().map(Self)
+            }
+        }
+
+        impl WrappingAdd for $name {
+            fn wrapping_add(&self, v: &Self) -> Self {
+                Self(self.0.wrapping_add(v.0))
+            }
+        }
+
+        impl WrappingSub for $name {
+            fn wrapping_sub(&self, v: &Self) -> Self {
+                Self(self.0.wrapping_sub(v.0))
+            }
+        }
+
+        impl WrappingMul for $name {
+            fn wrapping_mul(&self, v: &Self) -> Self {
+                Self(self.mul_wide(*v) as $bits)
+            }
+        }
+
+        impl WrappingNeg for $name {
+            fn wrapping_neg(&self) -> Self {
+                Self(self.0.wrapping_neg())
+            }
+        }
+
+        impl SaturatingAdd for $name {
+            fn saturating_add(&self, v: &Self) -> Self {
+                Self(self.0.saturating_add(v.0))
+            }
+        }
+
+        impl SaturatingSub for $name {
+            fn saturating_sub(&self, v: &Self) -> Self {
+                Self(self.0.saturating_sub(v.0))
+            }
+        }
+
+        impl SaturatingMul for $name {
+            fn saturating_mul(&self, v: &Self) -> Self {
+                Self::saturate(self.mul_wide(*v))
+            }
+        }
+
+        impl Saturating for $name {
+            fn saturating_add(self, v: Self) -> Self {
+                SaturatingAdd::saturating_add(&self, &v)
+            }
+
+            fn saturating_sub(self, v: Self) -> Self {
+                SaturatingSub::saturating_sub(&self, &v)
+            }
+        }
+
+        // --- Numeric traits ---
+
+        impl Zero for $name {
+            fn zero() -> Self {
+                Self::ZERO
+            }
+
+            fn is_zero(&self) -> bool {
+                self.0 == 0
+            }
+        }
+
+        impl One for $name {
+            fn one() -> Self {
+                Self::ONE
+            }
+        }
+
+        impl Num for $name {
+            type FromStrRadixErr = ParseFixedError;
+
+            fn from_str_radix(src: &str, radix: u32) -> Result<Self, ParseFixedError> {
+                let v = parse_fixed(src, radix, $frac)?;
+                <$bits>::try_from(v).map(Self).map_err(|_| ParseFixedError {
+                    kind: FixedErrorKind::Overflow,
+                })
+            }
+        }
+
+        impl Bounded for $name {
+            fn min_value() -> Self {
+                Self::MIN
+            }
+
+            fn max_value() -> Self {
+                Self::MAX
+            }
+        }
+
+        impl Signed for $name {
+            fn abs(&self) -> Self {
+                Real::abs(*self)
+            }
+
+            fn abs_sub(&self, other: &Self) -> Self {
+                Real::abs_sub(*self, *other)
+            }
+
+            /// Return `1`, `-1`, or zero for zero.
+            fn signum(&self) -> Self {
+                Real::signum(*self)
+            }
+
+            fn is_positive(&self) -> bool {
+                self.0 > 0
+            }
+
+            fn is_negative(&self) -> bool {
+                self.0 < 0
+            }
+        }
+
+        impl ToPrimitive for $name {
+            fn to_i64(&self) -> Option<i64> {
+                Some((self.0 / (1 << $frac)) as i64)
+            }
+
+            fn to_u64(&self) -> Option<u64> {
+                (self.0 / (1 << $frac)).to_u64()
+            }
+
+            fn to_f32(&self) -> Option<f32> {
+                self.to_f64().map(|v| v as f32)
+            }
+
+            fn to_f64(&self) -> Option<f64> {
+                Some(self.0 as f64 / (1u64 << $frac) as f64)
+            }
+        }
+
+        impl FromPrimitive for $name {
+            fn from_i64(n: i64) -> Option<Self> {
+                (n as $wide)
+                    .checked_mul(1 << $frac)
+                    .and_then(Self::narrow)
+            }
+
+            fn from_u64(n: u64) -> Option<Self> {
+                i64::try_from(n).ok().and_then(Self::from_i64)
+            }
+
+            fn from_f32(n: f32) -> Option<Self> {
+                Self::from_f64(n as f64)
+            }
+
+            /// Round to the nearest value, or return `None` if `n` is out of
+            /// range or NaN.
+            fn from_f64(n: f64) -> Option<Self> {
+                let scaled = n * (1u64 << $frac) as f64;
+                let (min, max) = (<$bits>::MIN as f64, <$bits>::MAX as f64);
+                if !(scaled >= min - 0.5 && scaled < max + 0.5) {
+                    return None;
+                }
+                let t = scaled as $wide;
+                let fract = scaled - t as f64;
+                let t = if fract >= 0.5 {
+                    t + 1
+                } else if fract <= -0.5 {
+                    t - 1
+                } else {
+                    t
+                };
+                Self::narrow(t)
+            }
+        }
+
+        impl NumCast for $name {
+            /// Convert the integer part exactly, and only the fractional part
+            /// through `f64`, so that integers and fixed-point numbers do not
+            /// lose precision.
+            fn from<T: ToPrimitive>(n: T) -> Option<Self> {
+                let float = n.to_f64()?;
+                let int = match n.to_i64() {
+                    Some(int) => int as i128,
+                    None => return Self::from_f64(float),
+                };
+                // The fractional part may round to 1 just below an integer.
+                let max = (1 << $frac) - 1;
+                let fract = (Self::from_f64(float - int as f64)?.0 as i128).clamp(-max, max);
+                let bits = int.checked_mul(1 << $frac)?.checked_add(fract)?;
+                <$bits>::try_from(bits).ok().map(Self)
+            }
+        }
+
+        impl FloatConst for $name {
+            constant! {
+                E() -> Self::from_q32(11674931555);
+                FRAC_1_PI() -> Self::from_q32(1367130551);
+                FRAC_1_SQRT_2() -> Self::from_q32(3037000500);
+                FRAC_2_PI() -> Self::from_q32(2734261102);
+                FRAC_2_SQRT_PI() -> Self::from_q32(4846351620);
+                FRAC_PI_2() -> Self::from_q32(6746518852);
+                FRAC_PI_3() -> Self::from_q32(4497679235);
+                FRAC_PI_4() -> Self::from_q32(3373259426);
+                FRAC_PI_6() -> Self::from_q32(2248839617);
+                FRAC_PI_8() -> Self::from_q32(1686629713);
+                LN_10() -> Self::from_q32(9889527671);
+                LN_2() -> Self::from_q32(2977044472);
+                LOG10_E() -> Self::from_q32(1865280597);
+                LOG2_E() -> Self::from_q32(6196328019);
+                PI() -> Self::from_q32(13493037705);
+                SQRT_2() -> Self::from_q32(6074001000);
+                TAU() -> Self::from_q32(26986075409);
+                LOG10_2() -> Self::from_q32(1292913986);
+                LOG2_10() -> Self::from_q32(14267572527);
+            }
+        }
+
+        impl Real for $name {
+            fn min_value() -> Self {
+                Self::MIN
+            }
+
+            fn min_positive_value() -> Self {
+                Self::DELTA
+            }
+
+            fn epsilon() -> Self {
+                Self::DELTA
+            }
+
+            fn max_value() -> Self {
+                Self::MAX
+            }
+
+            fn floor(self) -> Self {
+                Self(self.0 & !((1 << $frac) - 1))
+            }
+
+            fn ceil(self) -> Self {
+                Self(self.0.saturating_add((1 << $frac) - 1)).floor()
+            }
+
+            /// Round half away from zero. Like `ceil`, values which round
+            /// above `MAX` give the largest integer.
+            fn round(self) -> Self {
+                let half = 1 << ($frac - 1);
+                if self.0 >= 0 {
+                    Self(self.0.saturating_add(half)).floor()
+                } else {
+                    // `MIN` is an integer, only its negation overflows.
+                    let mask: $wide = (1 << $frac) - 1;
+                    Self::saturate(-((half as $wide - self.0 as $wide) & !mask))
+                }
+            }
+
+            fn trunc(self) -> Self {
+                if self.0 >= 0 {
+                    self.floor()
+                } else {
+                    self.ceil()
+                }
+            }
+
+            fn fract(self) -> Self {
+                self - self.trunc()
+            }
+
+            /// Return the absolute value, `MAX` for `MIN`.
+            fn abs(self) -> Self {
+                Self(self.0.saturating_abs())
+            }
+
+            /// Return `1`, `-1`, or zero for zero.
+            fn signum(self) -> Self {
+                Self(self.0.signum() << $frac)
+            }
+
+            fn is_sign_positive(self) -> bool {
+                self.0 >= 0
+            }
+
+            fn is_sign_negative(self) -> bool {
+                self.0 < 0
+            }
+
+            fn mul_add(self, a: Self, b: Self) -> Self {
+                let p = self.0 as $wide * a.0 as $wide + ((b.0 as $wide) << $frac);
+                Self::saturate((p + (1 << ($frac - 1))) >> $frac)
+            }
+
+            fn recip(self) -> Self {
+                if self.0 == 0 {
+                    return Self::MAX;
+                }
+                Self::saturate(Self::ONE.div_wide(self))
+            }
+
+            fn powi(self, n: i32) -> Self {
+                let mut base = self;
+                let mut acc = Self::ONE;
+                let mut e = n.unsigned_abs();
+                while e > 0 {
+                    if e & 1 == 1 {
+                        acc = SaturatingMul::saturating_mul(&acc, &base);
+                    }
+                    base = SaturatingMul::saturating_mul(&base, &base);
+                    e >>= 1;
+                }
+                if n < 0 {
+                    acc.recip()
+                } else {
+                    acc
+                }
+            }
+
+            /// Return zero for a non-positive base and a non-integer
+            /// exponent.
+            fn powf(self, n: Self) -> Self {
+                if n.fract().is_zero() {
+                    if let Some(n) = n.to_i32() {
+                        return self.powi(n);
+                    }
+                }
+                Self::from_q32(kernel::powf(self.to_q32(), n.to_q32()))
+            }
+
+            /// Return zero for negative numbers.
+            fn sqrt(self) -> Self {
+                Self::from_q32(kernel::sqrt(self.to_q32()))
+            }
+
+            fn exp(self) -> Self {
+                Self::from_q32(kernel::exp(self.to_q32()))
+            }
+
+            fn exp2(self) -> Self {
+                Self::from_q32(kernel::exp2(self.to_q32()))
+            }
+
+            /// Return `MIN` for non-positive numbers.
+            fn ln(self) -> Self {
+                Self::from_q32(kernel::ln(self.to_q32()))
+            }
+
+            fn log(self, base: Self) -> Self {
+                Self::from_q32(kernel::log(self.to_q32(), base.to_q32()))
+            }
+
+            fn log2(self) -> Self {
+                Self::from_q32(kernel::log2(self.to_q32()))
+            }
+
+            fn log10(self) -> Self {
+                Self::from_q32(kernel::log10(self.to_q32()))
+            }
+
+            fn to_degrees(self) -> Self {
+                Self::from_q32(kernel::to_degrees(self.to_q32()))
+            }
+
+            fn to_radians(self) -> Self {
+                Self::from_q32(kernel::to_radians(self.to_q32()))
+            }
+
+            fn max(self, other: Self) -> Self {
+                Ord::max(self, other)
+            }
+
+            fn min(self, other: Self) -> Self {
+                Ord::min(self, other)
+            }
+
+            fn abs_sub(self, other: Self) -> Self {
+                if self <= other {
+                    Self::ZERO
+                } else {
+                    Self(self.0.saturating_sub(other.0))
+                }
+            }
+
+            fn cbrt(self) -> Self {
+                Self::from_q32(kernel::cbrt(self.to_q32()))
+            }
+
+            fn hypot(self, other: Self) -> Self {
+                Self::from_q32(kernel::hypot(self.to_q32(), other.to_q32()))
+            }
+
+            fn sin(self) -> Self {
+                self.sin_cos().0
+            }
+
+            fn cos(self) -> Self {
+                self.sin_cos().1
+            }
+
+            fn tan(self) -> Self {
+                Self::from_q32(kernel::tan(self.to_q32()))
+            }
+
+            /// Clamp the input to `[-1, 1]`.
+            fn asin(self) -> Self {
+                Self::from_q32(kernel::asin(self.to_q32()))
+            }
+
+            /// Clamp the input to `[-1, 1]`.
+            fn acos(self) -> Self {
+                Self::from_q32(kernel::acos(self.to_q32()))
+            }
+
+            fn atan(self) -> Self {
+                Self::from_q32(kernel::atan2(self.to_q32(), 1 << 32))
+            }
+
+            fn atan2(self, other: Self) -> Self {
+                Self::from_q32(kernel::atan2(self.to_q32(), other.to_q32()))
+            }
+
+            fn sin_cos(self) -> (Self, Self) {
+                let (sin, cos) = kernel::sin_cos(self.to_q32());
+                (Self::from_q32(sin), Self::from_q32(cos))
+            }
+
+            fn exp_m1(self) -> Self {
+                Self::from_q32(kernel::exp_m1(self.to_q32()))
+            }
+
+            fn ln_1p(self) -> Self {
+                Self::from_q32(kernel::ln_1p(self.to_q32()))
+            }
+
+            fn sinh(self) -> Self {
+                Self::from_q32(kernel::sinh(self.to_q32()))
+            }
+
+            fn cosh(self) -> Self {
+                Self::from_q32(kernel::cosh(self.to_q32()))
+            }
+
+            fn tanh(self) -> Self {
+                Self::from_q32(kernel::tanh(self.to_q32()))
+            }
+
+            fn asinh(self) -> Self {
+                Self::from_q32(kernel::asinh(self.to_q32()))
+            }
+
+            /// Return zero below one.
+            fn acosh(self) -> Self {
+                Self::from_q32(kernel::acosh(self.to_q32()))
+            }
+
+            /// Saturate at `MIN` and `MAX` outside `(-1, 1)`.
+            fn atanh(self) -> Self {
+                Self::from_q32(kernel::atanh(self.to_q32()))
+            }
+        }
+    };
+}
+
+fixed_impl!(
+    Q16_16,
+    i32,
+    i64,
+    i16,
+    16,
+    "A fixed-point number with 16 integer bits, sign included, and 16 \
+     fractional bits, ranging from -32768 to 32768 by steps of about 1.5e-5."
+);
+fixed_impl!(
+    Q32_32,
+    i64,
+    i128,
+    i32,
+    32,
+    "A fixed-point number with 32 integer bits, sign included, and 32 \
+     fractional bits, ranging from -2147483648 to 2147483648 by steps of \
+     about 2.3e-10."
+);
+
+impl From<Q16_16> for Q32_32 {
+    fn from(x: Q16_16) -> Self {
+        Self(x.to_q32())
+    }
+}
@@ -0,0 +1,698 @@
+//! 16-bit floating point numbers.
+//!
+//! [`f16`] is the IEEE 754 binary16 format, with 5 exponent bits and 10
+//! mantissa bits. [`bf16`] is the "brain floating point" format, which keeps
+//! the 8 exponent bits of `f32` and only 7 mantissa bits. Both are storage
+//! formats for vertex and texture data: conversions round to nearest, ties to
+//! even, and keep subnormals, infinities and NaN.
+//!
+//! Arithmetic is carried out in `f32` and rounded back. `f32` has more than
+//! twice the precision of both formats, so the result of `+`, `-`, `*`, `/`
+//! and `sqrt` is the correctly rounded one.
+//!
+//! ```
+//! use numeric::half::f16;
+//!
+//! let x = f16::from_f32(0.1);
+//! assert_eq!(x.to_bits(), 0x2e66);
+//! assert_eq!(x.to_f32(), 0.099975586);
+//! assert_eq!(f16::from_f32(1e6), f16::INFINITY);
+//! ```
+
+#![allow(non_camel_case_types)]
+
+#[cfg(any(feature = "std", feature = "libm"))]
+use crate::float::Float;
+use crate::float::{FloatConst, FloatCore};
+use crate::{
+    AsPrimitive, Bounded, FromPrimitive, Num, NumCast, One, ParseFloatError, Signed, ToPrimitive,
+    Zero,
+};
+use core::cmp::Ordering;
+use core::f64;
+use core::fmt;
+use core::num::FpCategory;
+use core::ops::{Add, Div, Mul, Neg, Rem, Sub};
+use core::ops::{AddAssign, DivAssign, MulAssign, RemAssign, SubAssign};
+use core::str::FromStr;
+
+/// Round `x` to the nearest value of a 16-bit format with `man` mantissa bits
+/// and `exp` exponent bits, ties to even.
+fn encode(x: f64, man: u32, exp: u32) -> u16 {
+    let bits = x.to_bits();
+    let sign = ((bits >> 48) & 0x8000) as u16;
+    let bias = (1 << (exp - 1)) - 1;
+    let max_exp = (1 << exp) - 1;
+    let e64 = ((bits >> 52) & 0x7ff) as i32;
+    let m64 = bits & ((1 << 52) - 1);
+
+    if e64 == 0x7ff {
+        // Keep the NaN payload that fits, and make sure the result is a NaN.
+        let nan = if m64 != 0 {
+            (1 << (man - 1)) | (m64 >> (52 - man)) as u16
+        } else {
+            0
+        };
+        return sign | ((max_exp as u16) << man) | nan;
+    }
+    // `f64` subnormals are far below the smallest subnormal of both formats.
+    if e64 == 0 {
+        return sign;
+    }
+    let e = e64 - 1023 + bias;
+    if e >= max_exp {
+        return sign | ((max_exp as u16) << man);
+    }
+    let shift = if e > 0 { 52 - man } else { (53 - man as i32 - e) as u32 };
+    if shift > 53 {
+        return sign;
+    }
+
+    let full = m64 | (1 << 52);
+    let mut q = full >> shift;
+    let rest = full & ((1 << shift) - 1);
+    let half = 1 << (shift - 1);
+    if rest > half || (rest == half && q & 1 == 1) {
+        q += 1;
+    }
+    // For normal numbers `q` includes the implicit bit, which bumps the
+    // exponent back; a carry out of the mantissa moves to the next exponent
+    // and eventually to infinity.
+    let magnitude = if e > 0 {
+        (((e - 1) as u64) << man) + q
+    } else {
+        q
+    };
+    sign | magnitude as u16
+}
+
+/// Return the exact `f32` value of a 16-bit format with `man` mantissa bits
+/// and `exp` exponent bits.
+fn decode(h: u16, man: u32, exp: u32) -> f32 {
+    let sign = ((h as u32) & 0x8000) << 16;
+    let bias = (1 << (exp - 1)) - 1;
+    let max_exp = (1 << exp) - 1;
+    let e = ((h as u32) >> man) & max_exp;
+    let m = (h as u32) & ((1 << man) - 1);
+
+    let magnitude = if e == max_exp {
+        0x7f80_0000 | (m << (23 - man))
+    } else if e == 0 {
+        if m == 0 {
+            0
+        } else {
+            // Subnormal: `m * 2^(1 - bias - man)` is exact in `f32`.
+            let k = 1 - bias - man as i32;
+            let scale = if k >= -126 {
+                f32::from_bits(((k + 127) as u32) << 23)
+            } else {
+                f32::from_bits(1 << (k + 149))
+            };
+            (m as f32 * scale).to_bits()
+        }
+    } else {
+        ((e as i32 - bias + 127) as u32) << 23 | (m << (23 - man))
+    };
+    f32::from_bits(sign | magnitude)
+}
+
+macro_rules! half_impl {
+    ($name:ident, $man:expr, $exp:expr, $doc:expr,
+     MIN_POSITIVE = $min_positive:expr, EPSILON = $epsilon:expr, MAX = $max:expr) => {
+        #[doc = $doc]
+        #[derive(Copy, Clone, Default)]
+        #[repr(transparent)]
+        pub struct $name(u16);
+
+        impl $name {
+            /// Number of significant digits in base 2.
+            pub const MANTISSA_DIGITS: u32 = $man + 1;
+            /// Positive infinity.
+            pub const INFINITY: Self = Self((((1 << $exp) - 1) << $man) as u16);
+            /// Negative infinity.
+            pub const NEG_INFINITY: Self = Self(0x8000 | Self::INFINITY.0);
+            /// Not a Number.
+            pub const NAN: Self = Self(Self::INFINITY.0 | (1 << ($man - 1)));
+            /// Zero.
+            pub const ZERO: Self = Self(0);
+            /// Negative zero.
+            pub const NEG_ZERO: Self = Self(0x8000);
+            /// One.
+            pub const ONE: Self = Self(((1 << ($exp - 1)) - 1) << $man);
+            /// Largest finite value.
+            pub const MAX: Self = Self($max);
+            /// Smallest finite value.
+            pub const MIN: Self = Self(0x8000 | $max);
+            /// Smallest positive normal value.
+            pub const MIN_POSITIVE: Self = Self($min_positive);
+            /// Smallest positive subnormal value.
+            pub const MIN_POSITIVE_SUBNORMAL: Self = Self(1);
+            /// Difference between one and the next larger representable value.
+            pub const EPSILON: Self = Self($epsilon);
+
+            /// Create a number from its raw bits.
+            pub const fn from_bits(bits: u16) -> Self {
+                Self(bits)
+            }
+
+            /// Return the raw bits.
+            pub const fn to_bits(self) -> u16 {
+                self.0
+            }
+
+            /// Return `true` if this value is NaN.
+            pub const fn is_nan(self) -> bool {
+                self.0 & 0x7fff > Self::INFINITY.0
+            }
+
+            /// Return `true` if this value is positive or negative infinity.
+            pub const fn is_infinite(self) -> bool {
+                self.0 & 0x7fff == Self::INFINITY.0
+            }
+
+            /// Return `true` if this number is neither infinite nor NaN.
+            pub const fn is_finite(self) -> bool {
+                self.0 & 0x7fff < Self::INFINITY.0
+            }
+
+            /// Return the floating point category of the number.
+            pub const fn classify(self) -> FpCategory {
+                const EXP_MASK: u16 = $name::INFINITY.0;
+                const MAN_MASK: u16 = (1 << $man) - 1;
+                match (self.0 & MAN_MASK, self.0 & EXP_MASK) {
+                    (0, 0) => FpCategory::Zero,
+                    (_, 0) => FpCategory::Subnormal,
+                    (0, EXP_MASK) => FpCategory::Infinite,
+                    (_, EXP_MASK) => FpCategory::Nan,
+                    _ => FpCategory::Normal,
+                }
+            }
+
+            /// Round a `f32` to the nearest value, ties to even.
+            pub fn from_f32(x: f32) -> Self {
+                Self(encode(x as f64, $man, $exp))
+            }
+
+            /// Round a `f64` to the nearest value, ties to even.
+            pub fn from_f64(x: f64) -> Self {
+                Self(encode(x, $man, $exp))
+            }
+
+            /// Convert to `f32`, which is always exact.
+            pub fn to_f32(self) -> f32 {
+                decode(self.0, $man, $exp)
+            }
+
+            /// Convert to `f64`, which is always exact.
+            pub fn to_f64(self) -> f64 {
+                self.to_f32() as f64
+            }
+
+            /// Convert every element of `src` into `dst`.
+            ///
+            /// # Panics
+            ///
+            /// Panics if the slices have different lengths.
+            pub fn from_f32_slice(src: &[f32], dst: &mut [Self]) {
+                assert_eq!(src.len(), dst.len(), "slices have different lengths");
+                for (d, &s) in dst.iter_mut().zip(src) {
+                    *d = Self::from_f32(s);
+                }
+            }
+
+            /// Convert every element of `src` into `dst`.
+            ///
+            /// # Panics
+            ///
+            /// Panics if the slices have different lengths.
+            pub fn to_f32_slice(src: &[Self], dst: &mut [f32]) {
+                assert_eq!(src.len(), dst.len(), "slices have different lengths");
+                for (d, s) in dst.iter_mut().zip(src) {
+                    *d = $name::to_f32(*s);
+                }
+            }
+        }
+
+        impl PartialEq for $name {
+            fn eq(&self, other: &Self) -> bool {
+                $name::to_f32(*self) == $name::to_f32(*other)
+            }
+        }
+
+        impl PartialOrd for $name {
+            fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+                $name::to_f32(*self).partial_cmp(&$name::to_f32(*other))
+            }
+        }
+
+        impl fmt::Debug for $name {
+            fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                fmt::Debug::fmt(&$name::to_f32(*self), f)
+            }
+        }
+
+        impl fmt::Display for $name {
+            fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                fmt::Display::fmt(&$name::to_f32(*self), f)
+            }
+        }
+
+        impl fmt::LowerExp for $name {
+            fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                fmt::LowerExp::fmt(&$name::to_f32(*self), f)
+            }
+        }
+
+        impl FromStr for $name {
+            type Err = ParseFloatError;
+
+            fn from_str(src: &str) -> Result<Self, ParseFloatError> {
+                Self::from_str_radix(src, 10)
+            }
+        }
+
+        impl From<$name> for f32 {
+            fn from(x: $name) -> f32 {
+                x.to_f32()
+            }
+        }
+
+        impl From<$name> for f64 {
+            fn from(x: $name) -> f64 {
+                x.to_f64()
+            }
+        }
+
+        // --- Operators ---
+
+        impl Add for $name {
+            type Output = Self;
+
+            fn add(self, rhs: Self) -> Self {
+                Self::from_f32(self.to_f32() + rhs.to_f32())
+            }
+        }
+
+        impl Sub for $name {
+            type Output = Self;
+
+            fn sub(self, rhs: Self) -> Self {
+                Self::from_f32(self.to_f32() - rhs.to_f32())
+            }
+        }
+
+        impl Mul for $name {
+            type Output = Self;
+
+            fn mul(self, rhs: Self) -> Self {
+                Self::from_f32(self.to_f32() * rhs.to_f32())
+            }
+        }
+
+        impl Div for $name {
+            type Output = Self;
+
+            fn div(self, rhs: Self) -> Self {
+                Self::from_f32(self.to_f32() / rhs.to_f32())
+            }
+        }
+
+        impl Rem for $name {
+            type Output = Self;
+
+            fn rem(self, rhs: Self) -> Self {
+                Self::from_f32(self.to_f32() % rhs.to_f32())
+            }
+        }
+
+        impl Neg for $name {
+            type Output = Self;
+
+            fn neg(self) -> Self {
+                Self(self.0 ^ 0x8000)
+            }
+        }
+
+        impl AddAssign for $name {
+            fn add_assign(&mut self, rhs: Self) {
+                *self = *self + rhs;
+            }
+        }
+
+        impl SubAssign for $name {
+            fn sub_assign(&mut self, rhs: Self) {
+                *self = *self - rhs;
+            }
+        }
+
+        impl MulAssign for $name {
+            fn mul_assign(&mut self, rhs: Self) {
+                *self = *self * rhs;
+            }
+        }
+
+        impl DivAssign for $name {
+            fn div_assign(&mut self, rhs: Self) {
+                *self = *self / rhs;
+            }
+        }
+
+        impl RemAssign for $name {
+            fn rem_assign(&mut self, rhs: Self) {
+                *self = *self % rhs;
+            }
+        }
+
+        // --- Numeric traits ---
+
+        impl Zero for $name {
+            fn zero() -> Self {
+                Self::ZERO
+            }
+
+            fn is_zero(&self) -> bool {
+                self.0 & 0x7fff == 0
+            }
+        }
+
+        impl One for $name {
+            fn one() -> Self {
+                Self::ONE
+            }
+        }
+
+        impl Num for $name {
+            type FromStrRadixErr = ParseFloatError;
+
+            fn from_str_radix(src: &str, radix: u32) -> Result<Self, ParseFloatError> {
+                f64::from_str_radix(src, radix).map(Self::from_f64)
+            }
+        }
+
+        impl Bounded for $name {
+            fn min_value() -> Self {
+                Self::MIN
+            }
+
+            fn max_value() -> Self {
+                Self::MAX
+            }
+        }
+
+        impl Signed for $name {
+            fn abs(&self) -> Self {
+                FloatCore::abs(*self)
+            }
+
+            fn abs_sub(&self, other: &Self) -> Self {
+                if *self <= *other {
+                    Self::ZERO
+                } else {
+                    *self - *other
+                }
+            }
+
+            fn signum(&self) -> Self {
+                FloatCore::signum(*self)
+            }
+
+            fn is_positive(&self) -> bool {
+                FloatCore::is_sign_positive(*self)
+            }
+
+            fn is_negative(&self) -> bool {
+                FloatCore::is_sign_negative(*self)
+            }
+        }
+
+        impl ToPrimitive for $name {
+            fn to_i64(&self) -> Option<i64> {
+                $name::to_f32(*self).to_i64()
+            }
+
+            fn to_u64(&self) -> Option<u64> {
+                $name::to_f32(*self).to_u64()
+            }
+
+            fn to_f32(&self) -> Option<f32> {
+                Some($name::to_f32(*self))
+            }
+
+            fn to_f64(&self) -> Option<f64> {
+                Some($name::to_f64(*self))
+            }
+        }
+
+        impl FromPrimitive for $name {
+            fn from_i64(n: i64) -> Option<Self> {
+                Some(Self::from_f64(n as f64))
+            }
+
+            fn from_u64(n: u64) -> Option<Self> {
+                Some(Self::from_f64(n as f64))
+            }
+
+            fn from_f32(n: f32) -> Option<Self> {
+                Some($name::from_f32(n))
+            }
+
+            fn from_f64(n: f64) -> Option<Self> {
+                Some($name::from_f64(n))
+            }
+        }
+
+        impl NumCast for $name {
+            fn from<T: ToPrimitive>(n: T) -> Option<Self> {
+                n.to_f64().map(Self::from_f64)
+            }
+        }
+
+        half_as_primitive!($name => { u8, u16, u32, u64, usize, i8, i16, i32, i64, isize, f32, f64 });
+
+        impl FloatConst for $name {
+            constant! {
+                E() -> Self::from_f64(f64::consts::E);
+                FRAC_1_PI() -> Self::from_f64(f64::consts::FRAC_1_PI);
+                FRAC_1_SQRT_2() -> Self::from_f64(f64::consts::FRAC_1_SQRT_2);
+                FRAC_2_PI() -> Self::from_f64(f64::consts::FRAC_2_PI);
+                FRAC_2_SQRT_PI() -> Self::from_f64(f64::consts::FRAC_2_SQRT_PI);
+                FRAC_PI_2() -> Self::from_f64(f64::consts::FRAC_PI_2);
+                FRAC_PI_3() -> Self::from_f64(f64::consts::FRAC_PI_3);
+                FRAC_PI_4() -> Self::from_f64(f64::consts::FRAC_PI_4);
+                FRAC_PI_6() -> Self::from_f64(f64::consts::FRAC_PI_6);
+                FRAC_PI_8() -> Self::from_f64(f64::consts::FRAC_PI_8);
+                LN_10() -> Self::from_f64(f64::consts::LN_10);
+                LN_2() -> Self::from_f64(f64::consts::LN_2);
+                LOG10_E() -> Self::from_f64(f64::consts::LOG10_E);
+                LOG2_E() -> Self::from_f64(f64::consts::LOG2_E);
+                PI() -> Self::from_f64(f64::consts::PI);
+                SQRT_2() -> Self::from_f64(f64::consts::SQRT_2);
+                TAU() -> Self::from_f64(f64::consts::TAU);
+                LOG10_2() -> Self::from_f64(f64::consts::LOG10_2);
+                LOG2_10() -> Self::from_f64(f64::consts::LOG2_10);
+            }
+        }
+
+        impl FloatCore for $name {
+            constant! {
+                infinity() -> Self::INFINITY;
+                neg_infinity() -> Self::NEG_INFINITY;
+                nan() -> Self::NAN;
+                neg_zero() -> Self::NEG_ZERO;
+                min_value() -> Self::MIN;
+                min_positive_value() -> Self::MIN_POSITIVE;
+                epsilon() -> Self::EPSILON;
+                max_value() -> Self::MAX;
+            }
+
+            forward! {
+                Self::is_nan(self) -> bool;
+                Self::is_infinite(self) -> bool;
+                Self::is_finite(self) -> bool;
+                Self::classify(self) -> FpCategory;
+            }
+
+            fn is_sign_negative(self) -> bool {
+                self.0 & 0x8000 != 0
+            }
+
+            fn abs(self) -> Self {
+                Self(self.0 & 0x7fff)
+            }
+
+            fn to_degrees(self) -> Self {
+                Self::from_f32(FloatCore::to_degrees(self.to_f32()))
+            }
+
+            fn to_radians(self) -> Self {
+                Self::from_f32(FloatCore::to_radians(self.to_f32()))
+            }
+
+            fn integer_decode(self) -> (u64, i16, i8) {
+                let sign: i8 = if self.0 >> 15 == 0 { 1 } else { -1 };
+                let mut exponent = ((self.0 >> $man) & ((1 << $exp) - 1)) as i16;
+                let mantissa = if exponent == 0 {
+                    (self.0 & ((1 << $man) - 1)) << 1
+                } else {
+                    (self.0 & ((1 << $man) - 1)) | (1 << $man)
+                };
+                // Exponent bias + mantissa shift
+                exponent -= (1 << ($exp - 1)) - 1 + $man;
+                (mantissa as u64, exponent, sign)
+            }
+        }
+
+        #[cfg(any(feature = "std", feature = "libm"))]
+        impl Float for $name {
+            constant! {
+                nan() -> Self::NAN;
+                infinity() -> Self::INFINITY;
+                neg_infinity() -> Self::NEG_INFINITY;
+                neg_zero() -> Self::NEG_ZERO;
+                min_value() -> Self::MIN;
+                min_positive_value() -> Self::MIN_POSITIVE;
+                epsilon() -> Self::EPSILON;
+                max_value() -> Self::MAX;
+            }
+
+            forward! {
+                FloatCore::is_nan(self) -> bool;
+                FloatCore::is_infinite(self) -> bool;
+                FloatCore::is_finite(self) -> bool;
+                FloatCore::is_normal(self) -> bool;
+                FloatCore::classify(self) -> FpCategory;
+                FloatCore::abs(self) -> Self;
+                FloatCore::signum(self) -> Self;
+                FloatCore::is_sign_positive(self) -> bool;
+                FloatCore::is_sign_negative(self) -> bool;
+                FloatCore::to_degrees(self) -> Self;
+                FloatCore::to_radians(self) -> Self;
+                FloatCore::integer_decode(self) -> (u64, i16, i8);
+            }
+
+            via_f32! {
+                floor(self);
+                ceil(self);
+                round(self);
+                trunc(self);
+                fract(self);
+                mul_add(self, a, b);
+                recip(self);
+                powf(self, n);
+                sqrt(self);
+                exp(self);
+                exp2(self);
+                ln(self);
+                log(self, base);
+                log2(self);
+                log10(self);
+                max(self, other);
+                min(self, other);
+                abs_sub(self, other);
+                cbrt(self);
+                hypot(self, other);
+                sin(self);
+                cos(self);
+                tan(self);
+                asin(self);
+                acos(self);
+                atan(self);
+                atan2(self, other);
+                exp_m1(self);
+                ln_1p(self);
+                sinh(self);
+                cosh(self);
+                tanh(self);
+                asinh(self);
+                acosh(self);
+                atanh(self);
+            }
+
+            fn powi(self, n: i32) -> Self {
+                Self::from_f32(Float::powi(self.to_f32(), n))
+            }
+
+            fn sin_cos(self) -> (Self, Self) {
+                let (sin, cos) = Float::sin_cos(self.to_f32());
+                (Self::from_f32(sin), Self::from_f32(cos))
+            }
+        }
+    };
+}
+
+/// Implement `Float` methods by computing in `f32` and rounding the result.
+macro_rules! via_f32 {
+    ($( $method:ident ( self $( , $arg:ident )* ); )*) => {$(
+        #[allow(deprecated)]
+        fn $method(self $( , $arg: Self )*) -> Self {
+            Self::from_f32(Float::$method(self.to_f32() $( , $arg.to_f32() )*))
+        }
+    )*};
+}
+
+macro_rules! half_as_primitive {
+    ($name:ident => { $( $T:ty ),* }) => {$(
+        impl AsPrimitive<$T> for $name {
+            #[inline]
+            fn as_(self) -> $T {
+                self.to_f32() as $T
+            }
+        }
+
+        impl AsPrimitive<$name> for $T {
+            #[inline]
+            fn as_(self) -> $name {
+                $name::from_f64(self as f64)
+            }
+        }
+    )*};
+}
+
+half_impl!(
+    f16,
+    10,
+    5,
+    "The IEEE 754 half-precision floating point format, with a range of \
+     ±65504 and about 3 significant decimal digits.",
+    MIN_POSITIVE = 0x0400,
+    EPSILON = 0x1400,
+    MAX = 0x7bff
+);
+half_impl!(
+    bf16,
+    7,
+    8,
+    "The bfloat16 floating point format: the top half of a `f32`, with the \
+     same range and about 2 significant decimal digits.",
+    MIN_POSITIVE = 0x0080,
+    EPSILON = 0x3c00,
+    MAX = 0x7f7f
+);
+
+impl AsPrimitive<f16> for f16 {
+    #[inline]
+    fn as_(self) -> f16 {
+        self
+    }
+}
+
+impl AsPrimitive<bf16> for bf16 {
+    #[inline]
+    fn as_(self) -> bf16 {
+        self
+    }
+}
+
+impl AsPrimitive<bf16> for f16 {
+    #[inline]
+    fn as_(self) -> bf16 {
+        bf16::from_f32(self.to_f32())
+    }
+}
+
+impl AsPrimitive<f16> for bf16 {
+    #[inline]
+    fn as_(self) -> f16 {
+        f16::from_f32(self.to_f32())
+    }
+}
@@ -0,0 +1,976 @@
+//! Interval arithmetic.
+//!
+//! An [`Interval`] is a closed range `[lo, hi]` of floating point numbers.
+//! Every operation returns an interval that contains all the results of the
+//! operation applied to members of its operands, so a computation carried out
+//! on intervals bounds the exact result despite rounding errors.
+//!
+//! The basic operations and `sqrt` round their bounds outwards exactly, using
+//! the error terms recovered with `mul_add`. Other functions widen the bounds
+//! returned by `F` by one or two units in the last place, which assumes that
+//! they are accurate to within one unit, as the standard library's are on
+//! common platforms.
+//!
+//! The empty interval, whose bounds are NaN, is the result of operations which
+//! have none, like the square root of a negative interval or a division by
+//! `[0, 0]`, and it propagates through every operation. Like NaN, it is not
+//! equal to itself, so test it with [`Interval::is_empty`].
+//!
+//! ```
+//! use numeric::interval::Interval;
+//!
+//! let tenth = "0.1".parse::<Interval<f64>>().unwrap();
+//! let sum = (0..10).map(|_| tenth).sum::<Interval<f64>>();
+//! assert!(sum.contains(1.0));
+//! assert!(sum.width() < 1e-14);
+//! ```
+
+use core::cmp::Ordering;
+use core::fmt;
+use core::iter::{Product, Sum};
+use core::num::FpCategory;
+use core::ops::{Add, Div, Mul, Neg, Rem, Sub};
+use core::ops::{AddAssign, DivAssign, MulAssign, RemAssign, SubAssign};
+use core::str::FromStr;
+
+use crate::{Float, Num, NumCast, One, Signed, ToPrimitive, Zero};
+
+/// A closed interval `[lo, hi]`.
+///
+/// Intervals are compared with `==` as sets. `a < b` holds when every member
+/// of `a` is smaller than every member of `b`, and overlapping intervals are
+/// unordered.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Interval<F: Float> {
+    /// Lower bound.
+    pub lo: F,
+    /// Upper bound.
+    pub hi: F,
+}
+
+// --- Directed rounding ---
+
+/// Return a number smaller than `x` by at least one unit in the last place.
+fn next_down<F: Float>(x: F) -> F {
+    if x.is_nan() || x == F::neg_infinity() {
+        x
+    } else if x == F::infinity() {
+        F::max_value()
+    } else {
+        // The smallest subnormal number is one unit in the last place
+        // below the normal range.
+        let tiny = F::min_positive_value() * F::epsilon();
+        x - (x.abs() * F::epsilon()).max(tiny)
+    }
+}
+
+/// Return a number greater than `x` by at least one unit in the last place.
+fn next_up<F: Float>(x: F) -> F {
+    -next_down(-x)
+}
+
+/// Round the result `v` of an operation on finite operands outwards, given
+/// the sign of the rounding error `err = exact - v`.
+fn round<F: Float>(v: F, err: F, up: bool) -> F {
+    if v.is_infinite() {
+        // Overflow
+        match (up, v > F::zero()) {
+            (true, false) => -F::max_value(),
+            (false, true) => F::max_value(),
+            _ => v,
+        }
+    } else if up && err > F::zero() {
+        next_up(v)
+    } else if !up && err < F::zero() {
+        next_down(v)
+    } else {
+        v
+    }
+}
+
+fn add_round<F: Float>(a: F, b: F, up: bool) -> F {
+    let s = a + b;
+    if !a.is_finite() || !b.is_finite() {
+        return s;
+    }
+    // Knuth's two-sum
+    let bb = s - a;
+    let err = (a - (s - bb)) + (b - bb);
+    round(s, err, up)
+}
+
+fn mul_round<F: Float>(a: F, b: F, up: bool) -> F {
+    if a.is_zero() || b.is_zero() {
+        // Including 0 × ∞, as the bound of an unbounded interval.
+        return F::zero();
+    }
+    let p = a * b;
+    if !a.is_finite() || !b.is_finite() {
+        return p;
+    }
+    round(p, a.mul_add(b, -p), up)
+}
+
+fn div_round<F: Float>(a: F, b: F, up: bool) -> F {
+    let q = a / b;
+    if !a.is_finite() || !b.is_finite() || (q.is_zero() && a.is_zero()) {
+        return q;
+    }
+    let r = (-q).mul_add(b, a);
+    round(q, if b > F::zero() { r } else { -r }, up)
+}
+
+fn sqrt_round<F: Float>(x: F, up: bool) -> F {
+    let s = x.sqrt();
+    if !s.is_finite() {
+        return s;
+    }
+    round(s, (-s).mul_add(s, x), up)
+}
+
+/// Return whether `[lo, hi]` may contain a point `offset + k period` for an
+/// integer `k`, erring on the side of yes.
+fn may_contain_multiple<F: Float>(lo: F, hi: F, offset: F, period: F) -> bool {
+    let slack = |x: F| F::from(8.0).unwrap() * F::epsilon() * (x.abs() + F::one());
+    let a = (lo - offset) / period;
+    let b = (hi - offset) / period;
+    (a - slack(a)).ceil() <= (b + slack(b)).floor()
+}
+
+fn pi<F: Float>() -> F {
+    F::from(core::f64::consts::PI).unwrap()
+}
+
+impl<F: Float> Interval<F> {
+    /// Create a new interval, or the empty interval if a bound is NaN.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `lo > hi`.
+    pub fn new(lo: F, hi: F) -> Self {
+        if lo.is_nan() || hi.is_nan() {
+            return Self::empty();
+        }
+        assert!(lo <= hi, "the lower bound is greater than the upper bound");
+        Self { lo, hi }
+    }
+
+    /// Return the empty interval, whose bounds are NaN.
+    pub fn empty() -> Self {
+        Self::point(F::nan())
+    }
+
+    /// Return whether the interval is empty.
+    pub fn is_empty(self) -> bool {
+        self.lo.is_nan() || self.hi.is_nan()
+    }
+
+    /// Create an interval containing a single number.
+    pub fn point(x: F) -> Self {
+        Self { lo: x, hi: x }
+    }
+
+    /// Return the interval containing every number, `[-∞, ∞]`.
+    pub fn entire() -> Self {
+        Self::new(F::neg_infinity(), F::infinity())
+    }
+
+    /// Return the midpoint of the interval.
+    pub fn mid(self) -> F {
+        if self.lo == F::neg_infinity() && self.hi == F::infinity() {
+            return F::zero();
+        }
+        let half = F::from(0.5).unwrap();
+        self.lo * half + self.hi * half
+    }
+
+    /// Return an upper bound of the width of the interval.
+    pub fn width(self) -> F {
+        add_round(self.hi, -self.lo, true)
+    }
+
+    /// Return the largest absolute value in the interval.
+    pub fn mag(self) -> F {
+        self.lo.abs().max(self.hi.abs())
+    }
+
+    /// Return whether the interval contains a single number.
+    pub fn is_point(self) -> bool {
+        self.lo == self.hi
+    }
+
+    /// Return whether `x` belongs to the interval.
+    pub fn contains(self, x: F) -> bool {
+        self.lo <= x && x <= self.hi
+    }
+
+    /// Return whether every member of `self` belongs to `other`.
+    pub fn is_subset(self, other: Self) -> bool {
+        other.lo <= self.lo && self.hi <= other.hi
+    }
+
+    /// Return whether the intervals have a member in common.
+    pub fn overlaps(self, other: Self) -> bool {
+        self.lo <= other.hi && other.lo <= self.hi
+    }
+
+    /// Return the members common to both intervals, if any.
+    pub fn intersection(self, other: Self) -> Option<Self> {
+        if self.overlaps(other) {
+            Some(Self::new(self.lo.max(other.lo), self.hi.min(other.hi)))
+        } else {
+            None
+        }
+    }
+
+    /// Return the smallest interval containing both intervals.
+    pub fn hull(self, other: Self) -> Self {
+        Self::new(self.lo.min(other.lo), self.hi.max(other.hi))
+    }
+
+    /// Return an interval around `x` widened by one unit in the last place.
+    fn widened(x: F) -> Self {
+        Self::new(next_down(x), next_up(x))
+    }
+
+    /// Return the image of the interval by a non-decreasing function,
+    /// widened for rounding errors.
+    fn increasing(self, f: impl Fn(F) -> F) -> Self {
+        Self::new(next_down(f(self.lo)), next_up(f(self.hi)))
+    }
+
+    /// Return the image of the interval by a non-increasing function,
+    /// widened for rounding errors.
+    fn decreasing(self, f: impl Fn(F) -> F) -> Self {
+        Self::new(next_down(f(self.hi)), next_up(f(self.lo)))
+    }
+
+    /// Restrict the interval to the domain `[min, max]` of a function, or
+    /// return the empty interval if it lies outside.
+    fn restrict(self, min: F, max: F) -> Self {
+        self.intersection(Self::new(min, max))
+            .unwrap_or_else(Self::nan)
+    }
+
+    /// Return the image of the interval by a function of period 2π with a
+    /// maximum of one at `max` and a minimum of minus one at `max + π`.
+    fn periodic(self, f: impl Fn(F) -> F, max: F) -> Self {
+        if self.is_nan() {
+            return Self::nan();
+        }
+        if !self.is_finite() {
+            return Self::new(-F::one(), F::one());
+        }
+        let (a, b) = (f(self.lo), f(self.hi));
+        let mut lo = next_down(a.min(b));
+        let mut hi = next_up(a.max(b));
+        let period = pi::<F>() + pi::<F>();
+        if may_contain_multiple(self.lo, self.hi, max, period) {
+            hi = F::one();
+        }
+        if may_contain_multiple(self.lo, self.hi, max + pi(), period) {
+            lo = -F::one();
+        }
+        Self::new(lo, hi).restrict(-F::one(), F::one())
+    }
+
+    /// Return `x^n` for a positive integer `n` by squaring.
+    fn powu(mut self, mut n: u32) -> Self {
+        let mut acc = Self::one();
+        while n > 0 {
+            if n & 1 == 1 {
+                acc *= self;
+            }
+            self *= self;
+            n >>= 1;
+        }
+        acc
+    }
+}
+
+impl<F: Float> From<F> for Interval<F> {
+    fn from(x: F) -> Self {
+        Self::point(x)
+    }
+}
+
+impl<F: Float> From<(F, F)> for Interval<F> {
+    fn from((lo, hi): (F, F)) -> Self {
+        Self::new(lo, hi)
+    }
+}
+
+impl<F: Float> PartialOrd for Interval<F> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        if self == other {
+            Some(Ordering::Equal)
+        } else if self.hi < other.lo {
+            Some(Ordering::Less)
+        } else if self.lo > other.hi {
+            Some(Ordering::Greater)
+        } else {
+            None
+        }
+    }
+}
+
+impl<F: Float + fmt::Display> fmt::Display for Interval<F> {
+    /// Format as `[lo, hi]`, which `FromStr` parses back. The precision, if
+    /// any, applies to both bounds, which are then rounded to nearest.
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match f.precision() {
+            Some(p) => write!(f, "[{:.*}, {:.*}]", p, self.lo, p, self.hi),
+            None => write!(f, "[{}, {}]", self.lo, self.hi),
+        }
+    }
+}
+
+// --- Operators ---
+
+/// Implement the reference variants of a binary operator from the owned one,
+/// both with an interval and with a scalar right-hand side.
+macro_rules! forward_ref_binop {
+    ($imp:ident, $method:ident) => {
+        impl<'a, F: Float> $imp<&'a Interval<F>> for Interval<F> {
+            type Output = Interval<F>;
+
+            fn $method(self, other: &Interval<F>) -> Interval<F> {
+                self.$method(*other)
+            }
+        }
+
+        impl<'a, F: Float> $imp<Interval<F>> for &'a Interval<F> {
+            type Output = Interval<F>;
+
+            fn $method(self, other: Interval<F>) -> Interval<F> {
+                (*self).$method(other)
+            }
+        }
+
+        impl<'a, 'b, F: Float> $imp<&'b Interval<F>> for &'a Interval<F> {
+            type Output = Interval<F>;
+
+            fn $method(self, other: &Interval<F>) -> Interval<F> {
+                (*self).$method(*other)
+            }
+        }
+
+        impl<F: Float> $imp<F> for Interval<F> {
+            type Output = Interval<F>;
+
+            fn $method(self, other: F) -> Interval<F> {
+                self.$method(Interval::point(other))
+            }
+        }
+
+        impl<'a, F: Float> $imp<F> for &'a Interval<F> {
+            type Output = Interval<F>;
+
+            fn $method(self, other: F) -> Interval<F> {
+                (*self).$method(Interval::point(other))
+            }
+        }
+    };
+}
+
+/// Implement an assignment operator from the binary one.
+macro_rules! assign_op {
+    ($imp:ident, $method:ident, $op:ident) => {
+        impl<F: Float> $imp for Interval<F> {
+            fn $method(&mut self, other: Self) {
+                *self = (*self).$op(other);
+            }
+        }
+
+        impl<F: Float> $imp<F> for Interval<F> {
+            fn $method(&mut self, other: F) {
+                *self = (*self).$op(other);
+            }
+        }
+
+        impl<'a, F: Float> $imp<&'a Interval<F>> for Interval<F> {
+            fn $method(&mut self, other: &Self) {
+                *self = (*self).$op(*other);
+            }
+        }
+    };
+}
+
+impl<F: Float> Add for Interval<F> {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        Self::new(
+            add_round(self.lo, other.lo, false),
+            add_round(self.hi, other.hi, true),
+        )
+    }
+}
+
+impl<F: Float> Sub for Interval<F> {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self {
+        self + -other
+    }
+}
+
+impl<F: Float> Mul for Interval<F> {
+    type Output = Self;
+
+    fn mul(self, other: Self) -> Self {
+        if self.is_nan() || other.is_nan() {
+            return Self::nan();
+        }
+        let corners = [
+            (self.lo, other.lo),
+            (self.lo, other.hi),
+            (self.hi, other.lo),
+            (self.hi, other.hi),
+        ];
+        let lo = corners
+            .iter()
+            .map(|&(a, b)| mul_round(a, b, false))
+            .fold(F::infinity(), F::min);
+        let hi = corners
+            .iter()
+            .map(|&(a, b)| mul_round(a, b, true))
+            .fold(F::neg_infinity(), F::max);
+        Self::new(lo, hi)
+    }
+}
+
+impl<F: Float> Div for Interval<F> {
+    type Output = Self;
+
+    /// Divide the intervals. Dividing by an interval containing zero gives
+    /// the entire line, and dividing by `[0, 0]` gives the empty interval,
+    /// since no quotient exists.
+    fn div(self, other: Self) -> Self {
+        if self.is_nan() || other.is_nan() || other.is_zero() {
+            return Self::nan();
+        }
+        if other.contains(F::zero()) {
+            return Self::entire();
+        }
+        let corners = [
+            (self.lo, other.lo),
+            (self.lo, other.hi),
+            (self.hi, other.lo),
+            (self.hi, other.hi),
+        ];
+        let lo = corners
+            .iter()
+            .map(|&(a, b)| div_round(a, b, false))
+            .fold(F::infinity(), F::min);
+        let hi = corners
+            .iter()
+            .map(|&(a, b)| div_round(a, b, true))
+            .fold(F::neg_infinity(), F::max);
+        Self::new(lo, hi)
+    }
+}
+
+impl<F: Float> Rem for Interval<F> {
+    type Output = Self;
+
+    /// Bound the remainder of the truncated division, which has the sign of
+    /// the dividend and is smaller than the divisor in magnitude.
+    fn rem(self, other: Self) -> Self {
+        if self.is_nan() || other.contains(F::zero()) {
+            return Self::nan();
+        }
+        if self.is_point() && other.is_point() {
+            // The remainder of floating point numbers is exact.
+            return Self::point(self.lo % other.lo);
+        }
+        let m = other.mag();
+        Self::new(
+            self.lo.min(F::zero()).max(-m),
+            self.hi.max(F::zero()).min(m),
+        )
+    }
+}
+
+impl<F: Float> Neg for Interval<F> {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Self::new(-self.hi, -self.lo)
+    }
+}
+
+impl<F: Float> Neg for &Interval<F> {
+    type Output = Interval<F>;
+
+    fn neg(self) -> Interval<F> {
+        -*self
+    }
+}
+
+forward_ref_binop!(Add, add);
+forward_ref_binop!(Sub, sub);
+forward_ref_binop!(Mul, mul);
+forward_ref_binop!(Div, div);
+forward_ref_binop!(Rem, rem);
+
+assign_op!(AddAssign, add_assign, add);
+assign_op!(SubAssign, sub_assign, sub);
+assign_op!(MulAssign, mul_assign, mul);
+assign_op!(DivAssign, div_assign, div);
+assign_op!(RemAssign, rem_assign, rem);
+
+impl<F: Float> Sum for Interval<F> {
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Self::zero(), |acc, x| acc + x)
+    }
+}
+
+impl<'a, F: Float> Sum<&'a Interval<F>> for Interval<F> {
+    fn sum<I: Iterator<Item = &'a Self>>(iter: I) -> Self {
+        iter.fold(Self::zero(), |acc, x| acc + x)
+    }
+}
+
+impl<F: Float> Product for Interval<F> {
+    fn product<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Self::one(), |acc, x| acc * x)
+    }
+}
+
+impl<'a, F: Float> Product<&'a Interval<F>> for Interval<F> {
+    fn product<I: Iterator<Item = &'a Self>>(iter: I) -> Self {
+        iter.fold(Self::one(), |acc, x| acc * x)
+    }
+}
+
+// --- Numeric traits ---
+
+impl<F: Float> Zero for Interval<F> {
+    fn zero() -> Self {
+        Self::point(F::zero())
+    }
+
+    fn is_zero(&self) -> bool {
+        self.lo.is_zero() && self.hi.is_zero()
+    }
+}
+
+impl<F: Float> One for Interval<F> {
+    fn one() -> Self {
+        Self::point(F::one())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum IntervalErrorKind<E> {
+    Empty,
+    Invalid,
+    /// One of the bounds could not be parsed.
+    Bound(E),
+}
+
+/// An error which can be returned when parsing an interval.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseIntervalError<E> {
+    pub kind: IntervalErrorKind<E>,
+}
+
+impl<E: fmt::Display> fmt::Display for ParseIntervalError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self.kind {
+            IntervalErrorKind::Empty => "cannot parse interval from empty string".fmt(f),
+            IntervalErrorKind::Invalid => "invalid interval literal".fmt(f),
+            IntervalErrorKind::Bound(ref e) => e.fmt(f),
+        }
+    }
+}
+
+impl<F: Float> Num for Interval<F> {
+    type FromStrRadixErr = ParseIntervalError<F::FromStrRadixErr>;
+
+    /// Parse `[lo, hi]` or a single number `x`, where the numbers are parsed
+    /// with `F::from_str_radix`. As the numbers written may not be
+    /// representable, the bounds are widened by one unit in the last place.
+    fn from_str_radix(src: &str, radix: u32) -> Result<Self, Self::FromStrRadixErr> {
+        use self::IntervalErrorKind::*;
+
+        let parse = |s: &str| {
+            F::from_str_radix(s.trim(), radix).map_err(|e| ParseIntervalError { kind: Bound(e) })
+        };
+        let src = src.trim();
+        if src.is_empty() {
+            return Err(ParseIntervalError { kind: Empty });
+        }
+        let (lo, hi) = match src.strip_prefix('[') {
+            Some(rest) => {
+                let rest = rest
+                    .strip_suffix(']')
+                    .ok_or(ParseIntervalError { kind: Invalid })?;
+                let mut bounds = rest.split(',');
+                match (bounds.next(), bounds.next(), bounds.next()) {
+                    (Some(lo), Some(hi), None) => (parse(lo)?, parse(hi)?),
+                    _ => return Err(ParseIntervalError { kind: Invalid }),
+                }
+            }
+            None => {
+                let x = parse(src)?;
+                (x, x)
+            }
+        };
+        if lo > hi {
+            return Err(ParseIntervalError { kind: Invalid });
+        }
+        Ok(Self::new(next_down(lo), next_up(hi)))
+    }
+}
+
+impl<F: Float> FromStr for Interval<F> {
+    type Err = ParseIntervalError<F::FromStrRadixErr>;
+
+    fn from_str(src: &str) -> Result<Self, Self::Err> {
+        Self::from_str_radix(src, 10)
+    }
+}
+
+impl<F: Float> Signed for Interval<F> {
+    fn abs(&self) -> Self {
+        Float::abs(*self)
+    }
+
+    fn abs_sub(&self, other: &Self) -> Self {
+        Float::abs_sub(*self, *other)
+    }
+
+    fn signum(&self) -> Self {
+        Float::signum(*self)
+    }
+
+    fn is_positive(&self) -> bool {
+        self.lo > F::zero()
+    }
+
+    fn is_negative(&self) -> bool {
+        self.hi < F::zero()
+    }
+}
+
+impl<F: Float> ToPrimitive for Interval<F> {
+    fn to_i64(&self) -> Option<i64> {
+        self.mid().to_i64()
+    }
+
+    fn to_u64(&self) -> Option<u64> {
+        self.mid().to_u64()
+    }
+
+    fn to_f64(&self) -> Option<f64> {
+        self.mid().to_f64()
+    }
+}
+
+impl<F: Float> NumCast for Interval<F> {
+    /// Convert a number to a point, or to an interval around it if `F`
+    /// cannot represent it.
+    fn from<T: ToPrimitive>(n: T) -> Option<Self> {
+        let (i, f) = (n.to_i64(), n.to_f64());
+        let x = <F as NumCast>::from(n)?;
+        let exact = match i {
+            Some(i) => x.to_i64() == Some(i) && x.fract().is_zero(),
+            None => x.to_f64() == f,
+        };
+        Some(if exact || x.is_nan() {
+            Self::point(x)
+        } else {
+            Self::widened(x)
+        })
+    }
+}
+
+impl<F: Float> Float for Interval<F> {
+    constant! {
+        nan() -> Self::point(F::nan());
+        infinity() -> Self::point(F::infinity());
+        neg_infinity() -> Self::point(F::neg_infinity());
+        neg_zero() -> Self::point(F::neg_zero());
+        min_value() -> Self::point(F::min_value());
+        min_positive_value() -> Self::point(F::min_positive_value());
+        epsilon() -> Self::point(F::epsilon());
+        max_value() -> Self::point(F::max_value());
+    }
+
+    fn is_nan(self) -> bool {
+        self.lo.is_nan() || self.hi.is_nan()
+    }
+
+    fn is_infinite(self) -> bool {
+        !self.is_nan() && (self.lo.is_infinite() || self.hi.is_infinite())
+    }
+
+    fn is_finite(self) -> bool {
+        self.lo.is_finite() && self.hi.is_finite()
+    }
+
+    fn is_normal(self) -> bool {
+        self.classify() == FpCategory::Normal
+    }
+
+    fn classify(self) -> FpCategory {
+        if self.is_nan() {
+            FpCategory::Nan
+        } else if self.is_infinite() {
+            FpCategory::Infinite
+        } else {
+            self.mid().classify()
+        }
+    }
+
+    /// Return whether every member has a positive sign.
+    fn is_sign_positive(self) -> bool {
+        self.lo.is_sign_positive()
+    }
+
+    /// Return whether every member has a negative sign.
+    fn is_sign_negative(self) -> bool {
+        self.hi.is_sign_negative()
+    }
+
+    /// Decode the midpoint.
+    fn integer_decode(self) -> (u64, i16, i8) {
+        self.mid().integer_decode()
+    }
+
+    fn floor(self) -> Self {
+        Self::new(self.lo.floor(), self.hi.floor())
+    }
+
+    fn ceil(self) -> Self {
+        Self::new(self.lo.ceil(), self.hi.ceil())
+    }
+
+    fn round(self) -> Self {
+        Self::new(self.lo.round(), self.hi.round())
+    }
+
+    fn trunc(self) -> Self {
+        Self::new(self.lo.trunc(), self.hi.trunc())
+    }
+
+    fn fract(self) -> Self {
+        if self.lo.trunc() == self.hi.trunc() {
+            return Self::new(self.lo.fract(), self.hi.fract());
+        }
+        Self::new(
+            self.lo.min(F::zero()).max(-F::one()),
+            self.hi.max(F::zero()).min(F::one()),
+        )
+    }
+
+    fn abs(self) -> Self {
+        if self.lo >= F::zero() {
+            self
+        } else if self.hi <= F::zero() {
+            -self
+        } else {
+            Self::new(F::zero(), self.mag())
+        }
+    }
+
+    fn signum(self) -> Self {
+        Self::new(self.lo.signum(), self.hi.signum())
+    }
+
+    fn mul_add(self, a: Self, b: Self) -> Self {
+        self * a + b
+    }
+
+    fn recip(self) -> Self {
+        Self::one() / self
+    }
+
+    fn powi(self, n: i32) -> Self {
+        if n < 0 {
+            return self.powu(n.unsigned_abs()).recip();
+        }
+        let n = n as u32;
+        let x = if n.is_multiple_of(2) {
+            self.abs()
+        } else {
+            self
+        };
+        // x^n is monotonic on x, only the bounds need to be computed.
+        Self::new(Self::point(x.lo).powu(n).lo, Self::point(x.hi).powu(n).hi)
+    }
+
+    /// Raise a non-negative interval to a power. Negative members of `self`
+    /// are ignored.
+    fn powf(self, n: Self) -> Self {
+        if n.is_point() && n.lo.fract().is_zero() && n.lo.abs() <= F::from(i32::MAX).unwrap() {
+            return self.powi(n.lo.to_i32().unwrap());
+        }
+        let x = self.restrict(F::zero(), F::infinity());
+        if x.is_nan() || n.is_nan() {
+            return Self::nan();
+        }
+        // x^y is monotonic on each variable, the extremes are at the corners.
+        let corners = [
+            x.lo.powf(n.lo),
+            x.lo.powf(n.hi),
+            x.hi.powf(n.lo),
+            x.hi.powf(n.hi),
+        ];
+        let lo = corners.iter().copied().fold(F::infinity(), F::min);
+        let hi = corners.iter().copied().fold(F::neg_infinity(), F::max);
+        Self::new(next_down(lo).max(F::zero()), next_up(hi))
+    }
+
+    fn sqrt(self) -> Self {
+        let x = self.restrict(F::zero(), F::infinity());
+        Self::new(sqrt_round(x.lo, false), sqrt_round(x.hi, true))
+    }
+
+    fn exp(self) -> Self {
+        self.increasing(F::exp).restrict(F::zero(), F::infinity())
+    }
+
+    fn exp2(self) -> Self {
+        self.increasing(F::exp2).restrict(F::zero(), F::infinity())
+    }
+
+    fn ln(self) -> Self {
+        self.restrict(F::zero(), F::infinity()).increasing(F::ln)
+    }
+
+    fn log(self, base: Self) -> Self {
+        self.ln() / base.ln()
+    }
+
+    fn log2(self) -> Self {
+        self.restrict(F::zero(), F::infinity()).increasing(F::log2)
+    }
+
+    fn log10(self) -> Self {
+        self.restrict(F::zero(), F::infinity()).increasing(F::log10)
+    }
+
+    fn to_degrees(self) -> Self {
+        self.increasing(F::to_degrees)
+    }
+
+    fn to_radians(self) -> Self {
+        self.increasing(F::to_radians)
+    }
+
+    fn max(self, other: Self) -> Self {
+        Self::new(self.lo.max(other.lo), self.hi.max(other.hi))
+    }
+
+    fn min(self, other: Self) -> Self {
+        Self::new(self.lo.min(other.lo), self.hi.min(other.hi))
+    }
+
+    fn abs_sub(self, other: Self) -> Self {
+        (self - other).max(Self::zero())
+    }
+
+    fn cbrt(self) -> Self {
+        self.increasing(F::cbrt)
+    }
+
+    fn hypot(self, other: Self) -> Self {
+        (self.powi(2) + other.powi(2)).sqrt()
+    }
+
+    fn sin(self) -> Self {
+        self.periodic(F::sin, pi::<F>() / F::from(2.0).unwrap())
+    }
+
+    fn cos(self) -> Self {
+        self.periodic(F::cos, F::zero())
+    }
+
+    /// Return the tangent, or the entire line if the interval may contain a
+    /// pole.
+    fn tan(self) -> Self {
+        let half_pi = pi::<F>() / F::from(2.0).unwrap();
+        if !self.is_finite() || may_contain_multiple(self.lo, self.hi, half_pi, pi()) {
+            return if self.is_nan() {
+                Self::nan()
+            } else {
+                Self::entire()
+            };
+        }
+        self.increasing(F::tan)
+    }
+
+    fn asin(self) -> Self {
+        self.restrict(-F::one(), F::one()).increasing(F::asin)
+    }
+
+    fn acos(self) -> Self {
+        self.restrict(-F::one(), F::one()).decreasing(F::acos)
+    }
+
+    fn atan(self) -> Self {
+        self.increasing(F::atan)
+    }
+
+    /// Return the angle of the points `(other, self)`, or `[-π, π]` if the
+    /// box may cross the negative x axis.
+    fn atan2(self, other: Self) -> Self {
+        let (y, x) = (self, other);
+        if y.is_nan() || x.is_nan() {
+            return Self::nan();
+        }
+        let half_pi = Self::widened(pi::<F>() / F::from(2.0).unwrap());
+        if x.lo > F::zero() {
+            (y / x).atan()
+        } else if y.lo > F::zero() {
+            half_pi - (x / y).atan()
+        } else if y.hi < F::zero() {
+            -half_pi - (x / y).atan()
+        } else {
+            let pi = Self::widened(pi());
+            (-pi).hull(pi)
+        }
+    }
+
+    fn sin_cos(self) -> (Self, Self) {
+        (self.sin(), self.cos())
+    }
+
+    fn exp_m1(self) -> Self {
+        self.increasing(F::exp_m1)
+            .restrict(-F::one(), F::infinity())
+    }
+
+    fn ln_1p(self) -> Self {
+        self.restrict(-F::one(), F::infinity()).increasing(F::ln_1p)
+    }
+
+    fn sinh(self) -> Self {
+        self.increasing(F::sinh)
+    }
+
+    fn cosh(self) -> Self {
+        self.abs()
+            .increasing(F::cosh)
+            .restrict(F::one(), F::infinity())
+    }
+
+    fn tanh(self) -> Self {
+        self.increasing(F::tanh).restrict(-F::one(), F::one())
+    }
+
+    fn asinh(self) -> Self {
+        self.increasing(F::asinh)
+    }
+
+    fn acosh(self) -> Self {
+        self.restrict(F::one(), F::infinity()).increasing(F::acosh)
+    }
+
+    fn atanh(self) -> Self {
+        self.restrict(-F::one(), F::one()).increasing(F::atanh)
+    }
+}
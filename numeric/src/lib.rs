@@ -2,6 +2,8 @@
 #![no_std]
 #[cfg(feature = "std")]
 extern crate std;
+#[cfg(feature = "alloc")]
+extern crate alloc;
 
 // Only `no_std` builds actually use `libm`.
 #[cfg(all(not(feature = "std"), feature = "libm"))]
@@ -12,6 +14,7 @@ use core::num::Wrapping;
 use core::ops::{Add, Div, Mul, Rem, Sub};
 use core::ops::{AddAssign, DivAssign, MulAssign, RemAssign, SubAssign};
 
+pub use approx::ApproxEq;
 pub use bounds::Bounded;
 #[cfg(any(feature = "std", feature = "libm"))]
 pub use float::Float;
@@ -35,15 +38,31 @@ pub use sign::{abs, abs_sub, signum, Signed, Unsigned};
 #[macro_use]
 mod macros;
 
+pub mod approx;
+#[cfg(feature = "alloc")]
+pub mod bigint;
 pub mod bounds;
 pub mod cast;
+#[cfg(any(feature = "std", feature = "libm"))]
+pub mod complex;
+#[cfg(any(feature = "std", feature = "libm"))]
+pub mod dual;
+pub mod fixed;
 pub mod float;
+pub mod half;
 pub mod identities;
 pub mod int;
+#[cfg(any(feature = "std", feature = "libm"))]
+pub mod interval;
 pub mod ops;
+#[cfg(any(feature = "std", feature = "libm"))]
+pub mod poly;
 pub mod pow;
+pub mod ratio;
 pub mod real;
 pub mod sign;
+#[cfg(any(feature = "std", feature = "libm"))]
+pub mod special;
 
 /// The base trait for numeric types, covering `0` and `1` values,
 /// comparisons, basic numeric operations, and string conversion.
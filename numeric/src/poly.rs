@@ -0,0 +1,263 @@
+//! Real roots of polynomials of degree up to four.
+//!
+//! The solvers take the coefficients from the highest degree down, fall back
+//! to the lower degree when the leading coefficient is zero, and return the
+//! distinct real roots in increasing order. Quadratic roots come from a
+//! formula which avoids cancellation, and the roots of cubics and quartics are
+//! refined by Newton's method on the original polynomial.
+//!
+//! ```
+//! use numeric::poly::{solve_cubic, solve_quadratic};
+//!
+//! assert_eq!(*solve_quadratic(1.0, -3.0, 2.0), [1.0, 2.0]);
+//! // (x - 1)(x - 2)(x - 3)
+//! assert_eq!(*solve_cubic(1.0, -6.0, 11.0, -6.0), [1.0, 2.0, 3.0]);
+//! assert!(solve_quadratic(1.0, 0.0, 1.0).is_empty());
+//! ```
+
+use core::ops::Deref;
+
+use crate::Float;
+
+fn lit<F: Float>(x: f64) -> F {
+    F::from(x).unwrap()
+}
+
+/// The real roots of a polynomial, in increasing order.
+///
+/// Dereferences to a slice of the roots.
+#[derive(Copy, Clone, Debug)]
+pub struct Roots<F> {
+    roots: [F; 4],
+    len: usize,
+}
+
+impl<F: Float> Roots<F> {
+    fn new() -> Self {
+        Self {
+            roots: [F::zero(); 4],
+            len: 0,
+        }
+    }
+
+    fn push(&mut self, x: F) {
+        if x.is_finite() {
+            self.roots[self.len] = x;
+            self.len += 1;
+        }
+    }
+
+    /// Sort the roots and remove the duplicates.
+    fn finish(mut self) -> Self {
+        let roots = &mut self.roots[..self.len];
+        roots.sort_unstable_by(|a, b| a.partial_cmp(b).unwrap());
+        let mut len = 0;
+        for i in 0..roots.len() {
+            if len == 0 || roots[i] != roots[len - 1] {
+                roots[len] = roots[i];
+                len += 1;
+            }
+        }
+        self.len = len;
+        self
+    }
+
+    /// Refine the roots with a few Newton steps on `coeffs`, as long as they
+    /// reduce the residual.
+    fn polish(mut self, coeffs: &[F]) -> Self {
+        for x in &mut self.roots[..self.len] {
+            let mut residual = eval(coeffs, *x).0.abs();
+            for _ in 0..4 {
+                let (p, dp) = eval(coeffs, *x);
+                if residual.is_zero() || dp.is_zero() {
+                    break;
+                }
+                let y = *x - p / dp;
+                let r = eval(coeffs, y).0.abs();
+                if r >= residual {
+                    break;
+                }
+                *x = y;
+                residual = r;
+            }
+        }
+        self.finish()
+    }
+}
+
+impl<F> Deref for Roots<F> {
+    type Target = [F];
+
+    fn deref(&self) -> &[F] {
+        &self.roots[..self.len]
+    }
+}
+
+impl<F: PartialEq> PartialEq for Roots<F> {
+    fn eq(&self, other: &Self) -> bool {
+        **self == **other
+    }
+}
+
+/// Return the value and the derivative of the polynomial at `x` by Horner's
+/// method.
+fn eval<F: Float>(coeffs: &[F], x: F) -> (F, F) {
+    let mut p = F::zero();
+    let mut dp = F::zero();
+    for &c in coeffs {
+        dp = dp * x + p;
+        p = p * x + c;
+    }
+    (p, dp)
+}
+
+/// Return `b² - 4ac` with the rounding error of the products compensated.
+fn discriminant<F: Float>(a: F, b: F, c: F) -> F {
+    let four_a = lit::<F>(4.0) * a;
+    let p = b * b;
+    let q = four_a * c;
+    let dp = b.mul_add(b, -p);
+    let dq = four_a.mul_add(c, -q);
+    (p - q) + (dp - dq)
+}
+
+/// Return the real roots of `ax² + bx + c`.
+///
+/// The roots are computed without the cancellation of the textbook formula
+/// when `b²` dominates `4ac`, and a double root is returned once.
+pub fn solve_quadratic<F: Float>(a: F, b: F, c: F) -> Roots<F> {
+    let mut roots = Roots::new();
+    if a.is_zero() {
+        if !b.is_zero() {
+            roots.push(-c / b);
+        }
+        return roots;
+    }
+    // Scale the coefficients by a power of two, exactly, so that their
+    // squares neither overflow nor underflow. The logarithm of the largest
+    // numbers rounds up to the first exponent out of range.
+    let max_exponent = F::max_value().log2().floor() - F::one();
+    let exponent = a.abs().max(b.abs()).max(c.abs()).log2().floor();
+    let scale = exponent.min(max_exponent).exp2();
+    let (a, b, c) = (a / scale, b / scale, c / scale);
+    let d = discriminant(a, b, c);
+    if d < F::zero() {
+        return roots;
+    }
+    if d.is_zero() {
+        roots.push(-b / (lit::<F>(2.0) * a));
+        return roots;
+    }
+    // q has the sign of b, so that its terms add up.
+    let s = d.sqrt();
+    let q = if b < F::zero() { s - b } else { -(b + s) };
+    let q = q / lit(2.0);
+    roots.push(q / a);
+    roots.push(c / q);
+    roots.finish()
+}
+
+/// Return the real roots of `ax³ + bx² + cx + d`.
+pub fn solve_cubic<F: Float>(a: F, b: F, c: F, d: F) -> Roots<F> {
+    if a.is_zero() {
+        return solve_quadratic(b, c, d);
+    }
+    let coeffs = [a, b, c, d];
+    if d.is_zero() {
+        // Factor x out, exactly.
+        let mut roots = solve_quadratic(a, b, c);
+        roots.push(F::zero());
+        return roots.finish();
+    }
+    let (b, c, d) = (b / a, c / a, d / a);
+    let third = lit::<F>(1.0 / 3.0);
+    let shift = b * third;
+    let q = (b * b - lit::<F>(3.0) * c) / lit(9.0);
+    let r = (lit::<F>(2.0) * b * b * b - lit::<F>(9.0) * b * c + lit::<F>(27.0) * d) / lit(54.0);
+    let q3 = q * q * q;
+    let mut roots = Roots::new();
+    if r * r < q3 {
+        // Three real roots.
+        let theta = (r / q3.sqrt()).max(-F::one()).min(F::one()).acos();
+        let m = lit::<F>(-2.0) * q.sqrt();
+        let tau = lit::<F>(2.0 * core::f64::consts::PI);
+        roots.push(m * (theta * third).cos() - shift);
+        roots.push(m * ((theta + tau) * third).cos() - shift);
+        roots.push(m * ((theta - tau) * third).cos() - shift);
+    } else {
+        // One real root, or a double one.
+        let big = -r.signum() * (r.abs() + (r * r - q3).max(F::zero()).sqrt()).cbrt();
+        let small = if big.is_zero() { F::zero() } else { q / big };
+        roots.push(big + small - shift);
+        let double = -(big + small) / lit(2.0) - shift;
+        // The discriminant is zero up to rounding.
+        if (big - small).abs() <= lit::<F>(1e3) * F::epsilon() * big.abs() {
+            roots.push(double);
+        }
+    }
+    roots.polish(&coeffs)
+}
+
+/// Return the real roots of `ax⁴ + bx³ + cx² + dx + e`.
+///
+/// Uses Ferrari's method, which splits the quartic in two quadratics with a
+/// root of its resolvent cubic.
+pub fn solve_quartic<F: Float>(a: F, b: F, c: F, d: F, e: F) -> Roots<F> {
+    if a.is_zero() {
+        return solve_cubic(b, c, d, e);
+    }
+    let coeffs = [a, b, c, d, e];
+    if e.is_zero() {
+        let mut roots = solve_cubic(a, b, c, d);
+        roots.push(F::zero());
+        return roots.finish();
+    }
+    // Depress with x = y - b/4: y⁴ + py² + qy + r.
+    let (b, c, d, e) = (b / a, c / a, d / a, e / a);
+    let shift = b / lit(4.0);
+    let b2 = b * b;
+    let p = c - lit::<F>(3.0 / 8.0) * b2;
+    let q = d - b * c / lit(2.0) + b2 * b / lit(8.0);
+    let r = e - b * d / lit(4.0) + b2 * c / lit(16.0) - lit::<F>(3.0 / 256.0) * b2 * b2;
+
+    let mut roots = Roots::new();
+    let mut push_squares = |z: F| {
+        if z >= F::zero() {
+            let y = z.sqrt();
+            roots.push(y - shift);
+            roots.push(-y - shift);
+        }
+    };
+    // A length to compare q with.
+    let l = p.abs().sqrt() + r.abs().sqrt().sqrt();
+    if q.abs() <= lit::<F>(16.0) * F::epsilon() * l * l * l {
+        // Biquadratic in y.
+        for &z in solve_quadratic(F::one(), p, r).iter() {
+            push_squares(z);
+        }
+        return roots.polish(&coeffs);
+    }
+
+    // (y² + p/2 + m)² = 2m (y - q/4m)² for m a positive root of
+    // 8m³ + 8pm² + (2p² - 8r)m - q².
+    let resolvent = solve_cubic(
+        lit(8.0),
+        lit::<F>(8.0) * p,
+        lit::<F>(2.0) * p * p - lit::<F>(8.0) * r,
+        -q * q,
+    );
+    let m = match resolvent.last() {
+        Some(&m) if m > F::zero() => m,
+        _ => return roots,
+    };
+    let s = (lit::<F>(2.0) * m).sqrt();
+    let half_p = p / lit(2.0);
+    let t = q / (lit::<F>(2.0) * s);
+    for &y in solve_quadratic(F::one(), -s, half_p + m + t).iter() {
+        roots.push(y - shift);
+    }
+    for &y in solve_quadratic(F::one(), s, half_p + m - t).iter() {
+        roots.push(y - shift);
+    }
+    roots.polish(&coeffs)
+}
@@ -0,0 +1,594 @@
+//! Rational numbers.
+//!
+//! A [`Ratio`] is kept in lowest terms with a positive denominator, so equal
+//! ratios have equal representations. Arithmetic is carried out with the
+//! checked operations of the integer type: the `Checked*` traits return
+//! `None` on overflow, and the operators panic.
+//!
+//! ```
+//! use numeric::ratio::Ratio;
+//!
+//! let ntsc = Ratio::new(30000u32, 1001);
+//! let frame = ntsc.recip();
+//! assert_eq!(frame * Ratio::from(30000), Ratio::new(1001, 1));
+//! assert_eq!("60000/2002".parse::<Ratio<u32>>().unwrap(), ntsc);
+//! ```
+
+use core::cmp::Ordering;
+use core::fmt;
+use core::ops::{Add, Div, Mul, Neg, Rem, Sub};
+use core::ops::{AddAssign, DivAssign, MulAssign, RemAssign, SubAssign};
+use core::str::FromStr;
+
+#[cfg(any(feature = "std", feature = "libm"))]
+use crate::Float;
+use crate::{CheckedAdd, CheckedDiv, CheckedMul, CheckedNeg, CheckedSub};
+use crate::{Num, NumCast, One, PrimInt, Signed, ToPrimitive, Zero};
+
+/// A rational number `numer / denom` in lowest terms.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Ratio<T: PrimInt> {
+    numer: T,
+    denom: T,
+}
+
+/// Return `a % b`, which is zero where the division overflows.
+fn rem<T: PrimInt>(a: T, b: T) -> T {
+    match a.checked_div(&b) {
+        Some(_) => a % b,
+        None => T::zero(),
+    }
+}
+
+/// Return the greatest common divisor of `a` and `b`, which is negative
+/// when its magnitude is not representable.
+fn gcd<T: PrimInt>(mut a: T, mut b: T) -> T {
+    while !b.is_zero() {
+        let r = rem(a, b);
+        a = b;
+        b = r;
+    }
+    if a < T::zero() {
+        T::zero().checked_sub(&a).unwrap_or(a)
+    } else {
+        a
+    }
+}
+
+/// Return the quotient and remainder of `a / b` rounded towards negative
+/// infinity, for a positive `b`.
+fn div_mod_floor<T: PrimInt>(a: T, b: T) -> (T, T) {
+    let (q, r) = (a / b, a % b);
+    if r < T::zero() {
+        (q - T::one(), r + b)
+    } else {
+        (q, r)
+    }
+}
+
+impl<T: PrimInt> Ratio<T> {
+    /// Create a ratio and reduce it to lowest terms.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `denom` is zero, or if the reduced ratio is not
+    /// representable, like `1 / i32::MIN`.
+    pub fn new(numer: T, denom: T) -> Self {
+        assert!(!denom.is_zero(), "denominator is zero");
+        Self::reduce(numer, denom).expect("ratio overflow")
+    }
+
+    /// Create a ratio equal to an integer.
+    pub fn from_integer(n: T) -> Self {
+        Self {
+            numer: n,
+            denom: T::one(),
+        }
+    }
+
+    /// Reduce a ratio to lowest terms with a positive denominator, or return
+    /// `None` if the denominator is zero or the result overflows.
+    fn reduce(numer: T, denom: T) -> Option<Self> {
+        if denom.is_zero() {
+            return None;
+        }
+        let g = gcd(numer, denom);
+        let (mut numer, mut denom) = (numer.checked_div(&g)?, denom.checked_div(&g)?);
+        if denom < T::zero() {
+            numer = T::zero().checked_sub(&numer)?;
+            denom = T::zero().checked_sub(&denom)?;
+        }
+        Some(Self { numer, denom })
+    }
+
+    /// Return the numerator.
+    pub fn numer(&self) -> T {
+        self.numer
+    }
+
+    /// Return the denominator, which is positive.
+    pub fn denom(&self) -> T {
+        self.denom
+    }
+
+    /// Return whether the ratio is an integer.
+    pub fn is_integer(&self) -> bool {
+        self.denom.is_one()
+    }
+
+    /// Return the integer part, rounded towards zero.
+    pub fn to_integer(&self) -> T {
+        self.numer / self.denom
+    }
+
+    /// Return the largest integer less than or equal to the ratio.
+    pub fn floor(&self) -> Self {
+        Self::from_integer(div_mod_floor(self.numer, self.denom).0)
+    }
+
+    /// Return the smallest integer greater than or equal to the ratio.
+    pub fn ceil(&self) -> Self {
+        let (q, r) = div_mod_floor(self.numer, self.denom);
+        Self::from_integer(if r.is_zero() { q } else { q + T::one() })
+    }
+
+    /// Return the nearest integer, rounding half-way cases away from zero.
+    pub fn round(&self) -> Self {
+        let (q, r) = (self.numer / self.denom, self.numer % self.denom);
+        let abs_r = if r < T::zero() { T::zero() - r } else { r };
+        if abs_r < self.denom - abs_r {
+            Self::from_integer(q)
+        } else if r < T::zero() {
+            Self::from_integer(q - T::one())
+        } else {
+            Self::from_integer(q + T::one())
+        }
+    }
+
+    /// Return the integer part, rounded towards zero.
+    pub fn trunc(&self) -> Self {
+        Self::from_integer(self.to_integer())
+    }
+
+    /// Return the fractional part, with the sign of the ratio.
+    pub fn fract(&self) -> Self {
+        Self {
+            numer: self.numer % self.denom,
+            denom: self.denom,
+        }
+    }
+
+    /// Return the reciprocal, or `None` if the ratio is zero or its
+    /// reciprocal is not representable.
+    pub fn checked_recip(&self) -> Option<Self> {
+        Self::reduce(self.denom, self.numer)
+    }
+
+    /// Return the reciprocal.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the ratio is zero or its reciprocal is not representable.
+    pub fn recip(&self) -> Self {
+        assert!(!self.numer.is_zero(), "reciprocal of zero");
+        self.checked_recip().expect("ratio overflow")
+    }
+
+    /// Return the ratio with a denominator of at most `max_denom` nearest to
+    /// `x`, or `None` if `x` is not finite or out of range.
+    ///
+    /// This is the best rational approximation, found with the continued
+    /// fraction expansion of `x`.
+    ///
+    /// ```
+    /// use numeric::ratio::Ratio;
+    ///
+    /// let pi = Ratio::approximate_float(std::f64::consts::PI, 1000).unwrap();
+    /// assert_eq!(pi, Ratio::new(355, 113));
+    /// ```
+    #[cfg(any(feature = "std", feature = "libm"))]
+    pub fn approximate_float<F: Float>(x: F, max_denom: T) -> Option<Self> {
+        if !x.is_finite() || max_denom < T::one() {
+            return None;
+        }
+        if x < F::zero() {
+            // Approximate |x| and negate, which fails for unsigned types.
+            let r = Self::approximate_float(-x, max_denom)?;
+            return Some(Self {
+                numer: T::zero().checked_sub(&r.numer)?,
+                denom: r.denom,
+            });
+        }
+
+        // Convergents p / q of the continued fraction, starting from 0 / 1
+        // and 1 / 0.
+        let (mut p0, mut q0, mut p1, mut q1) = (T::zero(), T::one(), T::one(), T::zero());
+        let mut y = x;
+        loop {
+            let a = y.floor();
+            let step = <T as NumCast>::from(a).and_then(|a| {
+                let p = a.checked_mul(&p1)?.checked_add(&p0)?;
+                let q = a.checked_mul(&q1)?.checked_add(&q0)?;
+                Some((p, q)).filter(|&(_, q)| q <= max_denom)
+            });
+            let (p, q) = match step {
+                Some(pq) => pq,
+                None => break,
+            };
+            (p0, q0, p1, q1) = (p1, q1, p, q);
+            let f = y - a;
+            if f.is_zero() {
+                return Some(Self::new(p1, q1));
+            }
+            y = f.recip();
+        }
+        if q1.is_zero() {
+            // The integer part is out of range.
+            return None;
+        }
+
+        // The last convergent did not fit, try the largest semiconvergent
+        // (p0 + n p1) / (q0 + n q1) which does.
+        let best = Self::new(p1, q1);
+        let mut n = (max_denom - q0) / q1;
+        if !p1.is_zero() {
+            n = n.min((T::max_value() - p0) / p1);
+        }
+        if n.is_zero() {
+            return Some(best);
+        }
+        let semi = Self::new(p0 + n * p1, q0 + n * q1);
+        let error = |r: Self| (r.to_float::<F>() - x).abs();
+        Some(if error(semi) < error(best) {
+            semi
+        } else {
+            best
+        })
+    }
+
+    #[cfg(any(feature = "std", feature = "libm"))]
+    fn to_float<F: Float>(self) -> F {
+        F::from(self.numer).unwrap() / F::from(self.denom).unwrap()
+    }
+}
+
+impl<T: PrimInt> Default for Ratio<T> {
+    fn default() -> Self {
+        Self::zero()
+    }
+}
+
+impl<T: PrimInt> From<T> for Ratio<T> {
+    fn from(n: T) -> Self {
+        Self::from_integer(n)
+    }
+}
+
+impl<T: PrimInt> From<(T, T)> for Ratio<T> {
+    fn from((numer, denom): (T, T)) -> Self {
+        Self::new(numer, denom)
+    }
+}
+
+impl<T: PrimInt> Ord for Ratio<T> {
+    /// Compare the ratios without overflowing, by comparing the terms of
+    /// their continued fractions.
+    fn cmp(&self, other: &Self) -> Ordering {
+        let (mut a, mut b) = (*self, *other);
+        let mut reversed = false;
+        loop {
+            let (qa, ra) = div_mod_floor(a.numer, a.denom);
+            let (qb, rb) = div_mod_floor(b.numer, b.denom);
+            let ord = match (qa.cmp(&qb), ra.is_zero(), rb.is_zero()) {
+                (Ordering::Equal, true, true) => Ordering::Equal,
+                (Ordering::Equal, true, false) => Ordering::Less,
+                (Ordering::Equal, false, true) => Ordering::Greater,
+                (Ordering::Equal, false, false) => {
+                    // Compare the reciprocals of the fractional parts.
+                    a = Self {
+                        numer: a.denom,
+                        denom: ra,
+                    };
+                    b = Self {
+                        numer: b.denom,
+                        denom: rb,
+                    };
+                    reversed = !reversed;
+                    continue;
+                }
+                (ord, _, _) => ord,
+            };
+            return if reversed { ord.reverse() } else { ord };
+        }
+    }
+}
+
+impl<T: PrimInt> PartialOrd for Ratio<T> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<T: PrimInt + fmt::Display> fmt::Display for Ratio<T> {
+    /// Format as `numer/denom`, or as `numer` for an integer.
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if self.is_integer() {
+            write!(f, "{}", self.numer)
+        } else {
+            write!(f, "{}/{}", self.numer, self.denom)
+        }
+    }
+}
+
+// --- Checked arithmetic ---
+
+impl<T: PrimInt> Ratio<T> {
+    /// Return `self` and `other` over their least common denominator, as
+    /// `(self numerator, other numerator, denominator)`.
+    fn common(&self, other: &Self) -> Option<(T, T, T)> {
+        if self.denom == other.denom {
+            return Some((self.numer, other.numer, self.denom));
+        }
+        let g = gcd(self.denom, other.denom);
+        let (a, b) = (self.denom / g, other.denom / g);
+        Some((
+            self.numer.checked_mul(&b)?,
+            other.numer.checked_mul(&a)?,
+            self.denom.checked_mul(&b)?,
+        ))
+    }
+}
+
+impl<T: PrimInt> CheckedAdd for Ratio<T> {
+    fn checked_add(&self, other: &Self) -> Option<Self> {
+        let (a, b, d) = self.common(other)?;
+        Self::reduce(a.checked_add(&b)?, d)
+    }
+}
+
+impl<T: PrimInt> CheckedSub for Ratio<T> {
+    fn checked_sub(&self, other: &Self) -> Option<Self> {
+        let (a, b, d) = self.common(other)?;
+        Self::reduce(a.checked_sub(&b)?, d)
+    }
+}
+
+impl<T: PrimInt> CheckedMul for Ratio<T> {
+    fn checked_mul(&self, other: &Self) -> Option<Self> {
+        // Cancel the common factors first to delay overflow.
+        let g1 = gcd(self.numer, other.denom);
+        let g2 = gcd(other.numer, self.denom);
+        let numer = (self.numer.checked_div(&g1)?).checked_mul(&other.numer.checked_div(&g2)?)?;
+        let denom = (self.denom.checked_div(&g2)?).checked_mul(&other.denom.checked_div(&g1)?)?;
+        Self::reduce(numer, denom)
+    }
+}
+
+impl<T: PrimInt> CheckedDiv for Ratio<T> {
+    fn checked_div(&self, other: &Self) -> Option<Self> {
+        self.checked_mul(&other.checked_recip()?)
+    }
+}
+
+impl<T: PrimInt> Ratio<T> {
+    /// Return the remainder of the division truncated towards zero, or
+    /// `None` if `other` is zero or the computation overflows.
+    pub fn checked_rem(&self, other: &Self) -> Option<Self> {
+        let (a, b, d) = self.common(other)?;
+        if b.is_zero() {
+            return None;
+        }
+        Self::reduce(rem(a, b), d)
+    }
+}
+
+impl<T: PrimInt + CheckedNeg> CheckedNeg for Ratio<T> {
+    fn checked_neg(&self) -> Option<Self> {
+        Some(Self {
+            numer: self.numer.checked_neg()?,
+            denom: self.denom,
+        })
+    }
+}
+
+// --- Operators ---
+
+macro_rules! checked_op {
+    ($imp:ident, $method:ident, $checked:ident, $msg:expr) => {
+        impl<T: PrimInt> $imp for Ratio<T> {
+            type Output = Self;
+
+            fn $method(self, other: Self) -> Self {
+                self.$checked(&other).expect($msg)
+            }
+        }
+
+        impl<T: PrimInt> $imp<T> for Ratio<T> {
+            type Output = Self;
+
+            fn $method(self, other: T) -> Self {
+                self.$method(Self::from_integer(other))
+            }
+        }
+    };
+}
+
+checked_op!(Add, add, checked_add, "attempt to add with overflow");
+checked_op!(Sub, sub, checked_sub, "attempt to subtract with overflow");
+checked_op!(Mul, mul, checked_mul, "attempt to multiply with overflow");
+
+impl<T: PrimInt> Div for Ratio<T> {
+    type Output = Self;
+
+    fn div(self, other: Self) -> Self {
+        assert!(!other.is_zero(), "attempt to divide by zero");
+        self.checked_div(&other)
+            .expect("attempt to divide with overflow")
+    }
+}
+
+impl<T: PrimInt> Div<T> for Ratio<T> {
+    type Output = Self;
+
+    fn div(self, other: T) -> Self {
+        self / Self::from_integer(other)
+    }
+}
+
+impl<T: PrimInt> Rem for Ratio<T> {
+    type Output = Self;
+
+    fn rem(self, other: Self) -> Self {
+        assert!(
+            !other.is_zero(),
+            "attempt to calculate the remainder with a divisor of zero"
+        );
+        self.checked_rem(&other)
+            .expect("attempt to calculate the remainder with overflow")
+    }
+}
+
+impl<T: PrimInt> Rem<T> for Ratio<T> {
+    type Output = Self;
+
+    fn rem(self, other: T) -> Self {
+        self % Self::from_integer(other)
+    }
+}
+
+impl<T: PrimInt + Signed + CheckedNeg> Neg for Ratio<T> {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        self.checked_neg().expect("attempt to negate with overflow")
+    }
+}
+
+/// Implement an assignment operator from the binary one.
+macro_rules! assign_op {
+    ($imp:ident, $method:ident, $op:ident) => {
+        impl<T: PrimInt> $imp for Ratio<T> {
+            fn $method(&mut self, other: Self) {
+                *self = (*self).$op(other);
+            }
+        }
+
+        impl<T: PrimInt> $imp<T> for Ratio<T> {
+            fn $method(&mut self, other: T) {
+                *self = (*self).$op(other);
+            }
+        }
+    };
+}
+
+assign_op!(AddAssign, add_assign, add);
+assign_op!(SubAssign, sub_assign, sub);
+assign_op!(MulAssign, mul_assign, mul);
+assign_op!(DivAssign, div_assign, div);
+assign_op!(RemAssign, rem_assign, rem);
+
+// --- Numeric traits ---
+
+impl<T: PrimInt> Zero for Ratio<T> {
+    fn zero() -> Self {
+        Self::from_integer(T::zero())
+    }
+
+    fn is_zero(&self) -> bool {
+        self.numer.is_zero()
+    }
+}
+
+impl<T: PrimInt> One for Ratio<T> {
+    fn one() -> Self {
+        Self::from_integer(T::one())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RatioErrorKind<E> {
+    Empty,
+    Invalid,
+    ZeroDenominator,
+    /// The ratio cannot be reduced to a representable one.
+    Overflow,
+    /// The numerator or the denominator could not be parsed.
+    Part(E),
+}
+
+/// An error which can be returned when parsing a ratio.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseRatioError<E> {
+    pub kind: RatioErrorKind<E>,
+}
+
+impl<E: fmt::Display> fmt::Display for ParseRatioError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self.kind {
+            RatioErrorKind::Empty => "cannot parse ratio from empty string".fmt(f),
+            RatioErrorKind::Invalid => "invalid ratio literal".fmt(f),
+            RatioErrorKind::ZeroDenominator => "denominator is zero".fmt(f),
+            RatioErrorKind::Overflow => "ratio overflow".fmt(f),
+            RatioErrorKind::Part(ref e) => e.fmt(f),
+        }
+    }
+}
+
+impl<T: PrimInt> Num for Ratio<T> {
+    type FromStrRadixErr = ParseRatioError<T::FromStrRadixErr>;
+
+    /// Parse `numer/denom` or `numer`, where both are parsed with
+    /// `T::from_str_radix`. Spaces around the slash are allowed.
+    fn from_str_radix(src: &str, radix: u32) -> Result<Self, Self::FromStrRadixErr> {
+        use self::RatioErrorKind::*;
+
+        let parse = |s: &str| {
+            T::from_str_radix(s.trim(), radix).map_err(|e| ParseRatioError { kind: Part(e) })
+        };
+        if src.trim().is_empty() {
+            return Err(ParseRatioError { kind: Empty });
+        }
+        let mut parts = src.split('/');
+        let (numer, denom) = match (parts.next(), parts.next(), parts.next()) {
+            (Some(n), None, _) => (parse(n)?, T::one()),
+            (Some(n), Some(d), None) => (parse(n)?, parse(d)?),
+            _ => return Err(ParseRatioError { kind: Invalid }),
+        };
+        if denom.is_zero() {
+            return Err(ParseRatioError {
+                kind: ZeroDenominator,
+            });
+        }
+        Self::reduce(numer, denom).ok_or(ParseRatioError { kind: Overflow })
+    }
+}
+
+impl<T: PrimInt> FromStr for Ratio<T> {
+    type Err = ParseRatioError<T::FromStrRadixErr>;
+
+    fn from_str(src: &str) -> Result<Self, Self::Err> {
+        Self::from_str_radix(src, 10)
+    }
+}
+
+impl<T: PrimInt> ToPrimitive for Ratio<T> {
+    /// Convert the integer part.
+    fn to_i64(&self) -> Option<i64> {
+        self.to_integer().to_i64()
+    }
+
+    /// Convert the integer part.
+    fn to_u64(&self) -> Option<u64> {
+        self.to_integer().to_u64()
+    }
+
+    /// Convert to the nearest float, or one of the two nearest when the
+    /// terms do not fit in the mantissa.
+    fn to_f64(&self) -> Option<f64> {
+        Some(self.numer.to_f64()? / self.denom.to_f64()?)
+    }
+
+    fn to_f32(&self) -> Option<f32> {
+        self.to_f64().map(|x| x as f32)
+    }
+}
@@ -1,8 +1,8 @@
-#![cfg(any(feature = "std", feature = "libm"))]
-
 use core::ops::Neg;
 
-use crate::{Float, Num, NumCast};
+#[cfg(any(feature = "std", feature = "libm"))]
+use crate::Float;
+use crate::{Num, NumCast};
 
 // NOTE: These doctests have the same issue as those in src/float.rs.
 // They're testing the inherent methods directly, and not those of `Real`.
@@ -13,7 +13,9 @@ use crate::{Float, Num, NumCast};
 /// See [this Wikipedia article](https://en.wikipedia.org/wiki/Real_data_type)
 /// for a list of data types that could meaningfully implement this trait.
 ///
-/// This trait is only available with the `std` feature, or with the `libm` feature otherwise.
+/// It is implemented for every `Float`, which is only available with the `std`
+/// feature, or with the `libm` feature otherwise, and for the fixed-point types
+/// of the `fixed` module.
 pub trait Real: Num + Copy + NumCast + PartialOrd + Neg<Output = Self> {
     /// Returns the smallest finite value that this type can represent.
     ///
@@ -779,6 +781,7 @@ pub trait Real: Num + Copy + NumCast + PartialOrd + Neg<Output = Self> {
     fn atanh(self) -> Self;
 }
 
+#[cfg(any(feature = "std", feature = "libm"))]
 impl<T: Float> Real for T {
     forward! {
         Float::min_value() -> Self;
@@ -0,0 +1,443 @@
+//! Special functions: gamma, error and Bessel functions, and smooth steps.
+//!
+//! In `f64` the functions have a relative error around `1e-14`, absolute near
+//! the zeros of the Bessel functions, and `f32` uses the same algorithms.
+//!
+//! ```
+//! use numeric::special::{bessel_j0, erf, gamma, smoothstep};
+//!
+//! assert!((gamma(5.0) - 24.0f64).abs() < 1e-12);
+//! assert!((erf(0.5) - 0.5204998778130465f64).abs() < 1e-15);
+//! assert!(bessel_j0(2.404825557695773f64).abs() < 1e-15);
+//! assert_eq!(smoothstep(0.0, 2.0, 1.0), 0.5);
+//! ```
+
+use crate::Float;
+
+fn lit<F: Float>(x: f64) -> F {
+    F::from(x).unwrap()
+}
+
+fn pi<F: Float>() -> F {
+    lit(core::f64::consts::PI)
+}
+
+/// The Euler–Mascheroni constant.
+const EULER_GAMMA: f64 = 0.577_215_664_901_532_9;
+
+/// Lanczos approximation coefficients for `g = 7`.
+const LANCZOS_G: f64 = 7.0;
+const LANCZOS: [f64; 9] = [
+    0.999_999_999_999_809_9,
+    676.520_368_121_885_1,
+    -1_259.139_216_722_402_8,
+    771.323_428_777_653_1,
+    -176.615_029_162_140_6,
+    12.507_343_278_686_905,
+    -0.138_571_095_265_720_12,
+    9.984_369_578_019_572e-6,
+    1.505_632_735_149_311_6e-7,
+];
+
+/// Return `sin(πx)`, exact at the integers.
+fn sin_pi<F: Float>(x: F) -> F {
+    let two = lit::<F>(2.0);
+    // Reduce to [-1, 1], which is exact.
+    let r = x - two * (x / two).round();
+    if r.fract().is_zero() {
+        F::zero()
+    } else {
+        (pi::<F>() * r).sin()
+    }
+}
+
+/// The Lanczos series and `t = x + g - 1/2`, for `x >= 1/2`.
+fn lanczos<F: Float>(x: F) -> (F, F) {
+    let x = x - F::one();
+    let mut sum = lit::<F>(LANCZOS[0]);
+    for (i, &c) in LANCZOS.iter().enumerate().skip(1) {
+        sum = sum + lit::<F>(c) / (x + lit(i as f64));
+    }
+    (sum, x + lit(LANCZOS_G + 0.5))
+}
+
+/// Return the gamma function of `x`, which is `(x - 1)!` at the positive
+/// integers.
+///
+/// Returns NaN at the poles, zero and the negative integers, and infinity
+/// where the result overflows.
+pub fn gamma<F: Float>(x: F) -> F {
+    if x.is_nan() || x == F::neg_infinity() {
+        return F::nan();
+    }
+    if x == F::infinity() {
+        return x;
+    }
+    let half = lit::<F>(0.5);
+    if x < half {
+        let s = sin_pi(x);
+        if s.is_zero() {
+            return F::nan();
+        }
+        // Reflection formula.
+        return pi::<F>() / (s * gamma(F::one() - x));
+    }
+    let (sum, t) = lanczos(x);
+    // Split the power so that it does not overflow before the exponential
+    // brings it back.
+    let p = t.powf((x - half) / lit(2.0));
+    if p.is_infinite() {
+        // The exponential underflows here, and the result overflows anyway.
+        return F::infinity();
+    }
+    lit::<F>(2.506_628_274_631_000_5) * p * ((-t).exp() * p) * sum
+}
+
+/// Return the natural logarithm of the absolute value of the gamma function
+/// of `x`, which does not overflow.
+///
+/// Returns infinity at the poles, zero and the negative integers.
+pub fn ln_gamma<F: Float>(x: F) -> F {
+    if x.is_nan() {
+        return x;
+    }
+    let half = lit::<F>(0.5);
+    if x < half {
+        let s = sin_pi(x).abs();
+        if s.is_zero() {
+            return F::infinity();
+        }
+        return (pi::<F>() / s).ln() - ln_gamma(F::one() - x);
+    }
+    let (sum, t) = lanczos(x);
+    lit::<F>(0.918_938_533_204_672_8) + (x - half) * t.ln() - t + sum.ln()
+}
+
+/// Return `erf(x)` for `|x| <= 1` from its series, whose terms are positive.
+fn erf_series<F: Float>(x: F) -> F {
+    let x2 = x * x;
+    let mut term = x;
+    let mut sum = x;
+    let mut k = F::one();
+    loop {
+        term = term * lit::<F>(2.0) * x2 / (lit::<F>(2.0) * k + F::one());
+        sum = sum + term;
+        if term <= sum * F::epsilon() {
+            break;
+        }
+        k = k + F::one();
+    }
+    lit::<F>(2.0 / core::f64::consts::PI.sqrt()) * (-x2).exp() * sum
+}
+
+/// Return `erfc(x)` for `x >= 1` from its continued fraction.
+fn erfc_fraction<F: Float>(x: F) -> F {
+    if x.is_infinite() {
+        return F::zero();
+    }
+    // Modified Lentz's method on 1 / (x + (1/2) / (x + 1 / (x + (3/2) / ...))).
+    let tiny = F::min_positive_value();
+    let half = lit::<F>(0.5);
+    let mut f = x;
+    let mut c = x;
+    let mut d = F::zero();
+    let mut k = F::one();
+    for _ in 0..500 {
+        let a = k * half;
+        d = x + a * d;
+        if d.is_zero() {
+            d = tiny;
+        }
+        c = x + a / c;
+        if c.is_zero() {
+            c = tiny;
+        }
+        d = d.recip();
+        let delta = c * d;
+        f = f * delta;
+        if (delta - F::one()).abs() <= F::epsilon() {
+            break;
+        }
+        k = k + F::one();
+    }
+    lit::<F>(1.0 / core::f64::consts::PI.sqrt()) * (-x * x).exp() / f
+}
+
+/// Return the error function of `x`.
+pub fn erf<F: Float>(x: F) -> F {
+    if x.is_nan() {
+        return x;
+    }
+    let a = x.abs();
+    let y = if a <= F::one() {
+        erf_series(a)
+    } else {
+        F::one() - erfc_fraction(a)
+    };
+    if x < F::zero() {
+        -y
+    } else {
+        y
+    }
+}
+
+/// Return the complementary error function of `x`, `1 - erf(x)`, without
+/// losing precision when it is small.
+pub fn erfc<F: Float>(x: F) -> F {
+    if x.is_nan() {
+        return x;
+    }
+    let a = x.abs();
+    let y = if a <= F::one() {
+        F::one() - erf_series(a)
+    } else {
+        erfc_fraction(a)
+    };
+    if x < F::zero() {
+        lit::<F>(2.0) - y
+    } else {
+        y
+    }
+}
+
+/// Beyond this argument the Bessel functions use their asymptotic expansion.
+const BESSEL_ASYMPTOTIC: f64 = 25.0;
+
+/// Return `J_ν(x)` and `Y_ν(x)` of orders 0 and 1 for large `x` from the
+/// Hankel asymptotic expansion.
+fn bessel_asymptotic<F: Float>(order: u32, x: F) -> (F, F) {
+    let mu = lit::<F>(4.0 * (order * order) as f64);
+    let z = lit::<F>(8.0) * x;
+    let (mut p, mut q) = (F::one(), F::zero());
+    let mut term = F::one();
+    let mut k = 1;
+    loop {
+        let odd = lit::<F>((2 * k - 1) as f64);
+        let next = term * (mu - odd * odd) / (lit::<F>(k as f64) * z);
+        // The series diverges: stop at its smallest term.
+        if next.abs() >= term.abs() || next.is_zero() {
+            break;
+        }
+        term = next;
+        let sign = if (k / 2) % 2 == 0 { term } else { -term };
+        if k % 2 == 0 {
+            p = p + sign;
+        } else {
+            q = q + sign;
+        }
+        if term.abs() <= F::epsilon() * lit(1e-3) {
+            break;
+        }
+        k += 1;
+    }
+    // χ = x - (ν/2 + 1/4)π, expanded to keep the phase exact.
+    let (s, c) = x.sin_cos();
+    let h = lit::<F>(core::f64::consts::FRAC_1_SQRT_2);
+    let (cos_chi, sin_chi) = if order == 0 {
+        ((c + s) * h, (s - c) * h)
+    } else {
+        ((s - c) * h, -(s + c) * h)
+    };
+    let scale = (lit::<F>(2.0) / (pi::<F>() * x)).sqrt();
+    (
+        scale * (p * cos_chi - q * sin_chi),
+        scale * (p * sin_chi + q * cos_chi),
+    )
+}
+
+/// The Bessel functions of a positive `x` by Miller's backward recurrence.
+struct Miller<F> {
+    /// `J_n(x)` of the requested order.
+    jn: F,
+    j0: F,
+    j1: F,
+    /// `Σ (-1)^k J_2k(x) / k`, for `Y_0`.
+    y0_sum: F,
+    /// `Σ (-1)^k (J_2k-1(x) - J_2k+1(x)) / k`, for `Y_1`.
+    y1_sum: F,
+}
+
+fn bessel_miller<F: Float>(n: u32, x: F) -> Miller<F> {
+    let top = x.max(lit(n as f64));
+    let start = top + lit::<F>(30.0) + (lit::<F>(60.0) * top).sqrt();
+    let start = start.to_u32().unwrap_or(u32::MAX - 1) / 2 * 2 + 2;
+
+    let big = F::max_value().sqrt();
+    let two_over_x = lit::<F>(2.0) / x;
+    let (mut next, mut cur) = (F::zero(), F::min_positive_value().sqrt());
+    let (mut jn, mut j1) = (F::zero(), F::zero());
+    let (mut norm, mut y0_sum, mut y1_sum) = (F::zero(), F::zero(), F::zero());
+    for k in (1..=start).rev() {
+        // J_k-1 = 2k/x J_k - J_k+1
+        let prev = lit::<F>(k as f64) * two_over_x * cur - next;
+        if k % 2 == 0 {
+            let half = lit::<F>((k / 2) as f64);
+            let (j, d) = if (k / 2) % 2 == 0 {
+                (cur, prev - next)
+            } else {
+                (-cur, next - prev)
+            };
+            norm = norm + lit::<F>(2.0) * cur;
+            y0_sum = y0_sum + j / half;
+            y1_sum = y1_sum + d / half;
+        }
+        if k == n {
+            jn = cur;
+        }
+        if k == 1 {
+            j1 = cur;
+        }
+        next = cur;
+        cur = prev;
+        if cur.abs() > big {
+            let s = big.recip();
+            next = next * s;
+            cur = cur * s;
+            jn = jn * s;
+            j1 = j1 * s;
+            norm = norm * s;
+            y0_sum = y0_sum * s;
+            y1_sum = y1_sum * s;
+        }
+    }
+    let j0 = cur;
+    if n == 0 {
+        jn = j0;
+    }
+    let norm = (norm + j0).recip();
+    Miller {
+        jn: jn * norm,
+        j0: j0 * norm,
+        j1: j1 * norm,
+        y0_sum: y0_sum * norm,
+        y1_sum: y1_sum * norm,
+    }
+}
+
+/// Return the Bessel function of the first kind of order `n` of `x`.
+pub fn bessel_jn<F: Float>(n: i32, x: F) -> F {
+    // J_-n = (-1)^n J_n and J_n(-x) = (-1)^n J_n(x)
+    let odd = n % 2 != 0;
+    let sign = if odd && (n < 0) != (x < F::zero()) {
+        -F::one()
+    } else {
+        F::one()
+    };
+    let n = n.unsigned_abs();
+    let x = x.abs();
+    if x.is_nan() {
+        return x;
+    }
+    if x.is_zero() {
+        return if n == 0 { F::one() } else { F::zero() };
+    }
+    if x.is_infinite() {
+        return F::zero();
+    }
+    if x >= lit(BESSEL_ASYMPTOTIC) && lit::<F>(n as f64) < x {
+        let (j0, _) = bessel_asymptotic(0, x);
+        let (j1, _) = bessel_asymptotic(1, x);
+        // The forward recurrence is stable below the turning point n = x.
+        let (mut prev, mut cur) = (j0, j1);
+        if n == 0 {
+            return sign * j0;
+        }
+        for k in 1..n {
+            let next = lit::<F>(2.0 * k as f64) / x * cur - prev;
+            prev = cur;
+            cur = next;
+        }
+        return sign * cur;
+    }
+    sign * bessel_miller(n, x).jn
+}
+
+/// Return the Bessel function of the first kind of order 0 of `x`.
+pub fn bessel_j0<F: Float>(x: F) -> F {
+    bessel_jn(0, x)
+}
+
+/// Return the Bessel function of the first kind of order 1 of `x`.
+pub fn bessel_j1<F: Float>(x: F) -> F {
+    bessel_jn(1, x)
+}
+
+/// Return the Bessel functions of the second kind of orders 0 and 1 of `x`.
+fn bessel_y01<F: Float>(x: F) -> (F, F) {
+    if x >= lit(BESSEL_ASYMPTOTIC) {
+        return (bessel_asymptotic(0, x).1, bessel_asymptotic(1, x).1);
+    }
+    let m = bessel_miller(0, x);
+    let two_over_pi = lit::<F>(core::f64::consts::FRAC_2_PI);
+    let log = (x / lit(2.0)).ln() + lit(EULER_GAMMA);
+    // Neumann series of Y_0 and of its derivative -Y_1.
+    let y0 = two_over_pi * (log * m.j0 - lit::<F>(2.0) * m.y0_sum);
+    let y1 = two_over_pi * (log * m.j1 - m.j0 / x + m.y1_sum);
+    (y0, y1)
+}
+
+/// Return the Bessel function of the second kind of order `n` of `x`.
+///
+/// Returns negative infinity at zero and NaN for negative `x`.
+pub fn bessel_yn<F: Float>(n: i32, x: F) -> F {
+    if x.is_nan() || x < F::zero() {
+        return F::nan();
+    }
+    if x.is_zero() {
+        return F::neg_infinity();
+    }
+    if x.is_infinite() {
+        return F::zero();
+    }
+    // Y_-n = (-1)^n Y_n
+    let sign = if n < 0 && n % 2 != 0 {
+        -F::one()
+    } else {
+        F::one()
+    };
+    let n = n.unsigned_abs();
+    let (y0, y1) = bessel_y01(x);
+    if n == 0 {
+        return y0;
+    }
+    // The forward recurrence is stable for the functions of the second kind.
+    let (mut prev, mut cur) = (y0, y1);
+    for k in 1..n {
+        let next = lit::<F>(2.0 * k as f64) / x * cur - prev;
+        prev = cur;
+        cur = next;
+        if cur.is_infinite() {
+            break;
+        }
+    }
+    sign * cur
+}
+
+/// Return the Bessel function of the second kind of order 0 of `x`.
+pub fn bessel_y0<F: Float>(x: F) -> F {
+    bessel_yn(0, x)
+}
+
+/// Return the Bessel function of the second kind of order 1 of `x`.
+pub fn bessel_y1<F: Float>(x: F) -> F {
+    bessel_yn(1, x)
+}
+
+fn unit_step<F: Float>(edge0: F, edge1: F, x: F) -> F {
+    ((x - edge0) / (edge1 - edge0)).max(F::zero()).min(F::one())
+}
+
+/// Return the cubic Hermite interpolation of `x` between `edge0` and
+/// `edge1`, zero before `edge0` and one after `edge1`, like the GLSL
+/// function.
+pub fn smoothstep<F: Float>(edge0: F, edge1: F, x: F) -> F {
+    let t = unit_step(edge0, edge1, x);
+    t * t * (lit::<F>(3.0) - lit::<F>(2.0) * t)
+}
+
+/// Return the quintic interpolation of `x` between `edge0` and `edge1`,
+/// whose first and second derivatives vanish at the edges.
+pub fn smootherstep<F: Float>(edge0: F, edge1: F, x: F) -> F {
+    let t = unit_step(edge0, edge1, x);
+    t * t * t * (t * (t * lit(6.0) - lit(15.0)) + lit(10.0))
+}
@@ -1,5 +1,8 @@
 extern crate proc_macro;
 use proc_macro::TokenStream;
+use syn::{parse_macro_input, DeriveInput};
+
+mod vector_ops;
 
 #[proc_macro]
 pub fn fn_simple_as(ty: TokenStream) -> TokenStream {
@@ -49,3 +52,98 @@ pub fn fn_lower_bounded_as(toks: TokenStream) -> TokenStream {
     .parse()
     .unwrap()
 }
+
+/// Generate every swizzle accessor of two, three and four components, like
+/// `xy`, `zyx` or `xxzz`, from the given component names.
+/// Accessors of two components return a `Vector2<F>`, of three a `Vector<F>`
+/// and of four a `Vector4<F>`, so the macro must be used inside an
+/// `impl<F: Float>` block.
+#[proc_macro]
+pub fn swizzle(toks: TokenStream) -> TokenStream {
+    const USAGE_MSG: &str =
+        "Invalid usage, expected: swizzle!(component, component, ...) with up to 4 components";
+
+    let mut components = Vec::new();
+    let mut iter = toks.into_iter();
+    while let Some(tok) = iter.next() {
+        match tok {
+            proc_macro::TokenTree::Ident(ident) => components.push(ident.to_string()),
+            _ => std::panic::panic_any(USAGE_MSG),
+        }
+        match iter.next() {
+            None => break,
+            Some(proc_macro::TokenTree::Punct(p)) if p.as_char() == ',' => {}
+            Some(_) => std::panic::panic_any(USAGE_MSG),
+        }
+    }
+    if components.len() < 2 || components.len() > 4 {
+        std::panic::panic_any(USAGE_MSG);
+    }
+
+    let mut out = String::new();
+    for size in 2..=4 {
+        let ty = match size {
+            2 => "Vector2",
+            3 => "Vector",
+            _ => "Vector4",
+        };
+        // count in base `components.len()` to enumerate all the permutations with repetitions
+        let count = components.len().pow(size as u32);
+        for mut n in 0..count {
+            let mut picked = Vec::with_capacity(size);
+            for _ in 0..size {
+                picked.push(components[n % components.len()].as_str());
+                n /= components.len();
+            }
+            picked.reverse();
+
+            out += &format!(
+                "/// Return a `{ty}` made of the `{fields}` components.
+                #[inline]
+                pub fn {name}(self) -> {ty}<F> {{
+                    {ty}::new({args})
+                }}
+                ",
+                ty = ty,
+                fields = picked.join("`, `"),
+                name = picked.concat(),
+                args = picked
+                    .iter()
+                    .map(|c| format!("self.{}", c))
+                    .collect::<Vec<_>>()
+                    .join(", "),
+            );
+        }
+    }
+
+    out.parse().unwrap()
+}
+
+/// Derive the `Add`, `Sub`, `Mul`, `Div` and `Neg` operators, and their
+/// assigning counterparts, for a struct whose fields all share the same type.
+///
+/// Like the operators of `Vector`, they are implemented component-wise for
+/// owned, `&` and `&mut` receivers, with either another struct or a scalar
+/// of the field type as the right-hand side.
+///
+/// # Example
+/// ```
+/// use proc_vector::VectorOps;
+///
+/// #[derive(Copy, Clone, Debug, PartialEq, VectorOps)]
+/// struct Rgb<F> {
+///     r: F,
+///     g: F,
+///     b: F,
+/// }
+///
+/// let c = Rgb { r: 1.0, g: 0.5, b: 0.0 };
+/// assert_eq!(c * 0.5 + c, Rgb { r: 1.5, g: 0.75, b: 0.0 });
+/// ```
+#[proc_macro_derive(VectorOps)]
+pub fn derive_vector_ops(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    vector_ops::expand(input)
+        .unwrap_or_else(|err| err.to_compile_error())
+        .into()
+}
@@ -0,0 +1,187 @@
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+use syn::{spanned::Spanned, Data, DeriveInput, Error, Fields, Member, Result, Type};
+
+/// The binary operators to implement: trait, method and operator token.
+/// The `*Assign` traits and methods are derived from the first two.
+const BINARY_OPS: [(&str, &str, &str); 4] = [
+    ("Add", "add", "+"),
+    ("Sub", "sub", "-"),
+    ("Mul", "mul", "*"),
+    ("Div", "div", "/"),
+];
+
+/// Expand `#[derive(VectorOps)]` for the given struct.
+pub fn expand(input: DeriveInput) -> Result<TokenStream> {
+    let fields = match &input.data {
+        Data::Struct(data) => &data.fields,
+        Data::Enum(data) => {
+            return Err(Error::new(
+                data.enum_token.span(),
+                "VectorOps can only be derived for structs, not enums",
+            ))
+        }
+        Data::Union(data) => {
+            return Err(Error::new(
+                data.union_token.span(),
+                "VectorOps can only be derived for structs, not unions",
+            ))
+        }
+    };
+
+    if let Fields::Unit = fields {
+        return Err(Error::new(
+            input.ident.span(),
+            "VectorOps cannot be derived for unit structs, at least one field is required",
+        ));
+    }
+
+    let mut iter = fields.iter();
+    let scalar = match iter.next() {
+        Some(field) => &field.ty,
+        None => {
+            return Err(Error::new(
+                fields.span(),
+                "VectorOps cannot be derived for structs without fields",
+            ))
+        }
+    };
+    for field in iter {
+        if !same_type(&field.ty, scalar) {
+            return Err(Error::new(
+                field.ty.span(),
+                format!(
+                    "VectorOps requires all fields to have the same type, expected `{}`",
+                    quote!(#scalar)
+                ),
+            ));
+        }
+    }
+
+    let members: Vec<Member> = fields.members().collect();
+    let mut out = TokenStream::new();
+    for (name, method, op) in BINARY_OPS {
+        out.extend(binary(&input, scalar, &members, name, method, op));
+    }
+    out.extend(neg(&input, scalar, &members));
+    Ok(out)
+}
+
+/// Compare two types by their tokens, ignoring spans.
+fn same_type(a: &Type, b: &Type) -> bool {
+    quote!(#a).to_string() == quote!(#b).to_string()
+}
+
+fn binary(
+    input: &DeriveInput,
+    scalar: &Type,
+    members: &[Member],
+    name: &str,
+    method: &str,
+    op: &str,
+) -> TokenStream {
+    let ident = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    let predicates = where_clause.map(|w| &w.predicates);
+
+    let op_trait = format_ident!("{}", name);
+    let op_method = format_ident!("{}", method);
+    let assign_trait = format_ident!("{}Assign", name);
+    let assign_method = format_ident!("{}_assign", method);
+    let op: TokenStream = op.parse().unwrap();
+    let assign_op: TokenStream = format!("{}=", op).parse().unwrap();
+
+    let op_where = quote! {
+        where
+            #scalar: ::core::ops::#op_trait<#scalar, Output = #scalar> + Copy + Clone,
+            #predicates
+    };
+    let assign_where = quote! {
+        where
+            #scalar: ::core::ops::#assign_trait<#scalar> + Copy + Clone,
+            #predicates
+    };
+    let ty = quote!(#ident #ty_generics);
+
+    let mut out = TokenStream::new();
+    for (lhs, rhs) in [
+        (quote!(#ty), quote!(#ty)),
+        (quote!(&#ty), quote!(&#ty)),
+        (quote!(&mut #ty), quote!(&mut #ty)),
+    ] {
+        out.extend(quote! {
+            impl #impl_generics ::core::ops::#op_trait<#rhs> for #lhs
+            #op_where
+            {
+                type Output = #ty;
+
+                fn #op_method(self, rhs: #rhs) -> Self::Output {
+                    #ident {
+                        #(#members: self.#members #op rhs.#members,)*
+                    }
+                }
+            }
+
+            impl #impl_generics ::core::ops::#op_trait<#scalar> for #lhs
+            #op_where
+            {
+                type Output = #ty;
+
+                fn #op_method(self, rhs: #scalar) -> Self::Output {
+                    #ident {
+                        #(#members: self.#members #op rhs,)*
+                    }
+                }
+            }
+        });
+    }
+
+    for rhs in [quote!(#ty), quote!(&#ty), quote!(&mut #ty)] {
+        out.extend(quote! {
+            impl #impl_generics ::core::ops::#assign_trait<#rhs> for #ty
+            #assign_where
+            {
+                fn #assign_method(&mut self, rhs: #rhs) {
+                    #(self.#members #assign_op rhs.#members;)*
+                }
+            }
+        });
+    }
+    out.extend(quote! {
+        impl #impl_generics ::core::ops::#assign_trait<#scalar> for #ty
+        #assign_where
+        {
+            fn #assign_method(&mut self, rhs: #scalar) {
+                #(self.#members #assign_op rhs;)*
+            }
+        }
+    });
+    out
+}
+
+fn neg(input: &DeriveInput, scalar: &Type, members: &[Member]) -> TokenStream {
+    let ident = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    let predicates = where_clause.map(|w| &w.predicates);
+    let ty = quote!(#ident #ty_generics);
+
+    let mut out = TokenStream::new();
+    for lhs in [quote!(#ty), quote!(&#ty), quote!(&mut #ty)] {
+        out.extend(quote! {
+            impl #impl_generics ::core::ops::Neg for #lhs
+            where
+                #scalar: ::core::ops::Neg<Output = #scalar> + Copy + Clone,
+                #predicates
+            {
+                type Output = #ty;
+
+                fn neg(self) -> Self::Output {
+                    #ident {
+                        #(#members: -self.#members,)*
+                    }
+                }
+            }
+        });
+    }
+    out
+}
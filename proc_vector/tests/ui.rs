@@ -0,0 +1,7 @@
+//! Compile-fail tests of the derive macros.
+
+#[test]
+fn ui() {
+    let t = trybuild::TestCases::new();
+    t.compile_fail("tests/ui/*.rs");
+}
@@ -0,0 +1,9 @@
+use proc_vector::VectorOps;
+
+#[derive(VectorOps)]
+enum Direction {
+    Up,
+    Down,
+}
+
+fn main() {}
@@ -0,0 +1,9 @@
+use proc_vector::VectorOps;
+
+#[derive(VectorOps)]
+struct Mixed {
+    x: f32,
+    y: f64,
+}
+
+fn main() {}
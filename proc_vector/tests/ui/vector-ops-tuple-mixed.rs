@@ -0,0 +1,6 @@
+use proc_vector::VectorOps;
+
+#[derive(VectorOps)]
+struct Pair(f32, i32);
+
+fn main() {}
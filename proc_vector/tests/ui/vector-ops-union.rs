@@ -0,0 +1,9 @@
+use proc_vector::VectorOps;
+
+#[derive(VectorOps)]
+union Bits {
+    int: u32,
+    float: f32,
+}
+
+fn main() {}
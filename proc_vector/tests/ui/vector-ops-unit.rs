@@ -0,0 +1,6 @@
+use proc_vector::VectorOps;
+
+#[derive(VectorOps)]
+struct Unit;
+
+fn main() {}
@@ -0,0 +1,242 @@
+//! In-memory render targets.
+//!
+//! A [`Framebuffer`] has a color attachment, either of 8-bit or of floating
+//! point RGBA values, and an optional depth attachment. Its pixels are stored
+//! row by row from the top-left corner, and the [`image`](crate::image) module
+//! encodes them to image files.
+
+use alloc::vec;
+use alloc::vec::Vec;
+
+use crate::Vector4;
+
+/// The storage of the colors of a framebuffer.
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq, Hash)]
+pub enum ColorFormat {
+    /// 8 bits per channel, which quantizes colors to multiples of 1/255 in
+    /// `[0, 1]`.
+    Rgba8,
+    /// A 32-bit float per channel, which keeps colors unclamped.
+    #[default]
+    Rgba32F,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+enum ColorBuffer {
+    Rgba8(Vec<[u8; 4]>),
+    Rgba32F(Vec<Vector4<f32>>),
+}
+
+/// A color attachment and an optional depth attachment of the same size.
+///
+/// Depths go from 0 at the near plane to 1 at the far plane, and are cleared
+/// to 1.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Framebuffer {
+    width: usize,
+    height: usize,
+    color: ColorBuffer,
+    depth: Option<Vec<f32>>,
+}
+
+/// Return the 8-bit value of a color channel, clamped to `[0, 1]`.
+fn quantize(c: f32) -> u8 {
+    (c.clamp(0.0, 1.0) * 255.0 + 0.5) as u8
+}
+
+fn to_rgba8(c: Vector4<f32>) -> [u8; 4] {
+    [quantize(c.x), quantize(c.y), quantize(c.z), quantize(c.w)]
+}
+
+fn from_rgba8(c: [u8; 4]) -> Vector4<f32> {
+    let [r, g, b, a] = c.map(|c| c as f32 / 255.0);
+    Vector4::new(r, g, b, a)
+}
+
+impl Framebuffer {
+    /// Create a framebuffer with float colors and a depth attachment,
+    /// cleared to transparent black and to the far plane.
+    pub fn new(width: usize, height: usize) -> Self {
+        Self::with_attachments(width, height, ColorFormat::Rgba32F, true)
+    }
+
+    /// Create a framebuffer with colors stored in `format`, and a depth
+    /// attachment if `depth` is set.
+    pub fn with_attachments(width: usize, height: usize, format: ColorFormat, depth: bool) -> Self {
+        let len = width * height;
+        let color = match format {
+            ColorFormat::Rgba8 => ColorBuffer::Rgba8(vec![[0; 4]; len]),
+            ColorFormat::Rgba32F => {
+                ColorBuffer::Rgba32F(vec![Vector4::new(0.0, 0.0, 0.0, 0.0); len])
+            }
+        };
+        Self {
+            width,
+            height,
+            color,
+            depth: depth.then(|| vec![1.0; len]),
+        }
+    }
+
+    /// Create a framebuffer with 8-bit colors and no depth attachment from
+    /// RGBA bytes, row by row from the top-left corner.
+    ///
+    /// # Panics
+    ///
+    /// Panics if there are not `4 * width * height` bytes.
+    pub fn from_rgba8(width: usize, height: usize, bytes: &[u8]) -> Self {
+        assert_eq!(bytes.len(), 4 * width * height, "wrong number of bytes");
+        let pixels = bytes
+            .chunks_exact(4)
+            .map(|p| [p[0], p[1], p[2], p[3]])
+            .collect();
+        Self {
+            width,
+            height,
+            color: ColorBuffer::Rgba8(pixels),
+            depth: None,
+        }
+    }
+
+    /// Return the width in pixels.
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    /// Return the height in pixels.
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    /// Return the storage of the colors.
+    pub fn format(&self) -> ColorFormat {
+        match self.color {
+            ColorBuffer::Rgba8(_) => ColorFormat::Rgba8,
+            ColorBuffer::Rgba32F(_) => ColorFormat::Rgba32F,
+        }
+    }
+
+    /// Return whether the framebuffer has a depth attachment.
+    pub fn has_depth(&self) -> bool {
+        self.depth.is_some()
+    }
+
+    /// Fill the color attachment with `color`.
+    pub fn clear(&mut self, color: Vector4<f32>) {
+        match &mut self.color {
+            ColorBuffer::Rgba8(pixels) => pixels.fill(to_rgba8(color)),
+            ColorBuffer::Rgba32F(pixels) => pixels.fill(color),
+        }
+    }
+
+    /// Fill the depth attachment, if any, with `depth`.
+    pub fn clear_depth(&mut self, depth: f32) {
+        if let Some(buffer) = &mut self.depth {
+            buffer.fill(depth);
+        }
+    }
+
+    /// Return the color of the pixel at column `x` and row `y`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the pixel is outside of the framebuffer.
+    pub fn pixel(&self, x: usize, y: usize) -> Vector4<f32> {
+        self.get(self.index(x, y))
+    }
+
+    /// Set the color of the pixel at column `x` and row `y`, which is
+    /// quantized with 8-bit colors.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the pixel is outside of the framebuffer.
+    pub fn set_pixel(&mut self, x: usize, y: usize, color: Vector4<f32>) {
+        let i = self.index(x, y);
+        self.set(i, color);
+    }
+
+    /// Return the 8-bit color of the pixel at column `x` and row `y`, which
+    /// is clamped and rounded with float colors.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the pixel is outside of the framebuffer.
+    pub fn pixel_rgba8(&self, x: usize, y: usize) -> [u8; 4] {
+        let i = self.index(x, y);
+        match &self.color {
+            ColorBuffer::Rgba8(pixels) => pixels[i],
+            ColorBuffer::Rgba32F(pixels) => to_rgba8(pixels[i]),
+        }
+    }
+
+    /// Return the depth of the pixel at column `x` and row `y`, or `None`
+    /// without a depth attachment.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the pixel is outside of the framebuffer.
+    pub fn depth(&self, x: usize, y: usize) -> Option<f32> {
+        let i = self.index(x, y);
+        self.depth.as_ref().map(|depth| depth[i])
+    }
+
+    /// Set the depth of the pixel at column `x` and row `y`, if there is a
+    /// depth attachment.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the pixel is outside of the framebuffer.
+    pub fn set_depth(&mut self, x: usize, y: usize, depth: f32) {
+        let i = self.index(x, y);
+        if let Some(buffer) = &mut self.depth {
+            buffer[i] = depth;
+        }
+    }
+
+    /// Read back the colors, row by row from the top-left corner.
+    pub fn read_pixels(&self) -> Vec<Vector4<f32>> {
+        match &self.color {
+            ColorBuffer::Rgba8(pixels) => pixels.iter().map(|&c| from_rgba8(c)).collect(),
+            ColorBuffer::Rgba32F(pixels) => pixels.clone(),
+        }
+    }
+
+    /// Read back the colors as RGBA bytes, row by row from the top-left
+    /// corner.
+    pub fn read_rgba8(&self) -> Vec<u8> {
+        match &self.color {
+            ColorBuffer::Rgba8(pixels) => pixels.concat(),
+            ColorBuffer::Rgba32F(pixels) => pixels.iter().flat_map(|&c| to_rgba8(c)).collect(),
+        }
+    }
+
+    /// Return the depths, row by row from the top-left corner, or `None`
+    /// without a depth attachment.
+    pub fn read_depth(&self) -> Option<&[f32]> {
+        self.depth.as_deref()
+    }
+
+    fn index(&self, x: usize, y: usize) -> usize {
+        assert!(x < self.width && y < self.height, "pixel out of bounds");
+        y * self.width + x
+    }
+
+    pub(crate) fn get(&self, i: usize) -> Vector4<f32> {
+        match &self.color {
+            ColorBuffer::Rgba8(pixels) => from_rgba8(pixels[i]),
+            ColorBuffer::Rgba32F(pixels) => pixels[i],
+        }
+    }
+
+    pub(crate) fn set(&mut self, i: usize, color: Vector4<f32>) {
+        match &mut self.color {
+            ColorBuffer::Rgba8(pixels) => pixels[i] = to_rgba8(color),
+            ColorBuffer::Rgba32F(pixels) => pixels[i] = color,
+        }
+    }
+
+    pub(crate) fn depth_mut(&mut self) -> Option<&mut [f32]> {
+        self.depth.as_deref_mut()
+    }
+}
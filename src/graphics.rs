@@ -0,0 +1,543 @@
+//! An immediate-mode 2D drawing API in the style of Processing.
+//!
+//! A [`Graphics`] canvas draws shapes as soon as they are called, with its
+//! current [`Style`]: shapes are filled with the fill color, then outlined with
+//! the stroke color, the stroke weight, caps and joins. Coordinates are in
+//! pixels from the top-left corner, with `y` pointing down, and angles go
+//! clockwise from the `x` axis.
+//!
+//! Shapes are tessellated into triangles by the [`tessellate`] module, and
+//! drawn by the software rasterizer. Each fill and each stroke covers a pixel at
+//! most once, and is blended over the canvas with its alpha.
+//!
+//! ```
+//! use phoenyx::graphics::Graphics;
+//! use phoenyx::{Vector, Vector4};
+//!
+//! let mut g = Graphics::new(8, 8);
+//! g.background(Vector4::new(1.0, 1.0, 1.0, 1.0));
+//! g.no_stroke();
+//! g.fill(Vector4::new(1.0, 0.0, 0.0, 1.0));
+//! g.rect((2.0, 2.0), 4.0, 4.0);
+//! g.fill(Vector4::new(0.0, 0.0, 1.0, 0.5));
+//! g.ellipse(Vector::new(4.0, 4.0, 0.0), 8.0, 8.0);
+//! assert_eq!(g.canvas().pixel(3, 3), Vector4::new(0.5, 0.0, 0.5, 1.0));
+//! ```
+
+use alloc::vec::Vec;
+use core::f32::consts::TAU;
+
+use numeric::Float;
+
+use crate::framebuffer::Framebuffer;
+use crate::raster::{DepthTest, Rasterizer, Vertex};
+use crate::tessellate::{self, StrokeCap, StrokeJoin};
+use crate::{Matrix4, Vector, Vector2, Vector4};
+
+/// A point of the canvas: a `Vector`, whose `z` is ignored, a `Vector2`, or
+/// `(x, y)` or `[x, y]` coordinates.
+pub trait IntoPoint {
+    /// Return the point as a `Vector2`.
+    fn into_point(self) -> Vector2<f32>;
+}
+
+impl IntoPoint for Vector<f32> {
+    fn into_point(self) -> Vector2<f32> {
+        Vector2::new(self.x, self.y)
+    }
+}
+
+impl IntoPoint for Vector2<f32> {
+    fn into_point(self) -> Vector2<f32> {
+        self
+    }
+}
+
+impl IntoPoint for (f32, f32) {
+    fn into_point(self) -> Vector2<f32> {
+        Vector2::from(self)
+    }
+}
+
+impl IntoPoint for [f32; 2] {
+    fn into_point(self) -> Vector2<f32> {
+        Vector2::from(self)
+    }
+}
+
+/// How an arc is filled and outlined.
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq, Hash)]
+pub enum ArcMode {
+    /// Fill the region between the arc and its chord, and outline the arc.
+    Open,
+    /// Fill the region between the arc and its chord, and outline both.
+    Chord,
+    /// Fill the sector of the ellipse, and outline it.
+    #[default]
+    Pie,
+}
+
+/// How the vertices of a custom shape are connected.
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq, Hash)]
+pub enum ShapeKind {
+    /// A polygon through all the vertices.
+    #[default]
+    Polygon,
+    /// A point at each vertex.
+    Points,
+    /// A line between each pair of vertices.
+    Lines,
+    /// A triangle between each three vertices.
+    Triangles,
+    /// A triangle between the first vertex and each next pair of vertices.
+    TriangleFan,
+    /// A triangle between each three consecutive vertices.
+    TriangleStrip,
+    /// A quad between each four vertices.
+    Quads,
+    /// A quad between each two pairs of consecutive vertices.
+    QuadStrip,
+}
+
+/// The colors and the stroke settings which shapes are drawn with.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Style {
+    /// The color of the inside of shapes, if they are filled.
+    pub fill: Option<Vector4<f32>>,
+    /// The color of the outline of shapes, if they are stroked.
+    pub stroke: Option<Vector4<f32>>,
+    /// The width of outlines, in pixels.
+    pub stroke_weight: f32,
+    pub stroke_cap: StrokeCap,
+    pub stroke_join: StrokeJoin,
+}
+
+impl Default for Style {
+    /// Return a white fill with a black stroke of 1 pixel, round caps and
+    /// miter joins.
+    fn default() -> Self {
+        Self {
+            fill: Some(Vector4::new(1.0, 1.0, 1.0, 1.0)),
+            stroke: Some(Vector4::new(0.0, 0.0, 0.0, 1.0)),
+            stroke_weight: 1.0,
+            stroke_cap: StrokeCap::default(),
+            stroke_join: StrokeJoin::default(),
+        }
+    }
+}
+
+/// A canvas with a drawing style.
+#[derive(Clone, Debug)]
+pub struct Graphics {
+    canvas: Framebuffer,
+    style: Style,
+    shape: Option<(ShapeKind, Vec<Vector2<f32>>)>,
+    triangles: Vec<Vector2<f32>>,
+    covered: Vec<usize>,
+    mask: Vec<bool>,
+}
+
+impl Graphics {
+    /// Create a canvas with float colors and a depth attachment, cleared to
+    /// transparent black, with the default style.
+    pub fn new(width: usize, height: usize) -> Self {
+        Self::from_canvas(Framebuffer::new(width, height))
+    }
+
+    /// Draw into an existing framebuffer, with the default style.
+    pub fn from_canvas(canvas: Framebuffer) -> Self {
+        Self {
+            canvas,
+            style: Style::default(),
+            shape: None,
+            triangles: Vec::new(),
+            covered: Vec::new(),
+            mask: Vec::new(),
+        }
+    }
+
+    /// Return the width of the canvas in pixels.
+    pub fn width(&self) -> usize {
+        self.canvas.width()
+    }
+
+    /// Return the height of the canvas in pixels.
+    pub fn height(&self) -> usize {
+        self.canvas.height()
+    }
+
+    /// Return the canvas.
+    pub fn canvas(&self) -> &Framebuffer {
+        &self.canvas
+    }
+
+    /// Return the canvas, to draw into it.
+    pub fn canvas_mut(&mut self) -> &mut Framebuffer {
+        &mut self.canvas
+    }
+
+    /// Return the canvas, and drop the style.
+    pub fn into_canvas(self) -> Framebuffer {
+        self.canvas
+    }
+
+    /// Return the current style.
+    pub fn style(&self) -> &Style {
+        &self.style
+    }
+
+    /// Replace the current style, to restore a style saved with `style`.
+    pub fn set_style(&mut self, style: Style) {
+        self.style = style;
+    }
+
+    /// Fill the canvas with `color`, and clear its depth.
+    pub fn background(&mut self, color: Vector4<f32>) {
+        self.canvas.clear(color);
+        self.canvas.clear_depth(1.0);
+    }
+
+    /// Fill the next shapes with `color`.
+    pub fn fill(&mut self, color: Vector4<f32>) {
+        self.style.fill = Some(color);
+    }
+
+    /// Do not fill the next shapes.
+    pub fn no_fill(&mut self) {
+        self.style.fill = None;
+    }
+
+    /// Outline the next shapes with `color`.
+    pub fn stroke(&mut self, color: Vector4<f32>) {
+        self.style.stroke = Some(color);
+    }
+
+    /// Do not outline the next shapes.
+    pub fn no_stroke(&mut self) {
+        self.style.stroke = None;
+    }
+
+    /// Set the width of outlines, in pixels.
+    pub fn stroke_weight(&mut self, weight: f32) {
+        self.style.stroke_weight = weight;
+    }
+
+    /// Set the shape of the ends of lines and of points.
+    pub fn stroke_cap(&mut self, cap: StrokeCap) {
+        self.style.stroke_cap = cap;
+    }
+
+    /// Set the shape of the corners of outlines.
+    pub fn stroke_join(&mut self, join: StrokeJoin) {
+        self.style.stroke_join = join;
+    }
+
+    /// Draw a point with the stroke color, as a disc whose diameter is the
+    /// stroke weight, or as a square without round caps.
+    ///
+    /// Pixels are covered when their center is, so a point of weight 1 should
+    /// be at the center of a pixel, like `(x + 0.5, y + 0.5)`.
+    pub fn point(&mut self, p: impl IntoPoint) {
+        let p = p.into_point();
+        if let Some(color) = self.style.stroke {
+            self.dot(p);
+            self.paint(color);
+        }
+    }
+
+    /// Draw a line between two points, with the stroke color.
+    pub fn line(&mut self, a: impl IntoPoint, b: impl IntoPoint) {
+        let points = [a.into_point(), b.into_point()];
+        self.draw(&points, false, false);
+    }
+
+    /// Draw a rectangle from its top-left corner and its size.
+    pub fn rect(&mut self, corner: impl IntoPoint, width: f32, height: f32) {
+        let p = corner.into_point();
+        let points = [
+            p,
+            p + Vector2::new(width, 0.0),
+            p + Vector2::new(width, height),
+            p + Vector2::new(0.0, height),
+        ];
+        self.draw(&points, true, true);
+    }
+
+    /// Draw an ellipse from its center and its size.
+    pub fn ellipse(&mut self, center: impl IntoPoint, width: f32, height: f32) {
+        let mut points = Vec::new();
+        let radii = Vector2::new(width, height) * 0.5;
+        tessellate::ellipse(center.into_point(), radii, &mut points);
+        self.draw(&points, true, true);
+    }
+
+    /// Draw the arc of an ellipse from its center and its size, from angle
+    /// `start` to angle `stop` in radians.
+    ///
+    /// Arcs of a full turn or more are drawn as ellipses.
+    pub fn arc(
+        &mut self,
+        center: impl IntoPoint,
+        width: f32,
+        height: f32,
+        start: f32,
+        stop: f32,
+        mode: ArcMode,
+    ) {
+        let center = center.into_point();
+        let sweep = stop - start;
+        if sweep >= TAU {
+            return self.ellipse(center, width, height);
+        }
+        let sweep = sweep - TAU * Float::floor(sweep / TAU);
+        let mut points = Vec::new();
+        tessellate::arc(
+            center,
+            Vector2::new(width, height) * 0.5,
+            start,
+            start + sweep,
+            &mut points,
+        );
+        match mode {
+            ArcMode::Open => self.draw(&points, true, false),
+            ArcMode::Chord => self.draw(&points, true, true),
+            ArcMode::Pie => {
+                points.push(center);
+                self.draw(&points, true, true);
+            }
+        }
+    }
+
+    /// Draw a triangle.
+    pub fn triangle(&mut self, a: impl IntoPoint, b: impl IntoPoint, c: impl IntoPoint) {
+        let points = [a.into_point(), b.into_point(), c.into_point()];
+        self.draw(&points, true, true);
+    }
+
+    /// Draw a quadrilateral, whose corners go around it.
+    pub fn quad(
+        &mut self,
+        a: impl IntoPoint,
+        b: impl IntoPoint,
+        c: impl IntoPoint,
+        d: impl IntoPoint,
+    ) {
+        let points = [
+            a.into_point(),
+            b.into_point(),
+            c.into_point(),
+            d.into_point(),
+        ];
+        self.draw(&points, true, true);
+    }
+
+    /// Draw a cubic Bézier curve from `p0` to `p1` with control points `c0`
+    /// and `c1`, filled as if it were closed by a line.
+    pub fn bezier(
+        &mut self,
+        p0: impl IntoPoint,
+        c0: impl IntoPoint,
+        c1: impl IntoPoint,
+        p1: impl IntoPoint,
+    ) {
+        let p0 = p0.into_point();
+        let mut points = alloc::vec![p0];
+        tessellate::cubic_bezier(
+            p0,
+            c0.into_point(),
+            c1.into_point(),
+            p1.into_point(),
+            &mut points,
+        );
+        self.draw(&points, true, false);
+    }
+
+    /// Start a custom shape, whose vertices are added with `vertex` and
+    /// `bezier_vertex` until `end_shape`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if a shape is already started.
+    pub fn begin_shape(&mut self, kind: ShapeKind) {
+        assert!(self.shape.is_none(), "begin_shape called twice");
+        self.shape = Some((kind, Vec::new()));
+    }
+
+    /// Add a vertex to the custom shape.
+    ///
+    /// # Panics
+    ///
+    /// Panics if no shape is started.
+    pub fn vertex(&mut self, p: impl IntoPoint) {
+        let (_, vertices) = self
+            .shape
+            .as_mut()
+            .expect("vertex called outside of a shape");
+        vertices.push(p.into_point());
+    }
+
+    /// Add the vertices of a cubic Bézier curve to the custom shape, from its
+    /// last vertex to `p` with control points `c0` and `c1`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if no shape is started, or if the shape has no vertex yet.
+    pub fn bezier_vertex(&mut self, c0: impl IntoPoint, c1: impl IntoPoint, p: impl IntoPoint) {
+        let (_, vertices) = self
+            .shape
+            .as_mut()
+            .expect("bezier_vertex called outside of a shape");
+        let &p0 = vertices.last().expect("bezier_vertex called before vertex");
+        tessellate::cubic_bezier(
+            p0,
+            c0.into_point(),
+            c1.into_point(),
+            p.into_point(),
+            vertices,
+        );
+    }
+
+    /// Draw the custom shape, and close its outline back to the first vertex
+    /// if `close` is set and it is a polygon.
+    ///
+    /// # Panics
+    ///
+    /// Panics if no shape is started.
+    pub fn end_shape(&mut self, close: bool) {
+        let (kind, vertices) = self
+            .shape
+            .take()
+            .expect("end_shape called without begin_shape");
+        let v = &vertices;
+        let (fill, stroke) = (self.style.fill, self.style.stroke);
+        let (weight, cap, join) = (
+            self.style.stroke_weight,
+            self.style.stroke_cap,
+            self.style.stroke_join,
+        );
+        match kind {
+            ShapeKind::Polygon => self.draw(v, true, close),
+            ShapeKind::Points => {
+                if let Some(color) = stroke {
+                    v.iter().for_each(|&p| self.dot(p));
+                    self.paint(color);
+                }
+            }
+            ShapeKind::Lines => {
+                if let Some(color) = stroke {
+                    for pair in v.chunks_exact(2) {
+                        tessellate::polyline(pair, false, weight, cap, join, &mut self.triangles);
+                    }
+                    self.paint(color);
+                }
+            }
+            _ => {
+                let pieces: Vec<Vec<Vector2<f32>>> = match kind {
+                    ShapeKind::Triangles => v.chunks_exact(3).map(<[_]>::to_vec).collect(),
+                    ShapeKind::TriangleFan if v.len() >= 3 => v[1..]
+                        .windows(2)
+                        .map(|w| alloc::vec![v[0], w[0], w[1]])
+                        .collect(),
+                    ShapeKind::TriangleStrip => v.windows(3).map(<[_]>::to_vec).collect(),
+                    ShapeKind::Quads => v.chunks_exact(4).map(<[_]>::to_vec).collect(),
+                    ShapeKind::QuadStrip => v
+                        .windows(4)
+                        .step_by(2)
+                        .map(|w| alloc::vec![w[0], w[1], w[3], w[2]])
+                        .collect(),
+                    _ => Vec::new(),
+                };
+                if let Some(color) = fill {
+                    for piece in &pieces {
+                        tessellate::polygon(piece, &mut self.triangles);
+                    }
+                    self.paint(color);
+                }
+                if let Some(color) = stroke {
+                    for piece in &pieces {
+                        tessellate::polyline(piece, true, weight, cap, join, &mut self.triangles);
+                    }
+                    self.paint(color);
+                }
+            }
+        }
+    }
+
+    /// Fill the polygon through `points` if `filled`, then outline it, closed
+    /// back to the first point if `closed`.
+    fn draw(&mut self, points: &[Vector2<f32>], filled: bool, closed: bool) {
+        let style = self.style;
+        if let (Some(color), true) = (style.fill, filled) {
+            tessellate::polygon(points, &mut self.triangles);
+            self.paint(color);
+        }
+        if let Some(color) = style.stroke {
+            tessellate::polyline(
+                points,
+                closed,
+                style.stroke_weight,
+                style.stroke_cap,
+                style.stroke_join,
+                &mut self.triangles,
+            );
+            self.paint(color);
+        }
+    }
+
+    /// Append the triangles of a point drawn with the stroke.
+    fn dot(&mut self, p: Vector2<f32>) {
+        let cap = match self.style.stroke_cap {
+            StrokeCap::Round => StrokeCap::Round,
+            _ => StrokeCap::Square,
+        };
+        let join = self.style.stroke_join;
+        tessellate::polyline(
+            &[p],
+            false,
+            self.style.stroke_weight,
+            cap,
+            join,
+            &mut self.triangles,
+        );
+    }
+
+    /// Blend `color` once over every pixel covered by the pending triangles,
+    /// and clear them.
+    fn paint(&mut self, color: Vector4<f32>) {
+        let (width, height) = (self.canvas.width(), self.canvas.height());
+        let rasterizer = Rasterizer {
+            transform: Matrix4::orthographic(0.0, width as f32, height as f32, 0.0, -1.0, 1.0),
+            depth_test: DepthTest::Always,
+            depth_write: false,
+            ..Rasterizer::default()
+        };
+        let vertices: Vec<Vertex> = self
+            .triangles
+            .drain(..)
+            .map(|p| Vertex::new(p.extend(0.0), color))
+            .collect();
+
+        // Collect the covered pixels first, as the triangles of a shape may
+        // overlap.
+        let (covered, mask) = (&mut self.covered, &mut self.mask);
+        mask.resize(width * height, false);
+        rasterizer.draw_triangles_with(&mut self.canvas, &vertices, |f| {
+            let i = f.y * width + f.x;
+            if !mask[i] {
+                mask[i] = true;
+                covered.push(i);
+            }
+            None
+        });
+
+        let alpha = color.w;
+        for i in self.covered.drain(..) {
+            self.mask[i] = false;
+            let (x, y) = (i % width, i / width);
+            let below = self.canvas.pixel(x, y);
+            let mut blended = below.lerp(color, alpha);
+            blended.w = alpha + below.w * (1.0 - alpha);
+            self.canvas.set_pixel(x, y, blended);
+        }
+    }
+}
@@ -0,0 +1,747 @@
+//! Image file encoding and decoding for framebuffers.
+//!
+//! Framebuffers encode to binary PPM, which drops the alpha channel, to
+//! uncompressed 32-bit TGA, and to RGBA PNG whose zlib stream is made of
+//! stored blocks. The PNG decoder supports every non-interlaced image, so that
+//! reference images written by other tools can be compared with renders.
+//!
+//! ```
+//! use phoenyx::framebuffer::{ColorFormat, Framebuffer};
+//! use phoenyx::Vector4;
+//!
+//! let mut image = Framebuffer::with_attachments(3, 2, ColorFormat::Rgba8, false);
+//! image.set_pixel(1, 0, Vector4::new(1.0, 0.5, 0.0, 1.0));
+//! let png = image.encode_png();
+//! assert_eq!(Framebuffer::decode_png(&png), Ok(image));
+//! ```
+
+use alloc::format;
+use alloc::vec;
+use alloc::vec::Vec;
+use core::fmt;
+
+use crate::framebuffer::Framebuffer;
+
+const PNG_SIGNATURE: [u8; 8] = [0x89, b'P', b'N', b'G', b'\r', b'\n', 0x1a, b'\n'];
+
+impl Framebuffer {
+    /// Encode the colors to a binary PPM image, without their alpha.
+    pub fn encode_ppm(&self) -> Vec<u8> {
+        let mut out = format!("P6\n{} {}\n255\n", self.width(), self.height()).into_bytes();
+        for pixel in self.read_rgba8().chunks_exact(4) {
+            out.extend_from_slice(&pixel[..3]);
+        }
+        out
+    }
+
+    /// Encode the colors to an uncompressed 32-bit TGA image.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the width or the height is larger than 65535.
+    pub fn encode_tga(&self) -> Vec<u8> {
+        let dimension = |n: usize| u16::try_from(n).expect("image too large for TGA");
+        let (width, height) = (dimension(self.width()), dimension(self.height()));
+        // Uncompressed true-color, without an identifier or a color map.
+        let mut out = vec![0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 0, 0];
+        out.extend_from_slice(&width.to_le_bytes());
+        out.extend_from_slice(&height.to_le_bytes());
+        // 32 bits per pixel, 8 of which are alpha, with rows from the top.
+        out.extend_from_slice(&[32, 0x28]);
+        for pixel in self.read_rgba8().chunks_exact(4) {
+            out.extend_from_slice(&[pixel[2], pixel[1], pixel[0], pixel[3]]);
+        }
+        out
+    }
+
+    /// Encode the colors to an 8-bit RGBA PNG image, without compression.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the framebuffer is empty, or larger than the `2^31 - 1`
+    /// pixels a PNG allows on each side.
+    pub fn encode_png(&self) -> Vec<u8> {
+        let dimension = |n: usize| match u32::try_from(n) {
+            Ok(n) if n > 0 && n < 1 << 31 => n,
+            _ => panic!("image size not supported by PNG"),
+        };
+        let (width, height) = (dimension(self.width()), dimension(self.height()));
+        let mut raw = Vec::with_capacity((4 * self.width() + 1) * self.height());
+        for row in self.read_rgba8().chunks_exact(4 * self.width()) {
+            // No filter.
+            raw.push(0);
+            raw.extend_from_slice(row);
+        }
+
+        let mut header = Vec::with_capacity(13);
+        header.extend_from_slice(&width.to_be_bytes());
+        header.extend_from_slice(&height.to_be_bytes());
+        // 8 bits per sample, RGBA, deflate, adaptive filters, no interlace.
+        header.extend_from_slice(&[8, 6, 0, 0, 0]);
+
+        let mut out = PNG_SIGNATURE.to_vec();
+        write_chunk(&mut out, b"IHDR", &header);
+        write_chunk(&mut out, b"IDAT", &zlib_stored(&raw));
+        write_chunk(&mut out, b"IEND", &[]);
+        out
+    }
+
+    /// Decode a PNG image to a framebuffer with 8-bit colors and no depth
+    /// attachment.
+    ///
+    /// Samples of 16 bits are rounded to 8 bits, and the transparency of
+    /// `tRNS` chunks is applied. Interlaced images are not supported.
+    pub fn decode_png(data: &[u8]) -> Result<Self, DecodePngError> {
+        let rest = data
+            .strip_prefix(&PNG_SIGNATURE)
+            .ok_or(error(PngErrorKind::Signature))?;
+        let png = read_chunks(rest)?;
+        let header = png.header;
+        let raw = zlib_decompress(&png.data)?;
+        let rows = unfilter(&raw, &header)?;
+
+        let (width, height) = (header.width, header.height);
+        let mut pixels = Vec::with_capacity(4 * width * height);
+        for row in rows.chunks_exact(header.stride()) {
+            for i in 0..width {
+                let pixel = match header.color_type {
+                    ColorType::Gray => {
+                        let v = header.sample(row, i);
+                        let alpha = png.transparent.as_deref() == Some(&[v][..]);
+                        let g = header.scale(v);
+                        [g, g, g, if alpha { 0 } else { 255 }]
+                    }
+                    ColorType::Rgb => {
+                        let v = [0, 1, 2].map(|c| header.sample(row, 3 * i + c));
+                        let alpha = png.transparent.as_deref() == Some(&v[..]);
+                        let [r, g, b] = v.map(|v| header.scale(v));
+                        [r, g, b, if alpha { 0 } else { 255 }]
+                    }
+                    ColorType::Indexed => {
+                        let index = header.sample(row, i) as usize;
+                        let [r, g, b] =
+                            *png.palette.get(index).ok_or(error(PngErrorKind::Invalid))?;
+                        let alpha = png.palette_alpha.get(index).copied();
+                        [r, g, b, alpha.unwrap_or(255)]
+                    }
+                    ColorType::GrayAlpha => {
+                        let [g, a] = [0, 1].map(|c| header.scale(header.sample(row, 2 * i + c)));
+                        [g, g, g, a]
+                    }
+                    ColorType::Rgba => {
+                        [0, 1, 2, 3].map(|c| header.scale(header.sample(row, 4 * i + c)))
+                    }
+                };
+                pixels.extend_from_slice(&pixel);
+            }
+        }
+        Ok(Framebuffer::from_rgba8(width, height, &pixels))
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PngErrorKind {
+    Signature,
+    Truncated,
+    Checksum,
+    Invalid,
+    Unsupported,
+    TooLarge,
+}
+
+/// An error which can be returned when decoding a PNG image.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DecodePngError {
+    pub kind: PngErrorKind,
+}
+
+fn error(kind: PngErrorKind) -> DecodePngError {
+    DecodePngError { kind }
+}
+
+impl fmt::Display for DecodePngError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self.kind {
+            PngErrorKind::Signature => "missing PNG signature".fmt(f),
+            PngErrorKind::Truncated => "unexpected end of PNG data".fmt(f),
+            PngErrorKind::Checksum => "PNG checksum mismatch".fmt(f),
+            PngErrorKind::Invalid => "invalid PNG data".fmt(f),
+            PngErrorKind::Unsupported => "unsupported PNG feature".fmt(f),
+            PngErrorKind::TooLarge => "PNG image too large".fmt(f),
+        }
+    }
+}
+
+// --- Checksums ---
+
+const CRC_TABLE: [u32; 256] = crc_table();
+
+const fn crc_table() -> [u32; 256] {
+    let mut table = [0; 256];
+    let mut n = 0;
+    while n < 256 {
+        let mut c = n as u32;
+        let mut k = 0;
+        while k < 8 {
+            c = if c & 1 == 1 {
+                0xedb8_8320 ^ (c >> 1)
+            } else {
+                c >> 1
+            };
+            k += 1;
+        }
+        table[n] = c;
+        n += 1;
+    }
+    table
+}
+
+fn crc32(data: &[u8]) -> u32 {
+    !data.iter().fold(!0, |c, &b| {
+        CRC_TABLE[((c ^ b as u32) & 0xff) as usize] ^ (c >> 8)
+    })
+}
+
+fn adler32(data: &[u8]) -> u32 {
+    const MOD: u32 = 65521;
+    let (mut a, mut b) = (1, 0);
+    // The sums cannot overflow within 5552 bytes.
+    for block in data.chunks(5552) {
+        for &byte in block {
+            a += byte as u32;
+            b += a;
+        }
+        a %= MOD;
+        b %= MOD;
+    }
+    b << 16 | a
+}
+
+// --- Encoding ---
+
+fn write_chunk(out: &mut Vec<u8>, kind: &[u8; 4], data: &[u8]) {
+    let len = u32::try_from(data.len()).expect("PNG chunk too large");
+    out.extend_from_slice(&len.to_be_bytes());
+    let start = out.len();
+    out.extend_from_slice(kind);
+    out.extend_from_slice(data);
+    let crc = crc32(&out[start..]);
+    out.extend_from_slice(&crc.to_be_bytes());
+}
+
+/// Wrap `data` in a zlib stream of stored deflate blocks.
+fn zlib_stored(data: &[u8]) -> Vec<u8> {
+    let mut out = Vec::with_capacity(data.len() + data.len() / 65535 * 5 + 11);
+    // Deflate with a 32K window, and the fastest compression level.
+    out.extend_from_slice(&[0x78, 0x01]);
+    let mut blocks = data.chunks(65535).peekable();
+    if blocks.peek().is_none() {
+        out.extend_from_slice(&[1, 0, 0, 0xff, 0xff]);
+    }
+    while let Some(block) = blocks.next() {
+        let last = blocks.peek().is_none();
+        let len = block.len() as u16;
+        out.push(last as u8);
+        out.extend_from_slice(&len.to_le_bytes());
+        out.extend_from_slice(&(!len).to_le_bytes());
+        out.extend_from_slice(block);
+    }
+    out.extend_from_slice(&adler32(data).to_be_bytes());
+    out
+}
+
+// --- Decoding ---
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+enum ColorType {
+    Gray,
+    Rgb,
+    Indexed,
+    GrayAlpha,
+    Rgba,
+}
+
+/// The largest width and height of PNG images.
+const MAX_SIZE: usize = (1 << 31) - 1;
+
+#[derive(Copy, Clone, Debug)]
+struct Header {
+    width: usize,
+    height: usize,
+    depth: usize,
+    color_type: ColorType,
+}
+
+impl Header {
+    fn parse(data: &[u8]) -> Result<Self, DecodePngError> {
+        let invalid = error(PngErrorKind::Invalid);
+        if data.len() != 13 {
+            return Err(invalid);
+        }
+        let be32 = |i: usize| u32::from_be_bytes([data[i], data[i + 1], data[i + 2], data[i + 3]]);
+        let (width, height) = (be32(0) as usize, be32(4) as usize);
+        let depth = data[8] as usize;
+        let (color_type, depths): (_, &[usize]) = match data[9] {
+            0 => (ColorType::Gray, &[1, 2, 4, 8, 16]),
+            2 => (ColorType::Rgb, &[8, 16]),
+            3 => (ColorType::Indexed, &[1, 2, 4, 8]),
+            4 => (ColorType::GrayAlpha, &[8, 16]),
+            6 => (ColorType::Rgba, &[8, 16]),
+            _ => return Err(invalid),
+        };
+        let size = 1..=MAX_SIZE;
+        if !size.contains(&width)
+            || !size.contains(&height)
+            || !depths.contains(&depth)
+            || data[10] != 0
+            || data[11] != 0
+        {
+            return Err(invalid);
+        }
+        let header = match data[12] {
+            0 => Self {
+                width,
+                height,
+                depth,
+                color_type,
+            },
+            // Adam7 interlacing.
+            1 => return Err(error(PngErrorKind::Unsupported)),
+            _ => return Err(invalid),
+        };
+        // The filtered rows and the pixels must fit in memory, and then so
+        // does every offset into them.
+        let raw = width
+            .checked_mul(header.channels() * depth)
+            .and_then(|bits| (bits.div_ceil(8) + 1).checked_mul(height));
+        let pixels = width.checked_mul(height).and_then(|n| n.checked_mul(4));
+        if raw.is_none() || pixels.is_none() {
+            return Err(error(PngErrorKind::TooLarge));
+        }
+        Ok(header)
+    }
+
+    fn channels(&self) -> usize {
+        match self.color_type {
+            ColorType::Gray | ColorType::Indexed => 1,
+            ColorType::GrayAlpha => 2,
+            ColorType::Rgb => 3,
+            ColorType::Rgba => 4,
+        }
+    }
+
+    /// Return the number of bytes of a row, without its filter type.
+    fn stride(&self) -> usize {
+        (self.width * self.channels() * self.depth).div_ceil(8)
+    }
+
+    /// Return the `i`-th sample of a row.
+    fn sample(&self, row: &[u8], i: usize) -> u16 {
+        match self.depth {
+            8 => row[i] as u16,
+            16 => u16::from_be_bytes([row[2 * i], row[2 * i + 1]]),
+            depth => {
+                let bit = i * depth;
+                let shift = 8 - depth - bit % 8;
+                (row[bit / 8] >> shift) as u16 & ((1 << depth) - 1)
+            }
+        }
+    }
+
+    /// Scale a color or alpha sample to 8 bits.
+    fn scale(&self, v: u16) -> u8 {
+        let max = (1u32 << self.depth) - 1;
+        ((v as u32 * 255 + max / 2) / max) as u8
+    }
+}
+
+/// The chunks of a PNG image which matter for decoding it.
+struct Png {
+    header: Header,
+    palette: Vec<[u8; 3]>,
+    /// The alpha values of the first entries of the palette.
+    palette_alpha: Vec<u8>,
+    /// The samples of the transparent gray level or RGB color.
+    transparent: Option<Vec<u16>>,
+    /// The zlib stream of the concatenated `IDAT` chunks.
+    data: Vec<u8>,
+}
+
+fn read_chunks(mut rest: &[u8]) -> Result<Png, DecodePngError> {
+    let mut header = None;
+    let mut palette = Vec::new();
+    let mut trns = None;
+    let mut data = Vec::new();
+    loop {
+        if rest.len() < 12 {
+            return Err(error(PngErrorKind::Truncated));
+        }
+        let len = u32::from_be_bytes([rest[0], rest[1], rest[2], rest[3]]) as usize;
+        if rest.len() - 12 < len {
+            return Err(error(PngErrorKind::Truncated));
+        }
+        let (chunk, crc) = rest[4..12 + len].split_at(4 + len);
+        if crc32(chunk).to_be_bytes() != crc {
+            return Err(error(PngErrorKind::Checksum));
+        }
+        let (kind, body) = chunk.split_at(4);
+        rest = &rest[12 + len..];
+        match kind {
+            b"IHDR" if header.is_none() => header = Some(Header::parse(body)?),
+            _ if header.is_none() => return Err(error(PngErrorKind::Invalid)),
+            b"PLTE" => {
+                if body.len() % 3 != 0 || body.len() > 3 * 256 {
+                    return Err(error(PngErrorKind::Invalid));
+                }
+                palette = body.chunks_exact(3).map(|c| [c[0], c[1], c[2]]).collect();
+            }
+            b"tRNS" => trns = Some(body.to_vec()),
+            b"IDAT" => data.extend_from_slice(body),
+            b"IEND" => break,
+            // Ancillary chunks have a lowercase first letter.
+            _ if kind[0].is_ascii_lowercase() => {}
+            _ => return Err(error(PngErrorKind::Unsupported)),
+        }
+    }
+    let header = header.ok_or(error(PngErrorKind::Invalid))?;
+    if header.color_type == ColorType::Indexed && palette.is_empty() {
+        return Err(error(PngErrorKind::Invalid));
+    }
+    let (palette_alpha, transparent) = match (header.color_type, trns) {
+        (ColorType::Indexed, Some(alpha)) => (alpha, None),
+        (ColorType::Gray | ColorType::Rgb, Some(key)) => {
+            if key.len() != 2 * header.channels() {
+                return Err(error(PngErrorKind::Invalid));
+            }
+            let key = key
+                .chunks_exact(2)
+                .map(|c| u16::from_be_bytes([c[0], c[1]]));
+            (Vec::new(), Some(key.collect()))
+        }
+        _ => (Vec::new(), None),
+    };
+    Ok(Png {
+        header,
+        palette,
+        palette_alpha,
+        transparent,
+        data,
+    })
+}
+
+/// Undo the filters of the rows, and return them without their filter types.
+fn unfilter(raw: &[u8], header: &Header) -> Result<Vec<u8>, DecodePngError> {
+    let stride = header.stride();
+    if raw.len() < (stride + 1) * header.height {
+        return Err(error(PngErrorKind::Truncated));
+    }
+    // The distance to the corresponding byte of the previous pixel.
+    let bpp = (header.channels() * header.depth).div_ceil(8);
+    let mut rows = vec![0; stride * header.height];
+    for y in 0..header.height {
+        let line = &raw[y * (stride + 1)..(y + 1) * (stride + 1)];
+        let (previous, current) = rows.split_at_mut(y * stride);
+        let up = if y > 0 {
+            &previous[(y - 1) * stride..]
+        } else {
+            &[][..]
+        };
+        let row = &mut current[..stride];
+        for i in 0..stride {
+            let a = if i >= bpp { row[i - bpp] } else { 0 };
+            let b = up.get(i).copied().unwrap_or(0);
+            let c = if i >= bpp {
+                up.get(i - bpp).copied().unwrap_or(0)
+            } else {
+                0
+            };
+            let predictor = match line[0] {
+                0 => 0,
+                1 => a,
+                2 => b,
+                3 => ((a as u16 + b as u16) / 2) as u8,
+                4 => paeth(a, b, c),
+                _ => return Err(error(PngErrorKind::Invalid)),
+            };
+            row[i] = line[1 + i].wrapping_add(predictor);
+        }
+    }
+    Ok(rows)
+}
+
+fn paeth(a: u8, b: u8, c: u8) -> u8 {
+    let p = a as i16 + b as i16 - c as i16;
+    let (pa, pb, pc) = (
+        (p - a as i16).abs(),
+        (p - b as i16).abs(),
+        (p - c as i16).abs(),
+    );
+    if pa <= pb && pa <= pc {
+        a
+    } else if pb <= pc {
+        b
+    } else {
+        c
+    }
+}
+
+fn zlib_decompress(data: &[u8]) -> Result<Vec<u8>, DecodePngError> {
+    if data.len() < 2 {
+        return Err(error(PngErrorKind::Truncated));
+    }
+    let (cmf, flg) = (data[0], data[1]);
+    if cmf & 0x0f != 8 || cmf >> 4 > 7 || !(cmf as u16 * 256 + flg as u16).is_multiple_of(31) {
+        return Err(error(PngErrorKind::Invalid));
+    }
+    if flg & 0x20 != 0 {
+        // A preset dictionary.
+        return Err(error(PngErrorKind::Unsupported));
+    }
+    let mut bits = Bits::new(&data[2..]);
+    let out = inflate(&mut bits)?;
+    let end = 2 + bits.aligned_position();
+    let checksum = data
+        .get(end..end + 4)
+        .ok_or(error(PngErrorKind::Truncated))?;
+    if adler32(&out).to_be_bytes() != checksum {
+        return Err(error(PngErrorKind::Checksum));
+    }
+    Ok(out)
+}
+
+/// A reader of the bits of a deflate stream, from the least significant bit
+/// of each byte.
+struct Bits<'a> {
+    data: &'a [u8],
+    position: usize,
+    bit: u32,
+}
+
+impl<'a> Bits<'a> {
+    fn new(data: &'a [u8]) -> Self {
+        Self {
+            data,
+            position: 0,
+            bit: 0,
+        }
+    }
+
+    fn read(&mut self, n: u32) -> Result<u32, DecodePngError> {
+        let mut value = 0;
+        for i in 0..n {
+            let byte = self
+                .data
+                .get(self.position)
+                .ok_or(error(PngErrorKind::Truncated))?;
+            value |= ((byte >> self.bit) as u32 & 1) << i;
+            self.bit += 1;
+            if self.bit == 8 {
+                self.bit = 0;
+                self.position += 1;
+            }
+        }
+        Ok(value)
+    }
+
+    /// Skip to the next byte boundary, and return its position.
+    fn aligned_position(&mut self) -> usize {
+        if self.bit > 0 {
+            self.bit = 0;
+            self.position += 1;
+        }
+        self.position
+    }
+
+    fn bytes(&mut self, n: usize) -> Result<&'a [u8], DecodePngError> {
+        let start = self.aligned_position();
+        let bytes = self
+            .data
+            .get(start..start + n)
+            .ok_or(error(PngErrorKind::Truncated))?;
+        self.position += n;
+        Ok(bytes)
+    }
+}
+
+/// A canonical Huffman code, given by the number of codes of each length and
+/// the symbols ordered by code.
+struct Huffman {
+    counts: [u16; 16],
+    symbols: Vec<u16>,
+}
+
+impl Huffman {
+    fn new(lengths: &[u8]) -> Result<Self, DecodePngError> {
+        let mut counts = [0u16; 16];
+        for &len in lengths {
+            counts[len as usize] += 1;
+        }
+        counts[0] = 0;
+        let mut left = 1i32;
+        for &count in &counts[1..] {
+            left = 2 * left - count as i32;
+            if left < 0 {
+                return Err(error(PngErrorKind::Invalid));
+            }
+        }
+        let mut offsets = [0u16; 16];
+        for len in 1..15 {
+            offsets[len + 1] = offsets[len] + counts[len];
+        }
+        let mut symbols = vec![0; lengths.len()];
+        for (symbol, &len) in lengths.iter().enumerate() {
+            if len != 0 {
+                symbols[offsets[len as usize] as usize] = symbol as u16;
+                offsets[len as usize] += 1;
+            }
+        }
+        Ok(Self { counts, symbols })
+    }
+
+    fn decode(&self, bits: &mut Bits) -> Result<u16, DecodePngError> {
+        // The codes of each length are consecutive and follow those of the
+        // shorter lengths, shifted left.
+        let (mut code, mut first, mut index) = (0i32, 0i32, 0i32);
+        for &count in &self.counts[1..] {
+            code |= bits.read(1)? as i32;
+            let count = count as i32;
+            if code - first < count {
+                return Ok(self.symbols[(index + code - first) as usize]);
+            }
+            index += count;
+            first = (first + count) << 1;
+            code <<= 1;
+        }
+        Err(error(PngErrorKind::Invalid))
+    }
+}
+
+const LENGTH_BASE: [u16; 29] = [
+    3, 4, 5, 6, 7, 8, 9, 10, 11, 13, 15, 17, 19, 23, 27, 31, 35, 43, 51, 59, 67, 83, 99, 115, 131,
+    163, 195, 227, 258,
+];
+const LENGTH_EXTRA: [u8; 29] = [
+    0, 0, 0, 0, 0, 0, 0, 0, 1, 1, 1, 1, 2, 2, 2, 2, 3, 3, 3, 3, 4, 4, 4, 4, 5, 5, 5, 5, 0,
+];
+const DISTANCE_BASE: [u16; 30] = [
+    1, 2, 3, 4, 5, 7, 9, 13, 17, 25, 33, 49, 65, 97, 129, 193, 257, 385, 513, 769, 1025, 1537,
+    2049, 3073, 4097, 6145, 8193, 12289, 16385, 24577,
+];
+const DISTANCE_EXTRA: [u8; 30] = [
+    0, 0, 0, 0, 1, 1, 2, 2, 3, 3, 4, 4, 5, 5, 6, 6, 7, 7, 8, 8, 9, 9, 10, 10, 11, 11, 12, 12, 13,
+    13,
+];
+
+/// Decompress a raw deflate stream.
+fn inflate(bits: &mut Bits) -> Result<Vec<u8>, DecodePngError> {
+    let mut out = Vec::new();
+    loop {
+        let last = bits.read(1)? == 1;
+        match bits.read(2)? {
+            0 => {
+                let header = bits.bytes(4)?;
+                let len = u16::from_le_bytes([header[0], header[1]]);
+                if !len != u16::from_le_bytes([header[2], header[3]]) {
+                    return Err(error(PngErrorKind::Invalid));
+                }
+                out.extend_from_slice(bits.bytes(len as usize)?);
+            }
+            1 => {
+                let mut lengths = [8; 288];
+                lengths[144..256].fill(9);
+                lengths[256..280].fill(7);
+                let literals = Huffman::new(&lengths)?;
+                let distances = Huffman::new(&[5; 30])?;
+                inflate_block(bits, &mut out, &literals, &distances)?;
+            }
+            2 => {
+                let (literals, distances) = read_dynamic_codes(bits)?;
+                inflate_block(bits, &mut out, &literals, &distances)?;
+            }
+            _ => return Err(error(PngErrorKind::Invalid)),
+        }
+        if last {
+            return Ok(out);
+        }
+    }
+}
+
+fn read_dynamic_codes(bits: &mut Bits) -> Result<(Huffman, Huffman), DecodePngError> {
+    const ORDER: [usize; 19] = [
+        16, 17, 18, 0, 8, 7, 9, 6, 10, 5, 11, 4, 12, 3, 13, 2, 14, 1, 15,
+    ];
+    let invalid = error(PngErrorKind::Invalid);
+    let literals = bits.read(5)? as usize + 257;
+    let distances = bits.read(5)? as usize + 1;
+    let code_lengths = bits.read(4)? as usize + 4;
+    if literals > 286 || distances > 30 {
+        return Err(invalid);
+    }
+    let mut lengths = [0; 19];
+    for &i in &ORDER[..code_lengths] {
+        lengths[i] = bits.read(3)? as u8;
+    }
+    let code = Huffman::new(&lengths)?;
+
+    let mut lengths = vec![0u8; literals + distances];
+    let mut i = 0;
+    while i < lengths.len() {
+        let (value, repeat) = match code.decode(bits)? {
+            len @ 0..=15 => (len as u8, 1),
+            16 if i > 0 => (lengths[i - 1], 3 + bits.read(2)? as usize),
+            17 => (0, 3 + bits.read(3)? as usize),
+            18 => (0, 11 + bits.read(7)? as usize),
+            _ => return Err(invalid),
+        };
+        if i + repeat > lengths.len() {
+            return Err(invalid);
+        }
+        lengths[i..i + repeat].fill(value);
+        i += repeat;
+    }
+    if lengths[256] == 0 {
+        return Err(invalid);
+    }
+    let (literal_lengths, distance_lengths) = lengths.split_at(literals);
+    Ok((
+        Huffman::new(literal_lengths)?,
+        Huffman::new(distance_lengths)?,
+    ))
+}
+
+fn inflate_block(
+    bits: &mut Bits,
+    out: &mut Vec<u8>,
+    literals: &Huffman,
+    distances: &Huffman,
+) -> Result<(), DecodePngError> {
+    let invalid = error(PngErrorKind::Invalid);
+    loop {
+        let symbol = literals.decode(bits)? as usize;
+        match symbol {
+            0..=255 => out.push(symbol as u8),
+            256 => return Ok(()),
+            _ => {
+                let i = symbol - 257;
+                if i >= LENGTH_BASE.len() {
+                    return Err(invalid);
+                }
+                let len = LENGTH_BASE[i] as usize + bits.read(LENGTH_EXTRA[i] as u32)? as usize;
+                let i = distances.decode(bits)? as usize;
+                if i >= DISTANCE_BASE.len() {
+                    return Err(invalid);
+                }
+                let distance =
+                    DISTANCE_BASE[i] as usize + bits.read(DISTANCE_EXTRA[i] as u32)? as usize;
+                if distance > out.len() {
+                    return Err(invalid);
+                }
+                // The copy may overlap the bytes it produces.
+                let start = out.len() - distance;
+                for k in 0..len {
+                    out.push(out[start + k]);
+                }
+            }
+        }
+    }
+}
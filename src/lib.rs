@@ -1,3 +1,28 @@
 //! # Phoenyx
+//!
+//! Builds without the standard library when the default `std` feature is
+//! disabled and `libm` is enabled.
 
-pub use math_vector::Vector;
+#![no_std]
+#[cfg(feature = "alloc")]
+extern crate alloc;
+#[cfg(feature = "std")]
+extern crate std;
+
+#[cfg(feature = "alloc")]
+pub mod framebuffer;
+#[cfg(feature = "alloc")]
+pub mod graphics;
+#[cfg(feature = "alloc")]
+pub mod image;
+#[cfg(feature = "alloc")]
+pub mod raster;
+#[cfg(feature = "alloc")]
+pub mod sketch;
+#[cfg(feature = "alloc")]
+pub mod tessellate;
+
+pub use math_vector::{
+    IntVector, IntVector2, IntVector3, Matrix3, Matrix4, Precision, Quaternion, Rounding,
+    Transform, Vector, Vector2, Vector3, Vector4,
+};
@@ -0,0 +1,468 @@
+//! A headless software rasterizer.
+//!
+//! The rasterizer draws triangles, lines and points into a [`Framebuffer`]
+//! without a GPU, which makes it the reference backend of the rendering tests.
+//! It follows the OpenGL conventions of `Matrix4::perspective`: positions are
+//! transformed to clip space, clipped against the near and far planes, and
+//! mapped to the framebuffer with `y` pointing down.
+//!
+//! Triangles are sampled at pixel centers with the top-left fill rule, so that
+//! triangles sharing an edge cover each pixel exactly once, and colors are
+//! interpolated with perspective correction.
+//!
+//! ```
+//! use phoenyx::framebuffer::Framebuffer;
+//! use phoenyx::raster::{Rasterizer, Vertex};
+//! use phoenyx::{Vector, Vector4};
+//!
+//! let red = Vector4::new(1.0, 0.0, 0.0, 1.0);
+//! let triangle = [
+//!     Vertex::new(Vector::new(-1.0, -1.0, 0.0), red),
+//!     Vertex::new(Vector::new(1.0, -1.0, 0.0), red),
+//!     Vertex::new(Vector::new(-1.0, 1.0, 0.0), red),
+//! ];
+//! let mut target = Framebuffer::new(4, 4);
+//! Rasterizer::new().draw_triangles(&mut target, &triangle);
+//! assert_eq!(target.pixel(0, 3), red);
+//! assert_eq!(target.pixel(3, 0), Vector4::new(0.0, 0.0, 0.0, 0.0));
+//! ```
+
+use core::ops::Range;
+
+use numeric::Float;
+
+use crate::framebuffer::Framebuffer;
+use crate::{Matrix4, Vector, Vector4};
+
+/// The fraction of a pixel to which window coordinates are snapped, so that
+/// the edge functions of triangles sharing an edge are exact opposites.
+const SUBPIXEL: f64 = 256.0;
+
+/// A vertex, made of a position and of an RGBA color.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Vertex {
+    pub position: Vector<f32>,
+    pub color: Vector4<f32>,
+}
+
+impl Vertex {
+    /// Create a new vertex.
+    pub fn new(position: Vector<f32>, color: Vector4<f32>) -> Self {
+        Self { position, color }
+    }
+}
+
+/// A pixel covered by a primitive, with its interpolated attributes.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Fragment {
+    pub x: usize,
+    pub y: usize,
+    /// The window depth, from 0 at the near plane to 1 at the far plane.
+    pub depth: f32,
+    pub color: Vector4<f32>,
+}
+
+/// The triangles to discard according to their orientation.
+///
+/// Front faces are counter-clockwise in normalized device coordinates, as in
+/// OpenGL.
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq)]
+pub enum CullMode {
+    #[default]
+    None,
+    Back,
+    Front,
+}
+
+/// The comparison between the depth of a fragment and the stored one which
+/// lets the fragment through.
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq)]
+pub enum DepthTest {
+    Always,
+    Never,
+    #[default]
+    Less,
+    LessEqual,
+    Equal,
+    GreaterEqual,
+    Greater,
+}
+
+impl DepthTest {
+    /// Return whether a fragment at `depth` passes the test against `stored`.
+    pub fn passes(self, depth: f32, stored: f32) -> bool {
+        match self {
+            DepthTest::Always => true,
+            DepthTest::Never => false,
+            DepthTest::Less => depth < stored,
+            DepthTest::LessEqual => depth <= stored,
+            DepthTest::Equal => depth == stored,
+            DepthTest::GreaterEqual => depth >= stored,
+            DepthTest::Greater => depth > stored,
+        }
+    }
+}
+
+/// The state of the rasterizer.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Rasterizer {
+    /// The transformation from vertex positions to clip space, usually a
+    /// projection times a view times a model matrix.
+    pub transform: Matrix4<f32>,
+    pub cull: CullMode,
+    pub depth_test: DepthTest,
+    /// Whether the fragments which pass the depth test write their depth.
+    pub depth_write: bool,
+    /// The side of the square drawn for each point, in pixels.
+    pub point_size: f32,
+}
+
+impl Default for Rasterizer {
+    fn default() -> Self {
+        Self {
+            transform: Matrix4::identity(),
+            cull: CullMode::None,
+            depth_test: DepthTest::Less,
+            depth_write: true,
+            point_size: 1.0,
+        }
+    }
+}
+
+/// A vertex in clip space.
+#[derive(Copy, Clone, Debug)]
+struct ClipVertex {
+    position: Vector4<f32>,
+    color: Vector4<f32>,
+}
+
+impl ClipVertex {
+    fn lerp(self, other: Self, t: f32) -> Self {
+        Self {
+            position: self.position.lerp(other.position, t),
+            color: self.color.lerp(other.color, t),
+        }
+    }
+
+    /// Return the signed distances to the near and far planes, which are
+    /// non-negative inside the view volume.
+    fn distances(&self) -> [f32; 2] {
+        let p = self.position;
+        [p.w + p.z, p.w - p.z]
+    }
+}
+
+/// A vertex in window coordinates, with its color divided by `w` so that it
+/// can be interpolated linearly.
+#[derive(Copy, Clone, Debug)]
+struct WindowVertex {
+    x: f64,
+    y: f64,
+    z: f64,
+    inv_w: f64,
+    color: Vector4<f32>,
+}
+
+impl Rasterizer {
+    /// Create a rasterizer with an identity transform, no culling, and a
+    /// `Less` depth test.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Draw a list of triangles, made of each three consecutive vertices.
+    pub fn draw_triangles(&self, target: &mut Framebuffer, vertices: &[Vertex]) {
+        self.draw_triangles_with(target, vertices, |f| Some(f.color));
+    }
+
+    /// Draw a list of triangles, coloring their fragments with `shader`,
+    /// which may discard them by returning `None`.
+    ///
+    /// The shader runs after the depth test, and only the fragments it keeps
+    /// write their depth.
+    pub fn draw_triangles_with<S>(
+        &self,
+        target: &mut Framebuffer,
+        vertices: &[Vertex],
+        mut shader: S,
+    ) where
+        S: FnMut(&Fragment) -> Option<Vector4<f32>>,
+    {
+        for triangle in vertices.chunks_exact(3) {
+            let mut polygon = [self.clip_vertex(triangle[0]); 9];
+            polygon[1] = self.clip_vertex(triangle[1]);
+            polygon[2] = self.clip_vertex(triangle[2]);
+            let len = clip_polygon(&mut polygon, 3);
+            if len < 3 {
+                continue;
+            }
+            let window = polygon.map(|v| to_window(v, target));
+            for i in 1..len - 1 {
+                self.fill_triangle(target, [window[0], window[i], window[i + 1]], &mut shader);
+            }
+        }
+    }
+
+    /// Draw a list of line segments, made of each two consecutive vertices.
+    pub fn draw_lines(&self, target: &mut Framebuffer, vertices: &[Vertex]) {
+        self.draw_lines_with(target, vertices, |f| Some(f.color));
+    }
+
+    /// Draw a list of line segments, coloring their fragments with `shader`.
+    ///
+    /// Segments are one pixel wide, and cover the pixels whose centers they
+    /// cross along their major axis, excluding their end.
+    pub fn draw_lines_with<S>(&self, target: &mut Framebuffer, vertices: &[Vertex], mut shader: S)
+    where
+        S: FnMut(&Fragment) -> Option<Vector4<f32>>,
+    {
+        for segment in vertices.chunks_exact(2) {
+            let (a, b) = (self.clip_vertex(segment[0]), self.clip_vertex(segment[1]));
+            if let Some((a, b)) = clip_segment(a, b) {
+                let (a, b) = (to_window(a, target), to_window(b, target));
+                self.draw_segment(target, a, b, &mut shader);
+            }
+        }
+    }
+
+    /// Draw a list of points, as squares of `point_size` pixels.
+    pub fn draw_points(&self, target: &mut Framebuffer, vertices: &[Vertex]) {
+        self.draw_points_with(target, vertices, |f| Some(f.color));
+    }
+
+    /// Draw a list of points, coloring their fragments with `shader`.
+    pub fn draw_points_with<S>(&self, target: &mut Framebuffer, vertices: &[Vertex], mut shader: S)
+    where
+        S: FnMut(&Fragment) -> Option<Vector4<f32>>,
+    {
+        let half = self.point_size as f64 / 2.0;
+        for &vertex in vertices {
+            let v = self.clip_vertex(vertex);
+            if v.distances().iter().any(|&d| d < 0.0) {
+                continue;
+            }
+            let p = to_window(v, target);
+            for y in centers(p.y - half, p.y + half, target.height()) {
+                for x in centers(p.x - half, p.x + half, target.width()) {
+                    let depth = p.z as f32;
+                    let fragment = Fragment {
+                        x,
+                        y,
+                        depth,
+                        color: vertex.color,
+                    };
+                    self.shade(target, fragment, &mut shader);
+                }
+            }
+        }
+    }
+
+    fn clip_vertex(&self, vertex: Vertex) -> ClipVertex {
+        ClipVertex {
+            position: self.transform * vertex.position.extend(1.0),
+            color: vertex.color,
+        }
+    }
+
+    fn fill_triangle<S>(&self, target: &mut Framebuffer, mut v: [WindowVertex; 3], shader: &mut S)
+    where
+        S: FnMut(&Fragment) -> Option<Vector4<f32>>,
+    {
+        let snap = |x: f64| Float::round(x * SUBPIXEL) / SUBPIXEL;
+        let mut p = v.map(|v| (snap(v.x), snap(v.y)));
+        let mut area = edge(p[0], p[1], p[2]);
+        if area == 0.0 || !area.is_finite() {
+            return;
+        }
+        // The viewport flips `y`, so front faces, counter-clockwise in
+        // normalized device coordinates, are counter-clockwise in window
+        // coordinates too, where `edge` gives them a negative area.
+        let front = area < 0.0;
+        match self.cull {
+            CullMode::Back if !front => return,
+            CullMode::Front if front => return,
+            _ => {}
+        }
+        if area < 0.0 {
+            v.swap(1, 2);
+            p.swap(1, 2);
+            area = -area;
+        }
+
+        let xs = p.map(|p| p.0);
+        let ys = p.map(|p| p.1);
+        let min = |a: [f64; 3]| a[0].min(a[1]).min(a[2]);
+        let max = |a: [f64; 3]| a[0].max(a[1]).max(a[2]);
+        for y in centers(min(ys), max(ys), target.height()) {
+            for x in centers(min(xs), max(xs), target.width()) {
+                let c = (x as f64 + 0.5, y as f64 + 0.5);
+                let w = [
+                    edge(p[1], p[2], c),
+                    edge(p[2], p[0], c),
+                    edge(p[0], p[1], c),
+                ];
+                let inside = (0..3).all(|i| {
+                    w[i] > 0.0 || w[i] == 0.0 && is_top_left(p[(i + 1) % 3], p[(i + 2) % 3])
+                });
+                if !inside {
+                    continue;
+                }
+                let b = w.map(|w| w / area);
+                let depth = b[0] * v[0].z + b[1] * v[1].z + b[2] * v[2].z;
+                let inv_w = b[0] * v[0].inv_w + b[1] * v[1].inv_w + b[2] * v[2].inv_w;
+                let color =
+                    v[0].color * b[0] as f32 + v[1].color * b[1] as f32 + v[2].color * b[2] as f32;
+                let fragment = Fragment {
+                    x,
+                    y,
+                    depth: depth as f32,
+                    color: color * (1.0 / inv_w) as f32,
+                };
+                self.shade(target, fragment, shader);
+            }
+        }
+    }
+
+    fn draw_segment<S>(
+        &self,
+        target: &mut Framebuffer,
+        a: WindowVertex,
+        b: WindowVertex,
+        shader: &mut S,
+    ) where
+        S: FnMut(&Fragment) -> Option<Vector4<f32>>,
+    {
+        let (dx, dy) = (b.x - a.x, b.y - a.y);
+        let x_major = dx.abs() >= dy.abs();
+        let (start, delta, size) = if x_major {
+            (a.x, dx, target.width())
+        } else {
+            (a.y, dy, target.height())
+        };
+        if delta == 0.0 || !delta.is_finite() {
+            return;
+        }
+        // Cover the centers up to and including `hi`, then exclude the end
+        // vertex by its parameter, whichever direction the segment goes.
+        let (lo, hi) = (start.min(start + delta), start.max(start + delta));
+        let range = centers(lo, hi, size);
+        for i in range.start..(range.end + 1).min(size) {
+            let t = (i as f64 + 0.5 - start) / delta;
+            if !(0.0..1.0).contains(&t) {
+                continue;
+            }
+            let (x, y) = if x_major {
+                (i as f64, a.y + t * dy)
+            } else {
+                (a.x + t * dx, i as f64)
+            };
+            if x < 0.0 || y < 0.0 || x >= target.width() as f64 || y >= target.height() as f64 {
+                continue;
+            }
+            let inv_w = a.inv_w + t * (b.inv_w - a.inv_w);
+            let fragment = Fragment {
+                x: x as usize,
+                y: y as usize,
+                depth: (a.z + t * (b.z - a.z)) as f32,
+                color: a.color.lerp(b.color, t as f32) * (1.0 / inv_w) as f32,
+            };
+            self.shade(target, fragment, shader);
+        }
+    }
+
+    /// Run the depth test and the shader on a fragment, and write the result.
+    ///
+    /// Without a depth attachment, every fragment passes the depth test.
+    fn shade<S>(&self, target: &mut Framebuffer, fragment: Fragment, shader: &mut S)
+    where
+        S: FnMut(&Fragment) -> Option<Vector4<f32>>,
+    {
+        let i = fragment.y * target.width() + fragment.x;
+        if let Some(depth) = target.depth_mut() {
+            if !self.depth_test.passes(fragment.depth, depth[i]) {
+                return;
+            }
+        }
+        if let Some(color) = shader(&fragment) {
+            target.set(i, color);
+            if let (Some(depth), true) = (target.depth_mut(), self.depth_write) {
+                depth[i] = fragment.depth;
+            }
+        }
+    }
+}
+
+/// Clip the convex polygon made of the first `len` vertices against the near
+/// and far planes, in place, and return the new number of vertices.
+fn clip_polygon(polygon: &mut [ClipVertex; 9], mut len: usize) -> usize {
+    for plane in 0..2 {
+        let input = *polygon;
+        let mut out = 0;
+        for i in 0..len {
+            let (a, b) = (input[i], input[(i + 1) % len]);
+            let (da, db) = (a.distances()[plane], b.distances()[plane]);
+            if da >= 0.0 {
+                polygon[out] = a;
+                out += 1;
+            }
+            if (da >= 0.0) != (db >= 0.0) {
+                polygon[out] = a.lerp(b, da / (da - db));
+                out += 1;
+            }
+        }
+        len = out;
+    }
+    len
+}
+
+/// Clip a segment against the near and far planes.
+fn clip_segment(a: ClipVertex, b: ClipVertex) -> Option<(ClipVertex, ClipVertex)> {
+    let (mut t0, mut t1) = (0.0f32, 1.0f32);
+    for plane in 0..2 {
+        let (da, db) = (a.distances()[plane], b.distances()[plane]);
+        if da < 0.0 && db < 0.0 {
+            return None;
+        } else if da < 0.0 {
+            t0 = t0.max(da / (da - db));
+        } else if db < 0.0 {
+            t1 = t1.min(da / (da - db));
+        }
+    }
+    (t0 <= t1).then(|| (a.lerp(b, t0), a.lerp(b, t1)))
+}
+
+/// Divide by `w` and map normalized device coordinates to the framebuffer.
+fn to_window(v: ClipVertex, target: &Framebuffer) -> WindowVertex {
+    let p = v.position;
+    let inv_w = 1.0 / p.w as f64;
+    WindowVertex {
+        x: (p.x as f64 * inv_w + 1.0) * 0.5 * target.width() as f64,
+        y: (1.0 - p.y as f64 * inv_w) * 0.5 * target.height() as f64,
+        z: (p.z as f64 * inv_w + 1.0) * 0.5,
+        inv_w,
+        color: v.color * inv_w as f32,
+    }
+}
+
+/// Return twice the signed area of the triangle `a`, `b`, `c`, which is
+/// positive when it is clockwise in window coordinates.
+fn edge(a: (f64, f64), b: (f64, f64), c: (f64, f64)) -> f64 {
+    (b.0 - a.0) * (c.1 - a.1) - (b.1 - a.1) * (c.0 - a.0)
+}
+
+/// Return whether the edge from `a` to `b` of a clockwise triangle is a top
+/// or a left edge, whose pixel centers belong to the triangle.
+fn is_top_left(a: (f64, f64), b: (f64, f64)) -> bool {
+    let (dx, dy) = (b.0 - a.0, b.1 - a.1);
+    dy < 0.0 || dy == 0.0 && dx > 0.0
+}
+
+/// Return the pixels of a row or column of `size` pixels whose centers are in
+/// `[lo, hi)`.
+fn centers(lo: f64, hi: f64, size: usize) -> Range<usize> {
+    let clamp = |x: f64| Float::ceil(x - 0.5).max(0.0).min(size as f64) as usize;
+    if lo.is_nan() || hi.is_nan() {
+        return 0..0;
+    }
+    clamp(lo)..clamp(hi)
+}
@@ -0,0 +1,407 @@
+//! A runtime for Processing-style sketches.
+//!
+//! A [`Sketch`] is set up once, then drawn frame after frame into the canvas
+//! of a [`Context`], which also tells it about time and input like the globals
+//! of p5.js: `frameCount`, `deltaTime`, `millis()`, `mouseX`, `key`...
+//!
+//! A [`Runtime`] drives a sketch either in real time at its target frame rate,
+//! with [`Runtime::run`], or headless with a simulated clock, with
+//! [`Runtime::run_headless`], so that sketches can be tested without a window.
+//! Input events are queued with [`Runtime::push_event`] and dispatched to the
+//! sketch before the next frame.
+//!
+//! ```
+//! use phoenyx::sketch::{Context, Runtime, Sketch};
+//! use phoenyx::Vector4;
+//!
+//! struct Fade;
+//!
+//! impl Sketch for Fade {
+//!     fn draw(&mut self, ctx: &mut Context) {
+//!         let gray = ctx.frame_count() as f32 / 10.0;
+//!         ctx.background(Vector4::new(gray, gray, gray, 1.0));
+//!     }
+//! }
+//!
+//! let mut runtime = Runtime::new(4, 4);
+//! let canvas = runtime.run_headless(&mut Fade, 5);
+//! assert_eq!(canvas.pixel(0, 0), Vector4::new(0.5, 0.5, 0.5, 1.0));
+//! assert_eq!(runtime.context().frame_count(), 5);
+//! ```
+
+use alloc::collections::VecDeque;
+use alloc::vec::Vec;
+use core::ops::{Deref, DerefMut};
+
+use crate::framebuffer::Framebuffer;
+use crate::graphics::Graphics;
+use crate::Vector;
+
+/// The target frame rate of a new runtime, in frames per second.
+pub const DEFAULT_FRAME_RATE: f64 = 60.0;
+
+/// A key of the keyboard.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub enum Key {
+    /// A key which types a character, in the case it is typed with.
+    Char(char),
+    Enter,
+    Escape,
+    Backspace,
+    Delete,
+    Tab,
+    Up,
+    Down,
+    Left,
+    Right,
+    Shift,
+    Control,
+    Alt,
+}
+
+/// A button of the mouse.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub enum MouseButton {
+    Left,
+    Right,
+    Middle,
+}
+
+/// An input event, dispatched to the matching hook of a sketch.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum Event {
+    KeyPressed(Key),
+    KeyReleased(Key),
+    MousePressed(MouseButton),
+    MouseReleased(MouseButton),
+    /// The mouse moved to a position in pixels from the top-left corner of the
+    /// canvas.
+    MouseMoved(Vector<f32>),
+}
+
+/// A Processing-style sketch.
+///
+/// Every hook but [`draw`](Sketch::draw) does nothing by default.
+pub trait Sketch {
+    /// Called once before the first frame.
+    fn setup(&mut self, _ctx: &mut Context) {}
+
+    /// Called on every frame while the sketch is looping.
+    fn draw(&mut self, ctx: &mut Context);
+
+    /// Called when a key is pressed, after `ctx.key()` is updated.
+    fn key_pressed(&mut self, _ctx: &mut Context) {}
+
+    /// Called when a key is released, after `ctx.key()` is updated.
+    fn key_released(&mut self, _ctx: &mut Context) {}
+
+    /// Called when a mouse button is pressed, after `ctx.mouse_button()` is
+    /// updated.
+    fn mouse_pressed(&mut self, _ctx: &mut Context) {}
+
+    /// Called when a mouse button is released, after `ctx.mouse_button()` is
+    /// updated.
+    fn mouse_released(&mut self, _ctx: &mut Context) {}
+
+    /// Called when the mouse moves while no button is pressed.
+    fn mouse_moved(&mut self, _ctx: &mut Context) {}
+
+    /// Called when the mouse moves while a button is pressed.
+    fn mouse_dragged(&mut self, _ctx: &mut Context) {}
+}
+
+/// The canvas, the clock and the input state of a running sketch.
+///
+/// The context dereferences to its [`Graphics`] canvas, so that sketches draw
+/// with `ctx.line(...)` like Processing sketches do.
+#[derive(Clone, Debug)]
+pub struct Context {
+    graphics: Graphics,
+    frame_count: u64,
+    delta_time: f64,
+    millis: f64,
+    frame_rate: f64,
+    looping: bool,
+    redraw: bool,
+    exit: bool,
+    key: Option<Key>,
+    keys_down: Vec<Key>,
+    mouse: Vector<f32>,
+    pmouse: Vector<f32>,
+    mouse_button: Option<MouseButton>,
+    buttons_down: Vec<MouseButton>,
+}
+
+impl Context {
+    fn new(width: usize, height: usize) -> Self {
+        Self {
+            graphics: Graphics::new(width, height),
+            frame_count: 0,
+            delta_time: 0.0,
+            millis: 0.0,
+            frame_rate: DEFAULT_FRAME_RATE,
+            looping: true,
+            redraw: false,
+            exit: false,
+            key: None,
+            keys_down: Vec::new(),
+            mouse: Vector::zero(),
+            pmouse: Vector::zero(),
+            mouse_button: None,
+            buttons_down: Vec::new(),
+        }
+    }
+
+    /// Return the number of frames drawn so far, which is 0 during setup and 1
+    /// during the first frame.
+    pub fn frame_count(&self) -> u64 {
+        self.frame_count
+    }
+
+    /// Return the time between the start of the previous frame and of this
+    /// one, in milliseconds.
+    pub fn delta_time(&self) -> f64 {
+        self.delta_time
+    }
+
+    /// Return the time since the setup, in milliseconds.
+    pub fn millis(&self) -> f64 {
+        self.millis
+    }
+
+    /// Return the target frame rate, in frames per second.
+    pub fn frame_rate(&self) -> f64 {
+        self.frame_rate
+    }
+
+    /// Set the target frame rate, in frames per second.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `fps` is not positive and finite.
+    pub fn set_frame_rate(&mut self, fps: f64) {
+        assert!(fps > 0.0 && fps.is_finite(), "frame rate must be positive");
+        self.frame_rate = fps;
+    }
+
+    /// Stop calling `draw` on every frame. Events are still dispatched.
+    pub fn no_loop(&mut self) {
+        self.looping = false;
+    }
+
+    /// Call `draw` on every frame again.
+    pub fn loop_draw(&mut self) {
+        self.looping = true;
+    }
+
+    /// Return whether `draw` is called on every frame.
+    pub fn is_looping(&self) -> bool {
+        self.looping
+    }
+
+    /// Call `draw` once on the next frame, even if the sketch is not looping.
+    pub fn redraw(&mut self) {
+        self.redraw = true;
+    }
+
+    /// Stop the runtime after the current frame.
+    pub fn exit(&mut self) {
+        self.exit = true;
+    }
+
+    /// Return the last key pressed or released.
+    pub fn key(&self) -> Option<Key> {
+        self.key
+    }
+
+    /// Return whether a key is held down.
+    pub fn key_is_pressed(&self) -> bool {
+        !self.keys_down.is_empty()
+    }
+
+    /// Return whether `key` is held down.
+    pub fn key_is_down(&self, key: Key) -> bool {
+        self.keys_down.contains(&key)
+    }
+
+    /// Return the position of the mouse in pixels from the top-left corner of
+    /// the canvas, with a zero `z`.
+    pub fn mouse(&self) -> Vector<f32> {
+        self.mouse
+    }
+
+    /// Return the position of the mouse on the previous frame.
+    pub fn pmouse(&self) -> Vector<f32> {
+        self.pmouse
+    }
+
+    /// Return the last mouse button pressed or released.
+    pub fn mouse_button(&self) -> Option<MouseButton> {
+        self.mouse_button
+    }
+
+    /// Return whether a mouse button is held down.
+    pub fn mouse_is_pressed(&self) -> bool {
+        !self.buttons_down.is_empty()
+    }
+}
+
+impl Deref for Context {
+    type Target = Graphics;
+
+    fn deref(&self) -> &Graphics {
+        &self.graphics
+    }
+}
+
+impl DerefMut for Context {
+    fn deref_mut(&mut self) -> &mut Graphics {
+        &mut self.graphics
+    }
+}
+
+/// Runs a sketch, frame after frame.
+#[derive(Clone, Debug)]
+pub struct Runtime {
+    ctx: Context,
+    events: VecDeque<Event>,
+    started: bool,
+}
+
+impl Runtime {
+    /// Create a runtime whose canvas has float colors and a depth attachment.
+    pub fn new(width: usize, height: usize) -> Self {
+        Self {
+            ctx: Context::new(width, height),
+            events: VecDeque::new(),
+            started: false,
+        }
+    }
+
+    /// Return the context of the sketch.
+    pub fn context(&self) -> &Context {
+        &self.ctx
+    }
+
+    /// Return the canvas.
+    pub fn canvas(&self) -> &Framebuffer {
+        self.ctx.canvas()
+    }
+
+    /// Return whether the sketch asked to exit.
+    pub fn exited(&self) -> bool {
+        self.ctx.exit
+    }
+
+    /// Queue an input event, to dispatch before the next frame.
+    pub fn push_event(&mut self, event: Event) {
+        self.events.push_back(event);
+    }
+
+    /// Run one frame which started `delta_time` milliseconds after the
+    /// previous one: set the sketch up on the first frame, dispatch the queued
+    /// events, then draw if the sketch is looping or asked for a redraw.
+    pub fn step<S: Sketch + ?Sized>(&mut self, sketch: &mut S, delta_time: f64) {
+        let ctx = &mut self.ctx;
+        if self.started {
+            ctx.delta_time = delta_time;
+            ctx.millis += delta_time;
+        } else {
+            sketch.setup(ctx);
+            self.started = true;
+        }
+
+        while let Some(event) = self.events.pop_front() {
+            match event {
+                Event::KeyPressed(key) => {
+                    ctx.key = Some(key);
+                    if !ctx.keys_down.contains(&key) {
+                        ctx.keys_down.push(key);
+                    }
+                    sketch.key_pressed(ctx);
+                }
+                Event::KeyReleased(key) => {
+                    ctx.key = Some(key);
+                    ctx.keys_down.retain(|&k| k != key);
+                    sketch.key_released(ctx);
+                }
+                Event::MousePressed(button) => {
+                    ctx.mouse_button = Some(button);
+                    if !ctx.buttons_down.contains(&button) {
+                        ctx.buttons_down.push(button);
+                    }
+                    sketch.mouse_pressed(ctx);
+                }
+                Event::MouseReleased(button) => {
+                    ctx.mouse_button = Some(button);
+                    ctx.buttons_down.retain(|&b| b != button);
+                    sketch.mouse_released(ctx);
+                }
+                Event::MouseMoved(position) => {
+                    ctx.mouse = position;
+                    if ctx.mouse_is_pressed() {
+                        sketch.mouse_dragged(ctx);
+                    } else {
+                        sketch.mouse_moved(ctx);
+                    }
+                }
+            }
+        }
+
+        if ctx.looping || ctx.redraw {
+            ctx.redraw = false;
+            ctx.frame_count += 1;
+            sketch.draw(ctx);
+        }
+        ctx.pmouse = ctx.mouse;
+    }
+
+    /// Run `frames` frames with a simulated clock which ticks at the target
+    /// frame rate, or until the sketch exits, and return the canvas.
+    ///
+    /// The first frame starts at 0 milliseconds.
+    pub fn run_headless<S: Sketch + ?Sized>(
+        &mut self,
+        sketch: &mut S,
+        frames: u64,
+    ) -> &Framebuffer {
+        for _ in 0..frames {
+            if self.ctx.exit {
+                break;
+            }
+            let delta_time = 1000.0 / self.ctx.frame_rate;
+            self.step(sketch, delta_time);
+        }
+        self.ctx.canvas()
+    }
+
+    /// Run frames in real time at the target frame rate until the sketch
+    /// exits.
+    ///
+    /// After every frame, `present` is given the runtime, to show the canvas
+    /// and to queue the input events of a window.
+    #[cfg(feature = "std")]
+    pub fn run<S, P>(&mut self, sketch: &mut S, mut present: P)
+    where
+        S: Sketch + ?Sized,
+        P: FnMut(&mut Self),
+    {
+        use std::thread;
+        use std::time::{Duration, Instant};
+
+        let mut previous = Instant::now();
+        let mut delta_time = 0.0;
+        while !self.ctx.exit {
+            self.step(sketch, delta_time);
+            present(self);
+
+            let period = Duration::from_secs_f64(1.0 / self.ctx.frame_rate);
+            if let Some(rest) = period.checked_sub(previous.elapsed()) {
+                thread::sleep(rest);
+            }
+            let now = Instant::now();
+            delta_time = (now - previous).as_secs_f64() * 1000.0;
+            previous = now;
+        }
+    }
+}
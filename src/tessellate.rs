@@ -0,0 +1,342 @@
+//! Tessellation of 2D shapes into triangles.
+//!
+//! Fills and strokes are turned into lists of triangles, where every three
+//! points form a triangle, so that any backend which draws triangles can draw
+//! them: the [`Graphics`](crate::graphics::Graphics) canvas sends them to the
+//! software rasterizer. Triangles of the same shape may overlap, so backends
+//! should cover each pixel once per shape to blend translucent colors right.
+//!
+//! Curves are flattened to polylines which stay within [`TOLERANCE`] of the
+//! exact curves.
+//!
+//! ```
+//! use phoenyx::tessellate;
+//! use phoenyx::Vector2;
+//!
+//! let square = [(0.0, 0.0), (2.0, 0.0), (2.0, 2.0), (0.0, 2.0)].map(Vector2::from);
+//! let mut triangles = Vec::new();
+//! tessellate::polygon(&square, &mut triangles);
+//! assert_eq!(triangles.len(), 6);
+//! ```
+
+use alloc::vec::Vec;
+use core::f32::consts::{PI, TAU};
+
+use numeric::Float;
+
+use crate::Vector2;
+
+/// The largest distance between a flattened curve and the exact curve.
+pub const TOLERANCE: f32 = 0.125;
+
+/// The largest ratio of the length of a miter to the stroke weight, above
+/// which miter joins fall back to bevel joins.
+pub const MITER_LIMIT: f32 = 10.0;
+
+/// The shape of the ends of open strokes.
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq, Hash)]
+pub enum StrokeCap {
+    /// Stop at the end points.
+    Butt,
+    /// Add half discs at the end points.
+    #[default]
+    Round,
+    /// Extend the ends by half the stroke weight.
+    Square,
+}
+
+/// The shape of the corners of strokes.
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq, Hash)]
+pub enum StrokeJoin {
+    /// Extend the outer edges until they meet, up to [`MITER_LIMIT`].
+    #[default]
+    Miter,
+    /// Cut the corner with a straight line.
+    Bevel,
+    /// Round the corner with a circular arc.
+    Round,
+}
+
+/// Return the number of segments which flatten a full circle of `radius`.
+pub fn segments(radius: f32) -> usize {
+    let radius = radius.abs();
+    if radius <= TOLERANCE {
+        return 8;
+    }
+    let step = Float::acos(1.0 - TOLERANCE / radius);
+    (Float::ceil(PI / step) as usize).clamp(8, 1024)
+}
+
+/// Append the points of an ellipse with radii `radii` around `center`,
+/// without repeating the first point at the end.
+pub fn ellipse(center: Vector2<f32>, radii: Vector2<f32>, out: &mut Vec<Vector2<f32>>) {
+    let n = segments(radii.x.abs().max(radii.y.abs()));
+    out.extend((0..n).map(|i| point_at(center, radii, TAU * i as f32 / n as f32)));
+}
+
+/// Append the points of an elliptic arc from angle `start` to angle `stop`,
+/// both included, in radians.
+///
+/// Angles go from the `x` axis towards the `y` axis, which is clockwise on
+/// screens whose `y` axis points down. Arcs sweeping more than a full turn
+/// stop after one turn.
+pub fn arc(
+    center: Vector2<f32>,
+    radii: Vector2<f32>,
+    start: f32,
+    stop: f32,
+    out: &mut Vec<Vector2<f32>>,
+) {
+    let sweep = (stop - start).clamp(-TAU, TAU);
+    let full = segments(radii.x.abs().max(radii.y.abs())) as f32;
+    let n = (Float::ceil(full * sweep.abs() / TAU) as usize).max(1);
+    out.extend((0..=n).map(|i| point_at(center, radii, start + sweep * i as f32 / n as f32)));
+}
+
+fn point_at(center: Vector2<f32>, radii: Vector2<f32>, angle: f32) -> Vector2<f32> {
+    let (sin, cos) = Float::sin_cos(angle);
+    center + Vector2::new(radii.x * cos, radii.y * sin)
+}
+
+/// Append the points of a cubic Bézier curve from `p0` to `p1` with control
+/// points `c0` and `c1`, without `p0`.
+pub fn cubic_bezier(
+    p0: Vector2<f32>,
+    c0: Vector2<f32>,
+    c1: Vector2<f32>,
+    p1: Vector2<f32>,
+    out: &mut Vec<Vector2<f32>>,
+) {
+    // Wang's formula bounds the distance between the curve and its chords.
+    let m = (p0 - c0 * 2.0 + c1)
+        .length()
+        .max((c0 - c1 * 2.0 + p1).length());
+    let n = (Float::ceil(Float::sqrt(0.75 * m / TOLERANCE)) as usize).clamp(1, 1024);
+    out.extend((1..=n).map(|i| {
+        let t = i as f32 / n as f32;
+        let s = 1.0 - t;
+        p0 * (s * s * s) + c0 * (3.0 * s * s * t) + c1 * (3.0 * s * t * t) + p1 * (t * t * t)
+    }));
+}
+
+/// Remove repeated points, including the last points of closed shapes which
+/// repeat the first one.
+fn dedup(points: &[Vector2<f32>], closed: bool) -> Vec<Vector2<f32>> {
+    let mut ring: Vec<Vector2<f32>> = Vec::with_capacity(points.len());
+    for &p in points {
+        if ring.last() != Some(&p) {
+            ring.push(p);
+        }
+    }
+    while closed && ring.len() > 1 && ring.first() == ring.last() {
+        ring.pop();
+    }
+    ring
+}
+
+/// Return whether `p` is inside or on the edges of the triangle `abc`, whose
+/// orientation is the sign of `orientation`.
+fn inside(
+    p: Vector2<f32>,
+    a: Vector2<f32>,
+    b: Vector2<f32>,
+    c: Vector2<f32>,
+    orientation: f32,
+) -> bool {
+    (b - a).cross(p - a) * orientation >= 0.0
+        && (c - b).cross(p - b) * orientation >= 0.0
+        && (a - c).cross(p - c) * orientation >= 0.0
+}
+
+/// Append the triangles which fill a simple polygon, convex or not, by ear
+/// clipping.
+///
+/// Self-intersecting polygons are filled too, though not with a defined fill
+/// rule.
+pub fn polygon(points: &[Vector2<f32>], out: &mut Vec<Vector2<f32>>) {
+    let ring = dedup(points, true);
+    if ring.len() < 3 {
+        return;
+    }
+    let area: f32 = (0..ring.len())
+        .map(|i| ring[i].cross(ring[(i + 1) % ring.len()]))
+        .sum();
+    if area == 0.0 {
+        return;
+    }
+    let orientation = area.signum();
+
+    let mut indices: Vec<usize> = (0..ring.len()).collect();
+    let (mut i, mut stalled) = (0, 0);
+    while indices.len() > 3 {
+        let n = indices.len();
+        let (a, b, c) = (
+            ring[indices[(i + n - 1) % n]],
+            ring[indices[i]],
+            ring[indices[(i + 1) % n]],
+        );
+        let turn = (b - a).cross(c - b) * orientation;
+        let ear = turn > 0.0
+            && !indices.iter().any(|&j| {
+                let p = ring[j];
+                p != a && p != b && p != c && inside(p, a, b, c, orientation)
+            });
+        // Collinear vertices are removed without a triangle, and a full turn
+        // without an ear only happens with self-intersections.
+        if ear || turn == 0.0 || stalled >= n {
+            if turn != 0.0 {
+                out.extend([a, b, c]);
+            }
+            indices.remove(i);
+            i %= indices.len();
+            stalled = 0;
+        } else {
+            i = (i + 1) % n;
+            stalled += 1;
+        }
+    }
+    let [a, b, c] = [0, 1, 2].map(|k| ring[indices[k]]);
+    if (b - a).cross(c - b) != 0.0 {
+        out.extend([a, b, c]);
+    }
+}
+
+/// Append the triangles of a quad, whose corners go around it.
+fn quad(
+    a: Vector2<f32>,
+    b: Vector2<f32>,
+    c: Vector2<f32>,
+    d: Vector2<f32>,
+    out: &mut Vec<Vector2<f32>>,
+) {
+    out.extend([a, b, c, a, c, d]);
+}
+
+/// Append the triangles of a circular sector around `center`, from the
+/// direction of `from` turning by `sweep` radians.
+fn sector(center: Vector2<f32>, from: Vector2<f32>, sweep: f32, out: &mut Vec<Vector2<f32>>) {
+    let radius = from.length();
+    let n = (Float::ceil(segments(radius) as f32 * sweep.abs() / TAU) as usize).max(1);
+    let mut previous = center + from;
+    for i in 1..=n {
+        let next = center + from.rotated(sweep * i as f32 / n as f32);
+        out.extend([center, previous, next]);
+        previous = next;
+    }
+}
+
+/// Append the triangles of a disc.
+pub fn disc(center: Vector2<f32>, radius: f32, out: &mut Vec<Vector2<f32>>) {
+    sector(center, Vector2::new(radius, 0.0), TAU, out);
+}
+
+/// Append the triangles of the stroke of a polyline, with the ends drawn with
+/// `cap` unless the polyline is `closed`, and the corners with `join`.
+///
+/// A polyline with a single point is drawn as a dot, except with butt caps.
+pub fn polyline(
+    points: &[Vector2<f32>],
+    closed: bool,
+    weight: f32,
+    cap: StrokeCap,
+    join: StrokeJoin,
+    out: &mut Vec<Vector2<f32>>,
+) {
+    let half = weight / 2.0;
+    let ring = dedup(points, closed);
+    if half <= 0.0 || ring.is_empty() {
+        return;
+    }
+    let n = ring.len();
+    if n == 1 {
+        let p = ring[0];
+        match cap {
+            StrokeCap::Butt => {}
+            StrokeCap::Round => disc(p, half, out),
+            StrokeCap::Square => {
+                let (dx, dy) = (Vector2::new(half, 0.0), Vector2::new(0.0, half));
+                quad(p - dx - dy, p + dx - dy, p + dx + dy, p - dx + dy, out);
+            }
+        }
+        return;
+    }
+    let closed = closed && n > 2;
+
+    let segments = if closed { n } else { n - 1 };
+    for s in 0..segments {
+        let (a, b) = (ring[s], ring[(s + 1) % n]);
+        let normal = (b - a).normalized().perp() * half;
+        quad(a + normal, b + normal, b - normal, a - normal, out);
+    }
+
+    let corners = if closed { 0..n } else { 1..n - 1 };
+    for i in corners {
+        let (a, p, b) = (ring[(i + n - 1) % n], ring[i], ring[(i + 1) % n]);
+        corner(a, p, b, half, join, out);
+    }
+
+    if !closed {
+        end(ring[0], ring[0] - ring[1], half, cap, out);
+        end(ring[n - 1], ring[n - 1] - ring[n - 2], half, cap, out);
+    }
+}
+
+/// Append the triangles of the cap at the end `p` of a stroke, which goes
+/// outwards along `direction`.
+fn end(
+    p: Vector2<f32>,
+    direction: Vector2<f32>,
+    half: f32,
+    cap: StrokeCap,
+    out: &mut Vec<Vector2<f32>>,
+) {
+    let direction = direction.normalized() * half;
+    let normal = direction.perp();
+    match cap {
+        StrokeCap::Butt => {}
+        StrokeCap::Round => sector(p, normal, -PI, out),
+        StrokeCap::Square => quad(
+            p + normal,
+            p + normal + direction,
+            p - normal + direction,
+            p - normal,
+            out,
+        ),
+    }
+}
+
+/// Append the triangles which fill the outer side of the corner at `p`
+/// between the segments from `a` and to `b`.
+fn corner(
+    a: Vector2<f32>,
+    p: Vector2<f32>,
+    b: Vector2<f32>,
+    half: f32,
+    join: StrokeJoin,
+    out: &mut Vec<Vector2<f32>>,
+) {
+    let (d0, d1) = ((p - a).normalized(), (b - p).normalized());
+    let turn = d0.cross(d1);
+    if turn == 0.0 && d0.dot(d1) > 0.0 {
+        return;
+    }
+    // The outer side of a turn towards the normals is the opposite one.
+    let side = if turn > 0.0 { -half } else { half };
+    let (o0, o1) = (d0.perp() * side, d1.perp() * side);
+    match join {
+        StrokeJoin::Bevel => out.extend([p, p + o0, p + o1]),
+        StrokeJoin::Miter => {
+            out.extend([p, p + o0, p + o1]);
+            // The cosine of half the angle between the normals.
+            let bisector = (o0 + o1).normalized();
+            let cos = bisector.dot(o0) / half;
+            if cos > 0.0 && 1.0 / cos <= MITER_LIMIT {
+                out.extend([p + o0, p + bisector * (half / cos), p + o1]);
+            }
+        }
+        StrokeJoin::Round => {
+            let sweep = Float::atan2(o0.cross(o1), o0.dot(o1));
+            sector(p, o0, sweep, out);
+        }
+    }
+}
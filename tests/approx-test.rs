@@ -0,0 +1,118 @@
+use math_vector::{Matrix3, Quaternion, Transform, Vector, Vector2, Vector4};
+use numeric::approx::{ApproxEq, Tolerance};
+use numeric::{assert_approx_eq, assert_approx_ne};
+
+use std::f64::consts::PI;
+
+#[test]
+fn absolute_and_relative() {
+    assert!(1e-9f64.approx_eq(&0.0, Tolerance::absolute(1e-8)));
+    assert!(!1e-9f64.approx_eq(&0.0, Tolerance::relative(0.5)));
+    assert!(1e9f64.approx_eq(&(1e9 + 1.0), Tolerance::relative(1e-8)));
+    assert!(!1e9f64.approx_eq(&(1e9 + 1.0), Tolerance::absolute(0.5)));
+    assert!((-1.0f32).approx_eq(&-1.01, Tolerance::relative(0.01)));
+    assert!(!1.0f64.approx_eq(&-1.0, Tolerance::relative(1.0)));
+    assert!(1.0f64.approx_ne(&1.1, f64::default_tolerance()));
+}
+
+#[test]
+fn ulps() {
+    let one = 1.0f32;
+    let next = f32::from_bits(one.to_bits() + 3);
+    assert!(one.approx_eq(&next, Tolerance::ulps(3)));
+    assert!(!one.approx_eq(&next, Tolerance::ulps(2)));
+
+    // Across zero: the smallest subnormals are two ulps apart.
+    let tiny = f64::from_bits(1);
+    assert!(tiny.approx_eq(&-tiny, Tolerance::ulps(2)));
+    assert!(!tiny.approx_eq(&-tiny, Tolerance::ulps(1)));
+    assert!(0.0f64.approx_eq(&-0.0, Tolerance::exact()));
+    assert!(f64::MAX.approx_eq(&f64::from_bits(f64::MAX.to_bits() - 1), Tolerance::ulps(1)));
+    assert!(!f64::MAX.approx_eq(&-f64::MAX, Tolerance::ulps(u32::MAX)));
+}
+
+#[test]
+fn special_values() {
+    let tol = Tolerance {
+        abs: f64::INFINITY,
+        rel: 1.0,
+        ulps: u32::MAX,
+    };
+    assert!(f64::INFINITY.approx_eq(&f64::INFINITY, Tolerance::exact()));
+    assert!(!f64::INFINITY.approx_eq(&f64::MAX, tol));
+    assert!(!f64::NAN.approx_eq(&f64::NAN, tol));
+    assert!(!1.0f64.approx_eq(&f64::NAN, tol));
+}
+
+#[test]
+fn collections() {
+    assert!([1.0, 2.0].approx_eq(&[1.0, 2.0 + 1e-15], f64::default_tolerance()));
+    assert!(![1.0, 2.0].approx_eq(&[1.0, 2.1], f64::default_tolerance()));
+    let a: &[f32] = &[1.0, 2.0];
+    let b: &[f32] = &[1.0];
+    assert!(!a.approx_eq(b, Tolerance::absolute(1.0)));
+    assert!(a.approx_eq(&[1.5, 2.5][..], Tolerance::absolute(0.5)));
+}
+
+#[test]
+fn vectors_near_zero() {
+    let a = Vector::new(1e-17, -1e-17, 0.0);
+    let b = Vector::new(0.0, 0.0, 1e-18);
+    assert!(!a.is_close(b));
+    assert!(a.approx_eq(&b, Vector::<f64>::default_tolerance()));
+    assert_approx_eq!(a, b);
+    assert_approx_ne!(a, b, rel = 1e-3);
+
+    let v = Vector::new(0.1, 0.2, 0.3) * 3.0;
+    assert_approx_eq!(v, Vector::new(0.3, 0.6, 0.9));
+    assert_approx_eq!(
+        Vector2::new(1.0f32, 2.0),
+        Vector2::new(1.0, 2.00001),
+        abs = 1e-4
+    );
+    assert_approx_ne!(
+        Vector4::new(1.0, 2.0, 3.0, 4.0),
+        Vector4::new(1.0, 2.0, 3.0, 4.1)
+    );
+}
+
+#[test]
+fn rotations() {
+    let q = Quaternion::from_rotation(PI / 2.0, Vector::new(0.0, 0.0, 1.0));
+    let r = Quaternion::from_rotation(PI / 4.0, Vector::new(0.0, 0.0, 1.0));
+    assert_approx_eq!(r * r, q);
+    let m: Matrix3<f64> = q.to_matrix();
+    assert_approx_eq!(
+        m,
+        Matrix3::from_rotation(PI / 2.0, Vector::new(0.0, 0.0, 1.0))
+    );
+    assert_approx_eq!(m.cols[0], [0.0, 1.0, 0.0]);
+
+    let t = Transform::new(Vector::new(1.0, 2.0, 3.0), q, Vector::one());
+    let inverse = t.inverse().unwrap();
+    assert_approx_eq!(inverse.inverse().unwrap(), t);
+    assert_approx_ne!(inverse, t);
+}
+
+#[test]
+fn macro_tolerances() {
+    assert_approx_eq!(0.1 + 0.2, 0.3);
+    assert_approx_eq!(1e-300, 0.0);
+    assert_approx_eq!(100.0, 100.1, rel = 1e-3);
+    assert_approx_eq!(100.0, 100.1, abs = 0.2, rel = 0.0,);
+    assert_approx_eq!(1.0, 1.0 + f64::EPSILON, ulps = 1);
+    assert_approx_ne!(1.0, 1.0 + f64::EPSILON, ulps = 0);
+    assert_approx_eq!(1.0f32, 1.0, ulps = 0);
+}
+
+#[test]
+#[should_panic(expected = "assertion `left ≈ right` failed")]
+fn macro_failure() {
+    assert_approx_eq!(Vector::new(1.0, 0.0, 0.0), Vector::new(1.0, 1e-3, 0.0));
+}
+
+#[test]
+#[should_panic(expected = "assertion `left !≈ right` failed")]
+fn macro_ne_failure() {
+    assert_approx_ne!(1.0, 1.0 + 1e-12, rel = 1e-9);
+}
@@ -0,0 +1,271 @@
+#![cfg(feature = "alloc")]
+#![allow(clippy::op_ref)]
+
+use numeric::bigint::{BigInt, BigIntErrorKind, BigUint, Sign};
+use numeric::{
+    cast, checked_pow, pow, CheckedDiv, CheckedSub, FromPrimitive, Num, One, Signed, ToPrimitive,
+    Zero,
+};
+
+/// Return pseudo-random values covering small, boundary and wide operands.
+fn samples() -> Vec<i128> {
+    let mut values = vec![
+        0,
+        1,
+        -1,
+        2,
+        -7,
+        255,
+        256,
+        i64::MAX as i128,
+        i64::MIN as i128,
+    ];
+    values.extend([u32::MAX as i128, 1 << 32, -(1 << 63) - 1, u64::MAX as i128]);
+    let mut state = 0x2545_f491_4f6c_dd1du64;
+    for i in 0..40 {
+        state = state
+            .wrapping_mul(6364136223846793005)
+            .wrapping_add(1442695040888963407);
+        let bits = 8 + i * 2;
+        values.push((state as i64 >> (64 - bits.min(63))) as i128 * (state as i128 >> 60));
+    }
+    values
+}
+
+fn big(n: i128) -> BigInt {
+    BigInt::from(n)
+}
+
+#[test]
+fn arithmetic_matches_primitives() {
+    let values = samples();
+    for &a in &values {
+        for &b in &values {
+            let (x, y) = (big(a), big(b));
+            assert_eq!(&x + &y, big(a + b), "{} + {}", a, b);
+            assert_eq!(&x - &y, big(a - b), "{} - {}", a, b);
+            if let Some(p) = a.checked_mul(b) {
+                assert_eq!(&x * &y, big(p), "{} * {}", a, b);
+            }
+            if b != 0 {
+                assert_eq!(&x / &y, big(a / b), "{} / {}", a, b);
+                assert_eq!(&x % &y, big(a % b), "{} % {}", a, b);
+            }
+            assert_eq!(x.cmp(&y), a.cmp(&b));
+        }
+    }
+}
+
+#[test]
+fn large_values() {
+    let factorial: BigUint = (1..=30u32).map(BigUint::from).product();
+    assert_eq!(factorial.to_string(), "265252859812191058636308480000000");
+
+    let a = pow(BigUint::from(3u32), 200);
+    let b = pow(BigUint::from(7u32), 60) + BigUint::from(12345u32);
+    let (q, r) = a.div_rem(&b);
+    assert_eq!(
+        q.to_string(),
+        "522839683491514170494159221858129462316121929"
+    );
+    assert_eq!(
+        r.to_string(),
+        "89496710767698388287355451498565477403275129064567"
+    );
+
+    // Divisors whose quotient digit estimates need correcting.
+    let ones = |n: usize| BigUint::new(vec![u32::MAX; n]);
+    let mut state = 1u32;
+    let mut digits = |n: usize| -> BigUint {
+        BigUint::new(
+            (0..n)
+                .map(|_| {
+                    state = state.wrapping_mul(1664525).wrapping_add(1013904223);
+                    state
+                })
+                .collect(),
+        )
+    };
+    let mut cases = vec![
+        (ones(8), ones(3)),
+        (ones(6), BigUint::new(vec![u32::MAX, 0, 1 << 31])),
+        (
+            BigUint::new(vec![0, 0, 0x8000, 0x7fff]),
+            BigUint::new(vec![1, 0, 0x8000]),
+        ),
+        (
+            BigUint::new(vec![3, 0, 0x8000]),
+            BigUint::new(vec![1, 0, 0x2000]),
+        ),
+    ];
+    for (n, m) in [(12, 5), (7, 2), (20, 19), (3, 3), (2, 4)] {
+        cases.push((digits(n), digits(m)));
+    }
+    for (n, d) in cases {
+        let (q, r) = n.div_rem(&d);
+        assert!(r < d);
+        assert_eq!(&q * &d + &r, n);
+    }
+
+    assert_eq!(BigInt::from(-7) / BigInt::from(2), BigInt::from(-3));
+    assert_eq!(BigInt::from(-7) % BigInt::from(2), BigInt::from(-1));
+    assert_eq!(BigUint::from(5u32).checked_div(&BigUint::zero()), None);
+    assert_eq!(BigUint::from(5u32).checked_sub(&BigUint::from(6u32)), None);
+}
+
+#[test]
+#[should_panic(expected = "attempt to subtract with overflow")]
+fn unsigned_underflow() {
+    let _ = BigUint::from(1u32) - BigUint::from(2u32);
+}
+
+#[test]
+fn parse_and_format() {
+    let max = BigUint::from(u128::MAX);
+    assert_eq!(max.to_str_radix(16), "ffffffffffffffffffffffffffffffff");
+    assert_eq!(format!("{:#X}", max), "0xFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFF");
+    assert_eq!(format!("{:b}", BigUint::from(10u32)), "1010");
+    assert_eq!(format!("{:>6}", BigInt::from(-42)), "   -42");
+    assert_eq!(format!("{:+}", BigInt::from(42)), "+42");
+    assert_eq!(format!("{:#o}", BigInt::from(-8)), "-0o10");
+    assert_eq!(format!("{:?}", BigInt::zero()), "0");
+
+    for radix in 2..=36 {
+        for &n in &samples() {
+            let s = big(n).to_str_radix(radix);
+            assert_eq!(
+                BigInt::from_str_radix(&s, radix),
+                Ok(big(n)),
+                "{} {}",
+                s,
+                radix
+            );
+            assert_eq!(i128::from_str_radix(&s, radix), Ok(n));
+        }
+    }
+    let long = "123456789012345678901234567890123456789012345678901234567890";
+    assert_eq!(long.parse::<BigUint>().unwrap().to_string(), long);
+    assert_eq!("+17".parse::<BigInt>(), Ok(BigInt::from(17)));
+    assert_eq!("-0".parse::<BigInt>(), Ok(BigInt::zero()));
+    assert_eq!(BigInt::from_str_radix("-Zz", 36), Ok(BigInt::from(-1295)));
+
+    let kind = |r: Result<BigInt, numeric::bigint::ParseBigIntError>| r.unwrap_err().kind;
+    assert_eq!(kind("".parse()), BigIntErrorKind::Empty);
+    assert_eq!(kind("-".parse()), BigIntErrorKind::Empty);
+    assert_eq!(kind("12a".parse()), BigIntErrorKind::InvalidDigit);
+    assert_eq!(
+        kind(BigInt::from_str_radix("102", 2)),
+        BigIntErrorKind::InvalidDigit
+    );
+    assert!("-1".parse::<BigUint>().is_err());
+    assert_eq!(
+        "1 2".parse::<BigInt>().unwrap_err().to_string(),
+        "invalid digit found in string"
+    );
+}
+
+#[test]
+fn generic_helpers() {
+    let two = BigInt::from(2);
+    assert_eq!(
+        pow(two.clone(), 100).to_string(),
+        "1267650600228229401496703205376"
+    );
+    assert_eq!(checked_pow(BigInt::from(-3), 3), Some(BigInt::from(-27)));
+    assert_eq!(numeric::Pow::pow(&two, 10u32), BigInt::from(1024));
+    assert_eq!(two.pow(0), BigInt::one());
+
+    assert_eq!(cast::<i64, BigInt>(-5), Some(BigInt::from(-5)));
+    assert_eq!(
+        cast::<u64, BigUint>(u64::MAX),
+        Some(BigUint::from(u64::MAX))
+    );
+    assert_eq!(cast::<i32, BigUint>(-1), None);
+    assert_eq!(cast::<f64, BigInt>(-2.75), Some(BigInt::from(-2)));
+    assert_eq!(cast::<BigInt, i8>(BigInt::from(-128)), Some(-128i8));
+    assert_eq!(cast::<BigInt, u8>(BigInt::from(256)), None);
+    assert_eq!(
+        cast::<BigUint, f32>(BigUint::from(1u32) << 100),
+        Some(2f32.powi(100))
+    );
+
+    let x = BigInt::from(-12);
+    assert_eq!(x.abs(), BigInt::from(12));
+    assert_eq!(x.signum(), BigInt::from(-1));
+    assert!(x.is_negative() && !x.is_positive());
+    assert_eq!(x.abs_sub(&BigInt::from(-20)), BigInt::from(8));
+    assert_eq!(x.abs_sub(&BigInt::from(0)), BigInt::zero());
+    assert_eq!(x.sign(), Sign::Minus);
+
+    let values: Vec<BigInt> = (1..=5).map(BigInt::from).collect();
+    assert_eq!(values.iter().sum::<BigInt>(), BigInt::from(15));
+    assert_eq!(values.into_iter().product::<BigInt>(), BigInt::from(120));
+}
+
+#[test]
+fn bit_operations() {
+    let values: Vec<i64> = samples()
+        .into_iter()
+        .filter_map(|n| i64::try_from(n).ok())
+        .collect();
+    for &a in &values {
+        for &b in &values {
+            let (x, y) = (BigInt::from(a), BigInt::from(b));
+            assert_eq!(&x & &y, BigInt::from(a & b), "{} & {}", a, b);
+            assert_eq!(&x | &y, BigInt::from(a | b), "{} | {}", a, b);
+            assert_eq!(&x ^ &y, BigInt::from(a ^ b), "{} ^ {}", a, b);
+        }
+        let x = BigInt::from(a);
+        assert_eq!(!&x, BigInt::from(!a));
+        for n in [0, 1, 5, 31, 32, 33, 63, 100] {
+            assert_eq!(&x >> n, BigInt::from(a >> n.min(63)), "{} >> {}", a, n);
+            assert_eq!(&x << n, BigInt::from(a as i128) * pow(BigInt::from(2), n));
+        }
+    }
+
+    let mut x = BigUint::from(0b1011_0000u32);
+    assert_eq!(x.bits(), 8);
+    assert_eq!(x.trailing_zeros(), Some(4));
+    assert_eq!(x.count_ones(), 3);
+    assert!(x.bit(5) && !x.bit(6) && !x.bit(1000));
+    x.set_bit(70, true);
+    assert_eq!(x, (BigUint::one() << 70) | BigUint::from(0b1011_0000u32));
+    x.set_bit(70, false);
+    assert_eq!(x.digits(), [0b1011_0000]);
+    assert_eq!(BigUint::zero().trailing_zeros(), None);
+
+    let bytes = [1, 2, 3, 4, 5];
+    let n = BigUint::from_bytes_le(&bytes);
+    assert_eq!(n, BigUint::from(0x05_0403_0201u64));
+    assert_eq!(n.to_bytes_le(), bytes);
+    assert_eq!(BigUint::from_bytes_be(&[0, 1, 0]).to_bytes_be(), [1, 0]);
+    assert_eq!(BigInt::from(-258).to_bytes_le(), (Sign::Minus, vec![2, 1]));
+}
+
+#[test]
+fn float_conversions() {
+    let two = BigUint::from(2u32);
+    // Ties round to even, and anything beyond the tie rounds up.
+    let tie = pow(two.clone(), 53) + BigUint::one();
+    assert_eq!(tie.to_f64(), Some(2f64.powi(53)));
+    let above = (pow(two.clone(), 53) + BigUint::one()) * pow(two.clone(), 40) + BigUint::one();
+    assert_eq!(above.to_f64(), Some((2f64.powi(53) + 2.0) * 2f64.powi(40)));
+    assert_eq!(BigUint::from(u64::MAX).to_f64(), Some(2f64.powi(64)));
+    assert_eq!(BigUint::from(u64::MAX).to_f32(), Some(2f32.powi(64)));
+    assert_eq!(pow(two.clone(), 1023).to_f64(), Some(2f64.powi(1023)));
+    assert_eq!(pow(two.clone(), 1024).to_f64(), Some(f64::INFINITY));
+    assert_eq!(BigInt::from(-3).to_f64(), Some(-3.0));
+    assert_eq!(BigInt::from(i64::MIN).to_i64(), Some(i64::MIN));
+    assert_eq!((BigInt::from(i64::MIN) - BigInt::one()).to_i64(), None);
+
+    let exact = BigInt::from_f64(1e300).unwrap().to_string();
+    assert!(exact.starts_with("1000000000000000052504760255204420248704468581108159154915854"));
+    assert_eq!(exact.len(), 301);
+    assert_eq!(FromPrimitive::from_f64(f64::NAN), None::<BigInt>);
+    assert_eq!(FromPrimitive::from_f64(-0.5), Some(BigUint::zero()));
+    assert_eq!(FromPrimitive::from_f64(-1.5), None::<BigUint>);
+    assert_eq!(
+        FromPrimitive::from_f64(f64::MIN_POSITIVE),
+        Some(BigInt::zero())
+    );
+}
@@ -0,0 +1,194 @@
+#![allow(clippy::op_ref)]
+
+use numeric::complex::{Complex, ComplexErrorKind};
+use numeric::{Inv, Num, One, Pow, Zero};
+
+use std::f64::consts::{E, FRAC_PI_2, PI};
+
+fn c(re: f64, im: f64) -> Complex<f64> {
+    Complex::new(re, im)
+}
+
+fn assert_close(a: Complex<f64>, b: Complex<f64>) {
+    assert!((a - b).norm() < 1e-12, "{} != {}", a, b);
+}
+
+#[test]
+fn arithmetic() {
+    let a = c(1.0, 2.0);
+    let b = c(3.0, -1.0);
+    assert_eq!(a + b, c(4.0, 1.0));
+    assert_eq!(a - b, c(-2.0, 3.0));
+    assert_eq!(a * b, c(5.0, 5.0));
+    assert_close(a / b, c(0.1, 0.7));
+    assert_close(a / b * b, a);
+    assert_eq!(-a, c(-1.0, -2.0));
+    assert_eq!(&a + &b, a + b);
+    assert_eq!(a * &b, &a * b);
+    assert_eq!(-&a, -a);
+
+    assert_eq!(a + 1.0, c(2.0, 2.0));
+    assert_eq!(a - 1.0, c(0.0, 2.0));
+    assert_eq!(a * 2.0, c(2.0, 4.0));
+    assert_eq!(a / 2.0, c(0.5, 1.0));
+    assert_eq!(2.0 * a, c(2.0, 4.0));
+    assert_eq!(1.0 - a, c(0.0, -2.0));
+    assert_close(1.0 / c(0.0, 2.0), c(0.0, -0.5));
+
+    let mut d = a;
+    d += b;
+    d *= 2.0;
+    d -= &a;
+    d /= c(0.0, 1.0);
+    assert_eq!(d, c(0.0, -7.0));
+
+    // Gaussian integer remainder
+    assert_eq!(c(7.0, 3.0) % c(2.0, 0.0), c(1.0, 1.0));
+    assert_eq!(c(5.0, -3.0) % 2.0, c(1.0, -1.0));
+
+    assert_eq!(Complex::<f64>::i() * Complex::i(), -Complex::one());
+    assert!(Complex::<f64>::zero().is_zero());
+    assert_eq!([a, b].iter().sum::<Complex<f64>>(), c(4.0, 1.0));
+    assert_eq!([a, b].into_iter().product::<Complex<f64>>(), c(5.0, 5.0));
+}
+
+#[test]
+fn huge_division() {
+    // Squaring the divisor would overflow.
+    let big = c(1e300, 1e300);
+    assert_close(big / big, Complex::one());
+    assert_close(big.inv() * 1e300, c(0.5, -0.5));
+    assert_close(Inv::inv(&c(0.0, 4.0)), c(0.0, -0.25));
+}
+
+#[test]
+fn polar() {
+    let z = Complex::from_polar(2.0, FRAC_PI_2);
+    assert_close(z, c(0.0, 2.0));
+    let (r, theta) = c(-1.0, 0.0).to_polar();
+    assert_eq!((r, theta), (1.0, PI));
+    assert_eq!(c(3.0, 4.0).norm(), 5.0);
+    assert_eq!(c(3.0, 4.0).norm_sqr(), 25.0);
+    assert_eq!(c(3.0, -4.0).l1_norm(), 7.0);
+    assert_eq!(c(1e300, 1e300).norm(), 1e300 * 2f64.sqrt());
+    assert_eq!(c(1.0, 2.0).conj(), c(1.0, -2.0));
+}
+
+#[test]
+fn transcendental() {
+    // Euler's identity
+    assert_close(c(0.0, PI).exp(), c(-1.0, 0.0));
+    assert_close(c(1.0, 0.0).exp(), c(E, 0.0));
+    assert_close(c(-1.0, 0.0).ln(), c(0.0, PI));
+    assert_close(c(2.0, 3.0).ln().exp(), c(2.0, 3.0));
+
+    assert_eq!(c(-4.0, 0.0).sqrt(), c(0.0, 2.0));
+    assert_eq!(c(-4.0, -0.0).sqrt(), c(0.0, -2.0));
+    assert_close(c(0.0, 2.0).sqrt(), c(1.0, 1.0));
+    for &z in &[c(3.0, 4.0), c(-3.0, 4.0), c(-3.0, -4.0), c(1e-3, -5.0)] {
+        let s = z.sqrt();
+        assert!(s.re >= 0.0);
+        assert_close(s * s, z);
+    }
+
+    assert_close(Complex::i().powc(Complex::i()), c((-FRAC_PI_2).exp(), 0.0));
+    assert_close(c(1.0, 1.0).powi(4), c(-4.0, 0.0));
+    assert_close(c(1.0, 1.0).powi(-2), c(0.0, -0.5));
+    assert_close(c(1.0, 1.0).powf(2.0), c(0.0, 2.0));
+    assert_eq!(Complex::<f64>::zero().powc(Complex::zero()), Complex::one());
+    assert_eq!(Complex::<f64>::zero().powf(2.0), Complex::zero());
+    assert_close(c(2.0, 1.0).pow(3), c(2.0, 1.0).powi(3));
+    assert_close(c(2.0, 1.0).pow(0.5), c(2.0, 1.0).sqrt());
+    assert_close(c(2.0, 1.0).pow(c(0.5, 0.0)), c(2.0, 1.0).sqrt());
+
+    let z = c(0.5, -0.7);
+    let sum = z.sin() * z.sin() + z.cos() * z.cos();
+    assert_close(sum, Complex::one());
+    assert_close(z.tan(), z.sin() / z.cos());
+    assert_close(z.cosh() * z.cosh() - z.sinh() * z.sinh(), Complex::one());
+    assert_close(z.tanh(), z.sinh() / z.cosh());
+}
+
+#[test]
+fn classification() {
+    assert!(c(f64::NAN, 0.0).is_nan());
+    assert!(c(0.0, f64::INFINITY).is_infinite());
+    assert!(!c(f64::NAN, f64::INFINITY).is_infinite());
+    assert!(c(1.0, 2.0).is_finite());
+}
+
+#[test]
+fn parsing() {
+    let parse = |s: &str| s.parse::<Complex<f64>>().unwrap();
+    assert_eq!(parse("1+2i"), c(1.0, 2.0));
+    assert_eq!(parse("1 - 2i"), c(1.0, -2.0));
+    assert_eq!(parse("-1.5"), c(-1.5, 0.0));
+    assert_eq!(parse("2.5i"), c(0.0, 2.5));
+    assert_eq!(parse("-i"), c(0.0, -1.0));
+    assert_eq!(parse("3+i"), c(3.0, 1.0));
+    assert_eq!(parse("2i-3"), c(-3.0, 2.0));
+    assert_eq!(parse("1e-3+2.5e+3i"), c(1e-3, 2.5e3));
+    assert_eq!(parse("  4 "), c(4.0, 0.0));
+    assert_eq!(
+        Complex::<f64>::from_str_radix("-10.1+11i", 2).unwrap(),
+        c(-2.5, 3.0)
+    );
+    assert_eq!(
+        Complex::<f32>::from_str_radix("ff-e.8i", 16).unwrap(),
+        Complex::new(255.0, -14.5)
+    );
+
+    let kind = |s: &str| s.parse::<Complex<f64>>().unwrap_err().kind;
+    assert!(matches!(kind(""), ComplexErrorKind::Empty));
+    assert!(matches!(kind("1+2"), ComplexErrorKind::Invalid));
+    assert!(matches!(kind("i+2i"), ComplexErrorKind::Invalid));
+    assert!(matches!(kind("1++2i"), ComplexErrorKind::Invalid));
+    assert!(matches!(kind("x+2i"), ComplexErrorKind::Part(_)));
+}
+
+#[test]
+fn display() {
+    assert_eq!(c(1.0, 2.0).to_string(), "1+2i");
+    assert_eq!(c(1.5, -2.0).to_string(), "1.5-2i");
+    assert_eq!(format!("{:.2}", c(PI, -E)), "3.14-2.72i");
+    for &z in &[c(0.1, -0.2), c(-1e-20, 3e15), c(0.0, -0.0)] {
+        assert_eq!(z.to_string().parse::<Complex<f64>>().unwrap(), z);
+    }
+}
+
+#[test]
+fn conversions() {
+    assert_eq!(Complex::from(2.0), c(2.0, 0.0));
+    assert_eq!(Complex::from((1.0, 2.0)), c(1.0, 2.0));
+    assert_eq!(Complex::from([1.0, 2.0]), c(1.0, 2.0));
+    let (re, im): (f64, f64) = c(3.0, 4.0).into();
+    assert_eq!((re, im), (3.0, 4.0));
+    let a: [f64; 2] = c(3.0, 4.0).into();
+    assert_eq!(a, [3.0, 4.0]);
+}
+
+/// A naive DFT, the kind of code this type is for.
+fn dft(input: &[Complex<f64>]) -> Vec<Complex<f64>> {
+    let n = input.len();
+    (0..n)
+        .map(|k| {
+            input
+                .iter()
+                .enumerate()
+                .map(|(t, x)| x * Complex::from_polar(1.0, -2.0 * PI * (k * t) as f64 / n as f64))
+                .sum()
+        })
+        .collect()
+}
+
+#[test]
+fn fourier_transform() {
+    let signal: Vec<_> = (0..8)
+        .map(|t| c((2.0 * PI * t as f64 / 8.0).cos(), 0.0))
+        .collect();
+    let spectrum = dft(&signal);
+    for (k, x) in spectrum.iter().enumerate() {
+        let expected = if k == 1 || k == 7 { 4.0 } else { 0.0 };
+        assert!((x.norm() - expected).abs() < 1e-12, "bin {}: {}", k, x);
+    }
+}
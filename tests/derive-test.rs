@@ -0,0 +1,145 @@
+#![allow(clippy::op_ref)]
+
+use numeric::Float;
+use proc_vector::VectorOps;
+
+#[derive(Copy, Clone, Debug, PartialEq, VectorOps)]
+struct Rgb<F: Float> {
+    r: F,
+    g: F,
+    b: F,
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, VectorOps)]
+struct Velocity {
+    dx: f32,
+    dy: f32,
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, VectorOps)]
+struct Pair<F>(F, F)
+where
+    F: Float;
+
+#[test]
+fn generic_struct() {
+    let a = Rgb {
+        r: 1.0,
+        g: 2.0,
+        b: 3.0,
+    };
+    let b = Rgb {
+        r: 0.5,
+        g: 0.5,
+        b: 1.0,
+    };
+
+    assert_eq!(
+        a + b,
+        Rgb {
+            r: 1.5,
+            g: 2.5,
+            b: 4.0
+        }
+    );
+    assert_eq!(
+        a - b,
+        Rgb {
+            r: 0.5,
+            g: 1.5,
+            b: 2.0
+        }
+    );
+    assert_eq!(
+        a * b,
+        Rgb {
+            r: 0.5,
+            g: 1.0,
+            b: 3.0
+        }
+    );
+    assert_eq!(
+        a / b,
+        Rgb {
+            r: 2.0,
+            g: 4.0,
+            b: 3.0
+        }
+    );
+    assert_eq!(
+        -a,
+        Rgb {
+            r: -1.0,
+            g: -2.0,
+            b: -3.0
+        }
+    );
+
+    assert_eq!(
+        a + 1.0,
+        Rgb {
+            r: 2.0,
+            g: 3.0,
+            b: 4.0
+        }
+    );
+    assert_eq!(
+        a * 2.0,
+        Rgb {
+            r: 2.0,
+            g: 4.0,
+            b: 6.0
+        }
+    );
+    assert_eq!(
+        a / 2.0,
+        Rgb {
+            r: 0.5,
+            g: 1.0,
+            b: 1.5
+        }
+    );
+}
+
+#[test]
+fn references() {
+    let mut a = Velocity { dx: 1.0, dy: 2.0 };
+    let mut b = Velocity { dx: 3.0, dy: 4.0 };
+    let sum = Velocity { dx: 4.0, dy: 6.0 };
+
+    assert_eq!(&a + &b, sum);
+    assert_eq!(&mut a + &mut b, sum);
+    assert_eq!(&a - 1.0, Velocity { dx: 0.0, dy: 1.0 });
+    assert_eq!(&mut a * 3.0, Velocity { dx: 3.0, dy: 6.0 });
+    assert_eq!(-&a, Velocity { dx: -1.0, dy: -2.0 });
+    assert_eq!(-&mut a, Velocity { dx: -1.0, dy: -2.0 });
+}
+
+#[test]
+fn assign() {
+    let mut v = Velocity { dx: 1.0, dy: 2.0 };
+    let mut w = Velocity { dx: 1.0, dy: 1.0 };
+
+    v += w;
+    v -= &w;
+    v *= &mut w;
+    v /= 2.0;
+    assert_eq!(v, Velocity { dx: 0.5, dy: 1.0 });
+
+    v += 1.0;
+    v *= Velocity { dx: 2.0, dy: 2.0 };
+    v -= 0.5;
+    v /= Velocity { dx: 5.0, dy: 3.5 };
+    assert_eq!(v, Velocity { dx: 0.5, dy: 1.0 });
+}
+
+#[test]
+fn tuple_struct() {
+    let p = Pair(1.0f64, -2.0);
+    assert_eq!(p + p, Pair(2.0, -4.0));
+    assert_eq!(-p * 2.0, Pair(-2.0, 4.0));
+
+    let mut q = p;
+    q -= p;
+    assert_eq!(q, Pair(0.0, 0.0));
+}
@@ -0,0 +1,206 @@
+use math_vector::Vector;
+use numeric::dual::{derivative, gradient, Dual, DualN};
+use numeric::{Float, Num, NumCast, One, Zero};
+
+use std::f64::consts::{FRAC_PI_4, PI};
+
+/// Check the derivative of `f` at `x` against a central difference.
+fn check(f: impl Fn(Dual<f64>) -> Dual<f64>, x: f64) {
+    let (y, dy) = derivative(&f, x);
+    let h = 1e-6;
+    let fd = (f(Dual::constant(x + h)).re - f(Dual::constant(x - h)).re) / (2.0 * h);
+    assert_eq!(y, f(Dual::constant(x)).re);
+    assert!(
+        (dy - fd).abs() < 1e-6 * fd.abs().max(1.0),
+        "at {}: {} != {}",
+        x,
+        dy,
+        fd
+    );
+}
+
+#[test]
+fn arithmetic() {
+    let a = Dual::new(3.0, 1.0);
+    let b = Dual::new(2.0, -1.0);
+    assert_eq!((a + b).eps, 0.0);
+    assert_eq!((a - b).eps, 2.0);
+    assert_eq!((a * b).eps, 2.0 - 3.0);
+    assert_eq!((a / b).eps, (1.0 * 2.0 + 3.0) / 4.0);
+    assert_eq!((-a).eps, -1.0);
+    assert_eq!((a % b).eps, 1.0 + 1.0);
+    assert_eq!((a * 2.0).eps, 2.0);
+    assert_eq!((a / 2.0).eps, 0.5);
+    assert_eq!((a + 2.0).eps, 1.0);
+
+    let mut c = a;
+    c *= b;
+    c += a;
+    c -= Dual::one();
+    c /= Dual::constant(2.0);
+    assert_eq!((c.re, c.eps), (4.0, 0.0));
+
+    // Comparisons ignore the derivative.
+    assert_eq!(Dual::new(1.0, 2.0), Dual::new(1.0, 3.0));
+    assert!(Dual::new(1.0, 5.0) < Dual::new(2.0, 0.0));
+    assert!(Dual::<f64>::zero().is_zero());
+    assert_eq!(Dual::new(2.0, 0.0), 2.0.into());
+}
+
+#[test]
+fn elementary_functions() {
+    for &x in &[0.3, 0.7, 1.9] {
+        check(|x| x.sqrt(), x);
+        check(|x| x.cbrt(), x);
+        check(|x| x.exp(), x);
+        check(|x| x.exp2(), x);
+        check(|x| x.exp_m1(), x);
+        check(|x| x.ln(), x);
+        check(|x| x.ln_1p(), x);
+        check(|x| x.log2(), x);
+        check(|x| x.log10(), x);
+        check(|x| x.log(Dual::constant(3.0)), x);
+        check(|x| x.recip(), x);
+        check(|x| x.powi(3), x);
+        check(|x| x.powi(-2), x);
+        check(|x| x.powf(Dual::constant(2.5)), x);
+        check(|x| Dual::constant(2.5).powf(x), x);
+        check(|x| x.powf(x), x);
+        check(|x| x.abs(), -x);
+        check(|x| x.fract(), x);
+        check(|x| x.to_degrees(), x);
+        check(|x| x.to_radians(), x);
+        check(|x| x.mul_add(x, Dual::constant(1.0)), x);
+        check(|x| x.hypot(Dual::constant(2.0)), x);
+    }
+}
+
+#[test]
+fn trigonometric_functions() {
+    for &x in &[-0.6, 0.2, 0.9] {
+        check(|x| x.sin(), x);
+        check(|x| x.cos(), x);
+        check(|x| x.tan(), x);
+        check(|x| x.asin(), x);
+        check(|x| x.acos(), x);
+        check(|x| x.atan(), x);
+        check(|x| x.atan2(Dual::constant(-0.5)), x);
+        check(|x| Dual::constant(0.5).atan2(x), x);
+        check(|x| x.sinh(), x);
+        check(|x| x.cosh(), x);
+        check(|x| x.tanh(), x);
+        check(|x| x.asinh(), x);
+        check(|x| x.atanh(), x);
+        check(|x| (x + 2.0).acosh(), x);
+        check(|x| x.sin_cos().0 * x.sin_cos().1, x);
+    }
+}
+
+#[test]
+fn piecewise_functions() {
+    assert_eq!(derivative(|x| x.floor(), 1.5), (1.0, 0.0));
+    assert_eq!(derivative(|x| x.round(), 1.5), (2.0, 0.0));
+    assert_eq!(derivative(|x| x.signum(), -1.5), (-1.0, 0.0));
+    assert_eq!(derivative(|x| x.max(Dual::constant(1.0)), 2.0), (2.0, 1.0));
+    assert_eq!(derivative(|x| x.max(Dual::constant(3.0)), 2.0), (3.0, 0.0));
+    assert_eq!(derivative(|x| x.min(Dual::constant(3.0)), 2.0), (2.0, 1.0));
+    assert_eq!(derivative(|x| x.abs_sub(Dual::constant(3.0)), 2.0), (0.0, 0.0));
+    assert_eq!(derivative(|x| x.powi(0), 2.0), (1.0, 0.0));
+    assert_eq!(derivative(|x| x.sqrt(), 0.0), (0.0, f64::INFINITY));
+    assert_eq!(derivative(|x| x.hypot(x), 0.0), (0.0, 0.0));
+    assert_eq!(
+        derivative(|x| x.powi(i32::MIN), 1.0),
+        (1.0, i32::MIN as f64)
+    );
+    assert_eq!(
+        derivative(|x| x.powi(i32::MIN), -1.0),
+        (1.0, -(i32::MIN as f64))
+    );
+    assert_eq!(
+        derivative(|x| x.powi(i32::MAX), 1.0),
+        (1.0, i32::MAX as f64)
+    );
+
+    // constants keep a zero derivative where the function has none
+    assert_eq!(Dual::constant(0.0).sqrt(), Dual::constant(0.0));
+    assert_eq!(Dual::constant(0.0).cbrt(), Dual::constant(0.0));
+    assert_eq!(Dual::constant(0.0).ln(), Dual::constant(f64::NEG_INFINITY));
+    let x = DualN::new(0.0, [1.0, 0.0]).sqrt();
+    assert_eq!(x.eps, [f64::INFINITY, 0.0]);
+}
+
+#[test]
+fn numeric_traits() {
+    let x: Dual<f64> = NumCast::from(3u8).unwrap();
+    assert_eq!((x.re, x.eps), (3.0, 0.0));
+    let y = Dual::<f32>::from_str_radix("1.5", 10).unwrap();
+    assert_eq!((y.re, y.eps), (1.5, 0.0));
+    assert!(<Dual<f64> as Float>::nan().re.is_nan());
+    assert!(Dual::new(f64::INFINITY, 0.0).is_infinite());
+    assert_eq!(numeric::cast::<_, i32>(Dual::new(2.7, 1.0)), Some(2));
+}
+
+/// A function generic over `F: Float`, unaware of differentiation.
+fn rosenbrock<F: Float>(x: F, y: F) -> F {
+    let one = F::one();
+    let hundred = F::from(100).unwrap();
+    (one - x).powi(2) + hundred * (y - x * x).powi(2)
+}
+
+#[test]
+fn gradients() {
+    let (f, g) = gradient(|[x, y]| rosenbrock(x, y), [1.0, 1.0]);
+    assert_eq!((f, g), (0.0, [0.0, 0.0]));
+
+    let (x, y) = (-0.5, 2.0);
+    let (_, g) = gradient(|[x, y]| rosenbrock(x, y), [x, y]);
+    let dx = -2.0 * (1.0 - x) - 400.0 * x * (y - x * x);
+    let dy = 200.0 * (y - x * x);
+    assert_eq!(g, [dx, dy]);
+
+    let v = DualN::<f64, 3>::variable(2.0, 1);
+    assert_eq!(v.eps, [0.0, 1.0, 0.0]);
+    assert_eq!((v * v).eps, [0.0, 4.0, 0.0]);
+}
+
+#[test]
+#[should_panic]
+fn variable_out_of_range() {
+    DualN::<f64, 2>::variable(0.0, 2);
+}
+
+/// Signed distance to a sphere of radius 1 centred at `(1, 0, 0)`.
+fn sphere<F: Float>(p: Vector<F>) -> F {
+    (p - Vector::new(F::one(), F::zero(), F::zero())).length() - F::one()
+}
+
+#[test]
+fn vector_normals() {
+    let p = [3.0, 2.0, 1.0];
+    let (d, n) = gradient(|[x, y, z]| sphere(Vector::new(x, y, z)), p);
+    assert_eq!(d, 2.0);
+    let n = Vector::from(n);
+    assert!(n.is_close(Vector::new(2.0, 2.0, 1.0) / 3.0));
+
+    // Derivative along a ray.
+    let dir = Vector::new(0.0, FRAC_PI_4.cos(), FRAC_PI_4.sin());
+    let (_, slope) = derivative(
+        |t| {
+            let o = Vector::new(Dual::constant(1.0), Dual::zero(), Dual::zero());
+            let dir = Vector::new(dir.x.into(), dir.y.into(), dir.z.into());
+            sphere(o + dir * t)
+        },
+        3.0,
+    );
+    assert!((slope - 1.0).abs() < 1e-15);
+
+    let (angle, rate) = derivative(
+        |t| {
+            let v = Vector::from_angle(t);
+            v.y.atan2(v.x)
+        },
+        PI / 3.0,
+    );
+    assert!((angle - PI / 3.0).abs() < 1e-15);
+    assert!((rate - 1.0).abs() < 1e-15);
+}
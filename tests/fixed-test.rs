@@ -0,0 +1,289 @@
+use numeric::fixed::{FixedErrorKind, Q16_16, Q32_32};
+use numeric::real::Real;
+use numeric::{
+    Bounded, CheckedDiv, CheckedMul, FloatConst, FromPrimitive, Num, NumCast, SaturatingMul,
+    Signed, ToPrimitive, WrappingMul,
+};
+
+fn q16(v: f64) -> Q16_16 {
+    Q16_16::from_f64(v).unwrap()
+}
+
+fn q32(v: f64) -> Q32_32 {
+    Q32_32::from_f64(v).unwrap()
+}
+
+fn assert_near<T: ToPrimitive + std::fmt::Display>(x: T, expected: f64, tolerance: f64) {
+    let v = x.to_f64().unwrap();
+    assert!(
+        (v - expected).abs() <= tolerance,
+        "{} != {} (tolerance {})",
+        x,
+        expected,
+        tolerance
+    );
+}
+
+#[test]
+fn representation() {
+    assert_eq!(Q16_16::ONE.to_bits(), 1 << 16);
+    assert_eq!(Q32_32::ONE.to_bits(), 1 << 32);
+    assert_eq!(Q16_16::from_bits(3 << 15), q16(1.5));
+    assert_eq!(Q16_16::from_bits(-3 << 16), (-3i16).into());
+    assert_eq!(q32(0.75), q16(0.75).into());
+    assert_eq!(Q16_16::DELTA.to_f64(), Some(1.0 / 65536.0));
+    assert_eq!(<Q16_16 as Bounded>::min_value(), Q16_16::MIN);
+    assert_eq!(<Q32_32 as Bounded>::max_value(), Q32_32::MAX);
+}
+
+#[test]
+fn arithmetic() {
+    let a = q16(2.5);
+    let b = q16(-1.25);
+    assert_eq!(a + b, q16(1.25));
+    assert_eq!(a - b, q16(3.75));
+    assert_eq!(a * b, q16(-3.125));
+    assert_eq!(a / b, q16(-2.0));
+    assert_eq!(a % q16(1.0), q16(0.5));
+    assert_eq!(-a, q16(-2.5));
+
+    let mut c = a;
+    c += b;
+    c *= q16(4.0);
+    c -= q16(1.0);
+    c /= q16(2.0);
+    assert_eq!(c, q16(2.0));
+
+    // Products and quotients round to the nearest value.
+    assert_eq!(Q16_16::DELTA * q16(0.5), Q16_16::DELTA);
+    assert_eq!(Q16_16::DELTA * q16(0.25), Q16_16::ZERO);
+    assert_eq!(q16(1.0) / q16(3.0), Q16_16::from_bits(21845));
+    assert_eq!(q16(2.0) / q16(3.0), Q16_16::from_bits(43691));
+    assert_eq!(q16(-2.0) / q16(3.0), Q16_16::from_bits(-43691));
+}
+
+#[test]
+#[should_panic]
+fn divide_by_zero() {
+    let _ = Q16_16::ONE / Q16_16::ZERO;
+}
+
+#[test]
+fn checked_wrapping_saturating() {
+    let big = q16(30000.0);
+    assert_eq!(big.checked_mul(&q16(2.0)), None);
+    assert_eq!(q16(3.0).checked_mul(&q16(0.5)), Some(q16(1.5)));
+    assert_eq!(q16(1.0).checked_div(&Q16_16::ZERO), None);
+    assert_eq!(SaturatingMul::saturating_mul(&big, &q16(-2.0)), Q16_16::MIN);
+    assert_eq!(
+        big.wrapping_mul(&q16(2.0)),
+        Q16_16::from_bits(60000i32.wrapping_shl(16))
+    );
+    assert_eq!(Signed::abs(&Q16_16::MIN), Q16_16::MAX);
+}
+
+#[test]
+fn parsing_and_display() {
+    assert_eq!("1.5".parse::<Q16_16>(), Ok(q16(1.5)));
+    assert_eq!("-0.25".parse::<Q32_32>(), Ok(q32(-0.25)));
+    assert_eq!(Q16_16::from_str_radix("-10.1", 2), Ok(q16(-2.5)));
+    assert_eq!(Q16_16::from_str_radix("ff.8", 16), Ok(q16(255.5)));
+    assert_eq!(Q16_16::from_str_radix("-32768", 10), Ok(Q16_16::MIN));
+    assert_eq!("0.1".parse::<Q16_16>(), Ok(Q16_16::from_bits(6554)));
+
+    let kind = |s: &str| s.parse::<Q16_16>().unwrap_err().kind;
+    assert_eq!(kind(""), FixedErrorKind::Empty);
+    assert_eq!(kind("."), FixedErrorKind::Invalid);
+    assert_eq!(kind("1.2.3"), FixedErrorKind::Invalid);
+    assert_eq!(kind("x"), FixedErrorKind::Invalid);
+    assert_eq!(kind("32768"), FixedErrorKind::Overflow);
+
+    assert_eq!(q16(0.75).to_string(), "0.75");
+    assert_eq!(q16(-3.0).to_string(), "-3");
+    assert_eq!("0.1".parse::<Q16_16>().unwrap().to_string(), "0.1");
+    assert_eq!("0.7".parse::<Q16_16>().unwrap().to_string(), "0.7");
+    assert_eq!("123.456".parse::<Q32_32>().unwrap().to_string(), "123.456");
+    assert_eq!(format!("{:.3}", q16(1.9999)), "2.000");
+    assert_eq!(format!("{:>8.2}", q16(-1.5)), "   -1.50");
+
+    // Display always gives back the same number.
+    for bits in (-200_000..200_000).step_by(997) {
+        let x = Q16_16::from_bits(bits);
+        assert_eq!(x.to_string().parse(), Ok(x));
+    }
+}
+
+#[test]
+fn casts() {
+    assert_eq!(q16(-2.75).to_i32(), Some(-2));
+    assert_eq!(q16(2.75).to_u8(), Some(2));
+    assert_eq!(q16(-2.75).to_u8(), None);
+    assert_eq!(q16(0.5).to_f32(), Some(0.5));
+    assert_eq!(Q16_16::from_i32(40000), None);
+    assert_eq!(Q16_16::from_u8(7), Some(q16(7.0)));
+    assert_eq!(Q16_16::from_f64(f64::NAN), None);
+    assert_eq!(Q16_16::from_f64(1e10), None);
+    assert_eq!(Q16_16::from_f64(1.4 / 65536.0), Some(Q16_16::DELTA));
+    assert_eq!(<Q32_32 as NumCast>::from(1.5f32), Some(q32(1.5)));
+    assert_eq!(<Q32_32 as NumCast>::from(-12i8), Some(q32(-12.0)));
+    assert_eq!(<Q32_32 as NumCast>::from(Q32_32::MAX), Some(Q32_32::MAX));
+    assert_eq!(<Q32_32 as NumCast>::from(Q32_32::MIN), Some(Q32_32::MIN));
+    assert_eq!(
+        <Q32_32 as NumCast>::from(Q16_16::MAX),
+        Some(<Q32_32 as From<Q16_16>>::from(Q16_16::MAX))
+    );
+    assert_eq!(
+        <Q32_32 as NumCast>::from(i32::MAX as i64),
+        Some(q32(2147483647.0))
+    );
+    assert_eq!(
+        <Q32_32 as NumCast>::from(i32::MIN as i64),
+        Some(Q32_32::MIN)
+    );
+    assert_eq!(<Q32_32 as NumCast>::from(1i64 << 31), None);
+    assert_eq!(<Q32_32 as NumCast>::from(i64::MAX), None);
+    assert_eq!(<Q16_16 as NumCast>::from(f64::INFINITY), None);
+}
+
+#[test]
+fn rounding() {
+    for &v in &[-2.5, -1.75, -0.25, 0.0, 0.25, 1.5, 2.75] {
+        let x = q16(v);
+        assert_eq!(x.floor(), q16(v.floor()), "floor {}", v);
+        assert_eq!(x.ceil(), q16(v.ceil()), "ceil {}", v);
+        assert_eq!(x.round(), q16(v.round()), "round {}", v);
+        assert_eq!(x.trunc(), q16(v.trunc()), "trunc {}", v);
+        assert_eq!(x.fract(), q16(v.fract()), "fract {}", v);
+    }
+    assert_eq!(Q16_16::MAX.ceil(), Q16_16::MAX.floor());
+    assert_eq!(Q16_16::MAX.round(), q16(32767.0));
+    assert_eq!(Q16_16::MAX.floor(), q16(32767.0));
+    assert_eq!(Q16_16::MIN.round(), Q16_16::MIN);
+    assert_eq!(Q16_16::MIN.floor(), Q16_16::MIN);
+    assert_eq!(Q16_16::MIN.ceil(), Q16_16::MIN);
+    assert_eq!(Q32_32::MAX.round(), Q32_32::MAX.ceil());
+    assert_eq!(Q32_32::MAX.floor(), q32(2147483647.0));
+    assert_eq!(Q32_32::MIN.round(), Q32_32::MIN);
+    assert_eq!(Q32_32::MIN.floor(), Q32_32::MIN);
+    assert_eq!(Q32_32::MIN.ceil(), Q32_32::MIN);
+    assert_eq!((Q16_16::MIN + Q16_16::DELTA).round(), Q16_16::MIN);
+    assert_eq!(q16(-2.0).signum(), q16(-1.0));
+    assert_eq!(Q16_16::ZERO.signum(), Q16_16::ZERO);
+}
+
+#[test]
+fn sqrt() {
+    assert_eq!(q16(2.25).sqrt(), q16(1.5));
+    assert_eq!(q16(-1.0).sqrt(), Q16_16::ZERO);
+    for i in 1..1000 {
+        let v = i as f64 * 0.731;
+        assert_near(q32(v).sqrt(), v.sqrt(), 1e-9);
+        assert_near(q16(v).sqrt(), v.sqrt(), 2e-5);
+    }
+    assert_near(q32(3.0).hypot(q32(4.0)), 5.0, 1e-9);
+    assert_near(Q32_32::MAX.hypot(Q32_32::MAX), 2147483648.0, 1.0);
+    assert_near(q32(-27.0).cbrt(), -3.0, 1e-8);
+}
+
+#[test]
+fn trigonometry() {
+    for i in -500..500 {
+        let v = i as f64 * 0.0371;
+        assert_near(q32(v).sin(), v.sin(), 1e-9);
+        assert_near(q32(v).cos(), v.cos(), 1e-9);
+        assert_near(q16(v).sin(), v.sin(), 3e-5);
+        assert_near(q32(v).atan(), v.atan(), 1e-9);
+        if v.cos().abs() > 0.1 {
+            assert_near(q32(v).tan(), v.tan(), 1e-7);
+        }
+    }
+    assert_near(q32(1000.0).sin(), 1000f64.sin(), 1e-8);
+    for i in -100..=100 {
+        let v = i as f64 / 100.0;
+        assert_near(q32(v).asin(), v.asin(), 1e-9);
+        assert_near(q32(v).acos(), v.acos(), 1e-9);
+    }
+    for &(y, x) in &[
+        (1.0, 1.0),
+        (1.0, -1.0),
+        (-1.0, -1.0),
+        (0.0, -2.0),
+        (-3.0, 0.0),
+        (1e-6, 1e5),
+    ] {
+        assert_near(q32(y).atan2(q32(x)), f64::atan2(y, x), 1e-9);
+    }
+    assert_near(q32(90.0).to_radians(), std::f64::consts::FRAC_PI_2, 1e-9);
+    assert_near(Q32_32::PI().to_degrees(), 180.0, 1e-7);
+}
+
+#[test]
+fn exponentials() {
+    for i in -200..200 {
+        let v = i as f64 * 0.0937;
+        let e = v.exp();
+        assert_near(q32(v).exp(), e, 1e-9 * e.max(1.0));
+        assert_near(q32(v).exp2(), v.exp2(), 1e-9 * v.exp2().max(1.0));
+        assert_near(q32(v).exp_m1(), v.exp_m1(), 1e-9 * e.max(1.0));
+        assert_near(q32(v).sinh(), v.sinh(), 1e-9 * v.cosh());
+        assert_near(q32(v).cosh(), v.cosh(), 1e-9 * v.cosh());
+        assert_near(q32(v).tanh(), v.tanh(), 1e-9);
+        assert_near(q32(v).asinh(), v.asinh(), 1e-9);
+    }
+    assert_eq!(q32(30.0).exp(), Q32_32::MAX);
+    assert_eq!(q32(-30.0).exp(), Q32_32::ZERO);
+    assert_eq!(q16(11.0).exp(), Q16_16::MAX);
+    assert_near(q16(3.0).exp(), 3f64.exp(), 1e-3);
+}
+
+#[test]
+fn logarithms() {
+    for i in 1..2000 {
+        let v = i as f64 * 0.0613;
+        // The rounding of `v` itself is magnified by the slope of the logarithm.
+        let tolerance = 1e-9 * (1.0 + 1.0 / v);
+        assert_near(q32(v).ln(), v.ln(), tolerance);
+        assert_near(q32(v).log2(), v.log2(), tolerance);
+        assert_near(q32(v).log10(), v.log10(), tolerance);
+        assert_near(q32(v).log(q32(3.0)), v.log(3.0), tolerance);
+        assert_near(q32(v).powf(q32(0.37)), v.powf(0.37), 1e-8 * v.powf(0.37));
+        assert_near(q32(v + 1.0).acosh(), (v + 1.0).acosh(), 1e-9);
+    }
+    assert_near(Q32_32::DELTA.ln(), -32.0 * std::f64::consts::LN_2, 1e-9);
+    assert_near(q32(0.5).atanh(), 0.5f64.atanh(), 1e-9);
+    assert_eq!(Q16_16::ZERO.ln(), Q16_16::MIN);
+    assert_eq!(q16(2.0).powi(10), q16(1024.0));
+    assert_eq!(q16(2.0).powi(-2), q16(0.25));
+    assert_eq!(q16(2.0).powi(20), Q16_16::MAX);
+    assert_eq!(q16(-2.0).powf(q16(3.0)), q16(-8.0));
+}
+
+#[test]
+fn deterministic() {
+    // The exact bits must not change across platforms or releases.
+    assert_eq!(q32(1.0).sin().to_bits(), 3614090360);
+    assert_eq!(q32(1.0).exp().to_bits(), 11674931555);
+    assert_eq!(q32(2.0).sqrt().to_bits(), 6074001000);
+    assert_eq!(q16(0.5).atan2(q16(-0.5)).to_bits(), 154416);
+}
+
+/// A simulation step written once against `Real`.
+fn fall<R: Real>(height: R, steps: usize) -> R {
+    let dt = R::from(1.0 / 60.0).unwrap();
+    let g = R::from(9.81).unwrap();
+    let mut y = height;
+    let mut v = R::zero();
+    for _ in 0..steps {
+        v = v - g * dt;
+        y = v.mul_add(dt, y);
+    }
+    y
+}
+
+#[test]
+fn generic_over_real() {
+    let exact = fall(100.0f64, 120);
+    assert_near(fall(q32(100.0), 120), exact, 1e-6);
+    assert_near(fall(q16(100.0), 120), exact, 1e-2);
+    assert_eq!(fall(q16(100.0), 120), fall(q16(100.0), 120));
+}
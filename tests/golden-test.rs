@@ -0,0 +1,153 @@
+#![cfg(feature = "alloc")]
+
+mod golden;
+
+use std::panic;
+
+use golden::{Tolerance, UPDATE_VAR};
+use numeric::assert_approx_eq;
+use phoenyx::framebuffer::{ColorFormat, Framebuffer};
+use phoenyx::raster::{Rasterizer, Vertex};
+use phoenyx::{Vector, Vector4};
+
+fn image(pixels: &[[u8; 4]]) -> Framebuffer {
+    Framebuffer::from_rgba8(pixels.len(), 1, &pixels.concat())
+}
+
+fn triangle() -> Framebuffer {
+    let mut target = Framebuffer::with_attachments(32, 32, ColorFormat::Rgba8, true);
+    target.clear(Vector4::new(0.1, 0.1, 0.2, 1.0));
+    let triangle = [
+        Vertex::new(
+            Vector::new(-0.8, -0.8, 0.0),
+            Vector4::new(1.0, 0.0, 0.0, 1.0),
+        ),
+        Vertex::new(
+            Vector::new(0.8, -0.6, 0.0),
+            Vector4::new(0.0, 1.0, 0.0, 1.0),
+        ),
+        Vertex::new(Vector::new(0.0, 0.9, 0.0), Vector4::new(0.0, 0.0, 1.0, 1.0)),
+    ];
+    Rasterizer::new().draw_triangles(&mut target, &triangle);
+    target
+}
+
+#[test]
+fn delta_e() {
+    assert_approx_eq!(
+        golden::delta_e([0, 0, 0], [255, 255, 255]),
+        100.0,
+        abs = 1e-3
+    );
+    assert_eq!(golden::delta_e([12, 34, 56], [12, 34, 56]), 0.0);
+    let [l, a, b] = golden::lab([255, 0, 0]);
+    assert_approx_eq!(l, 53.24, abs = 0.01);
+    assert_approx_eq!(a, 80.09, abs = 0.01);
+    assert_approx_eq!(b, 67.20, abs = 0.01);
+    // Close grays are below the just noticeable difference of about 2.3.
+    assert!(golden::delta_e([128; 3], [130; 3]) < 2.3);
+    assert!(golden::delta_e([128; 3], [134; 3]) > 2.3);
+}
+
+#[test]
+fn thresholds() {
+    let expected = image(&[
+        [10, 20, 30, 255],
+        [100, 100, 100, 255],
+        [0, 0, 0, 0],
+        [5, 5, 5, 5],
+    ]);
+    let actual = image(&[
+        [12, 20, 29, 255],
+        [100, 100, 100, 255],
+        [0, 0, 0, 9],
+        [5, 5, 5, 5],
+    ]);
+
+    let exact = golden::compare(&expected, &actual, &Tolerance::default());
+    assert_eq!((exact.differing, exact.total), (2, 4));
+    assert_eq!(exact.max_channels, [2, 0, 1, 9]);
+    assert_eq!(exact.ratio(), 0.5);
+    assert!(!exact.passes(&Tolerance::default()));
+
+    let channels = Tolerance {
+        channels: [2, 0, 1, 0],
+        delta_e: 1.0,
+        ..Tolerance::default()
+    };
+    assert_eq!(golden::compare(&expected, &actual, &channels).differing, 1);
+    let alpha = Tolerance {
+        channels: [2, 0, 1, 9],
+        ..channels
+    };
+    assert_eq!(golden::compare(&expected, &actual, &alpha).differing, 0);
+    // A perceptual threshold below the difference of the first pixel.
+    let perceptual = Tolerance {
+        delta_e: exact.max_delta_e / 2.0,
+        ..alpha
+    };
+    assert_eq!(
+        golden::compare(&expected, &actual, &perceptual).differing,
+        1
+    );
+
+    let ratio = Tolerance {
+        max_ratio: 0.5,
+        ..Tolerance::default()
+    };
+    assert!(golden::compare(&expected, &actual, &ratio).passes(&ratio));
+}
+
+#[test]
+fn heatmap() {
+    let expected = image(&[[255, 255, 255, 255], [0, 0, 0, 255], [0, 0, 0, 255]]);
+    let actual = image(&[[255, 255, 255, 255], [40, 40, 40, 255], [80, 80, 80, 255]]);
+    let comparison = golden::compare(&expected, &actual, &Tolerance::default());
+    let heatmap = comparison.heatmap.read_rgba8();
+    // Matching pixels in dim gray, and the largest difference in red.
+    assert_eq!(heatmap[..4], [77, 77, 77, 255]);
+    assert_eq!(heatmap[8..], [255, 0, 0, 255]);
+    assert_eq!([heatmap[4], heatmap[6], heatmap[7]], [255, 0, 255]);
+    assert!(heatmap[5] > 0 && heatmap[5] < 255);
+}
+
+#[test]
+fn render() {
+    let tolerance = Tolerance {
+        channels: [1; 4],
+        delta_e: 1.0,
+        max_ratio: 0.0,
+    };
+    golden::assert_golden("triangle", &triangle(), &tolerance);
+}
+
+#[test]
+fn failure() {
+    // Updating the references would overwrite the triangle with this render.
+    if golden::updating() {
+        return;
+    }
+    let mut render = triangle();
+    render.set_pixel(3, 4, Vector4::new(1.0, 1.0, 1.0, 1.0));
+    let tolerance = Tolerance {
+        max_ratio: 0.0005,
+        ..Tolerance::default()
+    };
+    let result = panic::catch_unwind(|| golden::assert_golden("triangle", &render, &tolerance));
+    let message = *result.unwrap_err().downcast::<String>().unwrap();
+    assert!(message.contains("1 of 1024 pixels differ"), "{message}");
+
+    let diff = std::fs::read(golden::output_dir().join("triangle.diff.png")).unwrap();
+    let diff = Framebuffer::decode_png(&diff).unwrap();
+    assert_eq!(diff.pixel_rgba8(3, 4), [255, 0, 0, 255]);
+    assert_eq!(diff.pixel_rgba8(16, 16)[3], 255);
+    let actual = std::fs::read(golden::output_dir().join("triangle.actual.png")).unwrap();
+    assert_eq!(
+        Framebuffer::decode_png(&actual).unwrap().read_rgba8(),
+        render.read_rgba8()
+    );
+
+    let missing = panic::catch_unwind(|| golden::assert_golden("missing", &render, &tolerance));
+    let message = *missing.unwrap_err().downcast::<String>().unwrap();
+    assert!(message.contains(UPDATE_VAR), "{message}");
+}
@@ -0,0 +1,262 @@
+//! Comparison of renders with reference images.
+//!
+//! References are PNG images next to this file, named after the test that
+//! checks them. [`assert_golden`] compares a framebuffer with its reference
+//! within a [`Tolerance`], and on failure writes the render and a diff heatmap
+//! to the `golden` directory of Cargo's temporary directory for integration
+//! tests. Setting the `PHOENYX_UPDATE_GOLDEN` environment variable to a value
+//! other than empty or `0` writes the renders as the new references instead.
+
+#![allow(dead_code)]
+
+use std::path::PathBuf;
+use std::{env, fs};
+
+use phoenyx::framebuffer::{ColorFormat, Framebuffer};
+use phoenyx::Vector4;
+
+/// The environment variable which regenerates the references.
+pub const UPDATE_VAR: &str = "PHOENYX_UPDATE_GOLDEN";
+
+/// Return whether the references are being regenerated, that is whether
+/// [`UPDATE_VAR`] is set to a value other than empty or `0`.
+pub fn updating() -> bool {
+    env::var_os(UPDATE_VAR).is_some_and(|v| !v.is_empty() && v != "0")
+}
+
+/// How far a render may be from its reference.
+///
+/// A pixel differs when the difference of one of its channels is larger than
+/// the threshold of that channel, or when the CIE76 color difference of its
+/// RGB colors is larger than `delta_e`. The comparison fails when the ratio of
+/// differing pixels is larger than `max_ratio`. The default only accepts
+/// identical images.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Tolerance {
+    /// The largest difference of the 8-bit red, green, blue and alpha values.
+    pub channels: [u8; 4],
+    /// The largest ΔE*ab, where a difference of about 2.3 is just noticeable.
+    pub delta_e: f32,
+    /// The largest ratio of differing pixels, from 0 to 1.
+    pub max_ratio: f64,
+}
+
+impl Default for Tolerance {
+    fn default() -> Self {
+        Self {
+            channels: [0; 4],
+            delta_e: 0.0,
+            max_ratio: 0.0,
+        }
+    }
+}
+
+/// The differences between a render and its reference.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Comparison {
+    /// The number of differing pixels.
+    pub differing: usize,
+    /// The number of pixels.
+    pub total: usize,
+    /// The largest difference of each channel over all pixels.
+    pub max_channels: [u8; 4],
+    /// The largest ΔE*ab over all pixels.
+    pub max_delta_e: f32,
+    /// The heatmap of the differences: differing pixels go from yellow to red
+    /// with their ΔE*ab, and the other ones show the reference in dim gray.
+    pub heatmap: Framebuffer,
+}
+
+impl Comparison {
+    /// Return the ratio of differing pixels.
+    pub fn ratio(&self) -> f64 {
+        if self.total == 0 {
+            0.0
+        } else {
+            self.differing as f64 / self.total as f64
+        }
+    }
+
+    /// Return whether the ratio of differing pixels is within `tolerance`.
+    pub fn passes(&self, tolerance: &Tolerance) -> bool {
+        self.ratio() <= tolerance.max_ratio
+    }
+}
+
+/// Return the value of an 8-bit sRGB channel in linear light.
+fn linear(c: u8) -> f64 {
+    let c = c as f64 / 255.0;
+    if c <= 0.04045 {
+        c / 12.92
+    } else {
+        ((c + 0.055) / 1.055).powf(2.4)
+    }
+}
+
+/// Return the CIELAB coordinates of an 8-bit sRGB color, under D65.
+pub fn lab(rgb: [u8; 3]) -> [f64; 3] {
+    let [r, g, b] = rgb.map(linear);
+    let x = (0.4124564 * r + 0.3575761 * g + 0.1804375 * b) / 0.95047;
+    let y = 0.2126729 * r + 0.7151522 * g + 0.0721750 * b;
+    let z = (0.0193339 * r + 0.1191920 * g + 0.9503041 * b) / 1.08883;
+    let f = |t: f64| {
+        if t > 216.0 / 24389.0 {
+            t.cbrt()
+        } else {
+            (24389.0 / 27.0 * t + 16.0) / 116.0
+        }
+    };
+    let (fx, fy, fz) = (f(x), f(y), f(z));
+    [116.0 * fy - 16.0, 500.0 * (fx - fy), 200.0 * (fy - fz)]
+}
+
+/// Return the CIE76 color difference ΔE*ab of two 8-bit sRGB colors.
+pub fn delta_e(a: [u8; 3], b: [u8; 3]) -> f32 {
+    let (a, b) = (lab(a), lab(b));
+    let squares: f64 = (0..3).map(|i| (a[i] - b[i]).powi(2)).sum();
+    squares.sqrt() as f32
+}
+
+/// Compare a render with its reference, pixel by pixel.
+///
+/// # Panics
+///
+/// Panics if the images do not have the same size.
+pub fn compare(expected: &Framebuffer, actual: &Framebuffer, tolerance: &Tolerance) -> Comparison {
+    let (width, height) = (expected.width(), expected.height());
+    assert_eq!(
+        (actual.width(), actual.height()),
+        (width, height),
+        "render and reference sizes differ"
+    );
+    let (expected, actual) = (expected.read_rgba8(), actual.read_rgba8());
+
+    let mut differing = Vec::new();
+    let mut max_channels = [0; 4];
+    let mut max_delta_e = 0.0f32;
+    let mut heatmap = Framebuffer::with_attachments(width, height, ColorFormat::Rgba8, false);
+    for (i, (e, a)) in expected
+        .chunks_exact(4)
+        .zip(actual.chunks_exact(4))
+        .enumerate()
+    {
+        let channels: [u8; 4] = core::array::from_fn(|c| e[c].abs_diff(a[c]));
+        let delta = delta_e([e[0], e[1], e[2]], [a[0], a[1], a[2]]);
+        for (max, &c) in max_channels.iter_mut().zip(&channels) {
+            *max = (*max).max(c);
+        }
+        max_delta_e = max_delta_e.max(delta);
+        let exceeds = channels.iter().zip(&tolerance.channels).any(|(c, t)| c > t);
+        if exceeds || delta > tolerance.delta_e {
+            differing.push((i, delta));
+        } else {
+            let gray = lab([e[0], e[1], e[2]])[0] as f32 / 100.0 * 0.3;
+            heatmap.set_pixel(i % width, i / width, Vector4::new(gray, gray, gray, 1.0));
+        }
+    }
+    for &(i, delta) in &differing {
+        let heat = if max_delta_e > 0.0 {
+            delta / max_delta_e
+        } else {
+            0.0
+        };
+        heatmap.set_pixel(
+            i % width,
+            i / width,
+            Vector4::new(1.0, 1.0 - heat, 0.0, 1.0),
+        );
+    }
+
+    Comparison {
+        differing: differing.len(),
+        total: width * height,
+        max_channels,
+        max_delta_e,
+        heatmap,
+    }
+}
+
+/// Return the path of the reference image called `name`.
+pub fn reference_path(name: &str) -> PathBuf {
+    [
+        env!("CARGO_MANIFEST_DIR"),
+        "tests",
+        "golden",
+        &format!("{name}.png"),
+    ]
+    .iter()
+    .collect()
+}
+
+/// Return the directory where failed comparisons write their images.
+pub fn output_dir() -> PathBuf {
+    PathBuf::from(env!("CARGO_TARGET_TMPDIR")).join("golden")
+}
+
+/// Assert that a render matches the reference image called `name` within
+/// `tolerance`, or write it as the reference when [`updating`].
+///
+/// # Panics
+///
+/// Panics if the reference is missing, or if the render does not match it.
+/// The render and the diff heatmap are then written as `<name>.actual.png`
+/// and `<name>.diff.png` in [`output_dir`].
+pub fn assert_golden(name: &str, actual: &Framebuffer, tolerance: &Tolerance) {
+    let path = reference_path(name);
+    if updating() {
+        fs::write(&path, actual.encode_png()).expect("cannot write reference image");
+        return;
+    }
+    let data = fs::read(&path).unwrap_or_else(|err| {
+        panic!(
+            "cannot read reference image {}: {err}; set {UPDATE_VAR}=1 to create it",
+            path.display()
+        )
+    });
+    let expected = Framebuffer::decode_png(&data)
+        .unwrap_or_else(|err| panic!("cannot decode reference image {}: {err}", path.display()));
+
+    let sizes = (
+        expected.width(),
+        expected.height(),
+        actual.width(),
+        actual.height(),
+    );
+    if sizes.0 != sizes.2 || sizes.1 != sizes.3 {
+        let actual_path = write_output(name, "actual", actual);
+        panic!(
+            "{name}: render is {}x{} but reference is {}x{}; render written to {}",
+            sizes.2,
+            sizes.3,
+            sizes.0,
+            sizes.1,
+            actual_path.display()
+        );
+    }
+
+    let comparison = compare(&expected, actual, tolerance);
+    if !comparison.passes(tolerance) {
+        let actual_path = write_output(name, "actual", actual);
+        let diff_path = write_output(name, "diff", &comparison.heatmap);
+        panic!(
+            "{name}: {} of {} pixels differ ({:.3}% > {:.3}%), with channel differences up to \
+             {:?} and ΔE up to {:.2}; render written to {}, diff heatmap to {}",
+            comparison.differing,
+            comparison.total,
+            comparison.ratio() * 100.0,
+            tolerance.max_ratio * 100.0,
+            comparison.max_channels,
+            comparison.max_delta_e,
+            actual_path.display(),
+            diff_path.display()
+        );
+    }
+}
+
+fn write_output(name: &str, kind: &str, image: &Framebuffer) -> PathBuf {
+    let dir = output_dir();
+    fs::create_dir_all(&dir).expect("cannot create golden output directory");
+    let path = dir.join(format!("{name}.{kind}.png"));
+    fs::write(&path, image.encode_png()).expect("cannot write golden output image");
+    path
+}
@@ -0,0 +1,526 @@
+#![cfg(feature = "alloc")]
+
+mod golden;
+
+use std::f32::consts::{FRAC_PI_2, PI, TAU};
+
+use golden::Tolerance;
+use numeric::assert_approx_eq;
+use phoenyx::graphics::{ArcMode, Graphics, ShapeKind};
+use phoenyx::sketch::{Context, Runtime, Sketch};
+use phoenyx::tessellate::{self, StrokeCap, StrokeJoin};
+use phoenyx::{Vector, Vector2, Vector4};
+
+const WHITE: Vector4<f32> = Vector4 {
+    x: 1.0,
+    y: 1.0,
+    z: 1.0,
+    w: 1.0,
+};
+
+fn rgba(r: f32, g: f32, b: f32, a: f32) -> Vector4<f32> {
+    Vector4::new(r, g, b, a)
+}
+
+fn points(coordinates: &[(f32, f32)]) -> Vec<Vector2<f32>> {
+    coordinates.iter().map(|&p| Vector2::from(p)).collect()
+}
+
+/// Return the total area of a list of triangles.
+fn area(triangles: &[Vector2<f32>]) -> f32 {
+    assert_eq!(triangles.len() % 3, 0);
+    triangles
+        .chunks_exact(3)
+        .map(|t| (t[1] - t[0]).cross(t[2] - t[0]).abs() / 2.0)
+        .sum()
+}
+
+/// Return the pixels whose color is not `background`, as `(x, y)` pairs.
+fn drawn(g: &Graphics, background: Vector4<f32>) -> Vec<(usize, usize)> {
+    let canvas = g.canvas();
+    let mut pixels = Vec::new();
+    for y in 0..canvas.height() {
+        for x in 0..canvas.width() {
+            if canvas.pixel(x, y) != background {
+                pixels.push((x, y));
+            }
+        }
+    }
+    pixels
+}
+
+/// Golden images may differ on a few edge pixels with other float rounding.
+fn tolerance() -> Tolerance {
+    Tolerance {
+        channels: [2; 4],
+        delta_e: 1.0,
+        max_ratio: 0.002,
+    }
+}
+
+#[test]
+fn polygon_tessellation() {
+    let mut triangles = Vec::new();
+    let l = points(&[
+        (0.0, 0.0),
+        (4.0, 0.0),
+        (4.0, 1.0),
+        (1.0, 1.0),
+        (1.0, 3.0),
+        (0.0, 3.0),
+    ]);
+    tessellate::polygon(&l, &mut triangles);
+    assert_eq!(triangles.len(), 12);
+    assert_eq!(area(&triangles), 6.0);
+
+    // Both orientations, with a repeated closing point and a collinear one.
+    for reverse in [false, true] {
+        let mut star: Vec<_> = (0..10)
+            .map(|i| {
+                let r = if i % 2 == 0 { 10.0 } else { 4.0 };
+                Vector2::from_angle(TAU * i as f32 / 10.0) * r
+            })
+            .collect();
+        let exact: f32 = (0..10)
+            .map(|i| star[i].cross(star[(i + 1) % 10]) / 2.0)
+            .sum();
+        star.insert(1, star[0].lerp(star[1], 0.5));
+        star.push(star[0]);
+        if reverse {
+            star.reverse();
+        }
+        triangles.clear();
+        tessellate::polygon(&star, &mut triangles);
+        assert_approx_eq!(area(&triangles), exact, rel = 1e-5);
+    }
+
+    triangles.clear();
+    tessellate::polygon(
+        &points(&[(0.0, 0.0), (1.0, 1.0), (2.0, 2.0)]),
+        &mut triangles,
+    );
+    assert!(triangles.is_empty());
+}
+
+#[test]
+fn stroke_tessellation() {
+    let stroke = |line: &[(f32, f32)], closed, cap, join| {
+        let mut triangles = Vec::new();
+        tessellate::polyline(&points(line), closed, 2.0, cap, join, &mut triangles);
+        area(&triangles)
+    };
+    let segment = [(0.0, 0.0), (10.0, 0.0)];
+    assert_approx_eq!(
+        stroke(&segment, false, StrokeCap::Butt, StrokeJoin::Miter),
+        20.0,
+        rel = 1e-6
+    );
+    assert_approx_eq!(
+        stroke(&segment, false, StrokeCap::Square, StrokeJoin::Miter),
+        24.0,
+        rel = 1e-6
+    );
+    let round = stroke(&segment, false, StrokeCap::Round, StrokeJoin::Miter);
+    assert!(round < 20.0 + PI && round > 20.0 + PI * 0.85, "{round}");
+
+    // A right angle adds the outer corner, or half of it with a bevel.
+    let corner = [(0.0, 0.0), (10.0, 0.0), (10.0, 10.0)];
+    assert_approx_eq!(
+        stroke(&corner, false, StrokeCap::Butt, StrokeJoin::Miter),
+        41.0,
+        rel = 1e-6
+    );
+    assert_approx_eq!(
+        stroke(&corner, false, StrokeCap::Butt, StrokeJoin::Bevel),
+        40.5,
+        rel = 1e-6
+    );
+    let round = stroke(&corner, false, StrokeCap::Butt, StrokeJoin::Round);
+    assert!(
+        round < 40.0 + PI / 4.0 && round > 40.0 + PI / 4.0 * 0.85,
+        "{round}"
+    );
+
+    // A sharp turn falls back to a bevel above the miter limit.
+    let sharp = [(0.0, 0.0), (10.0, 0.0), (0.0, 0.5)];
+    let miter = stroke(&sharp, false, StrokeCap::Butt, StrokeJoin::Miter);
+    assert_eq!(
+        miter,
+        stroke(&sharp, false, StrokeCap::Butt, StrokeJoin::Bevel)
+    );
+
+    // A closed square has four joins and no caps.
+    let square = [(0.0, 0.0), (10.0, 0.0), (10.0, 10.0), (0.0, 10.0)];
+    assert_approx_eq!(
+        stroke(&square, true, StrokeCap::Square, StrokeJoin::Miter),
+        84.0,
+        rel = 1e-6
+    );
+
+    assert_eq!(
+        stroke(&[(1.0, 1.0)], false, StrokeCap::Butt, StrokeJoin::Miter),
+        0.0
+    );
+    assert_eq!(
+        stroke(&[(1.0, 1.0)], false, StrokeCap::Square, StrokeJoin::Miter),
+        4.0
+    );
+}
+
+#[test]
+fn curves() {
+    let mut ellipse = Vec::new();
+    tessellate::ellipse(
+        Vector2::new(0.0, 0.0),
+        Vector2::new(20.0, 10.0),
+        &mut ellipse,
+    );
+    assert_eq!(ellipse.len(), tessellate::segments(20.0));
+    assert_eq!(ellipse[0], Vector2::new(20.0, 0.0));
+
+    let mut arc = Vec::new();
+    tessellate::arc(
+        Vector2::new(0.0, 0.0),
+        Vector2::new(1.0, 1.0),
+        0.0,
+        FRAC_PI_2,
+        &mut arc,
+    );
+    assert_eq!(arc[0], Vector2::new(1.0, 0.0));
+    assert!(arc.last().unwrap().is_close(Vector2::new(0.0, 1.0)));
+
+    // Sweeps of many turns are flattened as a single one.
+    for stop in [1e20, -1e20, f32::MAX] {
+        arc.clear();
+        tessellate::arc(
+            Vector2::new(0.0, 0.0),
+            Vector2::new(1.0, 1.0),
+            0.0,
+            stop,
+            &mut arc,
+        );
+        assert_eq!(arc.len(), tessellate::segments(1.0) + 1);
+        assert!(arc.last().unwrap().is_close(arc[0]));
+    }
+    let mut g = Graphics::new(8, 8);
+    g.arc((4.0, 4.0), 6.0, 6.0, 1e30, -1e30, ArcMode::Pie);
+    g.arc((4.0, 4.0), 6.0, 6.0, -1e30, 3.0, ArcMode::Pie);
+
+    // The flattened curve stays close to the exact one.
+    let [p0, c0, c1, p1] = [(0.0, 0.0), (0.0, 40.0), (40.0, 40.0), (40.0, 0.0)].map(Vector2::from);
+    let mut curve = vec![p0];
+    tessellate::cubic_bezier(p0, c0, c1, p1, &mut curve);
+    assert_eq!(*curve.last().unwrap(), p1);
+    let exact: Vec<_> = (0..=1000)
+        .map(|i| {
+            let t = i as f32 / 1000.0;
+            Vector2::new(40.0 * t * t * (3.0 - 2.0 * t), 120.0 * t * (1.0 - t))
+        })
+        .collect();
+    for pair in curve.windows(2) {
+        let middle = pair[0].lerp(pair[1], 0.5);
+        let distance = exact
+            .iter()
+            .map(|&p| p.distance(middle))
+            .fold(f32::MAX, f32::min);
+        assert!(distance <= tessellate::TOLERANCE, "{distance}");
+    }
+}
+
+#[test]
+fn fills_and_strokes() {
+    let mut g = Graphics::new(8, 6);
+    g.background(WHITE);
+    g.no_stroke();
+    g.fill(rgba(1.0, 0.0, 0.0, 1.0));
+    g.rect((1.0, 1.0), 3.0, 2.0);
+    let expected: Vec<_> = (1..3).flat_map(|y| (1..4).map(move |x| (x, y))).collect();
+    assert_eq!(drawn(&g, WHITE), expected);
+
+    let mut g = Graphics::new(8, 6);
+    g.background(WHITE);
+    g.stroke_weight(2.0);
+    g.stroke_cap(StrokeCap::Butt);
+    g.line(Vector::new(1.0, 3.0, 5.0), [7.0, 3.0]);
+    let expected: Vec<_> = (2..4).flat_map(|y| (1..7).map(move |x| (x, y))).collect();
+    assert_eq!(drawn(&g, WHITE), expected);
+    assert_eq!(g.canvas().pixel(1, 2), rgba(0.0, 0.0, 0.0, 1.0));
+
+    // Outlines are centered on the edges of shapes.
+    let mut g = Graphics::new(8, 8);
+    g.no_fill();
+    g.stroke_weight(2.0);
+    g.rect(Vector2::new(2.0, 2.0), 4.0, 4.0);
+    assert_eq!(drawn(&g, Vector4::new(0.0, 0.0, 0.0, 0.0)).len(), 36 - 4);
+
+    let mut g = Graphics::new(8, 8);
+    g.no_fill();
+    g.no_stroke();
+    g.ellipse((4.0, 4.0), 6.0, 6.0);
+    g.point((4.0, 4.0));
+    assert!(drawn(&g, Vector4::new(0.0, 0.0, 0.0, 0.0)).is_empty());
+}
+
+#[test]
+fn point_caps() {
+    let mut g = Graphics::new(8, 8);
+    g.background(WHITE);
+    g.stroke_weight(4.0);
+    g.point((4.0, 4.0));
+    // A round point of diameter 4 misses the corners of its square.
+    assert_eq!(drawn(&g, WHITE).len(), 12);
+    g.stroke_cap(StrokeCap::Square);
+    g.point((4.0, 4.0));
+    assert_eq!(drawn(&g, WHITE).len(), 16);
+}
+
+#[test]
+fn blending() {
+    let mut g = Graphics::new(16, 16);
+    g.background(WHITE);
+    g.no_fill();
+    g.stroke(rgba(0.0, 0.0, 1.0, 0.5));
+    g.stroke_weight(3.0);
+    g.stroke_join(StrokeJoin::Round);
+    // The polyline crosses itself, and its joins overlap its segments.
+    g.begin_shape(ShapeKind::Polygon);
+    for p in [(2.0, 2.0), (14.0, 14.0), (14.0, 2.0), (2.0, 14.0)] {
+        g.vertex(p);
+    }
+    g.end_shape(true);
+    let half = rgba(0.5, 0.5, 1.0, 1.0);
+    let canvas = g.canvas();
+    for (x, y) in drawn(&g, WHITE) {
+        assert_eq!(canvas.pixel(x, y), half, "({x}, {y})");
+    }
+
+    // Separate shapes blend over each other.
+    g.no_stroke();
+    g.fill(rgba(1.0, 0.0, 0.0, 0.5));
+    g.rect((0.0, 0.0), 16.0, 16.0);
+    assert_eq!(g.canvas().pixel(2, 8), rgba(0.75, 0.25, 0.5, 1.0));
+    assert_eq!(g.canvas().pixel(8, 1), rgba(1.0, 0.5, 0.5, 1.0));
+
+    let mut g = Graphics::new(1, 1);
+    g.no_stroke();
+    g.fill(rgba(1.0, 1.0, 1.0, 0.5));
+    g.rect((0.0, 0.0), 1.0, 1.0);
+    g.rect((0.0, 0.0), 1.0, 1.0);
+    assert_eq!(g.canvas().pixel(0, 0), rgba(0.75, 0.75, 0.75, 0.75));
+}
+
+#[test]
+fn style() {
+    let mut g = Graphics::new(4, 4);
+    let saved = *g.style();
+    assert_eq!(saved.fill, Some(WHITE));
+    assert_eq!(saved.stroke, Some(rgba(0.0, 0.0, 0.0, 1.0)));
+    assert_eq!(
+        (saved.stroke_weight, saved.stroke_cap),
+        (1.0, StrokeCap::Round)
+    );
+    g.no_fill();
+    g.stroke_join(StrokeJoin::Bevel);
+    assert_eq!(g.style().fill, None);
+    g.set_style(saved);
+    assert_eq!(*g.style(), saved);
+}
+
+#[test]
+#[should_panic(expected = "outside of a shape")]
+fn vertex_outside_shape() {
+    Graphics::new(4, 4).vertex((1.0, 1.0));
+}
+
+#[test]
+fn golden_shapes() {
+    let mut g = Graphics::new(96, 64);
+    g.background(rgba(0.95, 0.93, 0.88, 1.0));
+    g.stroke_weight(2.0);
+
+    g.fill(rgba(0.9, 0.3, 0.2, 1.0));
+    g.rect((4.0, 4.0), 20.0, 14.0);
+    g.fill(rgba(0.2, 0.6, 0.9, 1.0));
+    g.ellipse((40.0, 11.0), 24.0, 14.0);
+    g.fill(rgba(0.3, 0.8, 0.3, 1.0));
+    g.triangle((58.0, 18.0), (68.0, 3.0), (78.0, 18.0));
+    g.fill(rgba(0.9, 0.8, 0.2, 1.0));
+    g.quad((82.0, 4.0), (93.0, 6.0), (90.0, 18.0), (84.0, 15.0));
+
+    g.fill(rgba(0.6, 0.3, 0.8, 1.0));
+    let modes = [ArcMode::Open, ArcMode::Chord, ArcMode::Pie];
+    for (i, mode) in modes.into_iter().enumerate() {
+        g.arc((14.0 + 24.0 * i as f32, 34.0), 20.0, 20.0, 0.3, 4.2, mode);
+    }
+    g.fill(rgba(0.95, 0.6, 0.7, 1.0));
+    g.bezier((74.0, 44.0), (74.0, 20.0), (94.0, 28.0), (92.0, 44.0));
+
+    g.stroke(rgba(0.1, 0.1, 0.3, 1.0));
+    g.line((4.0, 50.0), (40.0, 60.0));
+    for (i, weight) in [1.0, 3.0, 5.0].into_iter().enumerate() {
+        g.stroke_weight(weight);
+        g.point((50.5 + 10.0 * i as f32, 54.5));
+    }
+    g.stroke_cap(StrokeCap::Square);
+    g.point((80.5, 54.5));
+
+    golden::assert_golden("graphics-shapes", g.canvas(), &tolerance());
+}
+
+#[test]
+fn golden_strokes() {
+    let mut g = Graphics::new(96, 72);
+    g.background(WHITE);
+    g.no_fill();
+    g.stroke_weight(6.0);
+    let caps = [StrokeCap::Butt, StrokeCap::Round, StrokeCap::Square];
+    let joins = [StrokeJoin::Miter, StrokeJoin::Bevel, StrokeJoin::Round];
+    for (i, (&cap, &join)) in caps.iter().zip(&joins).enumerate() {
+        let x = 10.0 + 30.0 * i as f32;
+        g.stroke(rgba(0.2 * i as f32, 0.3, 0.8 - 0.2 * i as f32, 1.0));
+        g.stroke_cap(cap);
+        g.stroke_join(join);
+        g.begin_shape(ShapeKind::Polygon);
+        for p in [
+            (x, 36.0),
+            (x + 8.0, 8.0),
+            (x + 14.0, 30.0),
+            (x + 20.0, 20.0),
+        ] {
+            g.vertex(p);
+        }
+        g.end_shape(false);
+        g.line((x, 50.0), (x + 20.0, 50.0));
+        g.rect((x + 2.0, 58.0), 16.0, 8.0);
+    }
+    golden::assert_golden("graphics-strokes", g.canvas(), &tolerance());
+}
+
+#[test]
+fn golden_custom_shapes() {
+    let mut g = Graphics::new(96, 64);
+    g.background(rgba(0.1, 0.1, 0.15, 1.0));
+    g.stroke(WHITE);
+
+    // A concave star.
+    g.fill(rgba(1.0, 0.8, 0.2, 1.0));
+    g.begin_shape(ShapeKind::Polygon);
+    for i in 0..10 {
+        let r = if i % 2 == 0 { 14.0 } else { 6.0 };
+        let angle = TAU * i as f32 / 10.0 - FRAC_PI_2;
+        g.vertex(Vector2::new(16.0, 16.0) + Vector2::from_angle(angle) * r);
+    }
+    g.end_shape(true);
+
+    // A drop made of Bézier curves.
+    g.fill(rgba(0.3, 0.6, 1.0, 1.0));
+    g.begin_shape(ShapeKind::Polygon);
+    g.vertex((48.0, 2.0));
+    g.bezier_vertex((56.0, 14.0), (62.0, 28.0), (48.0, 30.0));
+    g.bezier_vertex((34.0, 28.0), (40.0, 14.0), (48.0, 2.0));
+    g.end_shape(true);
+
+    g.fill(rgba(0.8, 0.3, 0.5, 1.0));
+    g.begin_shape(ShapeKind::TriangleFan);
+    g.vertex((80.0, 16.0));
+    for i in 0..=5 {
+        g.vertex(Vector2::new(80.0, 16.0) + Vector2::from_angle(0.5 * i as f32) * 13.0);
+    }
+    g.end_shape(false);
+
+    g.fill(rgba(0.3, 0.8, 0.5, 1.0));
+    g.begin_shape(ShapeKind::TriangleStrip);
+    for i in 0..8 {
+        g.vertex((4.0 + 5.0 * i as f32, if i % 2 == 0 { 40.0 } else { 56.0 }));
+    }
+    g.end_shape(false);
+
+    g.fill(rgba(0.5, 0.4, 0.9, 1.0));
+    g.begin_shape(ShapeKind::Quads);
+    for p in [(46.0, 38.0), (58.0, 38.0), (58.0, 50.0), (46.0, 50.0)] {
+        g.vertex(p);
+    }
+    for p in [(52.0, 52.0), (62.0, 44.0), (66.0, 60.0), (50.0, 60.0)] {
+        g.vertex(p);
+    }
+    g.end_shape(false);
+
+    g.stroke_weight(3.0);
+    g.begin_shape(ShapeKind::Points);
+    for i in 0..4 {
+        g.vertex((72.0 + 6.0 * i as f32, 40.0));
+    }
+    g.end_shape(false);
+    g.begin_shape(ShapeKind::Lines);
+    for p in [(72.0, 48.0), (90.0, 60.0), (72.0, 60.0), (90.0, 48.0)] {
+        g.vertex(p);
+    }
+    g.end_shape(false);
+
+    golden::assert_golden("graphics-custom-shapes", g.canvas(), &tolerance());
+}
+
+#[test]
+fn golden_translucent() {
+    let mut g = Graphics::new(64, 64);
+    g.background(WHITE);
+    g.no_stroke();
+    let colors = [
+        rgba(1.0, 0.0, 0.0, 0.5),
+        rgba(0.0, 1.0, 0.0, 0.5),
+        rgba(0.0, 0.0, 1.0, 0.5),
+    ];
+    for (i, color) in colors.into_iter().enumerate() {
+        let angle = TAU * i as f32 / 3.0 - FRAC_PI_2;
+        g.fill(color);
+        g.ellipse(
+            Vector2::new(32.0, 34.0) + Vector2::from_angle(angle) * 10.0,
+            34.0,
+            34.0,
+        );
+    }
+    g.no_fill();
+    g.stroke(rgba(0.0, 0.0, 0.0, 0.4));
+    g.stroke_weight(5.0);
+    g.stroke_join(StrokeJoin::Round);
+    g.begin_shape(ShapeKind::Polygon);
+    for i in 0..5 {
+        let angle = TAU * 2.0 * i as f32 / 5.0 - FRAC_PI_2;
+        g.vertex(Vector2::new(32.0, 32.0) + Vector2::from_angle(angle) * 28.0);
+    }
+    g.end_shape(true);
+    golden::assert_golden("graphics-translucent", g.canvas(), &tolerance());
+}
+
+#[test]
+fn sketch() {
+    struct Bounce {
+        position: Vector<f32>,
+        velocity: Vector<f32>,
+    }
+
+    impl Sketch for Bounce {
+        fn setup(&mut self, ctx: &mut Context) {
+            ctx.stroke_weight(2.0);
+            ctx.fill(rgba(1.0, 0.5, 0.0, 1.0));
+        }
+
+        fn draw(&mut self, ctx: &mut Context) {
+            ctx.background(WHITE);
+            self.position += self.velocity;
+            if self.position.x > ctx.width() as f32 - 6.0 {
+                self.velocity.x = -self.velocity.x;
+            }
+            ctx.ellipse(self.position, 12.0, 12.0);
+            ctx.line(self.position, self.position - self.velocity * 4.0);
+        }
+    }
+
+    let mut sketch = Bounce {
+        position: Vector::new(8.0, 8.0, 0.0),
+        velocity: Vector::new(3.0, 1.0, 0.0),
+    };
+    let canvas = Runtime::new(48, 32).run_headless(&mut sketch, 14).clone();
+    assert_eq!(sketch.velocity.x, -3.0);
+    golden::assert_golden("graphics-sketch", &canvas, &tolerance());
+}
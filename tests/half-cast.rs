@@ -0,0 +1,235 @@
+//! Tests of `numeric::half`.
+
+use numeric::cast::*;
+use numeric::float::{Float, FloatCore};
+use numeric::half::{bf16, f16};
+use numeric::{Bounded, Num};
+
+use core::num::FpCategory;
+
+/// Exact value of a `f16`, computed independently of the implementation.
+fn f16_value(bits: u16) -> f64 {
+    let sign = if bits & 0x8000 == 0 { 1.0 } else { -1.0 };
+    let e = ((bits >> 10) & 0x1f) as i32;
+    let m = (bits & 0x3ff) as f64;
+    match e {
+        0 => sign * m * 2f64.powi(-24),
+        31 if m == 0.0 => sign * f64::INFINITY,
+        31 => f64::NAN,
+        _ => sign * (1024.0 + m) * 2f64.powi(e - 25),
+    }
+}
+
+#[test]
+fn to_f32_exact() {
+    for bits in 0..=u16::MAX {
+        let x = f16::from_bits(bits);
+        let expected = f16_value(bits);
+        if expected.is_nan() {
+            assert!(x.to_f32().is_nan(), "{:#06x}", bits);
+        } else {
+            assert_eq!(x.to_f64(), expected, "{:#06x}", bits);
+        }
+
+        // bf16 is the top half of a f32.
+        let y = bf16::from_bits(bits).to_f32();
+        let z = f32::from_bits((bits as u32) << 16);
+        assert!(y.to_bits() == z.to_bits(), "{:#06x}", bits);
+    }
+}
+
+#[test]
+fn round_trip() {
+    for bits in 0..=u16::MAX {
+        let x = f16::from_bits(bits);
+        let y = bf16::from_bits(bits);
+        if x.is_nan() {
+            // Signaling NaNs are quieted.
+            assert!(f16::from_f32(x.to_f32()).is_nan());
+            assert_eq!(f16::from_f32(x.to_f32()).to_bits() & 0x81ff, bits & 0x81ff);
+            continue;
+        }
+        assert_eq!(f16::from_f32(x.to_f32()).to_bits(), bits);
+        assert_eq!(f16::from_f64(x.to_f64()).to_bits(), bits);
+        assert_eq!(bf16::from_f32(y.to_f32()).to_bits(), bits);
+    }
+}
+
+/// Check that `r` is the nearest value to `x` among its neighbours, with ties
+/// going to the even mantissa.
+fn assert_nearest(x: f32, r: u16, value: impl Fn(u16) -> f64) {
+    let x = x as f64;
+    let d = (value(r) - x).abs();
+    let magnitude = r & 0x7fff;
+    let sign = r & 0x8000;
+    for n in [magnitude.wrapping_sub(1), magnitude + 1] {
+        if n > 0x7c00 {
+            continue;
+        }
+        let other = (value(sign | n) - x).abs();
+        assert!(d <= other, "{} rounded to {:#06x}", x, r);
+        if d == other {
+            assert_eq!(r & 1, 0, "{} should round to even", x);
+        }
+    }
+}
+
+#[test]
+fn from_f32_rounding() {
+    let mut bits = 0u32;
+    while bits < 0x7f80_0000 {
+        for x in [f32::from_bits(bits), -f32::from_bits(bits)] {
+            let r = f16::from_f32(x).to_bits();
+            if r & 0x7fff == 0x7c00 {
+                assert!(x.abs() >= 65520.0, "{} overflowed", x);
+            } else {
+                assert_nearest(x, r, f16_value);
+            }
+        }
+        bits += 4099;
+    }
+}
+
+#[test]
+fn f16_special_values() {
+    assert_eq!(f16::from_f32(65504.0), f16::MAX);
+    assert_eq!(f16::from_f32(65519.99), f16::MAX);
+    assert_eq!(f16::from_f32(65520.0), f16::INFINITY);
+    assert_eq!(f16::from_f32(-1e9), f16::NEG_INFINITY);
+    assert_eq!(f16::from_f32(f32::INFINITY), f16::INFINITY);
+    assert!(f16::from_f32(f32::NAN).is_nan());
+    assert!(f16::from_f64(-f64::NAN).is_nan());
+    assert!(f16::from_f32(f32::from_bits(0x7f80_0001)).is_nan());
+    assert_eq!(f16::from_f32(-0.0).to_bits(), 0x8000);
+    assert_eq!(f16::from_f32(1.0), f16::ONE);
+    assert_eq!(f16::from_f32(1.0 + 2f32.powi(-11)), f16::ONE);
+    assert_eq!(f16::from_f32(1.0 + 3.0 * 2f32.powi(-11)).to_bits(), 0x3c02);
+
+    // Subnormals
+    let tiny = 2f32.powi(-24);
+    assert_eq!(f16::MIN_POSITIVE_SUBNORMAL.to_f32(), tiny);
+    assert_eq!(f16::from_f32(tiny), f16::MIN_POSITIVE_SUBNORMAL);
+    assert_eq!(f16::from_f32(tiny / 2.0).to_bits(), 0);
+    assert_eq!(f16::from_f32(tiny * 0.75).to_bits(), 1);
+    assert_eq!(f16::from_f32(tiny * 1.5).to_bits(), 2);
+    assert_eq!(f16::from_f32(2f32.powi(-14)), f16::MIN_POSITIVE);
+    assert_eq!(f16::from_f32(2f32.powi(-14) - tiny / 2.0), f16::MIN_POSITIVE);
+    assert_eq!(f16::from_f32(1e-10).to_bits(), 0);
+    assert_eq!(f16::from_f32(-1e-10).to_bits(), 0x8000);
+
+    assert_eq!(f16::MIN_POSITIVE.classify(), FpCategory::Normal);
+    assert_eq!(f16::from_bits(0x0200).classify(), FpCategory::Subnormal);
+    assert_eq!(f16::INFINITY.classify(), FpCategory::Infinite);
+    assert_eq!(f16::NAN.classify(), FpCategory::Nan);
+    assert_eq!(f16::NEG_ZERO.classify(), FpCategory::Zero);
+    assert_eq!(f16::EPSILON.to_f32(), f32::powi(2.0, -10));
+    assert_eq!(<f16 as Bounded>::min_value().to_f32(), -65504.0);
+}
+
+#[test]
+fn bf16_special_values() {
+    assert_eq!(bf16::from_f32(1.0 + 2f32.powi(-8)), bf16::ONE);
+    assert_eq!(bf16::from_f32(1.0 + 3.0 * 2f32.powi(-8)).to_bits(), 0x3f82);
+    assert_eq!(bf16::from_f32(f32::MAX), bf16::INFINITY);
+    assert_eq!(bf16::MAX.to_f32(), f32::from_bits(0x7f7f_0000));
+    assert_eq!(bf16::from_f32(f32::MIN_POSITIVE), bf16::MIN_POSITIVE);
+    assert_eq!(bf16::MIN_POSITIVE_SUBNORMAL.to_f32(), f32::from_bits(1 << 16));
+    assert_eq!(bf16::from_f32(f32::from_bits(1 << 15)).to_bits(), 0);
+    assert_eq!(bf16::from_f32(f32::from_bits(3 << 15)).to_bits(), 2);
+    assert_eq!(bf16::from_f32(f32::from_bits(1)).to_bits(), 0);
+    assert_eq!(bf16::from_f32(-f32::INFINITY), bf16::NEG_INFINITY);
+    assert!(bf16::from_f32(f32::from_bits(0x7f80_0001)).is_nan());
+    assert_eq!(bf16::EPSILON.to_f32(), f32::powi(2.0, -7));
+}
+
+#[test]
+fn arithmetic() {
+    let a = f16::from_f32(1.5);
+    let b = f16::from_f32(0.25);
+    assert_eq!(a + b, f16::from_f32(1.75));
+    assert_eq!(a - b, f16::from_f32(1.25));
+    assert_eq!(a * b, f16::from_f32(0.375));
+    assert_eq!(a / b, f16::from_f32(6.0));
+    assert_eq!(-a, f16::from_f32(-1.5));
+    assert_eq!(f16::ONE + f16::EPSILON / f16::from_f32(2.0), f16::ONE);
+    assert_eq!(f16::MAX + f16::MAX, f16::INFINITY);
+    assert!((f16::ZERO / f16::ZERO).is_nan());
+    assert_ne!(f16::NAN, f16::NAN);
+    assert_eq!(f16::ZERO, f16::NEG_ZERO);
+    assert!(f16::ONE < f16::MAX);
+
+    let mut c = bf16::from_f32(3.0);
+    c *= bf16::from_f32(2.0);
+    c -= bf16::ONE;
+    assert_eq!(c, bf16::from_f32(5.0));
+}
+
+fn hypot<F: Float>(a: F, b: F) -> F {
+    (a * a + b * b).sqrt()
+}
+
+#[test]
+fn generic_float() {
+    assert_eq!(hypot(f16::from_f32(3.0), f16::from_f32(4.0)).to_f32(), 5.0);
+    assert_eq!(hypot(bf16::from_f32(6.0), bf16::from_f32(8.0)).to_f32(), 10.0);
+    assert_eq!(Float::sqrt(f16::from_f32(2.0)).to_bits(), 0x3da8);
+    assert_eq!(Float::floor(f16::from_f32(-1.5)), f16::from_f32(-2.0));
+    assert_eq!(Float::integer_decode(f16::ONE), (1024, -10, 1));
+    assert_eq!(FloatCore::integer_decode(f16::from_bits(0x8001)), (2, -25, -1));
+    assert_eq!(Float::integer_decode(bf16::ONE), (128, -7, 1));
+    assert!(Float::is_nan(Float::sqrt(f16::from_f32(-1.0))));
+    assert!(FloatCore::is_sign_negative(f16::NEG_ZERO));
+    assert_eq!(f16::from_str_radix("1.5", 10).unwrap(), f16::from_f32(1.5));
+    assert_eq!("-0.1".parse::<f16>().unwrap(), -f16::from_f32(0.1));
+    assert!("x".parse::<f16>().is_err());
+    assert_eq!(f16::from_f32(0.5).to_string(), "0.5");
+}
+
+#[test]
+fn casts() {
+    assert_eq!(cast::<f16, i32>(f16::from_f32(-2.75)), Some(-2));
+    assert_eq!(cast::<f16, u8>(f16::from_f32(-2.75)), None);
+    assert_eq!(cast::<f16, u8>(f16::NAN), None);
+    assert_eq!(cast::<f16, f64>(f16::MAX), Some(65504.0));
+    assert_eq!(cast::<i32, f16>(70000), Some(f16::INFINITY));
+    assert_eq!(cast::<u64, bf16>(70000), Some(bf16::from_f32(70144.0)));
+    assert_eq!(cast::<f64, f16>(0.1), Some(f16::from_bits(0x2e66)));
+    assert_eq!(f16::from_i8(-3), Some(f16::from_f32(-3.0)));
+    assert_eq!(f16::from_f32(7.9).to_u32(), Some(7));
+
+    let h: f16 = 300u16.as_();
+    assert_eq!(h.to_f32(), 300.0);
+    let i: i8 = f16::from_f32(-300.0).as_();
+    assert_eq!(i, -128);
+    let b: bf16 = f16::from_f32(1.0 / 3.0).as_();
+    assert_eq!(b, bf16::from_f32(1.0 / 3.0));
+    let f: f32 = bf16::ONE.as_();
+    assert_eq!(f, 1.0);
+}
+
+#[test]
+fn slices() {
+    let src = [0.0, -1.5, 1e-7, 65520.0, f32::NAN, core::f32::consts::PI];
+    let mut half = [f16::ZERO; 6];
+    f16::from_f32_slice(&src, &mut half);
+    for (h, &s) in half.iter().zip(&src) {
+        assert_eq!(h.to_bits(), f16::from_f32(s).to_bits());
+    }
+
+    let mut back = [0.0; 6];
+    f16::to_f32_slice(&half, &mut back);
+    assert_eq!(back[..4], [0.0, -1.5, 1.1920929e-7, f32::INFINITY]);
+    assert!(back[4].is_nan());
+    assert_eq!(back[5], 3.140625);
+
+    let mut brain = [bf16::ZERO; 6];
+    bf16::from_f32_slice(&src, &mut brain);
+    bf16::to_f32_slice(&brain, &mut back);
+    assert_eq!(back[5], 3.140625);
+}
+
+#[test]
+#[should_panic]
+fn slices_of_different_lengths() {
+    f16::from_f32_slice(&[1.0, 2.0], &mut [f16::ZERO]);
+}
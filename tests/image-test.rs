@@ -0,0 +1,300 @@
+#![cfg(feature = "alloc")]
+
+use phoenyx::framebuffer::{ColorFormat, Framebuffer};
+use phoenyx::image::PngErrorKind;
+use phoenyx::raster::{Rasterizer, Vertex};
+use phoenyx::{Vector, Vector4};
+
+// PNG images written with Python's zlib module, which compresses with fixed
+// and dynamic Huffman codes rather than stored blocks.
+const RGB_FILTERED: &[u8] = &[
+    0x89, 0x50, 0x4e, 0x47, 0x0d, 0x0a, 0x1a, 0x0a, 0x00, 0x00, 0x00, 0x0d, 0x49, 0x48, 0x44, 0x52,
+    0x00, 0x00, 0x00, 0x04, 0x00, 0x00, 0x00, 0x02, 0x08, 0x02, 0x00, 0x00, 0x00, 0xf0, 0xca, 0xea,
+    0x34, 0x00, 0x00, 0x00, 0x16, 0x49, 0x44, 0x41, 0x54, 0x78, 0xda, 0x63, 0x64, 0x60, 0x38, 0x61,
+    0xc3, 0x70, 0x03, 0x82, 0x58, 0x18, 0x52, 0x18, 0xe0, 0x00, 0x00, 0x4f, 0x10, 0x04, 0x6e, 0xd0,
+    0x12, 0x9a, 0xec, 0x00, 0x00, 0x00, 0x00, 0x49, 0x45, 0x4e, 0x44, 0xae, 0x42, 0x60, 0x82,
+];
+
+const PALETTE_2BIT: &[u8] = &[
+    0x89, 0x50, 0x4e, 0x47, 0x0d, 0x0a, 0x1a, 0x0a, 0x00, 0x00, 0x00, 0x0d, 0x49, 0x48, 0x44, 0x52,
+    0x00, 0x00, 0x00, 0x05, 0x00, 0x00, 0x00, 0x01, 0x02, 0x03, 0x00, 0x00, 0x00, 0x6b, 0x90, 0x8c,
+    0x60, 0x00, 0x00, 0x00, 0x0c, 0x50, 0x4c, 0x54, 0x45, 0xff, 0x00, 0x00, 0x00, 0xff, 0x00, 0x00,
+    0x00, 0xff, 0xff, 0xff, 0xff, 0xfb, 0x00, 0x60, 0xf6, 0x00, 0x00, 0x00, 0x01, 0x74, 0x52, 0x4e,
+    0x53, 0x80, 0xad, 0x5e, 0x5b, 0x46, 0x00, 0x00, 0x00, 0x0b, 0x49, 0x44, 0x41, 0x54, 0x78, 0xda,
+    0x63, 0x90, 0x76, 0x00, 0x00, 0x00, 0x79, 0x00, 0x5c, 0x9c, 0xd0, 0x30, 0xf5, 0x00, 0x00, 0x00,
+    0x00, 0x49, 0x45, 0x4e, 0x44, 0xae, 0x42, 0x60, 0x82,
+];
+
+const GRAY16_TRNS: &[u8] = &[
+    0x89, 0x50, 0x4e, 0x47, 0x0d, 0x0a, 0x1a, 0x0a, 0x00, 0x00, 0x00, 0x0d, 0x49, 0x48, 0x44, 0x52,
+    0x00, 0x00, 0x00, 0x02, 0x00, 0x00, 0x00, 0x01, 0x10, 0x00, 0x00, 0x00, 0x00, 0x81, 0xd9, 0xfc,
+    0x15, 0x00, 0x00, 0x00, 0x02, 0x74, 0x52, 0x4e, 0x53, 0x12, 0x34, 0x2f, 0xd3, 0x49, 0x5e, 0x00,
+    0x00, 0x00, 0x0d, 0x49, 0x44, 0x41, 0x54, 0x78, 0xda, 0x63, 0x10, 0x32, 0xf9, 0xff, 0x1f, 0x00,
+    0x03, 0xe6, 0x02, 0x45, 0xf1, 0x1c, 0x84, 0x65, 0x00, 0x00, 0x00, 0x00, 0x49, 0x45, 0x4e, 0x44,
+    0xae, 0x42, 0x60, 0x82,
+];
+
+const GRAY_DYNAMIC: &[u8] = &[
+    0x89, 0x50, 0x4e, 0x47, 0x0d, 0x0a, 0x1a, 0x0a, 0x00, 0x00, 0x00, 0x0d, 0x49, 0x48, 0x44, 0x52,
+    0x00, 0x00, 0x00, 0x20, 0x00, 0x00, 0x00, 0x20, 0x08, 0x00, 0x00, 0x00, 0x00, 0x56, 0x11, 0x25,
+    0x28, 0x00, 0x00, 0x04, 0x00, 0x49, 0x44, 0x41, 0x54, 0x78, 0xda, 0x85, 0xc7, 0xef, 0x4f, 0x92,
+    0x09, 0x1c, 0x00, 0xf0, 0xaf, 0x85, 0x8a, 0x84, 0x80, 0x88, 0x8a, 0x8a, 0x4f, 0x48, 0x0c, 0x89,
+    0xd0, 0x71, 0xc4, 0x90, 0xec, 0x59, 0x18, 0x81, 0xe3, 0xfc, 0x35, 0x74, 0x68, 0x44, 0x96, 0x54,
+    0xe4, 0x70, 0x23, 0xc7, 0x95, 0x29, 0x3a, 0x63, 0xcd, 0x48, 0x98, 0xb1, 0x49, 0xb3, 0x98, 0xa9,
+    0x6b, 0x44, 0xb4, 0xa1, 0x6d, 0xc5, 0x8c, 0x1c, 0x67, 0xe9, 0x3c, 0x73, 0x8e, 0xa6, 0x2b, 0x64,
+    0xb5, 0xe1, 0xa2, 0x0d, 0x97, 0x33, 0xe7, 0x16, 0x63, 0xe6, 0x16, 0x73, 0x54, 0xf7, 0x07, 0xdc,
+    0x8b, 0xfb, 0xbc, 0xfb, 0x00, 0xfc, 0xaf, 0x94, 0x7d, 0xfb, 0x31, 0xa9, 0x69, 0xe9, 0xd8, 0x0c,
+    0xdc, 0x01, 0x7c, 0x26, 0x81, 0x48, 0xca, 0x22, 0x67, 0x53, 0x72, 0x72, 0xf3, 0xa8, 0xf9, 0x05,
+    0x85, 0xb4, 0x22, 0xe4, 0x20, 0xc0, 0x3e, 0x4c, 0x1a, 0x16, 0x87, 0x27, 0x90, 0xc8, 0x94, 0x5c,
+    0x6a, 0x01, 0x0d, 0xa1, 0x33, 0x98, 0x2c, 0x36, 0x87, 0x5b, 0xc6, 0xe3, 0x0b, 0x84, 0xa2, 0x0a,
+    0xf4, 0x04, 0xc0, 0xfe, 0xb4, 0x0c, 0x3c, 0x91, 0x9c, 0x43, 0x2d, 0x44, 0x8a, 0x99, 0x25, 0x9c,
+    0x52, 0xde, 0x51, 0xe1, 0x31, 0x54, 0x2c, 0x91, 0xc9, 0x6b, 0xea, 0x1b, 0x94, 0xa7, 0xd5, 0xe7,
+    0x00, 0x30, 0x58, 0x3c, 0x89, 0x42, 0xa5, 0xd1, 0x99, 0x6c, 0x2e, 0x4f, 0x20, 0x42, 0x2b, 0xa5,
+    0xf2, 0x5a, 0x85, 0x52, 0xd5, 0xa2, 0xd1, 0xea, 0xf4, 0x86, 0x4e, 0x63, 0x1f, 0x40, 0x2a, 0x8e,
+    0x48, 0xc9, 0x47, 0x0e, 0xb1, 0x4b, 0xf9, 0xe5, 0xe8, 0xc9, 0xaa, 0x1a, 0x45, 0x93, 0xba, 0x55,
+    0xdb, 0xde, 0x71, 0xcd, 0x78, 0xa3, 0xdf, 0x62, 0xbb, 0xeb, 0x18, 0x03, 0x48, 0xc3, 0x93, 0xa9,
+    0x08, 0x93, 0xc3, 0x13, 0xa2, 0x12, 0x79, 0xbd, 0x52, 0xad, 0x69, 0xd3, 0x5f, 0x35, 0x9a, 0xcc,
+    0x83, 0x76, 0xc7, 0xb8, 0xcb, 0xf3, 0xcc, 0x37, 0x03, 0x90, 0x4e, 0xc8, 0xa1, 0x1d, 0xe2, 0xfc,
+    0x21, 0x12, 0x57, 0xd5, 0x29, 0xcf, 0x5e, 0x6c, 0x37, 0x74, 0x9b, 0x6e, 0xdb, 0x86, 0x47, 0x1f,
+    0x79, 0x9e, 0x4f, 0xcf, 0x2e, 0x2e, 0x87, 0xd6, 0x00, 0xb0, 0x24, 0x2a, 0x9d, 0xcd, 0x13, 0x55,
+    0xca, 0x15, 0x2a, 0x8d, 0xce, 0x60, 0xbc, 0x69, 0xb5, 0x8f, 0x38, 0x3d, 0x5e, 0xff, 0x7c, 0x20,
+    0x18, 0x8e, 0x6e, 0xc5, 0x13, 0x00, 0x19, 0xe4, 0x42, 0x66, 0xa9, 0x50, 0x2c, 0x6f, 0x50, 0x5f,
+    0xd2, 0x5f, 0x37, 0x59, 0xec, 0x0f, 0x5c, 0x4f, 0x7d, 0xb3, 0x4b, 0xef, 0xc3, 0xeb, 0xdb, 0xdf,
+    0x93, 0xa9, 0x84, 0x3c, 0x00, 0x1c, 0x05, 0x61, 0xf3, 0xd1, 0x2a, 0x85, 0x5a, 0xdb, 0x61, 0xec,
+    0xb7, 0x39, 0x9c, 0x93, 0xbe, 0xb9, 0x40, 0x28, 0xb2, 0x19, 0xdf, 0xc3, 0x10, 0xa8, 0x0c, 0xae,
+    0x10, 0xe0, 0x40, 0x6e, 0x31, 0xb7, 0x5c, 0x52, 0xa7, 0xba, 0xd4, 0xd1, 0x63, 0x1e, 0x1a, 0x7d,
+    0xe2, 0x7d, 0xb5, 0xb4, 0x1a, 0xf9, 0xba, 0xf3, 0x13, 0x9b, 0x8d, 0x1c, 0x16, 0x88, 0xab, 0x9b,
+    0x00, 0xf0, 0x54, 0x26, 0x0f, 0x95, 0x2b, 0x35, 0x7a, 0xa3, 0xd9, 0x3e, 0xee, 0xf1, 0xcd, 0xaf,
+    0x84, 0x37, 0xe2, 0x49, 0x2c, 0x85, 0xce, 0x15, 0x49, 0x15, 0x2d, 0xba, 0x4e, 0x80, 0xcc, 0x82,
+    0x12, 0xc1, 0xc9, 0xfa, 0xb3, 0xba, 0xeb, 0xfd, 0x43, 0xe3, 0x13, 0xd3, 0x6f, 0x82, 0x9f, 0xb7,
+    0x7f, 0x60, 0xb2, 0x90, 0x23, 0x22, 0x59, 0x63, 0xab, 0xbe, 0x67, 0x60, 0x18, 0x80, 0x40, 0xe3,
+    0x88, 0xaa, 0x94, 0x17, 0x0d, 0x26, 0xdb, 0xa8, 0x67, 0x7a, 0x31, 0x14, 0x8d, 0x25, 0x71, 0x54,
+    0x96, 0x40, 0xa2, 0x38, 0xaf, 0xef, 0xb5, 0x3a, 0xdc, 0x53, 0x00, 0x44, 0xa4, 0x14, 0xad, 0x51,
+    0xb7, 0x1b, 0x2d, 0x8e, 0x27, 0xbe, 0x37, 0xa1, 0xf5, 0xf8, 0x6f, 0x42, 0x11, 0xf7, 0x78, 0xf5,
+    0x19, 0x5d, 0xf7, 0xc0, 0x7d, 0xf7, 0x8b, 0x85, 0x55, 0x00, 0x12, 0x9d, 0x57, 0xa9, 0xd0, 0x18,
+    0x6e, 0xda, 0x9d, 0xde, 0xf9, 0x60, 0x34, 0xfe, 0x9f, 0x43, 0x16, 0xe3, 0xa8, 0xb4, 0xa9, 0xad,
+    0xdb, 0xfa, 0x60, 0xf2, 0xd5, 0xca, 0xe7, 0xd8, 0x6f, 0x52, 0x31, 0xff, 0x94, 0xf2, 0x72, 0x97,
+    0x65, 0x64, 0x62, 0x66, 0x39, 0xf2, 0xed, 0x17, 0x11, 0x80, 0xcc, 0x14, 0xca, 0xd5, 0x7a, 0x93,
+    0xdd, 0xe5, 0x5b, 0x0a, 0x6f, 0x27, 0x09, 0x74, 0xbe, 0xb4, 0x59, 0xd7, 0x6b, 0x7b, 0xe8, 0x5d,
+    0xf8, 0xb0, 0x99, 0xc0, 0xd1, 0xca, 0x00, 0xb2, 0x59, 0xc7, 0x6a, 0x5b, 0xaf, 0xde, 0x1e, 0x79,
+    0x3a, 0xb7, 0xba, 0xf1, 0x03, 0x57, 0xc4, 0x3b, 0xd5, 0xdc, 0xde, 0x37, 0xe4, 0x7a, 0x19, 0xf8,
+    0x14, 0x4b, 0xa1, 0x94, 0x54, 0xd4, 0x01, 0x50, 0xd8, 0xa8, 0x42, 0x6b, 0xb4, 0x39, 0x7d, 0x81,
+    0x48, 0x1c, 0x43, 0xe5, 0x56, 0x2a, 0x75, 0x7d, 0x76, 0xb7, 0x7f, 0x25, 0xba, 0x8b, 0xa5, 0xf1,
+    0xa4, 0x2a, 0x3d, 0x40, 0x0e, 0x47, 0xac, 0x6c, 0x37, 0x0d, 0x7b, 0x66, 0x43, 0x5f, 0x93, 0x59,
+    0xac, 0xe3, 0x8a, 0xcb, 0xbd, 0x43, 0xee, 0xbf, 0xdf, 0x7d, 0x49, 0x64, 0x32, 0xca, 0x6b, 0x2f,
+    0x74, 0xdd, 0x01, 0xc8, 0xe5, 0x4a, 0x54, 0x1d, 0xe6, 0x51, 0xef, 0x52, 0x64, 0x07, 0x8b, 0x08,
+    0xaa, 0x35, 0x5d, 0x36, 0x97, 0xff, 0xdd, 0xc6, 0x1e, 0x89, 0x85, 0x36, 0xea, 0x4c, 0xf7, 0x26,
+    0x01, 0xf2, 0xca, 0x64, 0x2d, 0xd7, 0x06, 0x1f, 0xf9, 0xdf, 0x6f, 0xfe, 0xa4, 0x1c, 0x91, 0x9c,
+    0x31, 0x58, 0x1e, 0xbe, 0x5c, 0xf9, 0xb2, 0x97, 0xc5, 0x16, 0x37, 0x5f, 0x31, 0x8f, 0x4d, 0xbd,
+    0x05, 0xa0, 0xf2, 0xe4, 0x1a, 0xa3, 0xdd, 0x33, 0x1f, 0x8e, 0x63, 0xe9, 0x22, 0x85, 0xee, 0xe6,
+    0x88, 0x37, 0x10, 0x4d, 0x90, 0xd8, 0x95, 0x2a, 0x83, 0xd5, 0xe9, 0x0f, 0x6e, 0x01, 0xe4, 0xf3,
+    0x6b, 0xb4, 0x37, 0x1c, 0xcf, 0x03, 0xeb, 0x7b, 0xd9, 0x5c, 0xd9, 0xf9, 0x6e, 0xfb, 0xc4, 0xc2,
+    0xa7, 0xdd, 0x4c, 0x96, 0x58, 0xf5, 0xd7, 0xe0, 0xe3, 0xd7, 0x1f, 0x63, 0xe9, 0x00, 0x05, 0x82,
+    0x7a, 0x5d, 0xff, 0xf8, 0x74, 0x70, 0x1b, 0x83, 0x88, 0x1a, 0xf5, 0x03, 0xce, 0x99, 0x0f, 0x31,
+    0x2c, 0x03, 0x6d, 0x36, 0x0c, 0xba, 0xe7, 0xc2, 0x3b, 0x78, 0x16, 0x40, 0xa1, 0xb0, 0x41, 0x6f,
+    0x71, 0xcd, 0x86, 0xbf, 0x13, 0x0e, 0x4b, 0x5b, 0x7b, 0xef, 0x7b, 0x97, 0x37, 0x53, 0x68, 0xe5,
+    0x8d, 0x57, 0xac, 0x8f, 0xe7, 0xd6, 0x76, 0x89, 0x1c, 0x19, 0x00, 0x4d, 0xa4, 0x34, 0xd8, 0x3c,
+    0x8b, 0xd1, 0x24, 0x55, 0xa0, 0xd0, 0x5b, 0xdd, 0xf3, 0x91, 0x04, 0x85, 0x57, 0xab, 0x33, 0x3b,
+    0x5f, 0x87, 0x77, 0x49, 0x5c, 0xb9, 0x16, 0xa0, 0xa8, 0xe2, 0x74, 0xe7, 0xdd, 0x67, 0xcb, 0x5b,
+    0xa9, 0x0c, 0x71, 0x4b, 0x8f, 0xe3, 0x45, 0xf0, 0x1b, 0xae, 0x44, 0x7a, 0xc1, 0x34, 0xe6, 0xff,
+    0xb8, 0x43, 0xe4, 0xfe, 0xd9, 0x76, 0x0b, 0x00, 0x41, 0xd5, 0x46, 0x87, 0x2f, 0x14, 0x27, 0x70,
+    0xab, 0x75, 0x03, 0xee, 0x85, 0xe8, 0x2f, 0x5a, 0x85, 0xaa, 0xeb, 0xde, 0x54, 0x30, 0x86, 0xe7,
+    0xc8, 0xdb, 0xcc, 0x2e, 0x80, 0x83, 0x27, 0xce, 0xf5, 0x8d, 0xcd, 0xac, 0x25, 0xf2, 0x84, 0x4d,
+    0x9d, 0xc3, 0x53, 0xab, 0x71, 0x62, 0x59, 0x9d, 0xfe, 0xce, 0xe4, 0xdb, 0xad, 0x74, 0x96, 0x4c,
+    0x7b, 0xcb, 0xf5, 0xcf, 0xbf, 0xb0, 0x14, 0x94, 0x10, 0x7b, 0x30, 0xe0, 0x2e, 0x00, 0x00, 0x00,
+    0x00, 0x49, 0x45, 0x4e, 0x44, 0xae, 0x42, 0x60, 0x82,
+];
+
+#[test]
+fn attachments() {
+    let mut image = Framebuffer::with_attachments(2, 1, ColorFormat::Rgba8, false);
+    assert_eq!(image.format(), ColorFormat::Rgba8);
+    assert!(!image.has_depth());
+    assert_eq!(image.depth(0, 0), None);
+    assert_eq!(image.read_depth(), None);
+
+    image.clear(Vector4::new(0.5, 2.0, -1.0, 1.0));
+    image.set_pixel(1, 0, Vector4::new(0.2, 0.4, 0.6, 0.8));
+    assert_eq!(image.pixel_rgba8(0, 0), [128, 255, 0, 255]);
+    let [r, g, b, a] = [51, 102, 153, 204].map(|c| c as f32 / 255.0);
+    assert_eq!(image.pixel(1, 0), Vector4::new(r, g, b, a));
+    assert_eq!(image.read_rgba8(), [128, 255, 0, 255, 51, 102, 153, 204]);
+
+    let mut image = Framebuffer::new(2, 2);
+    assert_eq!(image.format(), ColorFormat::Rgba32F);
+    assert_eq!(image.read_depth(), Some(&[1.0; 4][..]));
+    let color = Vector4::new(2.0, 0.5, -0.25, 1.0);
+    image.set_pixel(0, 1, color);
+    image.set_depth(0, 1, 0.25);
+    assert_eq!(image.pixel(0, 1), color);
+    assert_eq!(image.pixel_rgba8(0, 1), [255, 128, 0, 255]);
+    assert_eq!(image.depth(0, 1), Some(0.25));
+    image.clear_depth(0.5);
+    assert_eq!(image.read_depth(), Some(&[0.5; 4][..]));
+    assert_eq!(image.read_pixels()[2], color);
+}
+
+#[test]
+fn draw_without_depth() {
+    let mut image = Framebuffer::with_attachments(4, 4, ColorFormat::Rgba8, false);
+    let red = Vector4::new(1.0, 0.0, 0.0, 1.0);
+    let blue = Vector4::new(0.0, 0.0, 1.0, 1.0);
+    let quad = |z: f32, color| {
+        [
+            (-1.0, -1.0),
+            (1.0, -1.0),
+            (1.0, 1.0),
+            (-1.0, -1.0),
+            (1.0, 1.0),
+            (-1.0, 1.0),
+        ]
+        .map(|(x, y)| Vertex::new(Vector::new(x, y, z), color))
+    };
+    let rasterizer = Rasterizer::new();
+    rasterizer.draw_triangles(&mut image, &quad(0.5, red));
+    // Without depth, later triangles draw over earlier ones wherever they are.
+    rasterizer.draw_triangles(&mut image, &quad(0.9, blue));
+    assert!(image.read_rgba8().chunks(4).all(|p| p == [0, 0, 255, 255]));
+}
+
+#[test]
+fn ppm() {
+    let mut image = Framebuffer::with_attachments(2, 1, ColorFormat::Rgba8, false);
+    image.set_pixel(0, 0, Vector4::new(1.0, 0.0, 0.0, 0.0));
+    image.set_pixel(1, 0, Vector4::new(0.0, 0.2, 1.0, 1.0));
+    assert_eq!(
+        image.encode_ppm(),
+        b"P6\n2 1\n255\n\xff\x00\x00\x00\x33\xff"
+    );
+}
+
+#[test]
+fn tga() {
+    let mut image = Framebuffer::with_attachments(1, 2, ColorFormat::Rgba8, false);
+    image.set_pixel(0, 0, Vector4::new(1.0, 0.0, 0.0, 1.0));
+    image.set_pixel(0, 1, Vector4::new(0.0, 0.2, 1.0, 0.0));
+    assert_eq!(
+        image.encode_tga(),
+        [
+            0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 2, 0, 32, 0x28, //
+            0, 0, 255, 255, //
+            255, 51, 0, 0,
+        ]
+    );
+}
+
+#[test]
+fn png_round_trip() {
+    let mut image = Framebuffer::new(3, 2);
+    image.set_pixel(2, 1, Vector4::new(0.25, 0.5, 0.75, 1.0));
+    let png = image.encode_png();
+    assert_eq!(png[..8], *b"\x89PNG\r\n\x1a\n");
+    // An empty IEND chunk always has the same checksum.
+    assert_eq!(png[png.len() - 12..], *b"\0\0\0\0IEND\xae\x42\x60\x82");
+    let decoded = Framebuffer::decode_png(&png).unwrap();
+    assert_eq!(decoded.format(), ColorFormat::Rgba8);
+    assert!(!decoded.has_depth());
+    assert_eq!(decoded.read_rgba8(), image.read_rgba8());
+
+    // More than one stored block.
+    let (width, height) = (200, 100);
+    let bytes: Vec<u8> = (0..4 * width * height)
+        .map(|i| (i * 7 % 251) as u8)
+        .collect();
+    let image = Framebuffer::from_rgba8(width, height, &bytes);
+    assert_eq!(Framebuffer::decode_png(&image.encode_png()), Ok(image));
+}
+
+#[test]
+fn png_decode() {
+    let image = Framebuffer::decode_png(RGB_FILTERED).unwrap();
+    assert_eq!((image.width(), image.height()), (4, 2));
+    for y in 0..2 {
+        for x in 0..4 {
+            let expected = [x * 60, y * 100, 200 - x * 40, 255].map(|c| c as u8);
+            assert_eq!(image.pixel_rgba8(x, y), expected);
+        }
+    }
+
+    let image = Framebuffer::decode_png(PALETTE_2BIT).unwrap();
+    assert_eq!(
+        image.read_rgba8(),
+        [
+            255, 0, 0, 128, //
+            0, 255, 0, 255, //
+            0, 0, 255, 255, //
+            255, 255, 255, 255, //
+            0, 255, 0, 255,
+        ]
+    );
+
+    let image = Framebuffer::decode_png(GRAY16_TRNS).unwrap();
+    assert_eq!(image.read_rgba8(), [18, 18, 18, 0, 255, 255, 255, 255]);
+
+    let image = Framebuffer::decode_png(GRAY_DYNAMIC).unwrap();
+    assert_eq!((image.width(), image.height()), (32, 32));
+    for y in 0..32 {
+        for x in 0..32 {
+            let gray = (x * y % 256) as u8;
+            assert_eq!(image.pixel_rgba8(x, y), [gray, gray, gray, 255]);
+        }
+    }
+}
+
+#[test]
+fn png_errors() {
+    let kind = |data: &[u8]| Framebuffer::decode_png(data).unwrap_err().kind;
+    assert_eq!(kind(b"GIF89a"), PngErrorKind::Signature);
+    assert_eq!(kind(&RGB_FILTERED[..40]), PngErrorKind::Truncated);
+
+    let mut corrupt = RGB_FILTERED.to_vec();
+    corrupt[20] ^= 1;
+    assert_eq!(kind(&corrupt), PngErrorKind::Checksum);
+
+    let mut interlaced = RGB_FILTERED.to_vec();
+    interlaced[28] = 1;
+    let crc = &interlaced[12..29];
+    let crc = crc32(crc).to_be_bytes();
+    interlaced[29..33].copy_from_slice(&crc);
+    assert_eq!(kind(&interlaced), PngErrorKind::Unsupported);
+}
+
+#[test]
+fn png_oversized() {
+    let kind = |data: &[u8]| Framebuffer::decode_png(data).unwrap_err().kind;
+    // RGBA with 16-bit samples, whose rows of 2^31 - 1 pixels overflow.
+    let max = (1 << 31) - 1;
+    assert_eq!(kind(&with_header(max, max, 16, 6)), PngErrorKind::TooLarge);
+    assert_eq!(kind(&with_header(max + 1, 1, 8, 6)), PngErrorKind::Invalid);
+    assert_eq!(kind(&with_header(1, max + 1, 8, 6)), PngErrorKind::Invalid);
+    assert_eq!(kind(&with_header(0, 1, 8, 6)), PngErrorKind::Invalid);
+    // Sizes which fit, but not the data.
+    assert_eq!(kind(&with_header(max, max, 1, 0)), PngErrorKind::Truncated);
+}
+
+/// Return `RGB_FILTERED` with another size, bit depth and color type.
+fn with_header(width: u32, height: u32, depth: u8, color_type: u8) -> Vec<u8> {
+    let mut data = RGB_FILTERED.to_vec();
+    data[16..20].copy_from_slice(&width.to_be_bytes());
+    data[20..24].copy_from_slice(&height.to_be_bytes());
+    data[24] = depth;
+    data[25] = color_type;
+    let crc = crc32(&data[12..29]).to_be_bytes();
+    data[29..33].copy_from_slice(&crc);
+    data
+}
+
+fn crc32(data: &[u8]) -> u32 {
+    let mut crc = !0u32;
+    for &byte in data {
+        crc ^= byte as u32;
+        for _ in 0..8 {
+            crc = if crc & 1 == 1 {
+                crc >> 1 ^ 0xedb8_8320
+            } else {
+                crc >> 1
+            };
+        }
+    }
+    !crc
+}
@@ -0,0 +1,140 @@
+use math_vector::{IntVector, IntVector2, Rounding, Vector, Vector2};
+use numeric::{CheckedAdd, CheckedDiv, SaturatingAdd, SaturatingSub, WrappingAdd};
+
+use std::collections::HashSet;
+
+#[test]
+fn operators() {
+    let a = IntVector::new(1, 2, 3);
+    let b = IntVector::new(4, 5, 6);
+
+    assert_eq!(a + b, IntVector::new(5, 7, 9));
+    assert_eq!(b - a, IntVector::new(3, 3, 3));
+    assert_eq!(a * 2, IntVector::new(2, 4, 6));
+    assert_eq!(b / 2, IntVector::new(2, 2, 3));
+    assert_eq!(-a, IntVector::new(-1, -2, -3));
+
+    let mut c = a;
+    c += b;
+    c -= 1;
+    assert_eq!(c, IntVector::new(4, 6, 8));
+
+    let p = IntVector2::new(3u8, 4);
+    let mut q = p;
+    q += &p;
+    assert_eq!(q, IntVector2::new(6, 8));
+    assert_eq!(p.dot(p), 25);
+    assert_eq!(p.length_squared(), 25);
+}
+
+#[test]
+fn overflow() {
+    let a = IntVector2::new(250u8, 10);
+    let b = IntVector2::new(10u8, 10);
+
+    assert_eq!(a.checked_add(&b), None);
+    assert_eq!(b.checked_add(&b), Some(IntVector2::new(20, 20)));
+    assert_eq!(a.checked_div(&IntVector2::new(1, 0)), None);
+    assert_eq!(a.wrapping_add(&b), IntVector2::new(4, 20));
+    assert_eq!(a.saturating_add(&b), IntVector2::new(255, 20));
+    assert_eq!(b.saturating_sub(&a), IntVector2::new(0, 0));
+
+    let v = IntVector::new(i32::MAX, 0, i32::MIN);
+    assert_eq!(
+        v.saturating_add(&IntVector::new(1, 1, -1)),
+        IntVector::new(i32::MAX, 1, i32::MIN)
+    );
+}
+
+#[test]
+fn distances() {
+    let a = IntVector::new(-1, 2, 0);
+    let b = IntVector::new(2, -2, 1);
+    assert_eq!(a.manhattan(b), 8);
+    assert_eq!(a.chebyshev(b), 4);
+    assert_eq!(a.min(b), IntVector::new(-1, -2, 0));
+    assert_eq!(a.max(b), IntVector::new(2, 2, 1));
+
+    // unsigned distances do not underflow
+    let a = IntVector2::new(0u16, 10);
+    let b = IntVector2::new(10u16, 0);
+    assert_eq!(a.manhattan(b), 20);
+    assert_eq!(b.manhattan(a), 20);
+
+    // distances that do not fit in the component type
+    let a = IntVector::new(i32::MIN, 0, 0);
+    let b = IntVector::new(i32::MAX, 0, 0);
+    assert_eq!(a.checked_manhattan(b), None);
+    assert_eq!(a.checked_chebyshev(b), None);
+    let a = IntVector2::new(i8::MAX, 0);
+    let b = IntVector2::new(0, i8::MIN + 1);
+    assert_eq!(a.checked_chebyshev(b), Some(i8::MAX));
+    assert_eq!(a.checked_manhattan(b), None);
+    assert_eq!(
+        IntVector2::new(-3, 4).checked_manhattan(IntVector2::zero()),
+        Some(7)
+    );
+}
+
+#[test]
+#[should_panic(expected = "manhattan distance overflow")]
+fn manhattan_overflow() {
+    IntVector2::new(i32::MIN, 0).manhattan(IntVector2::new(i32::MAX, 0));
+}
+
+#[test]
+fn conversions() {
+    let v = Vector::new(-0.5, 1.5, 2.49);
+    assert_eq!(
+        IntVector::from_vector(v, Rounding::Floor),
+        Some(IntVector::new(-1, 1, 2))
+    );
+    assert_eq!(
+        IntVector::from_vector(v, Rounding::Ceil),
+        Some(IntVector::new(0, 2, 3))
+    );
+    assert_eq!(
+        IntVector::from_vector(v, Rounding::Round),
+        Some(IntVector::new(-1, 2, 2))
+    );
+    assert_eq!(
+        IntVector::from_vector(v, Rounding::Truncate),
+        Some(IntVector::new(0, 1, 2))
+    );
+    assert_eq!(
+        IntVector::<i32>::from_vector(Vector::new(f64::NAN, 0.0, 0.0), Rounding::Floor),
+        None
+    );
+    assert_eq!(
+        IntVector2::<i8>::from_vector(Vector2::new(200.0, 0.0), Rounding::Floor),
+        None
+    );
+
+    assert_eq!(
+        IntVector::new(1, -2, 3).to_vector(),
+        Vector::new(1.0, -2.0, 3.0)
+    );
+    assert_eq!(IntVector2::new(7, 8).to_vector(), Vector2::new(7.0f32, 8.0));
+
+    assert_eq!(
+        IntVector::new(1i32, 2, 3).cast::<u8>(),
+        Some(IntVector::new(1, 2, 3))
+    );
+    assert_eq!(IntVector2::new(-1i32, 2).cast::<u8>(), None);
+
+    assert_eq!(IntVector::new(1, 2, 3).truncate(), IntVector2::new(1, 2));
+    assert_eq!(IntVector2::new(1, 2).extend(3), IntVector::new(1, 2, 3));
+
+    let t: (i32, i32, i32) = IntVector::new(1, 2, 3).into();
+    assert_eq!(IntVector::from(t), IntVector::from([1, 2, 3]));
+}
+
+#[test]
+fn hash() {
+    let mut visited = HashSet::new();
+    visited.insert(IntVector2::new(0, 0));
+    visited.insert(IntVector2::new(1, 0));
+    visited.insert(IntVector2::new(0, 0));
+    assert_eq!(visited.len(), 2);
+    assert!(visited.contains(&IntVector2::new(1, 0)));
+}
@@ -0,0 +1,235 @@
+#![allow(clippy::op_ref)]
+
+use math_vector::Vector;
+use numeric::interval::{Interval, IntervalErrorKind};
+use numeric::{Float, NumCast, One, Signed, Zero};
+
+use std::f64::consts::{FRAC_PI_2, PI};
+
+fn i(lo: f64, hi: f64) -> Interval<f64> {
+    Interval::new(lo, hi)
+}
+
+/// Check that `x` contains `value` and is at most a few ulps wide.
+fn assert_tight(x: Interval<f64>, value: f64) {
+    assert!(x.contains(value), "{} does not contain {}", x, value);
+    assert!(
+        x.width() <= 8.0 * f64::EPSILON * value.abs().max(f64::MIN_POSITIVE),
+        "{} is too wide",
+        x
+    );
+}
+
+#[test]
+fn exact_arithmetic() {
+    let a = i(1.0, 2.0);
+    let b = i(-3.0, 0.5);
+    assert_eq!(a + b, i(-2.0, 2.5));
+    assert_eq!(a - b, i(0.5, 5.0));
+    assert_eq!(a * b, i(-6.0, 1.0));
+    assert_eq!(b * b, i(-1.5, 9.0));
+    assert_eq!(a / i(2.0, 4.0), i(0.25, 1.0));
+    assert_eq!(-a, i(-2.0, -1.0));
+    assert_eq!(a * 2.0, i(2.0, 4.0));
+    assert_eq!(&a + &b, a + b);
+    assert_eq!(a * &b, &a * b);
+
+    let mut c = a;
+    c += b;
+    c *= 2.0;
+    c -= &a;
+    assert_eq!(c, i(-6.0, 4.0));
+
+    assert_eq!(a / b, Interval::entire());
+    assert!((a / Interval::zero()).is_nan());
+    assert_eq!(i(0.0, 1.0) * i(1.0, f64::INFINITY), i(0.0, f64::INFINITY));
+    assert_eq!([a, b].iter().sum::<Interval<f64>>(), a + b);
+    assert_eq!([a, a].into_iter().product::<Interval<f64>>(), i(1.0, 4.0));
+}
+
+#[test]
+fn outward_rounding() {
+    let third = Interval::one() / 3.0;
+    assert!(third.lo < third.hi);
+    assert!((third * 3.0).contains(1.0));
+
+    // 0.1 is not representable, so ten tenths bound 1 on both sides.
+    let tenth = Interval::<f64>::one() / 10.0;
+    let sum: Interval<f64> = (0..10).map(|_| tenth).sum();
+    assert!(sum.lo < 1.0 && sum.hi > 1.0);
+
+    // Cancellation which rounds to nearest in floats.
+    let big = Interval::point(1e16);
+    let x = big + 1.0 - big;
+    assert!(x.contains(1.0));
+    assert_eq!(1e16 + 1.0 - 1e16, 0.0);
+
+    // Overflow keeps the finite bound.
+    let huge = Interval::point(f64::MAX) * 2.0;
+    assert_eq!(huge, i(f64::MAX, f64::INFINITY));
+    let sqrt2 = Interval::point(2.0).sqrt();
+    assert!(sqrt2.lo * sqrt2.lo < 2.0 && sqrt2.hi * sqrt2.hi > 2.0);
+    assert_eq!(Interval::point(4.0).sqrt(), Interval::point(2.0));
+}
+
+#[test]
+fn powers() {
+    assert_eq!(i(-2.0, 3.0).powi(2), i(0.0, 9.0));
+    assert_eq!(i(-2.0, 3.0).powi(3), i(-8.0, 27.0));
+    assert_eq!(i(-3.0, -2.0).powi(2), i(4.0, 9.0));
+    assert_eq!(i(2.0, 4.0).powi(-1), i(0.25, 0.5));
+    assert_eq!(i(2.0, 4.0).powi(0), Interval::one());
+    assert_eq!(i(-2.0, 3.0).powf(Interval::point(2.0)), i(0.0, 9.0));
+    let p = i(1.0, 4.0).powf(i(0.5, 1.5));
+    assert!(p.contains(1.0) && p.contains(8.0) && p.lo > 0.99 && p.hi < 8.01);
+    assert_eq!(i(-1.0, 4.0).sqrt(), i(0.0, 2.0));
+    assert!(i(-4.0, -1.0).sqrt().is_nan());
+    assert_tight(i(3.0, 3.0).hypot(Interval::point(4.0)), 5.0);
+}
+
+#[test]
+fn elementary_functions() {
+    for &x in &[0.1, 0.5, 2.0, 1e-300, 1e300] {
+        let p = Interval::point(x);
+        assert_tight(p.exp(), x.exp());
+        assert_tight(p.ln(), x.ln());
+        assert_tight(p.log2(), x.log2());
+        assert_tight(p.cbrt(), x.cbrt());
+        assert_tight(p.atan(), x.atan());
+        assert_tight(p.tanh(), x.tanh());
+    }
+    assert!(i(-1.0, 1.0).exp().contains(1.0 / std::f64::consts::E));
+    assert_eq!(i(-1.0, 0.0).ln().lo, f64::NEG_INFINITY);
+    assert!(i(-2.0, -1.0).ln().is_nan());
+    assert!(i(0.9, 1.2).acosh().contains(0.0));
+    assert!(i(0.5, 2.0).asin().contains(FRAC_PI_2));
+    assert!(i(0.5, 2.0).acos().contains(0.0));
+    assert!(i(-1.0, 2.0).cosh().contains(1.0));
+}
+
+#[test]
+fn trigonometry() {
+    assert_tight(Interval::point(1e-20).sin(), 1e-20);
+    assert_tight(Interval::point(1.0).cos(), 1f64.cos());
+    assert_eq!(i(0.0, 7.0).sin(), i(-1.0, 1.0));
+    assert_eq!(i(-1.0, 1.0).cos().hi, 1.0);
+    assert!(i(-1.0, 1.0).cos().lo < 1f64.cos());
+    assert_eq!(i(1.0, 2.0).sin().hi, 1.0);
+    assert!(i(3.0, 3.5).cos().lo == -1.0);
+    let s = i(0.1, 0.2).sin();
+    assert!(s.contains(0.1f64.sin()) && s.contains(0.2f64.sin()));
+    assert!(s.width() < 0.1);
+
+    assert_eq!(i(1.0, 2.0).tan(), Interval::entire());
+    assert_tight(Interval::point(0.5).tan(), 0.5f64.tan());
+
+    let angle = Interval::point(1.0).atan2(Interval::point(-1.0));
+    assert!(angle.contains(3.0 * PI / 4.0) && angle.width() < 1e-14);
+    assert!(i(-1.0, 1.0).atan2(i(-2.0, -1.0)).contains(PI));
+    assert!(i(1.0, 2.0).atan2(i(1.0, 2.0)).contains(PI / 4.0));
+}
+
+#[test]
+fn set_operations() {
+    let a = i(1.0, 3.0);
+    let b = i(2.0, 5.0);
+    assert!(a.contains(1.0) && !a.contains(3.5));
+    assert_eq!(a.intersection(b), Some(i(2.0, 3.0)));
+    assert_eq!(a.intersection(i(4.0, 5.0)), None);
+    assert_eq!(a.hull(i(4.0, 5.0)), i(1.0, 5.0));
+    assert!(i(2.0, 2.5).is_subset(a));
+    assert!(!b.is_subset(a));
+    assert!(a.overlaps(b));
+    assert_eq!(a.mid(), 2.0);
+    assert_eq!(a.width(), 2.0);
+    assert_eq!(i(-4.0, 3.0).mag(), 4.0);
+    assert!(Interval::point(1.0).is_point());
+
+    // Overlapping intervals are unordered.
+    assert!(a < i(4.0, 5.0));
+    assert_eq!(a.partial_cmp(&b), None);
+    assert!(a <= a);
+}
+
+#[test]
+fn numeric_traits() {
+    assert_eq!(i(-2.0, 1.0).abs(), i(0.0, 2.0));
+    assert_eq!(Signed::abs(&i(-2.0, -1.0)), i(1.0, 2.0));
+    assert_eq!(Signed::signum(&i(-2.0, 1.0)), i(-1.0, 1.0));
+    assert!(i(1.0, 2.0).is_positive());
+    assert!(!i(-1.0, 2.0).is_positive() && !i(-1.0, 2.0).is_negative());
+    assert_eq!(i(-2.5, 1.5).floor(), i(-3.0, 1.0));
+    assert_eq!(i(0.25, 0.75).fract(), i(0.25, 0.75));
+    assert_eq!(i(0.5, 1.5).fract(), i(0.0, 1.0));
+    assert_eq!(i(5.0, 7.0) % 4.0, i(0.0, 4.0));
+    assert_eq!(Interval::point(7.0) % 4.0, Interval::point(3.0));
+
+    let x: Interval<f64> = NumCast::from(3).unwrap();
+    assert_eq!(x, Interval::point(3.0));
+    let y: Interval<f32> = NumCast::from(0.1f64).unwrap();
+    assert!(y.lo < y.hi && y.lo < 0.1 && y.hi > 0.1);
+    let z: Interval<f32> = NumCast::from(16777217u32).unwrap();
+    assert!(z.contains(16777216.0) && !z.is_point());
+    assert_eq!(numeric::cast::<_, i32>(i(1.0, 3.0)), Some(2));
+}
+
+#[test]
+fn parsing() {
+    let x: Interval<f64> = "[1, 2]".parse().unwrap();
+    assert!(x.contains(1.0) && x.contains(2.0) && x.width() < 2.0 + 1e-15);
+    let tenth: Interval<f64> = " 0.1 ".parse().unwrap();
+    assert!(tenth.contains(0.1) && tenth.width() < 1e-16);
+    assert_eq!(format!("{}", i(1.0, 2.5)), "[1, 2.5]");
+    assert_eq!(format!("{:.1}", i(1.0, 2.5)), "[1.0, 2.5]");
+    let y: Interval<f64> = i(-0.1, 0.3).to_string().parse().unwrap();
+    assert!(i(-0.1, 0.3).is_subset(y));
+
+    let kind = |s: &str| s.parse::<Interval<f64>>().unwrap_err().kind;
+    assert!(matches!(kind(" "), IntervalErrorKind::Empty));
+    assert!(matches!(kind("[1, 2"), IntervalErrorKind::Invalid));
+    assert!(matches!(kind("[1, 2, 3]"), IntervalErrorKind::Invalid));
+    assert!(matches!(kind("[2, 1]"), IntervalErrorKind::Invalid));
+    assert!(matches!(kind("[x, 1]"), IntervalErrorKind::Bound(_)));
+}
+
+#[test]
+fn empty() {
+    let empty = Interval::<f64>::empty();
+    assert!(empty.is_empty() && empty.is_nan());
+    assert!(Interval::new(f64::NAN, 1.0).is_empty());
+    assert!(Interval::new(1.0, f64::NAN).lo.is_nan());
+    assert!((i(1.0, 2.0) / i(0.0, 0.0)).is_empty());
+    assert!((i(-1.0, 0.0) / i(0.0, 0.0)).is_empty());
+    assert!((empty + i(1.0, 2.0)).is_empty());
+    assert!((empty / i(1.0, 2.0)).is_empty());
+    assert!(!i(1.0, 2.0).is_empty());
+}
+
+#[test]
+#[should_panic]
+fn reversed_bounds() {
+    i(2.0, 1.0);
+}
+
+/// A ray from the origin along `dir` hits the unit sphere around `centre`.
+fn hits_sphere<F: Float>(dir: Vector<F>, centre: Vector<F>) -> F {
+    let b = dir.dot(centre);
+    b * b - dir.length_squared() * (centre.length_squared() - F::one())
+}
+
+#[test]
+fn vectors() {
+    // A ray exactly tangent to the sphere, on which floats may decide either
+    // way but intervals cannot exclude the hit.
+    let dir = Vector::new(0.1.into(), 0.2.into(), 0.3.into());
+    let c = Vector::new(0.3, 0.6, 0.9) + Vector::new(0.6, -0.3, 0.0).normalized();
+    let around = |x: f64| Interval::new(x - 1e-15, x + 1e-15);
+    let centre = Vector::new(around(c.x), around(c.y), around(c.z));
+    let d = hits_sphere(dir, centre);
+    assert!(d.contains(0.0), "{}", d);
+    assert!(d.width() < 1e-12);
+
+    let v = Vector::new(i(1.0, 2.0), i(-1.0, 1.0), Interval::zero());
+    let len = v.length();
+    assert!(len.contains(1.0) && len.contains(5f64.sqrt()));
+}
@@ -0,0 +1,167 @@
+use math_vector::{Matrix3, Matrix4, Vector};
+
+use std::f64::consts::PI;
+
+fn is_close3(a: Matrix3<f64>, b: Matrix3<f64>) -> bool {
+    a.to_cols_array()
+        .iter()
+        .zip(b.to_cols_array().iter())
+        .all(|(x, y)| (x - y).abs() < 1e-12)
+}
+
+fn is_close4(a: Matrix4<f64>, b: Matrix4<f64>) -> bool {
+    a.to_cols_array()
+        .iter()
+        .zip(b.to_cols_array().iter())
+        .all(|(x, y)| (x - y).abs() < 1e-12)
+}
+
+#[test]
+fn column_major() {
+    let m = Matrix3::new(1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0);
+    assert_eq!(m.col(1), Vector::new(4.0, 5.0, 6.0));
+    assert_eq!(m.row(1), Vector::new(2.0, 5.0, 8.0));
+    assert_eq!(
+        m.to_cols_array(),
+        [1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0]
+    );
+
+    let m = Matrix4::from_translation(Vector::new(1.0, 2.0, 3.0));
+    assert_eq!(&m.to_cols_array()[12..], &[1.0, 2.0, 3.0, 1.0]);
+}
+
+#[test]
+fn identity() {
+    let v = Vector::new(1.0, 2.0, 3.0);
+    assert_eq!(Matrix3::identity() * v, v);
+    assert_eq!(Matrix4::identity() * v, v);
+
+    let m = Matrix3::new(1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0);
+    assert_eq!(m * Matrix3::identity(), m);
+    assert_eq!(Matrix3::identity() * m, m);
+}
+
+#[test]
+fn add_sub_neg() {
+    let m = Matrix3::new(1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0);
+    assert_eq!(m + m, m * 2.0);
+    assert_eq!(m - m, Matrix3::zero());
+    assert_eq!(-m + m, Matrix3::zero());
+
+    let mut n = Matrix4::identity();
+    n += Matrix4::identity();
+    assert_eq!(n, Matrix4::identity() * 2.0);
+    n -= Matrix4::identity();
+    assert_eq!(n, Matrix4::identity());
+}
+
+#[test]
+fn mul() {
+    let a = Matrix3::new(1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0);
+    let b = Matrix3::new(9.0, 8.0, 7.0, 6.0, 5.0, 4.0, 3.0, 2.0, 1.0);
+    let expected = Matrix3::new(90.0, 114.0, 138.0, 54.0, 69.0, 84.0, 18.0, 24.0, 30.0);
+    assert_eq!(a * b, expected);
+    let (ra, rb) = (&a, &b);
+    assert_eq!(ra * rb, expected);
+
+    let mut c = a;
+    c *= b;
+    assert_eq!(c, expected);
+
+    let v = Vector::new(1.0, 0.0, -1.0);
+    assert_eq!(a * v, Vector::new(-6.0, -6.0, -6.0));
+    assert_eq!((a * b) * v, a * (b * v));
+}
+
+#[test]
+fn transform_point_and_vector() {
+    let t = Matrix4::from_translation(Vector::new(1.0, 2.0, 3.0));
+    let s = Matrix4::from_scale(Vector::new(2.0, 2.0, 2.0));
+    let m = t * s;
+
+    let v = Vector::new(1.0, 1.0, 1.0);
+    assert_eq!(m * v, Vector::new(3.0, 4.0, 5.0));
+    assert_eq!(m.transform_point(v), Vector::new(3.0, 4.0, 5.0));
+    assert_eq!(m.transform_vector(v), Vector::new(2.0, 2.0, 2.0));
+}
+
+#[test]
+fn rotation() {
+    let axis = Vector::new(1.0, 2.0, 3.0).normalized();
+    let v = Vector::new(3.0, -1.0, 2.0);
+
+    let m = Matrix3::from_rotation(PI / 3.0, axis);
+    assert!((m * v).is_close(v.rotated(PI / 3.0, axis)));
+
+    let m = Matrix4::from_rotation(PI / 3.0, axis);
+    assert!((m * v).is_close(v.rotated(PI / 3.0, axis)));
+
+    let z = Vector::new(0.0, 0.0, 1.0);
+    let m = Matrix3::from_rotation(PI / 2.0, z);
+    assert!((m * Vector::new(1.0, 0.0, 0.0)).is_close(Vector::new(0.0, 1.0, 0.0)));
+}
+
+#[test]
+fn transpose() {
+    let mut m = Matrix3::new(1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0);
+    let t = m.transposed();
+    assert_eq!(t, Matrix3::new(1.0, 4.0, 7.0, 2.0, 5.0, 8.0, 3.0, 6.0, 9.0));
+    m.transpose();
+    assert_eq!(m, t);
+
+    let m = Matrix4::from_translation(Vector::new(1.0, 2.0, 3.0));
+    assert_eq!(m.transposed().col(3), [0.0, 0.0, 0.0, 1.0]);
+    assert_eq!(m.transposed().transposed(), m);
+}
+
+#[test]
+fn determinant() {
+    let m = Matrix3::new(2.0, 0.0, 1.0, 1.0, 3.0, 2.0, 1.0, 1.0, 2.0);
+    assert_eq!(m.determinant(), 6.0);
+    assert_eq!(m.transposed().determinant(), 6.0);
+
+    let m = Matrix4::new(
+        1.0, 0.0, 2.0, -1.0, 3.0, 0.0, 0.0, 5.0, 2.0, 1.0, 4.0, -3.0, 1.0, 0.0, 5.0, 0.0,
+    );
+    assert_eq!(m.determinant(), 30.0);
+    assert_eq!(m.transposed().determinant(), 30.0);
+
+    let s = Matrix4::from_scale(Vector::new(2.0, 3.0, 4.0));
+    assert_eq!(s.determinant(), 24.0);
+}
+
+#[test]
+fn inverse() {
+    let m = Matrix3::new(2.0, 0.0, 1.0, 1.0, 3.0, 2.0, 1.0, 1.0, 2.0);
+    let inv = m.inverse().unwrap();
+    assert!(is_close3(m * inv, Matrix3::identity()));
+    assert!(is_close3(inv * m, Matrix3::identity()));
+
+    let m = Matrix4::from_translation(Vector::new(1.0, -2.0, 3.0))
+        * Matrix4::from_rotation(0.7, Vector::new(0.0, 1.0, 0.0))
+        * Matrix4::from_scale(Vector::new(2.0, 3.0, 4.0));
+    let inv = m.inverse().unwrap();
+    assert!(is_close4(m * inv, Matrix4::identity()));
+    assert!(is_close4(inv * m, Matrix4::identity()));
+
+    let m = Matrix4::new(
+        1.0, 0.0, 2.0, -1.0, 3.0, 0.0, 0.0, 5.0, 2.0, 1.0, 4.0, -3.0, 1.0, 0.0, 5.0, 0.0,
+    );
+    let inv = m.inverse().unwrap();
+    assert!(is_close4(m * inv, Matrix4::identity()));
+
+    let singular = Matrix3::new(1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0);
+    assert_eq!(singular.inverse(), None);
+    assert_eq!(Matrix4::from(singular).inverse(), None);
+}
+
+#[test]
+fn conversions() {
+    let m = Matrix3::new(1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0);
+    let n = Matrix4::from(m);
+    assert_eq!(n.col(3), [0.0, 0.0, 0.0, 1.0]);
+    assert_eq!(Matrix3::from(n), m);
+
+    let cols: [[f64; 3]; 3] = m.into();
+    assert_eq!(Matrix3::from(cols), m);
+}
@@ -0,0 +1,58 @@
+//! Run with the default features and with
+//! `cargo test --no-default-features --features libm`, in which case the
+//! library crates are `no_std` and their floating point functions come from
+//! `libm` rather than from the standard library used by this test.
+
+use numeric::{assert_approx_eq, Float};
+use phoenyx::{Matrix4, Quaternion, Vector, Vector2};
+
+use std::f64::consts::{FRAC_PI_2, PI};
+
+#[test]
+fn float_backend() {
+    for &x in &[0.1f64, 0.5, 1.0, 2.5, 10.0, 1e-8, 123.456] {
+        assert_approx_eq!(Float::sqrt(x), x.sqrt());
+        assert_approx_eq!(Float::cbrt(x), x.cbrt());
+        assert_approx_eq!(Float::exp(x.min(50.0)), x.min(50.0).exp());
+        assert_approx_eq!(Float::ln(x), x.ln());
+        assert_approx_eq!(Float::sin(x), x.sin());
+        assert_approx_eq!(Float::cos(x), x.cos());
+        assert_approx_eq!(Float::atan2(x, -1.0), x.atan2(-1.0));
+        assert_approx_eq!(Float::powf(x, 1.5), x.powf(1.5));
+        assert_approx_eq!(Float::hypot(x, 3.0), x.hypot(3.0));
+        assert_approx_eq!(Float::tanh(x), x.tanh());
+
+        let y = x as f32;
+        assert_approx_eq!(Float::sqrt(y), y.sqrt());
+        assert_approx_eq!(Float::sin(y), y.sin());
+        assert_approx_eq!(Float::exp(y.min(50.0)), y.min(50.0).exp());
+    }
+    assert_eq!(Float::floor(-2.5f64), -3.0);
+    assert_eq!(Float::round(2.5f32), 3.0);
+    assert!(Float::sqrt(-1.0f64).is_nan());
+}
+
+#[test]
+fn vector_math() {
+    let v = Vector::new(3.0, 4.0, 12.0);
+    assert_eq!(v.length(), 13.0);
+    assert_approx_eq!(v.normalized().length(), 1.0);
+    assert_approx_eq!(
+        v.angle_between(Vector::new(0.0, 0.0, 1.0)),
+        (12.0f64 / 13.0).acos()
+    );
+
+    let r = Vector2::new(1.0f32, 0.0).rotated(FRAC_PI_2 as f32);
+    assert_approx_eq!(r, Vector2::new(0.0, 1.0), abs = 1e-7);
+
+    let q = Quaternion::from_rotation(PI / 2.0, Vector::new(0.0, 0.0, 1.0));
+    assert_approx_eq!(
+        q.rotate_vector(Vector::new(1.0, 0.0, 0.0)),
+        Vector::new(0.0, 1.0, 0.0),
+        abs = 1e-15
+    );
+
+    let m = Matrix4::perspective(PI / 3.0, 1.5, 0.1, 100.0);
+    let id = m * m.inverse().unwrap();
+    assert_approx_eq!(id, Matrix4::identity(), abs = 1e-12);
+}
@@ -1,6 +1,13 @@
 //! Tests of `numeric::cast`.
 
 #![no_std]
+#![allow(unexpected_cfgs, arithmetic_overflow, unnecessary_transmutes)]
+#![allow(
+    clippy::legacy_numeric_constants,
+    clippy::approx_constant,
+    clippy::excessive_precision,
+    clippy::unnecessary_map_or
+)]
 
 #[cfg(feature = "std")]
 #[macro_use]
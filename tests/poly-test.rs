@@ -0,0 +1,141 @@
+use numeric::assert_approx_eq;
+use numeric::poly::{solve_cubic, solve_quadratic, solve_quartic};
+
+/// Return the coefficients of the monic polynomial with the given roots.
+fn from_roots(roots: &[f64]) -> Vec<f64> {
+    let mut coeffs = vec![1.0];
+    for &r in roots {
+        coeffs.push(0.0);
+        for i in (1..coeffs.len()).rev() {
+            coeffs[i] -= r * coeffs[i - 1];
+        }
+    }
+    coeffs
+}
+
+#[test]
+fn quadratic() {
+    assert_eq!(*solve_quadratic(1.0, -3.0, 2.0), [1.0, 2.0]);
+    assert_eq!(*solve_quadratic(2.0, 0.0, -8.0), [-2.0, 2.0]);
+    assert_eq!(*solve_quadratic(1.0, -2.0, 1.0), [1.0]);
+    assert!(solve_quadratic(1.0, 1.0, 1.0).is_empty());
+
+    // Degenerate leading coefficients.
+    assert_eq!(*solve_quadratic(0.0, 2.0, 1.0), [-0.5]);
+    assert!(solve_quadratic(0.0, 0.0, 1.0).is_empty());
+
+    // Coefficients whose squares overflow or underflow.
+    let golden = [-1.618033988749895, 0.6180339887498948];
+    assert_approx_eq!(*solve_quadratic(1e-300, 1e-300, -1e-300), golden);
+    assert_approx_eq!(*solve_quadratic(1e300, 1e300, -1e300), golden);
+    assert_approx_eq!(*solve_quadratic(1.0f32, 1.0, -1.0), [-1.618034, 0.618034]);
+
+    // Coefficients near the largest and smallest numbers.
+    assert_approx_eq!(*solve_quadratic(1e308, -1.5e308, 0.5e308), [0.5, 1.0]);
+    assert_approx_eq!(*solve_quadratic(f64::MAX, 0.0, -f64::MAX), [-1.0, 1.0]);
+    assert_approx_eq!(*solve_quadratic(f32::MAX, 0.0, -f32::MAX), [-1.0, 1.0]);
+    let tiny = 5e-324;
+    assert_approx_eq!(*solve_quadratic(tiny, 0.0, -tiny), [-1.0, 1.0]);
+}
+
+#[test]
+fn quadratic_cancellation() {
+    // The textbook formula computes the small root as the difference of two
+    // nearly equal numbers, and gets 7.45e-9 or even 0.
+    let roots = solve_quadratic(1.0, -1e8, 1.0);
+    assert_approx_eq!(roots[0], 1e-8, rel = 1e-15);
+    assert_approx_eq!(roots[1], 1e8, rel = 1e-15);
+    let naive = (1e8 - (1e16f64 - 4.0).sqrt()) / 2.0;
+    assert!((naive - 1e-8).abs() > 1e-10);
+
+    let roots = solve_quadratic(1.0, 1e10, 1.0);
+    assert_approx_eq!(*roots, [-1e10, -1e-10], rel = 1e-15);
+
+    // Nearly equal roots, whose discriminant 4ε² is lost when b² is rounded.
+    let e = f64::EPSILON;
+    assert_eq!((2.0 + 2.0 * e) * (2.0 + 2.0 * e) - 4.0 * (1.0 + 2.0 * e), 0.0);
+    let roots = solve_quadratic(1.0, -(2.0 + 2.0 * e), 1.0 + 2.0 * e);
+    assert_eq!(*roots, [1.0, 1.0 + 2.0 * e]);
+}
+
+#[test]
+fn cubic() {
+    assert_eq!(*solve_cubic(1.0, -6.0, 11.0, -6.0), [1.0, 2.0, 3.0]);
+    assert_eq!(*solve_cubic(2.0, 0.0, 0.0, -16.0), [2.0]);
+    assert_eq!(*solve_cubic(1.0, -3.0, 3.0, -1.0), [1.0]);
+    assert_eq!(*solve_cubic(1.0, -4.0, 5.0, -2.0), [1.0, 2.0]);
+    assert_eq!(*solve_cubic(1.0, -1.0, -2.0, 0.0), [-1.0, 0.0, 2.0]);
+    assert_approx_eq!(*solve_cubic(1.0, 0.0, 1.0, 1.0), [-0.6823278038280193]);
+    assert_eq!(*solve_cubic(0.0, 1.0, -3.0, 2.0), [1.0, 2.0]);
+
+    // Roots of very different magnitudes.
+    let roots = solve_cubic(1.0, -1.0, -1e-10, 1e-10);
+    assert_approx_eq!(*roots, [-1e-5, 1e-5, 1.0], rel = 1e-14);
+
+    for roots in [[-3.5, 0.25, 7.0], [1e-3, 2.0, 1e3], [-1.0, -0.999, 4.0]] {
+        let c = from_roots(&roots);
+        assert_approx_eq!(*solve_cubic(c[0], c[1], c[2], c[3]), roots, rel = 1e-12);
+    }
+}
+
+#[test]
+fn quartic() {
+    assert_eq!(
+        *solve_quartic(1.0, -10.0, 35.0, -50.0, 24.0),
+        [1.0, 2.0, 3.0, 4.0]
+    );
+    assert_eq!(
+        *solve_quartic(1.0, 2.0, -13.0, -14.0, 24.0),
+        [-4.0, -2.0, 1.0, 3.0]
+    );
+    assert_eq!(
+        *solve_quartic(1.0, 0.0, -5.0, 0.0, 4.0),
+        [-2.0, -1.0, 1.0, 2.0]
+    );
+    assert_eq!(*solve_quartic(1.0, 0.0, 0.0, 0.0, -1.0), [-1.0, 1.0]);
+    assert_eq!(*solve_quartic(1.0, -4.0, 6.0, -4.0, 1.0), [1.0]);
+    assert!(solve_quartic(1.0, 0.0, 0.0, 0.0, 1.0).is_empty());
+    assert_eq!(*solve_quartic(1.0, -1.0, -1.0, 1.0, 0.0), [-1.0, 0.0, 1.0]);
+    assert_eq!(*solve_quartic(0.0, 1.0, -6.0, 11.0, -6.0), [1.0, 2.0, 3.0]);
+    assert_approx_eq!(
+        *solve_quartic(3.0, -2.0, 0.5, 7.0, -1.0),
+        [-1.154879953673267, 0.14206021157555798]
+    );
+    assert_approx_eq!(
+        *solve_quartic(1.0f32, -10.0, 35.0, -50.0, 24.0),
+        [1.0, 2.0, 3.0, 4.0],
+        rel = 1e-6
+    );
+
+    for roots in [
+        [-2.0, -0.5, 0.75, 9.0],
+        [0.1, 0.2, 0.3, 0.4],
+        [-10.0, 1e-2, 1.0, 100.0],
+    ] {
+        let c = from_roots(&roots);
+        let found = solve_quartic(c[0], c[1], c[2], c[3], c[4]);
+        assert_approx_eq!(*found, roots, abs = 1e-12, rel = 1e-10);
+    }
+}
+
+#[test]
+fn ray_torus() {
+    // A ray from (-5, 0, 0.1) along x through a torus around z with radii
+    // 2 and 1/2 enters and leaves each side of the tube.
+    let (big, small) = (2.0f64, 0.5f64);
+    let (o, d) = ([-5.0, 0.0, 0.1], [1.0, 0.0, 0.0]);
+    let dot = |a: [f64; 3], b: [f64; 3]| a[0] * b[0] + a[1] * b[1] + a[2] * b[2];
+    let (dd, od) = (dot(d, d), dot(o, d));
+    let k = dot(o, o) - small * small - big * big;
+    let four_r2 = 4.0 * big * big;
+    let roots = solve_quartic(
+        dd * dd,
+        4.0 * dd * od,
+        2.0 * dd * k + 4.0 * od * od + four_r2 * d[2] * d[2],
+        4.0 * k * od + 2.0 * four_r2 * o[2] * d[2],
+        k * k - four_r2 * (small * small - o[2] * o[2]),
+    );
+    let h = (small * small - o[2] * o[2]).sqrt();
+    let expected = [5.0 - big - h, 5.0 - big + h, 5.0 + big - h, 5.0 + big + h];
+    assert_approx_eq!(*roots, expected, rel = 1e-14);
+}
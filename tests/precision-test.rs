@@ -0,0 +1,112 @@
+use math_vector::protocol::inv_sqrt;
+use math_vector::{Precision, Vector};
+
+fn relative_difference(lhs: f64, rhs: f64) -> f64 {
+    2.0 * (rhs - lhs).abs() / (rhs.abs() + lhs.abs())
+}
+
+/// Return the largest relative error of `inv_sqrt` over a wide range of values.
+fn max_error_f32(precision: Precision) -> f64 {
+    let mut value = 1e-8f32;
+    let mut max = 0.0f64;
+    while value < 1e8 {
+        let result = inv_sqrt(value, precision) as f64;
+        let reference = 1.0 / (value as f64).sqrt();
+        max = max.max(relative_difference(result, reference));
+        value *= 1.001;
+    }
+    max
+}
+
+fn max_error_f64(precision: Precision) -> f64 {
+    let mut value = 1e-8f64;
+    let mut max = 0.0f64;
+    while value < 1e8 {
+        let result = inv_sqrt(value, precision);
+        let reference = 1.0 / value.sqrt();
+        max = max.max(relative_difference(result, reference));
+        value *= 1.001;
+    }
+    max
+}
+
+#[test]
+fn exact() {
+    assert!(max_error_f32(Precision::Exact) <= f32::EPSILON as f64);
+    assert!(max_error_f64(Precision::Exact) <= f64::EPSILON);
+    assert_eq!(Precision::default(), Precision::Exact);
+
+    let v = Vector::new(3.0, 0.0, 4.0);
+    assert_eq!(v.normalized_with(Precision::Exact), v.normalized());
+    assert_eq!(v.limited_with(2.5, Precision::Exact), v.limited(2.5));
+
+    let mut w = v;
+    w.set_length_with(10.0, Precision::Exact);
+    assert_eq!(w, Vector::new(6.0, 0.0, 8.0));
+}
+
+#[cfg(not(feature = "fast-rsqrt"))]
+#[test]
+fn approximations_disabled() {
+    for precision in [Precision::Fast, Precision::Hardware] {
+        assert_eq!(inv_sqrt(2.0f32, precision), inv_sqrt(2.0, Precision::Exact));
+        assert_eq!(inv_sqrt(2.0f64, precision), inv_sqrt(2.0, Precision::Exact));
+    }
+}
+
+#[test]
+fn other_floats_are_exact() {
+    use numeric::dual::Dual;
+    use numeric::interval::Interval;
+
+    for precision in [Precision::Exact, Precision::Fast, Precision::Hardware] {
+        let x = Dual::variable(4.0f64);
+        assert_eq!(inv_sqrt(x, precision), Dual::new(0.5, -0.0625));
+
+        let x = Interval::new(4.0f64, 16.0);
+        assert_eq!(inv_sqrt(x, precision), Interval::new(0.25, 0.5));
+    }
+}
+
+#[cfg(feature = "fast-rsqrt")]
+#[test]
+fn fast() {
+    assert!(max_error_f32(Precision::Fast) <= 5e-6);
+    assert!(max_error_f64(Precision::Fast) <= 5e-6);
+}
+
+#[cfg(feature = "fast-rsqrt")]
+#[test]
+fn hardware() {
+    assert!(max_error_f32(Precision::Hardware) <= 2e-6);
+    assert!(max_error_f64(Precision::Hardware) <= 1e-9);
+
+    // the estimate is single precision, so these are computed exactly
+    for value in [1e-300f64, 1e-50, 1e50, 1e300] {
+        let result = inv_sqrt(value, Precision::Hardware);
+        assert!(relative_difference(result, 1.0 / value.sqrt()) <= 1e-9);
+    }
+}
+
+#[cfg(feature = "fast-rsqrt")]
+#[test]
+fn vectors() {
+    use math_vector::{Vector2, Vector4};
+
+    for precision in [Precision::Fast, Precision::Hardware] {
+        let v = Vector::new(1.0f32, -2.0, 3.0).normalized_with(precision);
+        assert!((v.length() - 1.0).abs() < 1e-5);
+
+        let v = Vector2::new(30.0f64, 40.0).limited_with(5.0, precision);
+        assert!((v.length() - 5.0).abs() < 1e-4);
+
+        let mut v = Vector4::new(1.0, 1.0, 1.0, 1.0);
+        v.set_length_with(4.0, precision);
+        assert!((v.x - 2.0f64).abs() < 1e-4);
+
+        // vectors of length zero are left untouched
+        let mut z = Vector::<f64>::zero();
+        z.normalize_with(precision);
+        assert_eq!(z, Vector::zero());
+    }
+}
@@ -0,0 +1,138 @@
+use math_vector::{Matrix4, Vector};
+
+use std::f64::consts::PI;
+
+fn assert_matrix_eq(m: Matrix4<f64>, expected: [f64; 16]) {
+    for (i, (a, b)) in m.to_cols_array().iter().zip(expected.iter()).enumerate() {
+        assert!((a - b).abs() < 1e-6, "component {}: {} != {}", i, a, b);
+    }
+}
+
+fn clip(m: Matrix4<f64>, v: Vector<f64>) -> [f64; 4] {
+    m.mul_array([v.x, v.y, v.z, 1.0])
+}
+
+#[test]
+fn perspective() {
+    // reference values from `glm::perspective(glm::radians(45.0), 4.0 / 3.0, 0.1, 100.0)`
+    let m = Matrix4::perspective(PI / 4.0, 4.0 / 3.0, 0.1, 100.0);
+    #[rustfmt::skip]
+    assert_matrix_eq(m, [
+        1.810660, 0.0, 0.0, 0.0,
+        0.0, 2.414214, 0.0, 0.0,
+        0.0, 0.0, -1.002002, -1.0,
+        0.0, 0.0, -0.200200, 0.0,
+    ]);
+
+    // near and far planes are mapped to -1 and 1
+    assert!(((m * Vector::new(0.0, 0.0, -0.1)).z + 1.0).abs() < 1e-12);
+    assert!(((m * Vector::new(0.0, 0.0, -100.0)).z - 1.0).abs() < 1e-12);
+    assert_eq!(clip(m, Vector::new(0.0, 0.0, -5.0))[3], 5.0);
+}
+
+#[test]
+fn perspective_infinite() {
+    let m = Matrix4::perspective_infinite(PI / 2.0, 1.0, 0.5);
+    #[rustfmt::skip]
+    assert_matrix_eq(m, [
+        1.0, 0.0, 0.0, 0.0,
+        0.0, 1.0, 0.0, 0.0,
+        0.0, 0.0, -1.0, -1.0,
+        0.0, 0.0, -1.0, 0.0,
+    ]);
+
+    assert!(((m * Vector::new(0.0, 0.0, -0.5)).z + 1.0).abs() < 1e-12);
+    assert!((m * Vector::new(0.0, 0.0, -1e12)).z < 1.0);
+
+    let far = Matrix4::perspective(PI / 2.0, 1.0, 0.5, 1e12);
+    let inf = m.to_cols_array();
+    for (a, b) in far.to_cols_array().iter().zip(inf.iter()) {
+        assert!((a - b).abs() < 1e-9);
+    }
+}
+
+#[test]
+fn perspective_reversed_z() {
+    let m = Matrix4::perspective_reversed_z(PI / 2.0, 2.0, 1.0, 10.0);
+    #[rustfmt::skip]
+    assert_matrix_eq(m, [
+        0.5, 0.0, 0.0, 0.0,
+        0.0, 1.0, 0.0, 0.0,
+        0.0, 0.0, 1.0 / 9.0, -1.0,
+        0.0, 0.0, 10.0 / 9.0, 0.0,
+    ]);
+
+    assert!(((m * Vector::new(0.0, 0.0, -1.0)).z - 1.0).abs() < 1e-12);
+    assert!((m * Vector::new(0.0, 0.0, -10.0)).z.abs() < 1e-12);
+
+    let m = Matrix4::perspective_infinite_reversed_z(PI / 2.0, 2.0, 1.0);
+    #[rustfmt::skip]
+    assert_matrix_eq(m, [
+        0.5, 0.0, 0.0, 0.0,
+        0.0, 1.0, 0.0, 0.0,
+        0.0, 0.0, 0.0, -1.0,
+        0.0, 0.0, 1.0, 0.0,
+    ]);
+
+    assert!(((m * Vector::new(0.0, 0.0, -1.0)).z - 1.0).abs() < 1e-12);
+    assert!((m * Vector::new(0.0, 0.0, -1e12)).z > 0.0);
+}
+
+#[test]
+fn orthographic() {
+    // reference values from `glm::ortho(-2.0, 2.0, -1.0, 1.0, 0.1, 100.0)`
+    let m = Matrix4::orthographic(-2.0, 2.0, -1.0, 1.0, 0.1, 100.0);
+    #[rustfmt::skip]
+    assert_matrix_eq(m, [
+        0.5, 0.0, 0.0, 0.0,
+        0.0, 1.0, 0.0, 0.0,
+        0.0, 0.0, -0.020020, 0.0,
+        0.0, 0.0, -1.002002, 1.0,
+    ]);
+
+    let m = Matrix4::orthographic(0.0, 800.0, 0.0, 600.0, -1.0, 1.0);
+    assert_eq!(m * Vector::new(0.0, 0.0, 0.0), Vector::new(-1.0, -1.0, 0.0));
+    assert_eq!(
+        m * Vector::new(400.0, 300.0, 1.0),
+        Vector::new(0.0, 0.0, -1.0)
+    );
+}
+
+#[test]
+fn look_at() {
+    // reference values from `glm::lookAt({1, 2, 3}, {0, 0, 0}, {0, 1, 0})`
+    let eye = Vector::new(1.0, 2.0, 3.0);
+    let m = Matrix4::look_at(eye, Vector::zero(), Vector::new(0.0, 1.0, 0.0));
+    #[rustfmt::skip]
+    assert_matrix_eq(m, [
+        0.948683, -0.169031, 0.267261, 0.0,
+        0.0, 0.845154, 0.534522, 0.0,
+        -0.316228, -0.507093, 0.801784, 0.0,
+        0.0, 0.0, -3.741657, 1.0,
+    ]);
+
+    assert!((m * eye).length() < 1e-12);
+    assert!((m * Vector::zero()).is_close(Vector::new(0.0, 0.0, -eye.length())));
+
+    // view matrices are rigid transforms
+    assert!((m.determinant() - 1.0).abs() < 1e-12);
+}
+
+#[test]
+fn view_projection() {
+    let view = Matrix4::look_at(
+        Vector::new(0.0, 0.0, 10.0),
+        Vector::zero(),
+        Vector::new(0.0, 1.0, 0.0),
+    );
+    let proj = Matrix4::perspective(PI / 2.0, 1.0, 1.0, 100.0);
+    let vp = proj * view;
+
+    // the target is projected at the center of the screen
+    let center = vp * Vector::zero();
+    assert!(center.x.abs() < 1e-12 && center.y.abs() < 1e-12);
+
+    // a point on the edge of the frustum is projected on the edge of the screen
+    let edge = vp * Vector::new(10.0, 0.0, 0.0);
+    assert!((edge.x - 1.0).abs() < 1e-12);
+}
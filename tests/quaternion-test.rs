@@ -0,0 +1,166 @@
+use math_vector::{Matrix3, Matrix4, Quaternion, Vector};
+
+use std::f64::consts::PI;
+
+fn is_close(a: Quaternion<f64>, b: Quaternion<f64>) -> bool {
+    (a - b).length() < 1e-12 || (a + b).length() < 1e-12
+}
+
+#[test]
+fn identity() {
+    let v = Vector::new(1.0, 2.0, 3.0);
+    assert_eq!(Quaternion::identity() * v, v);
+
+    let q = Quaternion::from_rotation(0.3, Vector::new(0.0, 1.0, 0.0));
+    assert_eq!(q * Quaternion::identity(), q);
+    assert_eq!(Quaternion::identity() * q, q);
+}
+
+#[test]
+fn rotate() {
+    let axis = Vector::new(1.0, 2.0, 3.0).normalized();
+    let v = Vector::new(3.0, -1.0, 2.0);
+
+    let q = Quaternion::from_rotation(PI / 3.0, axis);
+    assert!((q * v).is_close(v.rotated(PI / 3.0, axis)));
+    assert!((q * v).is_close(Matrix3::from_rotation(PI / 3.0, axis) * v));
+    assert!(((q * v).length() - v.length()).abs() < 1e-12);
+}
+
+#[test]
+fn compose() {
+    let x = Vector::new(1.0, 0.0, 0.0);
+    let y = Vector::new(0.0, 1.0, 0.0);
+    let z = Vector::new(0.0, 0.0, 1.0);
+
+    let a = Quaternion::from_rotation(PI / 2.0, z);
+    let b = Quaternion::from_rotation(PI / 2.0, x);
+
+    // `b` is applied after `a`
+    let v = (b * a) * x;
+    assert!(v.is_close(z));
+    assert!(v.is_close(b * (a * x)));
+
+    let mut c = b;
+    c *= a;
+    assert_eq!(c, b * a);
+    assert!((c * y).is_close(b * (a * y)));
+}
+
+#[test]
+fn conjugate_inverse() {
+    let axis = Vector::new(0.0, 1.0, 0.0);
+    let q = Quaternion::from_rotation(0.8, axis);
+    let v = Vector::new(1.0, 2.0, 3.0);
+
+    let mut c = q;
+    c.conjugate();
+    assert_eq!(c, q.conjugated());
+    assert!((c * (q * v)).is_close(v));
+    assert!(is_close(q * c, Quaternion::identity()));
+
+    let scaled = q * 2.0;
+    let inv = scaled.inverse().unwrap();
+    assert!(is_close(scaled * inv, Quaternion::identity()));
+    assert_eq!(Quaternion::new(0.0, 0.0, 0.0, 0.0).inverse(), None);
+}
+
+#[test]
+fn normalize() {
+    let mut q: Quaternion<f64> = Quaternion::new(1.0, 2.0, 3.0, 4.0);
+    q.normalize();
+    assert!((q.length() - 1.0).abs() < 1e-12);
+    assert_eq!(q, Quaternion::new(1.0, 2.0, 3.0, 4.0).normalized());
+
+    let zero = Quaternion::new(0.0, 0.0, 0.0, 0.0);
+    assert_eq!(zero.normalized(), zero);
+}
+
+#[test]
+fn from_two_vectors() {
+    let a = Vector::new(1.0, 2.0, 0.5);
+    let b = Vector::new(-3.0, 0.5, 2.0);
+    let q = Quaternion::from_two_vectors(a, b);
+    assert!((q * a.normalized()).is_close(b.normalized()));
+
+    let q = Quaternion::from_two_vectors(a, a * 3.0);
+    assert!(is_close(q, Quaternion::identity()));
+
+    let x = Vector::new(1.0, 0.0, 0.0);
+    let q = Quaternion::from_two_vectors(x, -x);
+    assert!((q * x).is_close(-x));
+
+    let q = Quaternion::from_two_vectors(a, -a);
+    assert!((q * a).is_close(-a));
+}
+
+#[test]
+fn to_rotation() {
+    let axis = Vector::new(1.0, -1.0, 2.0).normalized();
+    let q: Quaternion<f64> = Quaternion::from_rotation(1.2, axis);
+    let (angle, a) = q.to_rotation();
+    assert!((angle - 1.2).abs() < 1e-12);
+    assert!(a.is_close(axis));
+
+    let (angle, _) = Quaternion::<f64>::identity().to_rotation();
+    assert_eq!(angle, 0.0);
+}
+
+#[test]
+fn matrix_conversions() {
+    let axes = [
+        Vector::new(1.0, 0.0, 0.0),
+        Vector::new(0.0, 1.0, 0.0),
+        Vector::new(0.0, 0.0, 1.0),
+        Vector::new(1.0, 2.0, 3.0).normalized(),
+    ];
+    let v = Vector::new(0.5, -2.0, 1.5);
+
+    // covers every branch of `from_matrix`, including half turns
+    for axis in axes {
+        for angle in [0.0, 0.5, PI / 2.0, 2.5, PI] {
+            let q = Quaternion::from_rotation(angle, axis);
+            let m = Matrix3::from(q);
+            assert!((m * v).is_close(q * v));
+            assert!(is_close(Quaternion::from(m), q));
+
+            let m = Matrix4::from(q);
+            assert!((m * v).is_close(q * v));
+        }
+    }
+}
+
+#[test]
+fn slerp() {
+    let axis = Vector::new(0.0, 0.0, 1.0);
+    let a = Quaternion::from_rotation(0.2, axis);
+    let b = Quaternion::from_rotation(1.4, axis);
+
+    assert!(is_close(a.slerp(b, 0.0), a));
+    assert!(is_close(a.slerp(b, 1.0), b));
+    for t in [0.25, 0.5, 0.75] {
+        let expected = Quaternion::from_rotation(0.2 + 1.2 * t, axis);
+        assert!(is_close(a.slerp(b, t), expected));
+    }
+
+    // takes the shortest path even if the quaternions are in opposite hemispheres
+    assert!(is_close(
+        a.slerp(-b, 0.5),
+        Quaternion::from_rotation(0.8, axis)
+    ));
+
+    // nearly identical quaternions
+    assert!(is_close(a.slerp(a, 0.5), a));
+}
+
+#[test]
+fn nlerp() {
+    let axis = Vector::new(0.0, 1.0, 0.0);
+    let a: Quaternion<f64> = Quaternion::from_rotation(0.0, axis);
+    let b = Quaternion::from_rotation(1.0, axis);
+
+    let mid = a.nlerp(b, 0.5);
+    assert!((mid.length() - 1.0).abs() < 1e-12);
+    assert!(is_close(mid, Quaternion::from_rotation(0.5, axis)));
+    assert!(is_close(a.nlerp(-b, 1.0), b));
+}
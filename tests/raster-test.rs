@@ -0,0 +1,360 @@
+#![cfg(feature = "alloc")]
+
+use numeric::assert_approx_eq;
+use phoenyx::framebuffer::Framebuffer;
+use phoenyx::raster::{CullMode, DepthTest, Rasterizer, Vertex};
+use phoenyx::{Matrix4, Vector, Vector4};
+
+use std::f32::consts::FRAC_PI_2;
+
+const CLEAR: Vector4<f32> = Vector4 {
+    x: 0.0,
+    y: 0.0,
+    z: 0.0,
+    w: 0.0,
+};
+
+fn rgba(r: f32, g: f32, b: f32) -> Vector4<f32> {
+    Vector4::new(r, g, b, 1.0)
+}
+
+fn vertex(x: f32, y: f32, z: f32, color: Vector4<f32>) -> Vertex {
+    Vertex::new(Vector::new(x, y, z), color)
+}
+
+/// Return how many times each pixel is covered by the triangles.
+fn coverage(size: usize, triangles: &[Vertex]) -> Vec<u32> {
+    let mut target = Framebuffer::new(size, size);
+    let mut counts = vec![0; size * size];
+    let rasterizer = Rasterizer {
+        depth_test: DepthTest::Always,
+        ..Rasterizer::new()
+    };
+    rasterizer.draw_triangles_with(&mut target, triangles, |f| {
+        counts[f.y * size + f.x] += 1;
+        Some(f.color)
+    });
+    counts
+}
+
+#[test]
+fn shared_edges_are_watertight() {
+    // A grid of quads with jittered inner vertices, whose edges go through
+    // pixel centers and corners.
+    let n = 6;
+    let mut state = 7u32;
+    let mut jitter = || {
+        state = state.wrapping_mul(1664525).wrapping_add(1013904223);
+        (state >> 8) as f32 / (1 << 24) as f32 - 0.5
+    };
+    let mut grid = vec![vec![(0.0, 0.0); n + 1]; n + 1];
+    for (i, row) in grid.iter_mut().enumerate() {
+        for (j, p) in row.iter_mut().enumerate() {
+            let inner = |k: usize| k > 0 && k < n;
+            let (dx, dy) = if inner(i) && inner(j) {
+                (jitter() * 0.2, jitter() * 0.2)
+            } else {
+                (0.0, 0.0)
+            };
+            *p = (
+                -1.0 + 2.0 * j as f32 / n as f32 + dx,
+                -1.0 + 2.0 * i as f32 / n as f32 + dy,
+            );
+        }
+    }
+    let mut triangles = Vec::new();
+    let white = rgba(1.0, 1.0, 1.0);
+    for i in 0..n {
+        for j in 0..n {
+            let [a, b, c, d] = [
+                grid[i][j],
+                grid[i][j + 1],
+                grid[i + 1][j + 1],
+                grid[i + 1][j],
+            ];
+            for (x, y) in [a, b, c, a, c, d] {
+                triangles.push(vertex(x, y, 0.0, white));
+            }
+        }
+    }
+    assert!(coverage(48, &triangles).iter().all(|&c| c == 1));
+
+    // A fan of thin triangles around a pixel corner, in both orientations.
+    let mut fan = Vec::new();
+    let spokes = 37;
+    for k in 0..spokes {
+        let angle = |k: usize| k as f32 * std::f32::consts::TAU / spokes as f32;
+        let (a, b) = (angle(k), angle(k + 1));
+        let (first, second) = if k % 2 == 0 { (a, b) } else { (b, a) };
+        fan.push(vertex(0.0, 0.0, 0.0, white));
+        fan.push(vertex(3.0 * first.cos(), 3.0 * first.sin(), 0.0, white));
+        fan.push(vertex(3.0 * second.cos(), 3.0 * second.sin(), 0.0, white));
+    }
+    assert!(coverage(32, &fan).iter().all(|&c| c == 1));
+}
+
+#[test]
+fn perspective_correct_interpolation() {
+    // A floor at y = -1 from z = -1 to z = -5, whose red channel goes from 0
+    // at the front to 1 at the back.
+    let size = 64;
+    let (front, back) = (rgba(0.0, 0.0, 0.0), rgba(1.0, 0.0, 0.0));
+    let floor = [
+        vertex(-1.0, -1.0, -1.0, front),
+        vertex(1.0, -1.0, -1.0, front),
+        vertex(1.0, -1.0, -5.0, back),
+        vertex(-1.0, -1.0, -1.0, front),
+        vertex(1.0, -1.0, -5.0, back),
+        vertex(-1.0, -1.0, -5.0, back),
+    ];
+    let rasterizer = Rasterizer {
+        transform: Matrix4::perspective(FRAC_PI_2, 1.0, 0.5, 10.0),
+        ..Rasterizer::new()
+    };
+    let mut target = Framebuffer::new(size, size);
+    rasterizer.draw_triangles(&mut target, &floor);
+
+    let mut checked = 0;
+    for y in 0..size {
+        for x in 0..size {
+            // The view ray through the pixel center hits the floor where
+            // y = -1, at a distance -1 / ny along -z.
+            let nx = (x as f32 + 0.5) / size as f32 * 2.0 - 1.0;
+            let ny = 1.0 - (y as f32 + 0.5) / size as f32 * 2.0;
+            let (hit_x, hit_z) = (-nx / ny, 1.0 / ny);
+            let inside = ny < 0.0 && hit_x.abs() < 0.99 && hit_z < -1.01 && hit_z > -4.99;
+            if !inside {
+                continue;
+            }
+            // Up to the snapping of the vertices to a 256th of a pixel, which
+            // matters near the horizon where u changes by 0.2 per pixel.
+            let u = (-hit_z - 1.0) / 4.0;
+            assert_approx_eq!(target.pixel(x, y).x, u, abs = 1e-3);
+            checked += 1;
+        }
+    }
+    assert!(checked > 300);
+
+    // The front and back edges are at ny = -1 and -0.2, and the row halfway
+    // between them, at ny = -0.6, is much closer to the front, where affine
+    // interpolation would give 0.5.
+    let middle = target.pixel(size / 2, 51).x;
+    assert!(middle < 0.2, "{}", middle);
+}
+
+#[test]
+fn depth_testing() {
+    let (red, green) = (rgba(1.0, 0.0, 0.0), rgba(0.0, 1.0, 0.0));
+    let quad = |z: f32, color| {
+        [
+            (-1.0, -1.0),
+            (1.0, -1.0),
+            (1.0, 1.0),
+            (-1.0, -1.0),
+            (1.0, 1.0),
+            (-1.0, 1.0),
+        ]
+        .map(|(x, y)| vertex(x, y, z, color))
+    };
+    let (near, far) = (quad(-0.5, green), quad(0.5, red));
+
+    let mut target = Framebuffer::new(4, 4);
+    let rasterizer = Rasterizer::new();
+    rasterizer.draw_triangles(&mut target, &near);
+    rasterizer.draw_triangles(&mut target, &far);
+    assert!(target.read_pixels().iter().all(|&c| c == green));
+    assert_eq!(target.depth(1, 2), Some(0.25));
+
+    let always = Rasterizer {
+        depth_test: DepthTest::Always,
+        depth_write: false,
+        ..rasterizer
+    };
+    always.draw_triangles(&mut target, &far);
+    assert!(target.read_pixels().iter().all(|&c| c == red));
+    assert_eq!(target.depth(1, 2), Some(0.25));
+
+    // Discarded fragments do not write their depth.
+    target.clear_depth(1.0);
+    rasterizer.draw_triangles_with(&mut target, &near, |_| None);
+    assert_eq!(target.depth(0, 0), Some(1.0));
+}
+
+#[test]
+fn face_culling() {
+    let white = rgba(1.0, 1.0, 1.0);
+    let ccw = [
+        vertex(-1.0, -1.0, 0.0, white),
+        vertex(1.0, -1.0, 0.0, white),
+        vertex(0.0, 1.0, 0.0, white),
+    ];
+    let cw = [ccw[0], ccw[2], ccw[1]];
+    let drawn = |cull, triangle: &[Vertex]| {
+        let mut target = Framebuffer::new(8, 8);
+        let rasterizer = Rasterizer {
+            cull,
+            ..Rasterizer::new()
+        };
+        rasterizer.draw_triangles(&mut target, triangle);
+        target.read_pixels().iter().filter(|&&c| c == white).count()
+    };
+    assert_eq!(drawn(CullMode::None, &ccw), drawn(CullMode::None, &cw));
+    assert!(drawn(CullMode::Back, &ccw) > 0);
+    assert_eq!(drawn(CullMode::Back, &cw), 0);
+    assert_eq!(drawn(CullMode::Front, &ccw), 0);
+    assert!(drawn(CullMode::Front, &cw) > 0);
+
+    // Culling applies to the projected orientation, which a mirroring
+    // transform reverses.
+    let mut target = Framebuffer::new(8, 8);
+    let rasterizer = Rasterizer {
+        transform: Matrix4::from_scale(Vector::new(-1.0, 1.0, 1.0)),
+        cull: CullMode::Back,
+        ..Rasterizer::new()
+    };
+    rasterizer.draw_triangles(&mut target, &cw);
+    assert!(target.read_pixels().contains(&white));
+}
+
+#[test]
+fn lines_and_points() {
+    let (red, blue) = (rgba(1.0, 0.0, 0.0), rgba(0.0, 0.0, 1.0));
+    let mut target = Framebuffer::new(8, 8);
+    let rasterizer = Rasterizer::new();
+
+    // A horizontal line through the centers of the third row.
+    rasterizer.draw_lines(
+        &mut target,
+        &[vertex(-1.0, 0.375, 0.0, red), vertex(1.0, 0.375, 0.0, red)],
+    );
+    for y in 0..8 {
+        for x in 0..8 {
+            assert_eq!(target.pixel(x, y) == red, y == 2, "{} {}", x, y);
+        }
+    }
+
+    // A diagonal covers one pixel per column and interpolates its colors.
+    target.clear(CLEAR);
+    target.clear_depth(1.0);
+    rasterizer.draw_lines(
+        &mut target,
+        &[vertex(-1.0, 1.0, 0.0, red), vertex(1.0, -1.0, 0.0, blue)],
+    );
+    let covered = target.read_pixels().iter().filter(|&&c| c != CLEAR).count();
+    assert_eq!(covered, 8);
+    for i in 0..8 {
+        let t = (i as f32 + 0.5) / 8.0;
+        assert_approx_eq!(target.pixel(i, i), red.lerp(blue, t), abs = 1e-6);
+    }
+
+    target.clear(CLEAR);
+    target.clear_depth(1.0);
+    rasterizer.draw_points(&mut target, &[vertex(-0.125, 0.125, 0.0, red)]);
+    let big = Rasterizer {
+        point_size: 3.0,
+        ..rasterizer
+    };
+    big.draw_points(&mut target, &[vertex(0.5, -0.5, 0.0, blue)]);
+    assert_eq!(target.pixel(3, 3), red);
+    assert_eq!(
+        target.read_pixels().iter().filter(|&&c| c == red).count(),
+        1
+    );
+    assert_eq!(
+        target.read_pixels().iter().filter(|&&c| c == blue).count(),
+        9
+    );
+    assert_eq!(target.pixel(5, 5), blue);
+    assert_eq!(target.pixel(7, 7), CLEAR);
+}
+
+#[test]
+fn lines_exclude_their_end() {
+    let red = rgba(1.0, 0.0, 0.0);
+    let mut target = Framebuffer::new(8, 8);
+    let rasterizer = Rasterizer::new();
+    let row = |target: &Framebuffer| {
+        (0..8)
+            .filter(|&x| target.pixel(x, 2) == red)
+            .collect::<Vec<_>>()
+    };
+    let column = |target: &Framebuffer| {
+        (0..8)
+            .filter(|&y| target.pixel(2, y) == red)
+            .collect::<Vec<_>>()
+    };
+
+    // Both ends lie on pixel centers, in the second and sixth columns.
+    let (left, right) = (
+        vertex(-0.625, 0.375, 0.0, red),
+        vertex(0.375, 0.375, 0.0, red),
+    );
+    rasterizer.draw_lines(&mut target, &[left, right]);
+    assert_eq!(row(&target), [1, 2, 3, 4]);
+    target.clear(CLEAR);
+    target.clear_depth(1.0);
+    rasterizer.draw_lines(&mut target, &[right, left]);
+    assert_eq!(row(&target), [2, 3, 4, 5]);
+
+    // And in the second and sixth rows.
+    let (top, bottom) = (
+        vertex(-0.375, 0.625, 0.0, red),
+        vertex(-0.375, -0.375, 0.0, red),
+    );
+    target.clear(CLEAR);
+    target.clear_depth(1.0);
+    rasterizer.draw_lines(&mut target, &[top, bottom]);
+    assert_eq!(column(&target), [1, 2, 3, 4]);
+    target.clear(CLEAR);
+    target.clear_depth(1.0);
+    rasterizer.draw_lines(&mut target, &[bottom, top]);
+    assert_eq!(column(&target), [2, 3, 4, 5]);
+}
+
+#[test]
+fn near_and_far_clipping() {
+    let white = rgba(1.0, 1.0, 1.0);
+    let rasterizer = Rasterizer {
+        transform: Matrix4::perspective(FRAC_PI_2, 1.0, 1.0, 10.0),
+        ..Rasterizer::new()
+    };
+    let covered = |primitives: &dyn Fn(&mut Framebuffer)| {
+        let mut target = Framebuffer::new(16, 16);
+        primitives(&mut target);
+        target.read_pixels().iter().filter(|&&c| c == white).count()
+    };
+
+    // A triangle reaching behind the camera only draws its visible part,
+    // and one entirely behind it or beyond the far plane draws nothing.
+    let crossing = [
+        vertex(-1.0, -1.0, -2.0, white),
+        vertex(1.0, -1.0, -2.0, white),
+        vertex(0.0, -1.0, 5.0, white),
+    ];
+    let visible = covered(&|t| rasterizer.draw_triangles(t, &crossing));
+    assert!(visible > 0 && visible < 16 * 8);
+    let behind = crossing.map(|v| vertex(v.position.x, v.position.y, 3.0, white));
+    assert_eq!(covered(&|t| rasterizer.draw_triangles(t, &behind)), 0);
+    let beyond = crossing.map(|v| vertex(v.position.x, v.position.y, -20.0, white));
+    assert_eq!(covered(&|t| rasterizer.draw_triangles(t, &beyond)), 0);
+
+    let line = [
+        vertex(0.0, -1.0, -2.0, white),
+        vertex(0.0, -1.0, 5.0, white),
+    ];
+    let visible = covered(&|t| rasterizer.draw_lines(t, &line));
+    assert!(visible > 0 && visible <= 8);
+    let point = [vertex(0.0, 0.0, 1.0, white)];
+    assert_eq!(covered(&|t| rasterizer.draw_points(t, &point)), 0);
+
+    // Depths of clipped triangles stay within the depth range.
+    let mut target = Framebuffer::new(16, 16);
+    target.clear_depth(2.0);
+    rasterizer.draw_triangles(&mut target, &crossing);
+    for y in 0..16 {
+        for x in 0..16 {
+            let depth = target.depth(x, y).unwrap();
+            assert!(depth == 2.0 || (0.0..=1.0).contains(&depth));
+        }
+    }
+}
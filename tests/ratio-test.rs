@@ -0,0 +1,176 @@
+use numeric::ratio::{Ratio, RatioErrorKind};
+use numeric::{CheckedAdd, CheckedDiv, CheckedMul, CheckedNeg, CheckedSub};
+use numeric::{Num, One, ToPrimitive, Zero};
+
+use std::f64::consts::{E, PI};
+
+fn r(n: i32, d: i32) -> Ratio<i32> {
+    Ratio::new(n, d)
+}
+
+#[test]
+fn normalization() {
+    let x = r(6, -4);
+    assert_eq!((x.numer(), x.denom()), (-3, 2));
+    assert_eq!(r(0, -5), Ratio::zero());
+    assert_eq!(r(-7, -7), Ratio::one());
+    assert_eq!(r(i32::MIN, i32::MIN), Ratio::one());
+    assert_eq!(r(i32::MIN, 2).numer(), i32::MIN / 2);
+    assert_eq!(Ratio::new(30000u32, 1001).denom(), 1001);
+    assert!(r(4, 2).is_integer());
+    assert_eq!(Ratio::from(3), r(3, 1));
+    assert_eq!(Ratio::from((2, 4)), r(1, 2));
+}
+
+#[test]
+#[should_panic(expected = "denominator is zero")]
+fn zero_denominator() {
+    r(1, 0);
+}
+
+#[test]
+#[should_panic(expected = "ratio overflow")]
+fn unrepresentable() {
+    r(1, i32::MIN);
+}
+
+#[test]
+fn arithmetic() {
+    let a = r(1, 6);
+    let b = r(3, 4);
+    assert_eq!(a + b, r(11, 12));
+    assert_eq!(a - b, r(-7, 12));
+    assert_eq!(a * b, r(1, 8));
+    assert_eq!(a / b, r(2, 9));
+    assert_eq!(b % a, r(1, 12));
+    assert_eq!(r(-7, 2) % r(1, 1), r(-1, 2));
+    assert_eq!(-a, r(-1, 6));
+    assert_eq!(a * 3, r(1, 2));
+    assert_eq!(a + 1, r(7, 6));
+    assert_eq!(b / 3, r(1, 4));
+
+    let mut c = a;
+    c += b;
+    c *= r(12, 11);
+    c -= 1;
+    assert_eq!(c, Ratio::zero());
+    c /= 5;
+    assert!(c.is_zero());
+
+    // 30000/1001 frames per second for an hour
+    let fps = Ratio::new(30000u64, 1001);
+    let frames = fps * 3600;
+    assert_eq!(frames.floor().to_integer(), 107_892);
+    assert_eq!(frames.round(), Ratio::from(107_892));
+    assert_eq!(frames.ceil(), Ratio::from(107_893));
+}
+
+#[test]
+fn overflow() {
+    let big = r(i32::MAX, 1);
+    assert_eq!(big.checked_add(&Ratio::one()), None);
+    assert_eq!(big.checked_sub(&r(-1, 1)), None);
+    assert_eq!(big.checked_mul(&r(2, 1)), None);
+    assert_eq!(r(1, 1).checked_div(&Ratio::zero()), None);
+    assert_eq!(r(i32::MIN, 1).checked_neg(), None);
+    assert_eq!(r(1, 2).checked_rem(&Ratio::zero()), None);
+
+    // Cancellation keeps the intermediate terms small.
+    let x = r(i32::MAX, 3);
+    assert_eq!(x * r(3, i32::MAX), Ratio::one());
+    let y = r(1, i32::MAX - 1);
+    assert_eq!(y + y, r(1, i32::MAX / 2));
+    assert_eq!(r(1, 46349).checked_add(&r(1, 46351)), None);
+}
+
+#[test]
+#[should_panic(expected = "attempt to add with overflow")]
+fn add_overflow() {
+    let _ = Ratio::new(u8::MAX, 1) + Ratio::one();
+}
+
+#[test]
+fn rounding() {
+    assert_eq!(r(7, 2).floor(), r(3, 1));
+    assert_eq!(r(-7, 2).floor(), r(-4, 1));
+    assert_eq!(r(7, 2).ceil(), r(4, 1));
+    assert_eq!(r(-7, 2).ceil(), r(-3, 1));
+    assert_eq!(r(7, 2).round(), r(4, 1));
+    assert_eq!(r(-7, 2).round(), r(-4, 1));
+    assert_eq!(r(-5, 3).round(), r(-2, 1));
+    assert_eq!(r(4, 3).round(), r(1, 1));
+    assert_eq!(r(-7, 2).trunc(), r(-3, 1));
+    assert_eq!(r(-7, 2).fract(), r(-1, 2));
+    assert_eq!(r(i32::MAX, i32::MAX - 1).round(), r(1, 1));
+    assert_eq!(r(-2, 3).recip(), r(-3, 2));
+    assert_eq!(r(1, i32::MIN + 1).recip(), r(i32::MIN + 1, 1));
+}
+
+#[test]
+fn ordering() {
+    assert!(r(1, 3) < r(1, 2));
+    assert!(r(-1, 2) < r(-1, 3));
+    assert!(r(7, 3) > r(9, 4));
+    assert_eq!(r(2, 4).cmp(&r(1, 2)), std::cmp::Ordering::Equal);
+    // The cross products overflow.
+    assert!(r(i32::MAX - 2, i32::MAX - 1) < r(i32::MAX - 1, i32::MAX));
+    assert!(Ratio::new(u64::MAX, 3) > Ratio::new(u64::MAX - 1, 3));
+    let mut v = vec![r(1, 2), r(-1, 3), r(5, 7), r(0, 1)];
+    v.sort();
+    assert_eq!(v, [r(-1, 3), r(0, 1), r(1, 2), r(5, 7)]);
+}
+
+#[test]
+fn conversions() {
+    assert_eq!(r(7, 2).to_i64(), Some(3));
+    assert_eq!(r(-7, 2).to_u64(), None);
+    assert_eq!(r(1, 4).to_f64(), Some(0.25));
+    assert_eq!(r(1, 3).to_f32(), Some(1.0 / 3.0));
+    assert_eq!(Ratio::new(30000u32, 1001).to_f64(), Some(30000.0 / 1001.0));
+}
+
+#[test]
+fn approximation() {
+    assert_eq!(Ratio::approximate_float(PI, 10), Some(r(22, 7)));
+    assert_eq!(Ratio::approximate_float(PI, 1000), Some(r(355, 113)));
+    assert_eq!(Ratio::approximate_float(-PI, 100), Some(r(-311, 99)));
+    assert_eq!(Ratio::approximate_float(E, 100), Some(r(193, 71)));
+    assert_eq!(Ratio::approximate_float(0.75, 100), Some(r(3, 4)));
+    assert_eq!(Ratio::approximate_float(29.97, 1000), Some(r(2997, 100)));
+    assert_eq!(Ratio::approximate_float(1.0 / 3.0, i32::MAX), Some(r(1, 3)));
+    assert_eq!(Ratio::approximate_float(0.001, 10), Some(r(0, 1)));
+    assert_eq!(Ratio::approximate_float(2.6f32, 1), Some(r(3, 1)));
+    assert_eq!(Ratio::<u8>::approximate_float(-0.5, 10), None);
+    assert_eq!(Ratio::<i8>::approximate_float(300.0, 10), None);
+    assert_eq!(Ratio::<i32>::approximate_float(f64::NAN, 10), None);
+
+    // Check against a brute force search.
+    for &x in &[0.1234, 2.7, 0.999, 1e-3, 7.0 / 13.0 + 1e-9] {
+        let max = 200;
+        let best = Ratio::approximate_float(x, max).unwrap();
+        let err = (best.to_f64().unwrap() - x).abs();
+        for d in 1..=max {
+            let n = (x * d as f64).round() as i32;
+            let e = (n as f64 / d as f64 - x).abs();
+            assert!(err <= e + 1e-15, "{} better than {} for {}", r(n, d), best, x);
+        }
+    }
+}
+
+#[test]
+fn parsing() {
+    assert_eq!("3/4".parse::<Ratio<i32>>().unwrap(), r(3, 4));
+    assert_eq!(" -6 / 8 ".parse::<Ratio<i32>>().unwrap(), r(-3, 4));
+    assert_eq!("5".parse::<Ratio<i32>>().unwrap(), r(5, 1));
+    assert_eq!(Ratio::<u16>::from_str_radix("ff/11", 16).unwrap(), Ratio::new(15, 1));
+    assert_eq!(r(-3, 4).to_string(), "-3/4");
+    assert_eq!(r(8, 4).to_string(), "2");
+    assert_eq!(r(-3, 4).to_string().parse::<Ratio<i32>>().unwrap(), r(-3, 4));
+
+    let kind = |s: &str| s.parse::<Ratio<i32>>().unwrap_err().kind;
+    assert_eq!(kind(""), RatioErrorKind::Empty);
+    assert_eq!(kind("1/2/3"), RatioErrorKind::Invalid);
+    assert_eq!(kind("1/0"), RatioErrorKind::ZeroDenominator);
+    assert_eq!(kind("1/-2147483648"), RatioErrorKind::Overflow);
+    assert!(matches!(kind("x/2"), RatioErrorKind::Part(_)));
+}
@@ -0,0 +1,114 @@
+#![allow(clippy::op_ref)]
+
+use math_vector::simd::{
+    add_scaled_slice, add_slice, dot_slice, length_slice, limit_slice, normalize_slice,
+    scale_slice, sub_slice,
+};
+use math_vector::{SimdVector, Vector};
+
+#[test]
+fn conversions() {
+    let v = Vector::new(1.0f32, 2.0, 3.0);
+    let s = SimdVector::from(v);
+    assert_eq!((s.x(), s.y(), s.z()), (1.0, 2.0, 3.0));
+    assert_eq!(Vector::from(s), v);
+
+    let a: [f32; 3] = SimdVector::from([4.0, 5.0, 6.0]).into();
+    assert_eq!(a, [4.0, 5.0, 6.0]);
+    assert_eq!(format!("{}", s), "SimdVector(1.0, 2.0, 3.0)");
+}
+
+#[test]
+fn operators() {
+    let a = SimdVector::new(1.0, 2.0, 3.0);
+    let b = SimdVector::new(4.0, 5.0, 6.0);
+
+    assert_eq!(a + b, SimdVector::new(5.0, 7.0, 9.0));
+    assert_eq!(&b - &a, SimdVector::splat(3.0));
+    assert_eq!(a * b, SimdVector::new(4.0, 10.0, 18.0));
+    assert_eq!(b / 2.0, SimdVector::new(2.0, 2.5, 3.0));
+    assert_eq!(-a, SimdVector::new(-1.0, -2.0, -3.0));
+
+    let mut c = a;
+    c += b;
+    c -= 1.0;
+    c *= &SimdVector::splat(2.0);
+    c /= 4.0;
+    assert_eq!(c, SimdVector::new(2.0, 3.0, 4.0));
+
+    assert_eq!(a.min(b), a);
+    assert_eq!(a.max(b), b);
+    assert_eq!(a.lerp(b, 0.5), SimdVector::new(2.5, 3.5, 4.5));
+}
+
+#[test]
+fn matches_scalar_vector() {
+    let (a, b) = (
+        Vector::new(1.5f32, -2.0, 0.25),
+        Vector::new(-3.0f32, 0.5, 8.0),
+    );
+    let (sa, sb) = (SimdVector::from(a), SimdVector::from(b));
+
+    assert_eq!(sa.dot(sb), a.dot(b));
+    assert_eq!(sa.length_squared(), a.length_squared());
+    assert_eq!(sa.length(), a.length());
+    assert_eq!(sa.distance(sb), a.distance(b));
+    assert_eq!(Vector::from(sa.cross(sb)), a.cross(b));
+    assert!(Vector::from(sa.normalized()).is_close(a.normalized()));
+    assert!(Vector::from(sa.with_length(3.0)).is_close(a.with_length(3.0)));
+    assert!(Vector::from(sb.limited(2.0)).is_close(b.limited(2.0)));
+}
+
+#[test]
+fn padding_does_not_leak() {
+    // dividing fills the padding lane with NaN, as it computes 0 / 0
+    let v = SimdVector::new(0.0, 3.0, 4.0) / SimdVector::new(1.0, 1.0, 1.0);
+    assert_eq!(v.length(), 5.0);
+    assert_eq!(v, SimdVector::new(0.0, 3.0, 4.0));
+
+    let mut z = SimdVector::zero();
+    z.normalize();
+    assert_eq!(z, SimdVector::zero());
+}
+
+#[test]
+fn slices() {
+    let mut a: Vec<SimdVector> = (0..10)
+        .map(|i| SimdVector::new(i as f32, 1.0, 0.0))
+        .collect();
+    let b = vec![SimdVector::new(0.0, 0.0, 2.0); 10];
+    let mut out = vec![0.0; 10];
+
+    dot_slice(&a, &a, &mut out);
+    assert_eq!(out[3], 10.0);
+
+    add_slice(&mut a, &b);
+    assert_eq!(a[3], SimdVector::new(3.0, 1.0, 2.0));
+    sub_slice(&mut a, &b);
+    scale_slice(&mut a, 2.0);
+    assert_eq!(a[3], SimdVector::new(6.0, 2.0, 0.0));
+
+    add_scaled_slice(&mut a, &b, 0.5);
+    assert_eq!(a[3], SimdVector::new(6.0, 2.0, 1.0));
+
+    limit_slice(&mut a, 3.0);
+    length_slice(&a, &mut out);
+    assert!(out.iter().all(|&l| l <= 3.0 + 1e-6));
+    assert!((out[0] - 5.0f32.sqrt()).abs() < 1e-6);
+
+    a[1] = SimdVector::zero();
+    normalize_slice(&mut a);
+    length_slice(&a, &mut out);
+    assert_eq!(a[1], SimdVector::zero());
+    assert!(out
+        .iter()
+        .enumerate()
+        .all(|(i, &l)| i == 1 || (l - 1.0).abs() < 1e-6));
+}
+
+#[test]
+#[should_panic]
+fn slices_of_different_lengths() {
+    let mut a = vec![SimdVector::zero(); 3];
+    add_slice(&mut a, &[SimdVector::one()]);
+}
@@ -0,0 +1,251 @@
+#![cfg(feature = "alloc")]
+
+use phoenyx::sketch::{Context, Event, Key, MouseButton, Runtime, Sketch};
+use phoenyx::{Vector, Vector4};
+
+/// A sketch which records the hooks called, with the clock of the context.
+#[derive(Default)]
+struct Recorder {
+    calls: Vec<(&'static str, u64, f64, f64)>,
+    exit_at: Option<u64>,
+}
+
+impl Recorder {
+    fn record(&mut self, hook: &'static str, ctx: &Context) {
+        let call = (hook, ctx.frame_count(), ctx.delta_time(), ctx.millis());
+        self.calls.push(call);
+    }
+
+    fn hooks(&self) -> Vec<&'static str> {
+        self.calls.iter().map(|call| call.0).collect()
+    }
+}
+
+impl Sketch for Recorder {
+    fn setup(&mut self, ctx: &mut Context) {
+        self.record("setup", ctx);
+        ctx.set_frame_rate(50.0);
+    }
+
+    fn draw(&mut self, ctx: &mut Context) {
+        self.record("draw", ctx);
+        if Some(ctx.frame_count()) == self.exit_at {
+            ctx.exit();
+        }
+    }
+
+    fn key_pressed(&mut self, ctx: &mut Context) {
+        self.record("key_pressed", ctx);
+    }
+
+    fn key_released(&mut self, ctx: &mut Context) {
+        self.record("key_released", ctx);
+    }
+
+    fn mouse_pressed(&mut self, ctx: &mut Context) {
+        self.record("mouse_pressed", ctx);
+    }
+
+    fn mouse_released(&mut self, ctx: &mut Context) {
+        self.record("mouse_released", ctx);
+    }
+
+    fn mouse_moved(&mut self, ctx: &mut Context) {
+        self.record("mouse_moved", ctx);
+    }
+
+    fn mouse_dragged(&mut self, ctx: &mut Context) {
+        self.record("mouse_dragged", ctx);
+    }
+}
+
+#[test]
+fn headless_clock() {
+    let mut sketch = Recorder::default();
+    let mut runtime = Runtime::new(2, 2);
+    runtime.run_headless(&mut sketch, 3);
+    assert_eq!(
+        sketch.calls,
+        [
+            ("setup", 0, 0.0, 0.0),
+            ("draw", 1, 0.0, 0.0),
+            ("draw", 2, 20.0, 20.0),
+            ("draw", 3, 20.0, 40.0),
+        ]
+    );
+
+    // Running again continues the same clock.
+    runtime.run_headless(&mut sketch, 1);
+    assert_eq!(sketch.calls[4], ("draw", 4, 20.0, 60.0));
+    assert_eq!(runtime.context().frame_rate(), 50.0);
+}
+
+#[test]
+fn looping() {
+    struct Counter(u64);
+
+    impl Sketch for Counter {
+        fn setup(&mut self, ctx: &mut Context) {
+            ctx.no_loop();
+        }
+
+        fn draw(&mut self, _ctx: &mut Context) {
+            self.0 += 1;
+        }
+
+        fn key_pressed(&mut self, ctx: &mut Context) {
+            match ctx.key() {
+                Some(Key::Char('r')) => ctx.redraw(),
+                Some(Key::Char('l')) => ctx.loop_draw(),
+                _ => {}
+            }
+        }
+    }
+
+    let mut sketch = Counter(0);
+    let mut runtime = Runtime::new(1, 1);
+    runtime.run_headless(&mut sketch, 3);
+    assert_eq!((sketch.0, runtime.context().frame_count()), (0, 0));
+    assert!(!runtime.context().is_looping());
+    // The clock goes on without drawing.
+    assert_eq!(runtime.context().millis(), 2000.0 / 60.0);
+
+    runtime.push_event(Event::KeyPressed(Key::Char('r')));
+    runtime.run_headless(&mut sketch, 3);
+    assert_eq!((sketch.0, runtime.context().frame_count()), (1, 1));
+
+    runtime.push_event(Event::KeyPressed(Key::Char('l')));
+    runtime.run_headless(&mut sketch, 3);
+    assert_eq!((sketch.0, runtime.context().frame_count()), (4, 4));
+}
+
+#[test]
+fn exit() {
+    let mut sketch = Recorder {
+        exit_at: Some(2),
+        ..Recorder::default()
+    };
+    let mut runtime = Runtime::new(1, 1);
+    runtime.run_headless(&mut sketch, 10);
+    assert!(runtime.exited());
+    assert_eq!(sketch.hooks(), ["setup", "draw", "draw"]);
+    runtime.run_headless(&mut sketch, 10);
+    assert_eq!(sketch.calls.len(), 3);
+}
+
+#[test]
+fn events() {
+    let mut sketch = Recorder::default();
+    let mut runtime = Runtime::new(8, 8);
+    runtime.push_event(Event::KeyPressed(Key::Shift));
+    runtime.push_event(Event::KeyPressed(Key::Char('A')));
+    runtime.push_event(Event::MouseMoved(Vector::new(1.0, 2.0, 0.0)));
+    runtime.run_headless(&mut sketch, 1);
+    // Events are dispatched after the setup and before the frame.
+    assert_eq!(
+        sketch.hooks(),
+        ["setup", "key_pressed", "key_pressed", "mouse_moved", "draw"]
+    );
+    let ctx = runtime.context();
+    assert_eq!(ctx.key(), Some(Key::Char('A')));
+    assert!(ctx.key_is_pressed() && ctx.key_is_down(Key::Shift));
+    assert_eq!(ctx.mouse(), Vector::new(1.0, 2.0, 0.0));
+    assert_eq!(ctx.pmouse(), Vector::new(1.0, 2.0, 0.0));
+
+    sketch.calls.clear();
+    runtime.push_event(Event::KeyReleased(Key::Char('A')));
+    runtime.push_event(Event::MousePressed(MouseButton::Left));
+    runtime.push_event(Event::MouseMoved(Vector::new(3.0, 4.0, 0.0)));
+    runtime.push_event(Event::MouseReleased(MouseButton::Left));
+    runtime.push_event(Event::KeyReleased(Key::Shift));
+    runtime.step(&mut sketch, 5.0);
+    assert_eq!(
+        sketch.hooks(),
+        [
+            "key_released",
+            "mouse_pressed",
+            "mouse_dragged",
+            "mouse_released",
+            "key_released",
+            "draw"
+        ]
+    );
+    assert_eq!(sketch.calls[5], ("draw", 2, 5.0, 5.0));
+    let ctx = runtime.context();
+    assert_eq!(ctx.key(), Some(Key::Shift));
+    assert!(!ctx.key_is_pressed());
+    assert_eq!(ctx.mouse_button(), Some(MouseButton::Left));
+    assert!(!ctx.mouse_is_pressed());
+}
+
+#[test]
+fn pmouse() {
+    struct Trail(Vec<(Vector<f32>, Vector<f32>)>);
+
+    impl Sketch for Trail {
+        fn draw(&mut self, ctx: &mut Context) {
+            self.0.push((ctx.pmouse(), ctx.mouse()));
+        }
+    }
+
+    let mut sketch = Trail(Vec::new());
+    let mut runtime = Runtime::new(8, 8);
+    runtime.step(&mut sketch, 0.0);
+    runtime.push_event(Event::MouseMoved(Vector::new(1.0, 0.0, 0.0)));
+    runtime.push_event(Event::MouseMoved(Vector::new(2.0, 0.0, 0.0)));
+    runtime.step(&mut sketch, 10.0);
+    runtime.step(&mut sketch, 10.0);
+    let x = |v: Vector<f32>| v.x;
+    let trail: Vec<_> = sketch.0.iter().map(|&(p, m)| (x(p), x(m))).collect();
+    assert_eq!(trail, [(0.0, 0.0), (0.0, 2.0), (2.0, 2.0)]);
+}
+
+#[test]
+fn canvas() {
+    struct Gradient;
+
+    impl Sketch for Gradient {
+        fn draw(&mut self, ctx: &mut Context) {
+            ctx.background(Vector4::new(0.0, 0.0, 0.0, 1.0));
+            let t = ctx.frame_count() as f32 / 4.0;
+            for y in 0..ctx.height() {
+                ctx.canvas_mut()
+                    .set_pixel(0, y, Vector4::new(t, 0.0, 0.0, 1.0));
+            }
+        }
+    }
+
+    let mut runtime = Runtime::new(2, 3);
+    let canvas = runtime.run_headless(&mut Gradient, 2);
+    assert_eq!((canvas.width(), canvas.height()), (2, 3));
+    assert_eq!(canvas.pixel(0, 2), Vector4::new(0.5, 0.0, 0.0, 1.0));
+    assert_eq!(canvas.pixel(1, 2), Vector4::new(0.0, 0.0, 0.0, 1.0));
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn real_time() {
+    let mut sketch = Recorder {
+        exit_at: Some(4),
+        ..Recorder::default()
+    };
+    let mut runtime = Runtime::new(1, 1);
+    let mut presented = 0;
+    runtime.run(&mut sketch, |runtime| {
+        presented += 1;
+        if presented == 1 {
+            runtime.push_event(Event::KeyPressed(Key::Enter));
+        }
+    });
+    assert_eq!(presented, 4);
+    assert_eq!(
+        sketch.hooks(),
+        ["setup", "draw", "key_pressed", "draw", "draw", "draw"]
+    );
+    // Frames are at least 20 milliseconds apart at 50 frames per second.
+    for &(hook, _, delta_time, _) in &sketch.calls[3..] {
+        assert_eq!(hook, "draw");
+        assert!(delta_time >= 20.0, "{delta_time}");
+    }
+    assert!(runtime.context().millis() >= 60.0);
+}
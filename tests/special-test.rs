@@ -0,0 +1,179 @@
+use numeric::assert_approx_eq;
+use numeric::special::{
+    bessel_j0, bessel_j1, bessel_jn, bessel_y0, bessel_y1, bessel_yn, erf, erfc, gamma, ln_gamma,
+    smootherstep, smoothstep,
+};
+
+use std::f64::consts::{LN_2, PI};
+
+// Reference values computed with mpmath at 30 digits.
+
+#[test]
+fn gamma_table() {
+    let table = [
+        (1e-5, 99999.42279422555),
+        (0.1, 9.51350769866873),
+        (0.5, 1.772453850905516),
+        (1.5, 0.886226925452758),
+        (2.5, 1.329340388179137),
+        (7.25, 1155.3810139199898),
+        (30.5, 4.822696933490909e31),
+        (-0.5, -3.544907701811032),
+        (-2.5, -0.9453087204829419),
+    ];
+    for (x, y) in table {
+        assert_approx_eq!(gamma(x), y, rel = 1e-14);
+    }
+    assert_approx_eq!(gamma(170.5), 5.56209241456e305, rel = 1e-12);
+
+    let mut factorial = 1.0;
+    for n in 1..20 {
+        assert_approx_eq!(gamma(n as f64), factorial, rel = 1e-14);
+        factorial *= n as f64;
+    }
+    assert!(gamma(0.0f64).is_nan());
+    assert!(gamma(-3.0f64).is_nan());
+    assert_eq!(gamma(200.0f64), f64::INFINITY);
+    for x in [745.0f64, 1000.0, 1e300, f64::MAX] {
+        assert_eq!(gamma(x), f64::INFINITY);
+    }
+    for x in [36.0f32, 100.0, 1e30] {
+        assert_eq!(gamma(x), f32::INFINITY);
+    }
+    // the reflection of an overflowing gamma is a signed zero
+    assert_eq!(gamma(-1000.5f64), 0.0);
+    assert!(gamma(-1000.5f64).is_sign_negative());
+    assert_eq!(gamma(-999.5f64), 0.0);
+    assert!(gamma(-999.5f64).is_sign_positive());
+    assert_eq!(gamma(-100.5f32), 0.0);
+    assert_approx_eq!(gamma(4.5f32), 11.631728, rel = 1e-5);
+}
+
+#[test]
+fn ln_gamma_table() {
+    let table = [
+        (0.5, 0.5723649429247001),
+        (3.0, LN_2),
+        (10.5, 13.940625219403763),
+        (100.0, 359.1342053695754),
+        (1000.0, 5905.220423209181),
+        (-2.5, -0.056243716497674054),
+    ];
+    for (x, y) in table {
+        assert_approx_eq!(ln_gamma(x), y, abs = 1e-14, rel = 1e-14);
+    }
+    assert_approx_eq!(ln_gamma(1.0), 0.0, abs = 1e-15);
+    assert_approx_eq!(ln_gamma(2.0), 0.0, abs = 1e-15);
+    assert_eq!(ln_gamma(-1.0f64), f64::INFINITY);
+}
+
+#[test]
+fn error_function_table() {
+    let table = [
+        (1e-10, 1.1283791670955126e-10, 0.999999999887162),
+        (0.1, 0.1124629160182849, 0.887537083981715),
+        (0.5, 0.5204998778130465, 0.4795001221869535),
+        (0.9, 0.7969082124228322, 0.20309178757716786),
+        (1.0, 0.8427007929497149, 0.15729920705028513),
+        (1.1, 0.8802050695740817, 0.11979493042591827),
+        (2.0, 0.9953222650189527, 0.004677734981047266),
+        (3.5, 0.9999992569016276, 7.430983723414128e-7),
+        (5.0, 0.9999999999984626, 1.537459794428035e-12),
+        (10.0, 1.0, 2.088487583762545e-45),
+        (26.0, 1.0, 5.663192408856143e-296),
+        (-0.5, -0.5204998778130465, 1.5204998778130465),
+        (-3.0, -0.9999779095030014, 1.9999779095030015),
+    ];
+    for (x, e, c) in table {
+        assert_approx_eq!(erf(x), e, rel = 1e-15);
+        // The complement keeps its relative precision in the tail.
+        assert_approx_eq!(erfc(x), c, rel = 1e-14);
+    }
+    assert_eq!(erf(0.0f64), 0.0);
+    assert_eq!(erf(f64::INFINITY), 1.0);
+    assert_eq!(erfc(f64::NEG_INFINITY), 2.0);
+    assert_approx_eq!(erf(0.5f32), 0.5204999, rel = 1e-6);
+}
+
+#[test]
+fn bessel_table() {
+    // (n, x, J_n(x), Y_n(x)) on both sides of the asymptotic expansion and of
+    // the turning point n = x.
+    let table = [
+        (0, 0.01, 0.9999750001562495, -3.005455637083646),
+        (0, 1.0, 0.7651976865579666, 0.08825696421567696),
+        (0, 2.4, 0.002507683297243859, 0.5104147486657438),
+        (0, 10.0, -0.24593576445134835, 0.055671167283599395),
+        (0, 24.0, -0.056230274166859266, -0.15283402879758778),
+        (0, 26.0, 0.15599931552242113, 0.012044625860755602),
+        (0, 200.0, -0.015437439930565091, -0.05426577524981791),
+        (1, 0.01, 0.004999937500260416, -63.67859628206065),
+        (1, 1.0, 0.4400505857449335, -0.7812128213002887),
+        (1, 5.0, -0.32757913759146523, 0.14786314339122683),
+        (1, 24.0, -0.15403806518312121, 0.05305977612120217),
+        (1, 50.0, -0.09751182812517514, -0.05679566856201477),
+        (2, 0.5, 0.03060402345868264, -5.441370837174266),
+        (2, 26.0, -0.1548419516311199, -0.024028976109186577),
+        (5, 0.01, 2.604155815991599e-14, -2444635204829.711),
+        (5, 10.0, -0.23406152818679363, 0.13540304768936232),
+        (5, 200.0, -0.055132678944014676, 0.012019640832200107),
+        (20, 0.01, 3.919899683074647e-65, -4.06017949192239e62),
+        (20, 10.0, 1.1513369247813398e-5, -1597.483848269626),
+        (20, 24.0, 0.16191265166449528, 0.14420188606021456),
+        (20, 26.0, -0.0704108664455881, 0.18182802221684954),
+        (20, 50.0, -0.11670435275957974, 0.01644263394811578),
+    ];
+    for (n, x, j, y) in table {
+        // Absolute precision near the zeros, relative in the tails.
+        assert_approx_eq!(bessel_jn(n, x), j, abs = 1e-15, rel = 1e-13);
+        assert_approx_eq!(bessel_yn(n, x), y, abs = 1e-15, rel = 1e-13);
+    }
+    assert_eq!(bessel_j0(1.0), bessel_jn(0, 1.0));
+    assert_eq!(bessel_j1(1.0), bessel_jn(1, 1.0));
+    assert_eq!(bessel_y0(1.0), bessel_yn(0, 1.0));
+    assert_eq!(bessel_y1(1.0), bessel_yn(1, 1.0));
+}
+
+#[test]
+fn bessel_identities() {
+    assert_eq!(bessel_j0(0.0f64), 1.0);
+    assert_eq!(bessel_jn(3, 0.0f64), 0.0);
+    assert_eq!(bessel_y0(0.0f64), f64::NEG_INFINITY);
+    assert!(bessel_y1(-1.0f64).is_nan());
+    assert_eq!(bessel_jn(-3, 2.0), -bessel_jn(3, 2.0));
+    assert_eq!(bessel_jn(3, -2.0), -bessel_jn(3, 2.0));
+    assert_eq!(bessel_jn(2, -2.0), bessel_jn(2, 2.0));
+    assert_eq!(bessel_yn(-3, 2.0), -bessel_yn(3, 2.0));
+
+    // Wronskian J_1 Y_0 - J_0 Y_1 = 2 / πx.
+    for &x in &[0.3, 1.0, 4.7, 12.0, 30.0, 100.0] {
+        let w = bessel_j1(x) * bessel_y0(x) - bessel_j0(x) * bessel_y1(x);
+        assert_approx_eq!(w, 2.0 / (PI * x), rel = 1e-13);
+    }
+    // The first zero of J_0.
+    assert_approx_eq!(bessel_j0(2.404825557695773), 0.0, abs = 1e-15);
+    assert_approx_eq!(bessel_j1(2.0f32), 0.5767248, rel = 1e-5);
+}
+
+#[test]
+fn smooth_steps() {
+    assert_eq!(smoothstep(1.0, 3.0, 0.0), 0.0);
+    assert_eq!(smoothstep(1.0, 3.0, 4.0), 1.0);
+    assert_eq!(smoothstep(1.0, 3.0, 2.0), 0.5);
+    assert_eq!(smoothstep(0.0, 1.0, 0.25), 0.15625);
+    assert_eq!(smoothstep(1.0, 0.0, 0.25), 0.84375);
+    assert_eq!(smootherstep(0.0, 1.0, 0.5), 0.5);
+    assert_eq!(smootherstep(0.0, 1.0, 0.25), 0.103515625);
+    assert_eq!(smootherstep(0.0, 4.0, -1.0f32), 0.0);
+
+    // Both are monotonic with flat ends.
+    let mut last = (0.0, 0.0);
+    for i in 0..=100 {
+        let x = i as f64 / 100.0;
+        let s = (smoothstep(0.0, 1.0, x), smootherstep(0.0, 1.0, x));
+        assert!(s.0 >= last.0 && s.1 >= last.1);
+        last = s;
+    }
+    assert!(smoothstep(0.0, 1.0, 1e-4) < 1e-7);
+    assert!(smootherstep(0.0, 1.0, 1e-4) < 1e-11);
+}
@@ -0,0 +1,33 @@
+use math_vector::{Vector, Vector2, Vector4};
+
+#[test]
+fn vector() {
+    let v = Vector::new(1.0, 2.0, 3.0);
+    assert_eq!(v.xy(), Vector2::new(1.0, 2.0));
+    assert_eq!(v.zx(), Vector2::new(3.0, 1.0));
+    assert_eq!(v.xyz(), v);
+    assert_eq!(v.zyx(), Vector::new(3.0, 2.0, 1.0));
+    assert_eq!(v.xxz(), Vector::new(1.0, 1.0, 3.0));
+    assert_eq!(v.zzzz(), Vector4::new(3.0, 3.0, 3.0, 3.0));
+    assert_eq!(v.xyzx(), Vector4::new(1.0, 2.0, 3.0, 1.0));
+}
+
+#[test]
+fn vector2() {
+    let v = Vector2::new(1.0f32, 2.0);
+    assert_eq!(v.yx(), Vector2::new(2.0, 1.0));
+    assert_eq!(v.xyy(), Vector::new(1.0, 2.0, 2.0));
+    assert_eq!(v.yxyx(), Vector4::new(2.0, 1.0, 2.0, 1.0));
+}
+
+#[test]
+fn vector4() {
+    let v = Vector4::new(1.0, 2.0, 3.0, 4.0);
+    assert_eq!(v.xy(), Vector2::new(1.0, 2.0));
+    assert_eq!(v.ww(), Vector2::new(4.0, 4.0));
+    assert_eq!(v.xyz(), v.truncate());
+    assert_eq!(v.wzy(), Vector::new(4.0, 3.0, 2.0));
+    assert_eq!(v.xyzw(), v);
+    assert_eq!(v.wzyx(), Vector4::new(4.0, 3.0, 2.0, 1.0));
+    assert_eq!(v.xxyy(), Vector4::new(1.0, 1.0, 2.0, 2.0));
+}
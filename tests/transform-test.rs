@@ -0,0 +1,137 @@
+use math_vector::{Matrix4, Quaternion, Transform, Vector};
+
+use std::f64::consts::PI;
+
+fn assert_close(a: Vector<f64>, b: Vector<f64>) {
+    assert!((a - b).length() < 1e-9, "{} != {}", a, b);
+}
+
+fn sample() -> Transform<f64> {
+    Transform::new(
+        Vector::new(1.0, -2.0, 3.0),
+        Quaternion::from_rotation(PI / 3.0, Vector::new(1.0, 2.0, 2.0) / 3.0),
+        Vector::new(2.0, 0.5, 3.0),
+    )
+}
+
+#[test]
+fn identity() {
+    let t = Transform::identity();
+    let p = Vector::new(1.0, 2.0, 3.0);
+    assert_eq!(t.transform_point(p), p);
+    assert_eq!(t.to_matrix(), Matrix4::identity());
+}
+
+#[test]
+fn scale_rotate_translate() {
+    let t = Transform::new(
+        Vector::new(10.0, 0.0, 0.0),
+        Quaternion::from_rotation(PI / 2.0, Vector::new(0.0, 0.0, 1.0)),
+        Vector::new(2.0, 1.0, 1.0),
+    );
+
+    // (1, 0, 0) is scaled to (2, 0, 0), rotated to (0, 2, 0), then translated
+    assert_close(
+        t.transform_point(Vector::new(1.0, 0.0, 0.0)),
+        Vector::new(10.0, 2.0, 0.0),
+    );
+    assert_close(
+        t.transform_vector(Vector::new(1.0, 0.0, 0.0)),
+        Vector::new(0.0, 2.0, 0.0),
+    );
+    assert_close(t * Vector::new(0.0, 1.0, 0.0), Vector::new(9.0, 0.0, 0.0));
+}
+
+#[test]
+fn matrix() {
+    let t = sample();
+    let m = Matrix4::from(t);
+    for p in [
+        Vector::new(0.0, 0.0, 0.0),
+        Vector::new(1.0, 2.0, 3.0),
+        Vector::new(-4.0, 0.5, 7.0),
+    ] {
+        assert_close(m.transform_point(p), t.transform_point(p));
+        assert_close(m.transform_vector(p), t.transform_vector(p));
+    }
+
+    let back = Transform::from_matrix(m).unwrap();
+    assert_close(back.position, t.position);
+    assert_close(back.scale, t.scale);
+    assert!((back.rotation.dot(t.rotation).abs() - 1.0).abs() < 1e-9);
+
+    // a reflection is carried by the scale
+    let mirror = Transform::from_matrix(Matrix4::from_scale(Vector::new(1.0, -1.0, 1.0))).unwrap();
+    assert!(mirror.scale.x * mirror.scale.y * mirror.scale.z < 0.0);
+    let p = Vector::new(1.0, 2.0, 3.0);
+    assert_close(mirror.transform_point(p), Vector::new(1.0, -2.0, 3.0));
+
+    assert_eq!(Transform::from_matrix(Matrix4::<f64>::zero()), None);
+}
+
+#[test]
+fn inverse() {
+    let t = sample();
+    let p = Vector::new(0.3, -1.2, 4.0);
+
+    // exact even with a non-uniform scale
+    assert_close(t.inverse_transform_point(t.transform_point(p)), p);
+    assert_close(t.inverse_transform_vector(t.transform_vector(p)), p);
+
+    let uniform = Transform {
+        scale: Vector::new(2.0, 2.0, 2.0),
+        ..t
+    };
+    let inv = uniform.inverse().unwrap();
+    assert_close(inv.transform_point(uniform.transform_point(p)), p);
+    assert_close((uniform * inv).transform_point(p), p);
+
+    let flat = Transform::from_scale(Vector::new(1.0, 0.0, 1.0));
+    assert_eq!(flat.inverse(), None);
+}
+
+#[test]
+fn hierarchy() {
+    let root = Transform::new(
+        Vector::new(0.0, 5.0, 0.0),
+        Quaternion::from_rotation(PI / 2.0, Vector::new(0.0, 1.0, 0.0)),
+        Vector::new(2.0, 2.0, 2.0),
+    );
+    let arm = Transform::from_translation(Vector::new(1.0, 0.0, 0.0));
+    let hand = Transform::from_rotation(Quaternion::from_rotation(
+        PI / 4.0,
+        Vector::new(0.0, 0.0, 1.0),
+    ));
+
+    let world = root * arm * hand;
+    let matrix = root.to_matrix() * arm.to_matrix() * hand.to_matrix();
+    let p = Vector::new(1.0, 1.0, 1.0);
+    assert_close(world.transform_point(p), matrix.transform_point(p));
+    assert_close(
+        world.transform_point(p),
+        root.transform_point(arm.transform_point(hand.transform_point(p))),
+    );
+
+    let mut accumulated = root;
+    accumulated *= arm;
+    accumulated *= &hand;
+    assert_eq!(accumulated, world);
+}
+
+#[test]
+fn lerp() {
+    let a = Transform::identity();
+    let b = Transform::new(
+        Vector::new(2.0, 0.0, -2.0),
+        Quaternion::from_rotation(PI / 2.0, Vector::new(0.0, 0.0, 1.0)),
+        Vector::new(3.0, 3.0, 3.0),
+    );
+
+    assert_eq!(a.lerp(b, 0.0), a);
+    let half = a.lerp(b, 0.5);
+    assert_close(half.position, Vector::new(1.0, 0.0, -1.0));
+    assert_close(half.scale, Vector::new(2.0, 2.0, 2.0));
+    let (angle, _) = half.rotation.to_rotation();
+    assert!((angle - PI / 4.0).abs() < 1e-12);
+    assert_close(a.lerp(b, 1.0).position, b.position);
+}
@@ -1,3 +1,5 @@
+#![allow(clippy::clone_on_copy, clippy::op_ref)]
+
 use math_vector::Vector;
 
 use std::f64::consts::PI;
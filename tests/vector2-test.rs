@@ -0,0 +1,93 @@
+#![allow(clippy::op_ref)]
+
+use math_vector::{Vector, Vector2};
+
+use std::f64::consts::PI;
+
+#[test]
+fn new() {
+    let v = Vector2::new(1.0, 2.0);
+    assert_eq!(v.x, 1.0);
+    assert_eq!(v.y, 2.0);
+    assert_eq!(v, Vector2::from((1.0, 2.0)));
+    assert_eq!(v, Vector2::from([1.0, 2.0]));
+}
+
+#[test]
+fn ops() {
+    let x = Vector2::new(1.0, 2.0);
+    let y = Vector2::new(4.0, 8.0);
+
+    assert_eq!(x + y, Vector2::new(5.0, 10.0));
+    assert_eq!(x - y, Vector2::new(-3.0, -6.0));
+    assert_eq!(x * y, Vector2::new(4.0, 16.0));
+    assert_eq!(x / y, Vector2::new(0.25, 0.25));
+    assert_eq!(-x, Vector2::new(-1.0, -2.0));
+    assert_eq!(x * 2.0, Vector2::new(2.0, 4.0));
+    assert_eq!(&x + &y, x + y);
+
+    let mut v = Vector2::one();
+    v += x;
+    v *= 2.0;
+    v -= y;
+    v /= Vector2::new(1.0, -1.0);
+    assert_eq!(v, Vector2::new(0.0, 2.0));
+}
+
+#[test]
+fn products() {
+    let x = Vector2::new(1.0, 0.0);
+    let y = Vector2::new(0.0, 1.0);
+
+    assert_eq!(x.dot(y), 0.0);
+    assert_eq!(x.cross(y), 1.0);
+    assert_eq!(y.cross(x), -1.0);
+    assert_eq!(x.perp(), y);
+}
+
+#[test]
+fn length() {
+    let v = Vector2::new(3.0, 4.0);
+    assert_eq!(v.length(), 5.0);
+    assert_eq!(v.length_squared(), 25.0);
+    assert_eq!(v.distance(Vector2::zero()), 5.0);
+    assert!(v.normalized().is_close(Vector2::new(0.6, 0.8)));
+    assert!(v.with_length(10.0).is_close(Vector2::new(6.0, 8.0)));
+    assert_eq!(v.limited(1.0), v.normalized());
+    assert_eq!(v.limited(10.0), v);
+    assert_eq!(Vector2::<f64>::zero().normalized(), Vector2::zero());
+}
+
+#[test]
+fn angles() {
+    let v = Vector2::from_angle(PI / 3.0);
+    assert!((v.heading() - PI / 3.0).abs() < 1e-12);
+    assert!(v.rotated(PI / 2.0).is_close(v.perp()));
+
+    let mut w = Vector2::new(1.0, 0.0);
+    w.rotate(PI);
+    assert!(w.is_close(Vector2::new(-1.0, 0.0)));
+
+    let x = Vector2::new(2.0, 0.0);
+    assert!((x.angle_between(Vector2::new(0.0, 3.0)) - PI / 2.0).abs() < 1e-12);
+    assert!((x.angle_between(Vector2::new(0.0, -3.0)) + PI / 2.0).abs() < 1e-12);
+}
+
+#[test]
+fn project() {
+    let v = Vector2::new(1.0, 1.0);
+    let x = Vector2::new(2.0, 0.0);
+    assert_eq!(v.project(x), Vector2::new(1.0, 0.0));
+    assert_eq!(v.reject(x), Vector2::new(0.0, 1.0));
+    assert_eq!(Vector2::new(1.0, -1.0).reflect(Vector2::new(0.0, 1.0)), v);
+    assert_eq!(v.lerp(x, 0.5), Vector2::new(1.5, 0.5));
+}
+
+#[test]
+fn dimensions() {
+    let v = Vector2::new(1.0, 2.0);
+    assert_eq!(v.extend(3.0), Vector::new(1.0, 2.0, 3.0));
+    assert_eq!(Vector::from(v), Vector::new(1.0, 2.0, 0.0));
+    assert_eq!(Vector::new(1.0, 2.0, 3.0).truncate(), v);
+    assert_eq!(v.extend(3.0).truncate(), v);
+}
@@ -0,0 +1,63 @@
+#![allow(clippy::op_ref)]
+
+use math_vector::{Matrix4, Vector, Vector4};
+
+#[test]
+fn new() {
+    let v = Vector4::new(1.0, 2.0, 3.0, 4.0);
+    assert_eq!(v.x, 1.0);
+    assert_eq!(v.y, 2.0);
+    assert_eq!(v.z, 3.0);
+    assert_eq!(v.w, 4.0);
+    assert_eq!(v, Vector4::from((1.0, 2.0, 3.0, 4.0)));
+    assert_eq!(v, Vector4::from([1.0, 2.0, 3.0, 4.0]));
+}
+
+#[test]
+fn ops() {
+    let x = Vector4::new(1.0, 2.0, 3.0, 4.0);
+    let y = Vector4::new(2.0, 2.0, 2.0, 2.0);
+
+    assert_eq!(x + y, Vector4::new(3.0, 4.0, 5.0, 6.0));
+    assert_eq!(x - y, Vector4::new(-1.0, 0.0, 1.0, 2.0));
+    assert_eq!(x * y, Vector4::new(2.0, 4.0, 6.0, 8.0));
+    assert_eq!(x / y, Vector4::new(0.5, 1.0, 1.5, 2.0));
+    assert_eq!(-x, Vector4::new(-1.0, -2.0, -3.0, -4.0));
+    assert_eq!(&x * &y, x * y);
+
+    let mut v = Vector4::zero();
+    v += x;
+    v -= 1.0;
+    v *= y;
+    v /= 2.0;
+    assert_eq!(v, Vector4::new(0.0, 1.0, 2.0, 3.0));
+}
+
+#[test]
+fn length() {
+    let v = Vector4::new(1.0, 1.0, 1.0, 1.0);
+    assert_eq!(v.dot(v), 4.0);
+    assert_eq!(v.length(), 2.0);
+    assert_eq!(v.normalized(), Vector4::new(0.5, 0.5, 0.5, 0.5));
+    assert_eq!(v.with_length(4.0), v * 2.0);
+    assert_eq!(v.limited(1.0), v.normalized());
+    assert_eq!(v.distance(Vector4::zero()), 2.0);
+    assert_eq!(v.lerp(Vector4::zero(), 0.5), v / 2.0);
+}
+
+#[test]
+fn dimensions() {
+    let v = Vector::new(1.0, 2.0, 3.0);
+    assert_eq!(v.extend(1.0), Vector4::new(1.0, 2.0, 3.0, 1.0));
+    assert_eq!(v.extend(1.0).truncate(), v);
+}
+
+#[test]
+fn homogeneous() {
+    let m = Matrix4::from_translation(Vector::new(1.0, 2.0, 3.0));
+    let v = Vector::new(1.0, 1.0, 1.0);
+
+    assert_eq!(m * v.extend(1.0), Vector4::new(2.0, 3.0, 4.0, 1.0));
+    assert_eq!(m * v.extend(0.0), v.extend(0.0));
+    assert_eq!((m * v.extend(1.0)).truncate(), m * v);
+}